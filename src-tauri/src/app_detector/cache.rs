@@ -8,8 +8,8 @@ use super::platform::{default_source, ContextSignalSource};
 #[cfg(test)]
 use super::registry::AppRegistry;
 use super::types::{
-    BrowserAccessStatus, BrowserTarget, ContextProfile, ContextSnapshot, ContextSource,
-    RecordingContext, TargetAppGuard,
+    AppOutputPolicy, BrowserAccessStatus, BrowserTarget, ContextProfile, ContextSnapshot,
+    ContextSource, RecordingContext, TargetAppGuard,
 };
 use super::user_mappings::{
     candidate_from_signals, MappingCandidate, MappingCandidateView, UserAppMappingStore,
@@ -29,7 +29,12 @@ enum RefreshSignal {
 struct CachedContext {
     snapshot: ContextSnapshot,
     target_guard: TargetAppGuard,
+    /// Bumped each time a refresh sees a different target app.
+    focus_generation: u64,
+    /// Most recent target that was not one of our own windows.
+    last_external_target: Option<TargetAppGuard>,
     mapped_scene_id: Option<String>,
+    output_policy: AppOutputPolicy,
     candidate_template: Option<MappingCandidate>,
     browser_access_status: BrowserAccessStatus,
     browser_target: Option<BrowserTarget>,
@@ -117,7 +122,10 @@ impl ContextDetectorHandle {
                 captured_at: Instant::now(),
             },
             target_guard: TargetAppGuard::default(),
+            focus_generation: 0,
+            last_external_target: None,
             mapped_scene_id: None,
+            output_policy: AppOutputPolicy::default(),
             candidate_template: None,
             browser_access_status: BrowserAccessStatus::NotApplicable,
             browser_target: None,
@@ -183,6 +191,13 @@ impl ContextDetectorHandle {
         } else {
             None
         };
+        // Output policy follows the target app even without context
+        // adaptation; a stale snapshot may belong to another app.
+        let output_policy = if stale {
+            AppOutputPolicy::default()
+        } else {
+            cached.output_policy.clone()
+        };
         let candidate_template = if stale {
             None
         } else {
@@ -217,6 +232,7 @@ impl ContextDetectorHandle {
             profile,
             target_guard,
             mapped_scene_id,
+            output_policy,
             browser_access_status,
             browser_target,
         }
@@ -276,6 +292,25 @@ impl ContextDetectorHandle {
             && expected.matches(&cached.target_guard)
     }
 
+    /// Changes whenever the detector has seen focus move to another app, so
+    /// state tied to one target can tell it may be stale.
+    pub fn focus_generation(&self) -> u64 {
+        self.cached
+            .read()
+            .unwrap_or_else(|error| error.into_inner())
+            .focus_generation
+    }
+
+    /// The last app other than OpenTypeless that had focus, for actions such
+    /// as re-polish that start from our own window.
+    pub fn last_external_target(&self) -> Option<TargetAppGuard> {
+        self.cached
+            .read()
+            .unwrap_or_else(|error| error.into_inner())
+            .last_external_target
+            .clone()
+    }
+
     pub fn target_still_matches_now(&self, expected: &TargetAppGuard) -> bool {
         if expected.is_empty() {
             return true;
@@ -332,7 +367,10 @@ impl ContextDetectorHandle {
                 captured_at,
             },
             target_guard,
+            focus_generation: 0,
+            last_external_target: None,
             mapped_scene_id: None,
+            output_policy: AppOutputPolicy::default(),
             candidate_template: None,
             browser_access_status: BrowserAccessStatus::NotApplicable,
             browser_target: None,
@@ -394,6 +432,7 @@ fn refresh_context(
         profile,
         target_guard,
         mapped_scene_id,
+        output_policy,
         candidate_template,
         browser_access_status,
         browser_target,
@@ -409,6 +448,7 @@ fn refresh_context(
                 resolved.profile,
                 target_guard,
                 resolved.mapped_scene_id,
+                resolved.output_policy,
                 candidate_template,
                 browser_access_status,
                 browser_target,
@@ -418,25 +458,40 @@ fn refresh_context(
             ContextProfile::general_native(),
             TargetAppGuard::default(),
             None,
+            AppOutputPolicy::default(),
             None,
             BrowserAccessStatus::NotApplicable,
             None,
         ),
     };
 
-    *cached.write().unwrap_or_else(|error| error.into_inner()) = CachedContext {
+    let mut cached = cached.write().unwrap_or_else(|error| error.into_inner());
+    let focus_generation = cached.focus_generation + u64::from(cached.target_guard != target_guard);
+    let last_external_target = if is_external_target(&target_guard) {
+        Some(target_guard.clone())
+    } else {
+        cached.last_external_target.take()
+    };
+    *cached = CachedContext {
         snapshot: ContextSnapshot {
             profile,
             captured_at: Instant::now(),
         },
         target_guard,
+        focus_generation,
+        last_external_target,
         mapped_scene_id,
+        output_policy,
         candidate_template,
         browser_access_status,
         browser_target,
     };
 }
 
+fn is_external_target(guard: &TargetAppGuard) -> bool {
+    !guard.is_empty() && guard.process_id != Some(std::process::id())
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::atomic::{AtomicUsize, Ordering};
@@ -524,6 +579,7 @@ mod tests {
         let source = Arc::new(FakeSource::new(Some(gmail_signals())));
         let handle = detector(source.clone());
         wait_for_profile(&handle, "email.gmail");
+        let gmail_generation = handle.focus_generation();
 
         source.set(Some(ContextSignals {
             native_identity: Some("com.tinyspeck.slackmacgap".to_string()),
@@ -536,6 +592,43 @@ mod tests {
         let captured = handle.snapshot_for_recording();
         assert_eq!(captured.profile.id, "chat.slack");
         assert_eq!(captured.target_guard.process_id, Some(77));
+
+        let slack_generation = handle.focus_generation();
+        assert!(slack_generation > gmail_generation);
+        handle.notify_focus_changed();
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(handle.focus_generation(), slack_generation);
+    }
+
+    #[test]
+    fn last_external_target_skips_our_own_window() {
+        let source = Arc::new(FakeSource::new(Some(gmail_signals())));
+        let handle = detector(source.clone());
+        wait_for_profile(&handle, "email.gmail");
+        assert_eq!(
+            handle
+                .last_external_target()
+                .and_then(|guard| guard.process_id),
+            Some(42)
+        );
+
+        source.set(Some(ContextSignals {
+            process_id: Some(std::process::id()),
+            native_identity: Some("opentypeless".to_string()),
+            ..ContextSignals::default()
+        }));
+        handle.notify_focus_changed();
+        wait_for_profile(&handle, "general.native");
+        assert_eq!(
+            handle.snapshot_for_recording().target_guard.process_id,
+            Some(std::process::id())
+        );
+        assert_eq!(
+            handle
+                .last_external_target()
+                .and_then(|guard| guard.process_id),
+            Some(42)
+        );
     }
 
     #[test]
@@ -551,6 +644,7 @@ mod tests {
             TargetAppGuard {
                 process_id: Some(42),
                 native_identity: Some("com.google.Chrome".to_string()),
+                ..TargetAppGuard::default()
             },
         );
 
@@ -1,4 +1,5 @@
 pub mod cache;
+pub mod packs;
 pub mod platform;
 pub mod profiles;
 pub mod registry;
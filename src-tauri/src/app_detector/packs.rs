@@ -0,0 +1,522 @@
+//! Context profile packs: JSON files in the `profile_packs` folder under the
+//! app data directory that extend the built-in registry with extra
+//! `ProfileDefinition`s and style overrides, so teams can share matchers for
+//! their internal tools without waiting for a release.
+//!
+//! ```json
+//! {
+//!   "schema_version": 1,
+//!   "name": "Acme internal tools",
+//!   "style_overrides": {
+//!     "acme_wiki": { "artifactKind": "prose", "formality": "professional",
+//!                    "density": "balanced", "markup": "light",
+//!                    "listBehavior": "preserve" }
+//!   },
+//!   "profiles": [
+//!     { "id": "doc.acme_wiki", "family": "document", "app_label": "Acme Wiki",
+//!       "icon_key": "document", "override_id": "acme_wiki",
+//!       "host_suffixes": ["wiki.acme.dev"],
+//!       "title_markers": [{ "suffix": " - Acme Wiki", "required_host_suffix": "acme.dev" }] }
+//!   ]
+//! }
+//! ```
+//!
+//! Packs apply in file-name order after the built-in profiles. A pack profile
+//! with the id of an earlier profile replaces it; one that claims a matcher
+//! owned by another profile is rejected, as is a style override id that is
+//! already taken. A `<pack>.json.minisig` next to a pack must verify against
+//! a key in `trusted_keys.pub`, otherwise the whole pack is skipped.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use minisign_verify::{PublicKey, Signature};
+use serde::Deserialize;
+
+use super::cache::ContextDetectorHandle;
+use super::profiles::{
+    builtin_profile_definitions, builtin_style_override, install_pack_style_overrides,
+    ProfileDefinition, ReleaseTier, TitleMarkerDefinition,
+};
+use super::registry::{validate_definitions, AppRegistry};
+use super::types::{AppOutputPolicy, AppStyleOverride, ContextFamily};
+use super::user_mappings::UserAppMappingStore;
+
+pub const PROFILE_PACK_DIR: &str = "profile_packs";
+const TRUSTED_KEYS_FILE: &str = "trusted_keys.pub";
+const PACK_SCHEMA_VERSION: u32 = 1;
+const MAX_PACKS: usize = 20;
+const MAX_PACK_BYTES: u64 = 256 * 1024;
+const MAX_PROFILES_PER_PACK: usize = 200;
+const MAX_STYLE_OVERRIDES_PER_PACK: usize = 50;
+const MAX_OVERRIDE_ID_CHARS: usize = 40;
+const WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PackFile {
+    schema_version: u32,
+    name: String,
+    #[serde(default)]
+    style_overrides: BTreeMap<String, AppStyleOverride>,
+    #[serde(default)]
+    profiles: Vec<ProfileSpec>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProfileSpec {
+    id: String,
+    family: ContextFamily,
+    app_label: String,
+    icon_key: String,
+    #[serde(default)]
+    override_id: Option<String>,
+    #[serde(default)]
+    exact_hosts: Vec<String>,
+    #[serde(default)]
+    host_suffixes: Vec<String>,
+    #[serde(default)]
+    native_identities: Vec<String>,
+    #[serde(default)]
+    process_aliases: Vec<String>,
+    #[serde(default)]
+    title_markers: Vec<TitleMarkerSpec>,
+    #[serde(default)]
+    output_policy: AppOutputPolicy,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TitleMarkerSpec {
+    suffix: String,
+    #[serde(default)]
+    required_host_suffix: Option<String>,
+    #[serde(default)]
+    required_native_identity: Option<String>,
+}
+
+impl ProfileSpec {
+    fn into_definition(self) -> ProfileDefinition {
+        ProfileDefinition {
+            id: self.id,
+            family: self.family,
+            app_label: self.app_label,
+            icon_key: self.icon_key,
+            override_id: self.override_id,
+            exact_hosts: self.exact_hosts,
+            host_suffixes: self.host_suffixes,
+            native_identities: self.native_identities,
+            process_aliases: self.process_aliases,
+            title_markers: self
+                .title_markers
+                .into_iter()
+                .map(|marker| TitleMarkerDefinition {
+                    suffix: marker.suffix,
+                    required_host_suffix: marker.required_host_suffix,
+                    required_native_identity: marker.required_native_identity,
+                })
+                .collect(),
+            output_policy: self.output_policy,
+            release_tier: ReleaseTier::Extended,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LoadedPack {
+    pub file_name: String,
+    pub name: String,
+    pub signed: bool,
+    pub profile_count: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct ProfilePackLoad {
+    pub definitions: Vec<ProfileDefinition>,
+    pub style_overrides: HashMap<String, AppStyleOverride>,
+    pub packs: Vec<LoadedPack>,
+    /// One message per pack, profile or style override that was skipped.
+    pub rejected: Vec<String>,
+}
+
+impl ProfilePackLoad {
+    fn builtin() -> Self {
+        Self {
+            definitions: builtin_profile_definitions(),
+            ..Self::default()
+        }
+    }
+
+    /// Validates `source` as a pack and merges whatever survives.
+    fn merge(&mut self, file_name: &str, source: &str, signed: bool) {
+        let file: PackFile = match serde_json::from_str(source) {
+            Ok(file) => file,
+            Err(error) => {
+                self.rejected
+                    .push(format!("{file_name}: invalid profile pack: {error}"));
+                return;
+            }
+        };
+        if file.schema_version != PACK_SCHEMA_VERSION {
+            self.rejected.push(format!(
+                "{file_name}: unsupported schema_version {}",
+                file.schema_version
+            ));
+            return;
+        }
+
+        for (index, (id, value)) in file.style_overrides.into_iter().enumerate() {
+            let label = format!("{file_name}: style override {id}");
+            if index >= MAX_STYLE_OVERRIDES_PER_PACK {
+                self.rejected.push(format!(
+                    "{label}: more than {MAX_STYLE_OVERRIDES_PER_PACK} style overrides"
+                ));
+            } else if !is_valid_override_id(&id) {
+                self.rejected.push(format!("{label}: invalid id"));
+            } else if builtin_style_override(&id).is_some()
+                || self.style_overrides.contains_key(&id)
+            {
+                self.rejected.push(format!("{label}: id already defined"));
+            } else {
+                self.style_overrides.insert(id, value);
+            }
+        }
+
+        let mut profile_count = 0;
+        for (index, spec) in file.profiles.into_iter().enumerate() {
+            let label = format!("{file_name}: profile {}", spec.id);
+            if index >= MAX_PROFILES_PER_PACK {
+                self.rejected.push(format!(
+                    "{label}: more than {MAX_PROFILES_PER_PACK} profiles"
+                ));
+                continue;
+            }
+            let definition = spec.into_definition();
+            let mut candidate = self.definitions.clone();
+            match candidate
+                .iter()
+                .position(|existing| existing.id == definition.id)
+            {
+                Some(position) => candidate[position] = definition,
+                None => candidate.push(definition),
+            }
+            match validate_definitions(&candidate, &self.style_overrides) {
+                Ok(()) => {
+                    self.definitions = candidate;
+                    profile_count += 1;
+                }
+                Err(message) => self.rejected.push(format!("{label}: {message}")),
+            }
+        }
+
+        self.packs.push(LoadedPack {
+            file_name: file_name.to_string(),
+            name: file.name.trim().to_string(),
+            signed,
+            profile_count,
+        });
+    }
+
+    /// Installs the pack style overrides and builds the merged registry.
+    pub fn install(self) -> Result<AppRegistry, String> {
+        let registry = AppRegistry::build_with_overrides(self.definitions, &self.style_overrides)?;
+        install_pack_style_overrides(self.style_overrides);
+        Ok(registry)
+    }
+}
+
+fn is_valid_override_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_OVERRIDE_ID_CHARS
+        && id.bytes().all(|byte| {
+            byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'_' || byte == b'-'
+        })
+}
+
+/// Pack files in `dir`, in the order they are applied.
+fn pack_paths(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut paths = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "json")
+        })
+        .collect::<Vec<_>>();
+    paths.sort();
+    paths
+}
+
+fn trusted_keys(dir: &Path) -> Vec<PublicKey> {
+    std::fs::read_to_string(dir.join(TRUSTED_KEYS_FILE))
+        .map(|source| {
+            source
+                .lines()
+                .filter_map(|line| PublicKey::from_base64(line.trim()).ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Whether `bytes` carry a signature: `Ok(false)` for an unsigned pack, an
+/// error when a signature exists but no trusted key verifies it.
+fn verify_signature(path: &Path, bytes: &[u8], keys: &[PublicKey]) -> Result<bool, String> {
+    let mut signature_path = path.as_os_str().to_owned();
+    signature_path.push(".minisig");
+    let Ok(signature) = std::fs::read_to_string(PathBuf::from(signature_path)) else {
+        return Ok(false);
+    };
+    let signature =
+        Signature::decode(&signature).map_err(|_| "unreadable signature".to_string())?;
+    if keys
+        .iter()
+        .any(|key| key.verify(bytes, &signature, false).is_ok())
+    {
+        Ok(true)
+    } else {
+        Err("signature does not match a trusted key".to_string())
+    }
+}
+
+/// Reads every pack in `dir` on top of the built-in profiles. A missing
+/// folder is just the built-in registry.
+pub fn load_profile_packs(dir: &Path) -> ProfilePackLoad {
+    let mut load = ProfilePackLoad::builtin();
+    let keys = trusted_keys(dir);
+    for (index, path) in pack_paths(dir).into_iter().enumerate() {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        if index >= MAX_PACKS {
+            load.rejected
+                .push(format!("{file_name}: more than {MAX_PACKS} profile packs"));
+            continue;
+        }
+        if std::fs::metadata(&path).is_ok_and(|metadata| metadata.len() > MAX_PACK_BYTES) {
+            load.rejected
+                .push(format!("{file_name}: larger than {MAX_PACK_BYTES} bytes"));
+            continue;
+        }
+        let bytes = match std::fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(error) => {
+                load.rejected.push(format!("{file_name}: {error}"));
+                continue;
+            }
+        };
+        let signed = match verify_signature(&path, &bytes, &keys) {
+            Ok(signed) => signed,
+            Err(message) => {
+                load.rejected.push(format!("{file_name}: {message}"));
+                continue;
+            }
+        };
+        match String::from_utf8(bytes) {
+            Ok(source) => load.merge(&file_name, &source, signed),
+            Err(_) => load.rejected.push(format!("{file_name}: not UTF-8")),
+        }
+    }
+    load
+}
+
+/// Loads the packs in `dir` and builds the registry, logging skipped entries.
+pub fn load_registry(dir: &Path) -> Result<AppRegistry, String> {
+    let load = load_profile_packs(dir);
+    for rejected in &load.rejected {
+        tracing::warn!("Skipped profile pack entry {rejected}");
+    }
+    for pack in &load.packs {
+        tracing::info!(
+            "Loaded profile pack {} ({}) with {} profiles{}",
+            pack.file_name,
+            pack.name,
+            pack.profile_count,
+            if pack.signed { ", signed" } else { "" }
+        );
+    }
+    load.install()
+}
+
+/// Names, sizes and modification times of everything that affects the
+/// loaded packs.
+fn fingerprint(dir: &Path) -> Vec<(PathBuf, u64, Option<SystemTime>)> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok()?;
+            Some((entry.path(), metadata.len(), metadata.modified().ok()))
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    files
+}
+
+/// Polls `dir` and swaps in a rebuilt registry whenever a pack, signature or
+/// the trusted key list changes.
+pub fn watch_profile_packs(
+    dir: PathBuf,
+    mappings: UserAppMappingStore,
+    detector: ContextDetectorHandle,
+) {
+    let spawned = std::thread::Builder::new()
+        .name("profile-pack-watcher".to_string())
+        .spawn(move || {
+            let mut last = fingerprint(&dir);
+            loop {
+                std::thread::sleep(WATCH_INTERVAL);
+                let current = fingerprint(&dir);
+                if current == last {
+                    continue;
+                }
+                last = current;
+                match load_registry(&dir) {
+                    Ok(registry) => {
+                        mappings.replace_registry(registry);
+                        detector.notify_focus_changed();
+                    }
+                    Err(error) => tracing::warn!("Keeping previous profile packs: {error}"),
+                }
+            }
+        });
+    if let Err(error) = spawned {
+        tracing::warn!("Profile pack hot reload unavailable: {error}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app_detector::types::ContextSignals;
+    use std::time::UNIX_EPOCH;
+
+    const SIGNED_PACK: &str = r#"{"schema_version":1,"name":"Acme tools","profiles":[{"id":"doc.acme_wiki","family":"document","app_label":"Acme Wiki","icon_key":"document","host_suffixes":["wiki.acme.dev"]}]}"#;
+    const SIGNED_PACK_KEY: &str = "RWQBI0VniavN7xPEaw75rdj4ZNR+eMBAXtiCly5x/aykH8prSwzuZ++Z";
+    const SIGNED_PACK_SIGNATURE: &str = "untrusted comment: signature from minisign secret key
+RUQBI0VniavN7zMAXje8X2rIx5b6sEHOgNpgjnAVAoh/jua1laHUapzwKWHZsEowXp8ICfl0cReSQ16dfEzTadINf/2zyY53agY=
+trusted comment: acme tools
+qRtOp80w16nf3LKi57NOKCU681YQnagtD8O/+84VpXzwN4GO40vaVemu51qxV6AadIEG/QfJCn4vx9woNyObBg==
+";
+
+    fn browser(host: &str) -> ContextSignals {
+        ContextSignals {
+            browser_host: Some(host.to_string()),
+            is_supported_browser: true,
+            ..ContextSignals::default()
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!(
+            "opentypeless-profile-packs-{name}-{}-{nonce}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn packs_add_profiles_and_style_overrides_with_deterministic_precedence() {
+        let mut load = ProfilePackLoad::builtin();
+        load.merge(
+            "10-acme.json",
+            r#"{ "schema_version": 1, "name": "Acme",
+                 "style_overrides": { "acme_wiki": { "artifactKind": "prose",
+                    "formality": "professional", "density": "balanced",
+                    "markup": "light", "listBehavior": "preserve" },
+                    "slack": { "artifactKind": "prose", "formality": "casual",
+                    "density": "compact", "markup": "plain_text", "listBehavior": "preserve" } },
+                 "profiles": [
+                   { "id": "doc.acme_wiki", "family": "document", "app_label": "Acme Wiki",
+                     "icon_key": "document", "override_id": "acme_wiki",
+                     "host_suffixes": ["wiki.acme.dev"],
+                     "title_markers": [{ "suffix": " - Acme Wiki", "required_host_suffix": "acme.dev" }] },
+                   { "id": "dev.acme_github", "family": "developer_collaboration",
+                     "app_label": "Acme GitHub", "icon_key": "github",
+                     "host_suffixes": ["github.com"] }
+                 ] }"#,
+            false,
+        );
+        load.merge(
+            "20-override.json",
+            r#"{ "schema_version": 1, "name": "Later",
+                 "profiles": [
+                   { "id": "doc.acme_wiki", "family": "document", "app_label": "Wiki",
+                     "icon_key": "document", "host_suffixes": ["docs.acme.dev"] }
+                 ] }"#,
+            false,
+        );
+
+        assert_eq!(load.rejected.len(), 2, "{:?}", load.rejected);
+        assert!(load.rejected[0].contains("style override slack: id already defined"));
+        assert!(load.rejected[1].contains("duplicate host suffix github.com"));
+        assert_eq!(load.packs[0].profile_count, 1);
+        assert!(load.style_overrides.contains_key("acme_wiki"));
+
+        let registry = load.install().unwrap();
+        assert_eq!(
+            registry.classify(&browser("docs.acme.dev")).id,
+            "doc.acme_wiki"
+        );
+        assert_eq!(
+            registry.classify(&browser("wiki.acme.dev")).id,
+            "general.browser"
+        );
+        assert_eq!(registry.classify(&browser("github.com")).id, "dev.github");
+    }
+
+    #[test]
+    fn malformed_packs_are_rejected_whole() {
+        let mut load = ProfilePackLoad::builtin();
+        load.merge(
+            "a.json",
+            r#"{ "schema_version": 2, "name": "Future" }"#,
+            false,
+        );
+        load.merge(
+            "b.json",
+            r#"{ "schema_version": 1, "name": "Extra", "script": "rm -rf" }"#,
+            false,
+        );
+
+        assert!(load.packs.is_empty());
+        assert_eq!(load.rejected.len(), 2);
+        assert!(load.rejected[0].contains("unsupported schema_version 2"));
+        assert_eq!(load.definitions, builtin_profile_definitions());
+    }
+
+    #[test]
+    fn signed_packs_must_verify_against_a_trusted_key() {
+        let dir = temp_dir("signed");
+        std::fs::write(dir.join("acme.json"), SIGNED_PACK).unwrap();
+        std::fs::write(dir.join("acme.json.minisig"), SIGNED_PACK_SIGNATURE).unwrap();
+        std::fs::write(
+            dir.join(TRUSTED_KEYS_FILE),
+            format!("# Acme\n{SIGNED_PACK_KEY}\n"),
+        )
+        .unwrap();
+
+        let load = load_profile_packs(&dir);
+        assert!(load.rejected.is_empty(), "{:?}", load.rejected);
+        assert!(load.packs[0].signed);
+
+        std::fs::write(dir.join("acme.json"), SIGNED_PACK.replace("wiki.", "evil.")).unwrap();
+        let load = load_profile_packs(&dir);
+        assert!(load.packs.is_empty());
+        assert!(load.rejected[0].contains("signature does not match a trusted key"));
+
+        assert!(load_profile_packs(&dir.join("missing")).packs.is_empty());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -2,13 +2,22 @@ use std::path::PathBuf;
 use std::process::Command;
 
 use super::ContextSignalSource;
-use crate::app_detector::types::{BrowserAccessStatus, ContextSignals, TargetAppGuard};
+use crate::app_detector::types::{
+    BrowserAccessStatus, BrowserTarget, ContextSignals, TargetAppGuard,
+};
+
+mod atspi;
+mod wayland;
 
 pub struct LinuxContextSource;
 
+pub(crate) fn request_browser_access() -> BrowserAccessStatus {
+    atspi::enable_accessibility()
+}
+
 pub(crate) fn restore_target_application(target: &TargetAppGuard) -> bool {
     if crate::platform::is_wayland_session() {
-        return false;
+        return wayland::activate(target);
     }
     let Some(process_id) = target.process_id else {
         return false;
@@ -33,7 +42,22 @@ pub(crate) fn restore_target_application(target: &TargetAppGuard) -> bool {
 impl ContextSignalSource for LinuxContextSource {
     fn collect(&self) -> Option<ContextSignals> {
         if crate::platform::is_wayland_session() {
-            return None;
+            let window = wayland::active_window()?;
+            let process_id = window.process_id.or_else(|| {
+                window
+                    .app_id
+                    .as_deref()
+                    .and_then(wayland::process_id_for_app_id)
+            });
+            // Sandboxed or remote clients may hide their process; the app id
+            // still identifies the app.
+            let process_alias = process_id
+                .and_then(process_alias)
+                .or_else(|| window.app_id.as_deref().map(str::to_ascii_lowercase));
+            return Some(ContextSignals {
+                toplevel_app_id: window.app_id,
+                ..signals(process_id, process_alias, window.title)
+            });
         }
 
         let window_id = command_output(&["getactivewindow"])?;
@@ -44,29 +68,48 @@ impl ContextSignalSource for LinuxContextSource {
         let window_title = command_output(&["getwindowname", window_id.trim()])
             .map(|value| value.trim().to_string())
             .filter(|value| !value.is_empty());
-        let process_alias = std::fs::read_link(PathBuf::from(format!("/proc/{process_id}/exe")))
-            .ok()
-            .and_then(|path| {
-                path.file_name()
-                    .map(|value| value.to_string_lossy().to_string())
-            });
-        let is_supported_browser = process_alias.as_deref().is_some_and(is_supported_browser);
-
-        Some(ContextSignals {
-            process_id: Some(process_id),
-            native_identity: process_alias.clone(),
-            process_alias,
+        Some(signals(
+            Some(process_id),
+            process_alias(process_id),
             window_title,
-            browser_host: None,
-            is_supported_browser,
-            browser_access_status: BrowserAccessStatus::for_unavailable_url_adapter(
-                is_supported_browser,
-            ),
-            browser_target: None,
-        })
+        ))
+    }
+}
+
+fn signals(
+    process_id: Option<u32>,
+    process_alias: Option<String>,
+    window_title: Option<String>,
+) -> ContextSignals {
+    let browser_target = process_alias.as_deref().and_then(browser_target);
+    let (browser_host, browser_access_status) = match (browser_target, process_id) {
+        (Some(_), Some(process_id)) => atspi::browser_host(process_id),
+        (Some(_), None) => (None, BrowserAccessStatus::Unknown),
+        (None, _) => (None, BrowserAccessStatus::NotApplicable),
+    };
+    ContextSignals {
+        process_id,
+        native_identity: process_alias.clone(),
+        process_alias,
+        window_title,
+        browser_host,
+        is_supported_browser: browser_target.is_some(),
+        browser_access_status,
+        browser_target,
+        toplevel_app_id: None,
     }
 }
 
+/// Executable name of `process_id`, read from `/proc`.
+fn process_alias(process_id: u32) -> Option<String> {
+    std::fs::read_link(PathBuf::from(format!("/proc/{process_id}/exe")))
+        .ok()
+        .and_then(|path| {
+            path.file_name()
+                .map(|value| value.to_string_lossy().to_string())
+        })
+}
+
 fn command_output(args: &[&str]) -> Option<String> {
     let output = Command::new("xdotool").args(args).output().ok()?;
     output
@@ -75,9 +118,18 @@ fn command_output(args: &[&str]) -> Option<String> {
         .then(|| String::from_utf8_lossy(&output.stdout).to_string())
 }
 
-fn is_supported_browser(process_name: &str) -> bool {
-    matches!(
-        process_name.to_ascii_lowercase().as_str(),
-        "google-chrome" | "chrome" | "chromium" | "microsoft-edge" | "brave-browser" | "arc"
-    )
+/// Browser behind an executable name or, on Wayland, a lowercased app id.
+fn browser_target(process_name: &str) -> Option<BrowserTarget> {
+    match process_name.to_ascii_lowercase().as_str() {
+        "google-chrome" | "chrome" | "chromium" | "chromium-browser" | "org.chromium.chromium" => {
+            Some(BrowserTarget::Chrome)
+        }
+        "microsoft-edge" | "msedge" => Some(BrowserTarget::Edge),
+        "brave-browser" | "brave" | "com.brave.browser" => Some(BrowserTarget::Brave),
+        "arc" => Some(BrowserTarget::Arc),
+        "firefox" | "firefox-bin" | "firefox-esr" | "org.mozilla.firefox" => {
+            Some(BrowserTarget::Firefox)
+        }
+        _ => None,
+    }
 }
@@ -0,0 +1,366 @@
+//! Browser address bars read through the AT-SPI accessibility bus.
+//!
+//! Chromium-based browsers and Firefox publish their UI tree on the a11y bus
+//! once assistive technologies are enabled. We locate the focused browser's
+//! active window, read the first address-bar entry outside the page content
+//! and keep only its host; the full URL never leaves this module.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+use url::Url;
+use zbus::blocking::{connection, Connection};
+use zbus::zvariant::{DynamicDeserialize, DynamicType, OwnedObjectPath, OwnedValue, Value};
+
+use crate::app_detector::types::BrowserAccessStatus;
+
+const DBUS_NAME: &str = "org.freedesktop.DBus";
+const DBUS_PATH: &str = "/org/freedesktop/DBus";
+const A11Y_BUS_NAME: &str = "org.a11y.Bus";
+const A11Y_BUS_PATH: &str = "/org/a11y/bus";
+const A11Y_STATUS_INTERFACE: &str = "org.a11y.Status";
+const PROPERTIES_INTERFACE: &str = "org.freedesktop.DBus.Properties";
+const ACCESSIBLE_INTERFACE: &str = "org.a11y.atspi.Accessible";
+const TEXT_INTERFACE: &str = "org.a11y.atspi.Text";
+const REGISTRY_NAME: &str = "org.a11y.atspi.Registry";
+const ROOT_PATH: &str = "/org/a11y/atspi/accessible/root";
+
+const ROLE_FRAME: u32 = 23;
+const ROLE_ENTRY: u32 = 79;
+const ROLE_DOCUMENT_FRAME: u32 = 82;
+const ROLE_DOCUMENT_WEB: u32 = 95;
+const STATE_ACTIVE: u32 = 1;
+
+const CALL_TIMEOUT: Duration = Duration::from_millis(250);
+/// Page content is never entered, so a few hundred nodes cover the toolbar of
+/// every supported browser.
+const MAX_VISITED_NODES: usize = 400;
+
+type ObjectRef = (String, OwnedObjectPath);
+
+/// The address bar found last time, so the per-second refresh only re-reads
+/// its text while the same browser window stays active.
+struct AddressBar {
+    process_id: u32,
+    frame: ObjectRef,
+    entry: ObjectRef,
+}
+
+static LAST_ADDRESS_BAR: Mutex<Option<AddressBar>> = Mutex::new(None);
+
+/// Session and accessibility bus connections kept across refreshes. Each is
+/// reopened only after a call on it fails.
+struct Buses {
+    session: Connection,
+    a11y: Option<Connection>,
+}
+
+static BUSES: Mutex<Option<Buses>> = Mutex::new(None);
+
+/// Application bus name found for each browser process, so the registry is
+/// only walked again when the cached one stops answering.
+fn applications() -> &'static Mutex<HashMap<u32, ObjectRef>> {
+    static APPLICATIONS: OnceLock<Mutex<HashMap<u32, ObjectRef>>> = OnceLock::new();
+    APPLICATIONS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Host shown in the address bar of the active window of `process_id`.
+pub(super) fn browser_host(process_id: u32) -> (Option<String>, BrowserAccessStatus) {
+    let mut buses = BUSES.lock().unwrap_or_else(|error| error.into_inner());
+    if buses.is_none() {
+        *buses = Connection::session().ok().map(|session| Buses {
+            session,
+            a11y: None,
+        });
+    }
+    let Some(current) = buses.as_mut() else {
+        return (None, BrowserAccessStatus::Unknown);
+    };
+    match accessibility_enabled(&current.session) {
+        Ok(true) => {}
+        // The bus answered, so only accessibility is off.
+        Ok(false) | Err(zbus::Error::MethodError(..)) => {
+            current.a11y = None;
+            return (None, BrowserAccessStatus::NeedsPermission);
+        }
+        Err(_) => {
+            *buses = None;
+            return (None, BrowserAccessStatus::Unknown);
+        }
+    }
+    if current.a11y.is_none() {
+        current.a11y = a11y_connection(&current.session);
+    }
+    let Some(a11y) = current.a11y.as_ref() else {
+        return (None, BrowserAccessStatus::Unknown);
+    };
+    if let Some(host) = read_host(a11y, process_id) {
+        return (Some(host), BrowserAccessStatus::Available);
+    }
+    if !is_alive(a11y) {
+        current.a11y = None;
+        applications()
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+            .clear();
+    }
+    (None, BrowserAccessStatus::Unknown)
+}
+
+/// Turns the accessibility bus on so browsers start exposing their UI tree.
+/// Chromium-based browsers pick this up on their next launch.
+pub(super) fn enable_accessibility() -> BrowserAccessStatus {
+    let enabled = Connection::session().is_ok_and(|session| {
+        session
+            .call_method(
+                Some(A11Y_BUS_NAME),
+                A11Y_BUS_PATH,
+                Some(PROPERTIES_INTERFACE),
+                "Set",
+                &(A11Y_STATUS_INTERFACE, "IsEnabled", Value::from(true)),
+            )
+            .is_ok()
+    });
+    if enabled {
+        BrowserAccessStatus::Available
+    } else {
+        BrowserAccessStatus::Unknown
+    }
+}
+
+fn accessibility_enabled(session: &Connection) -> zbus::Result<bool> {
+    let reply = session.call_method(
+        Some(A11Y_BUS_NAME),
+        A11Y_BUS_PATH,
+        Some(PROPERTIES_INTERFACE),
+        "Get",
+        &(A11Y_STATUS_INTERFACE, "IsEnabled"),
+    )?;
+    Ok(reply
+        .body()
+        .deserialize::<OwnedValue>()
+        .ok()
+        .and_then(|value| bool::try_from(value).ok())
+        .unwrap_or(false))
+}
+
+fn is_alive(a11y: &Connection) -> bool {
+    a11y.call_method(
+        Some(DBUS_NAME),
+        DBUS_PATH,
+        Some("org.freedesktop.DBus.Peer"),
+        "Ping",
+        &(),
+    )
+    .is_ok()
+}
+
+fn a11y_connection(session: &Connection) -> Option<Connection> {
+    let address: String = session
+        .call_method(
+            Some(A11Y_BUS_NAME),
+            A11Y_BUS_PATH,
+            Some(A11Y_BUS_NAME),
+            "GetAddress",
+            &(),
+        )
+        .ok()?
+        .body()
+        .deserialize()
+        .ok()?;
+    connection::Builder::address(address.as_str())
+        .ok()?
+        .method_timeout(CALL_TIMEOUT)
+        .build()
+        .ok()
+}
+
+fn read_host(a11y: &Connection, process_id: u32) -> Option<String> {
+    let cached = applications()
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+        .get(&process_id)
+        .cloned();
+    let frame = match cached.and_then(|application| active_frame(a11y, &application)) {
+        Some(frame) => frame,
+        None => {
+            let application = application_for_process(a11y, process_id);
+            let mut applications = applications()
+                .lock()
+                .unwrap_or_else(|error| error.into_inner());
+            match &application {
+                Some(application) => applications.insert(process_id, application.clone()),
+                None => applications.remove(&process_id),
+            };
+            drop(applications);
+            active_frame(a11y, &application?)?
+        }
+    };
+
+    let mut last = LAST_ADDRESS_BAR.lock().ok()?;
+    if let Some(bar) = last
+        .as_ref()
+        .filter(|bar| bar.process_id == process_id && bar.frame == frame)
+    {
+        if let Some(host) = entry_host(a11y, &bar.entry) {
+            return Some(host);
+        }
+    }
+
+    let (entry, host) = find_address_bar(a11y, &frame)?;
+    *last = Some(AddressBar {
+        process_id,
+        frame,
+        entry,
+    });
+    Some(host)
+}
+
+fn application_for_process(a11y: &Connection, process_id: u32) -> Option<ObjectRef> {
+    let root = (
+        REGISTRY_NAME.to_string(),
+        OwnedObjectPath::try_from(ROOT_PATH).ok()?,
+    );
+    children(a11y, &root).into_iter().find(|(bus_name, _)| {
+        a11y.call_method(
+            Some(DBUS_NAME),
+            DBUS_PATH,
+            Some(DBUS_NAME),
+            "GetConnectionUnixProcessID",
+            &(bus_name.as_str(),),
+        )
+        .ok()
+        .and_then(|reply| reply.body().deserialize::<u32>().ok())
+            == Some(process_id)
+    })
+}
+
+fn active_frame(a11y: &Connection, application: &ObjectRef) -> Option<ObjectRef> {
+    let frames = children(a11y, application);
+    let active = frames.iter().find(|frame| {
+        call::<_, Vec<u32>>(a11y, frame, ACCESSIBLE_INTERFACE, "GetState", &())
+            .and_then(|states| states.first().copied())
+            .is_some_and(|bits| bits & (1 << STATE_ACTIVE) != 0)
+    });
+    active
+        .or_else(|| {
+            frames.iter().find(|frame| {
+                call::<_, u32>(a11y, frame, ACCESSIBLE_INTERFACE, "GetRole", &())
+                    == Some(ROLE_FRAME)
+            })
+        })
+        .cloned()
+}
+
+/// Breadth-first search of the browser UI; the toolbar entry sits well above
+/// any text field inside the page, which is skipped entirely.
+fn find_address_bar(a11y: &Connection, frame: &ObjectRef) -> Option<(ObjectRef, String)> {
+    let mut queue = std::collections::VecDeque::from([frame.clone()]);
+    let mut visited = 0;
+    while let Some(node) = queue.pop_front() {
+        visited += 1;
+        if visited > MAX_VISITED_NODES {
+            return None;
+        }
+        match call::<_, u32>(a11y, &node, ACCESSIBLE_INTERFACE, "GetRole", &()) {
+            Some(ROLE_DOCUMENT_FRAME | ROLE_DOCUMENT_WEB) => continue,
+            Some(ROLE_ENTRY) => {
+                if let Some(host) = entry_host(a11y, &node) {
+                    return Some((node, host));
+                }
+            }
+            _ => {}
+        }
+        queue.extend(children(a11y, &node));
+    }
+    None
+}
+
+fn entry_host(a11y: &Connection, entry: &ObjectRef) -> Option<String> {
+    let text: String = call(a11y, entry, TEXT_INTERFACE, "GetText", &(0i32, -1i32))?;
+    host_from_address_bar(&text)
+}
+
+/// Host of an address bar's contents. Browsers elide the scheme, so bare
+/// `example.com/path` values are accepted; search terms and internal pages
+/// are not.
+fn host_from_address_bar(text: &str) -> Option<String> {
+    let text = text.trim();
+    if text.is_empty() || text.chars().any(char::is_whitespace) {
+        return None;
+    }
+    let url = if text.contains("://") {
+        Url::parse(text).ok()?
+    } else {
+        Url::parse(&format!("https://{text}")).ok()?
+    };
+    if !matches!(url.scheme(), "http" | "https") {
+        return None;
+    }
+    let host = url.host_str()?.trim_end_matches('.').to_ascii_lowercase();
+    (host.contains('.') || host == "localhost").then_some(host)
+}
+
+fn children(a11y: &Connection, node: &ObjectRef) -> Vec<ObjectRef> {
+    call(a11y, node, ACCESSIBLE_INTERFACE, "GetChildren", &()).unwrap_or_default()
+}
+
+fn call<B, R>(
+    a11y: &Connection,
+    (bus_name, path): &ObjectRef,
+    interface: &str,
+    method: &str,
+    body: &B,
+) -> Option<R>
+where
+    B: serde::Serialize + DynamicType,
+    R: for<'d> DynamicDeserialize<'d>,
+{
+    a11y.call_method(
+        Some(bus_name.as_str()),
+        path.as_str(),
+        Some(interface),
+        method,
+        body,
+    )
+    .ok()?
+    .body()
+    .deserialize()
+    .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn address_bar_text_keeps_only_the_host() {
+        assert_eq!(
+            host_from_address_bar("https://Mail.Google.com./mail/u/0/#inbox").as_deref(),
+            Some("mail.google.com")
+        );
+        assert_eq!(
+            host_from_address_bar("github.com/owner/repo/pull/1").as_deref(),
+            Some("github.com")
+        );
+        assert_eq!(
+            host_from_address_bar("localhost:3000/app").as_deref(),
+            Some("localhost")
+        );
+    }
+
+    #[test]
+    fn address_bar_text_rejects_searches_and_internal_pages() {
+        for text in [
+            "",
+            "rust borrow checker",
+            "chrome://settings",
+            "about:blank",
+            "file:///home/me/notes.txt",
+            "intranet",
+        ] {
+            assert_eq!(host_from_address_bar(text), None, "{text}");
+        }
+    }
+}
@@ -0,0 +1,376 @@
+//! Wayland has no global "active window" query. wlroots-style compositors
+//! expose the foreign-toplevel protocol (read through `lswt`, activated
+//! through `wlrctl`); GNOME Shell and KWin keep window state behind their own
+//! D-Bus interfaces (the Window Calls extension through `gdbus`, and KWin
+//! scripting through `kdotool`).
+
+use std::process::Command;
+
+use serde::Deserialize;
+
+use crate::app_detector::types::TargetAppGuard;
+
+const GNOME_WINDOWS_PATH: &str = "/org/gnome/Shell/Extensions/Windows";
+const GNOME_WINDOWS_INTERFACE: &str = "org.gnome.Shell.Extensions.Windows";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(super) struct WaylandWindow {
+    pub(super) process_id: Option<u32>,
+    pub(super) app_id: Option<String>,
+    pub(super) title: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Backend {
+    ForeignToplevel,
+    GnomeShell,
+    KWin,
+}
+
+/// GNOME and KWin do not implement the wlroots foreign-toplevel protocol, so
+/// their D-Bus interfaces go first there.
+fn backend_order(current_desktop: &str) -> &'static [Backend] {
+    let desktops = current_desktop.to_ascii_lowercase();
+    let desktops = desktops.split(':').collect::<Vec<_>>();
+    if desktops.contains(&"gnome") {
+        &[Backend::GnomeShell, Backend::ForeignToplevel]
+    } else if desktops.contains(&"kde") {
+        &[Backend::KWin, Backend::ForeignToplevel]
+    } else {
+        &[Backend::ForeignToplevel, Backend::GnomeShell, Backend::KWin]
+    }
+}
+
+fn current_backends() -> &'static [Backend] {
+    backend_order(&std::env::var("XDG_CURRENT_DESKTOP").unwrap_or_default())
+}
+
+pub(super) fn active_window() -> Option<WaylandWindow> {
+    current_backends().iter().find_map(|backend| match backend {
+        Backend::ForeignToplevel => {
+            command_output("lswt", &["--json"]).and_then(|json| parse_lswt_active(&json))
+        }
+        Backend::GnomeShell => gnome_windows().and_then(|windows| {
+            windows
+                .into_iter()
+                .find(|window| window.focus)
+                .map(GnomeWindow::into_window)
+        }),
+        Backend::KWin => kwin_active_window(),
+    })
+}
+
+/// Brings the target's window forward where the compositor allows it.
+pub(super) fn activate(target: &TargetAppGuard) -> bool {
+    if target.is_empty() {
+        return false;
+    }
+    current_backends().iter().any(|backend| match backend {
+        Backend::ForeignToplevel => target.toplevel_app_id.as_deref().is_some_and(|app_id| {
+            command_succeeds(
+                "wlrctl",
+                &["toplevel", "focus", &format!("app_id:{app_id}")],
+            )
+        }),
+        Backend::GnomeShell => gnome_windows()
+            .and_then(|windows| windows.into_iter().find(|window| window.matches(target)))
+            .is_some_and(|window| {
+                command_succeeds(
+                    "gdbus",
+                    &[
+                        "call",
+                        "--session",
+                        "--dest",
+                        "org.gnome.Shell",
+                        "--object-path",
+                        GNOME_WINDOWS_PATH,
+                        "--method",
+                        &format!("{GNOME_WINDOWS_INTERFACE}.Activate"),
+                        &window.id.to_string(),
+                    ],
+                )
+            }),
+        Backend::KWin => target.process_id.is_some_and(|process_id| {
+            command_output("kdotool", &["search", "--pid", &process_id.to_string()])
+                .and_then(|output| output.lines().next().map(str::to_string))
+                .is_some_and(|window_id| {
+                    command_succeeds("kdotool", &["windowactivate", window_id.trim()])
+                })
+        }),
+    })
+}
+
+/// Finds a running process for a Wayland app id by its `/proc` command name.
+pub(super) fn process_id_for_app_id(app_id: &str) -> Option<u32> {
+    std::fs::read_dir("/proc")
+        .ok()?
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse::<u32>().ok())
+        .filter(|process_id| {
+            std::fs::read_to_string(format!("/proc/{process_id}/comm"))
+                .is_ok_and(|comm| comm_matches_app_id(comm.trim(), app_id))
+        })
+        .min()
+}
+
+/// `comm` is truncated to 15 bytes by the kernel, and reverse-DNS app ids
+/// ("org.gnome.Nautilus") name the executable in their last segment.
+fn comm_matches_app_id(comm: &str, app_id: &str) -> bool {
+    let app_id = app_id.trim().to_ascii_lowercase();
+    if comm.is_empty() || app_id.is_empty() {
+        return false;
+    }
+    let last_segment = app_id.rsplit('.').next().unwrap_or(&app_id);
+    [app_id.as_str(), last_segment].iter().any(|candidate| {
+        let truncated = candidate
+            .char_indices()
+            .take_while(|(index, character)| index + character.len_utf8() <= 15)
+            .map(|(_, character)| character)
+            .collect::<String>();
+        comm.eq_ignore_ascii_case(&truncated)
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct LswtOutput {
+    #[serde(default)]
+    toplevels: Vec<LswtToplevel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LswtToplevel {
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default, rename = "app-id", alias = "app_id")]
+    app_id: Option<String>,
+    #[serde(default)]
+    activated: bool,
+}
+
+fn parse_lswt_active(json: &str) -> Option<WaylandWindow> {
+    let output = serde_json::from_str::<LswtOutput>(json).ok()?;
+    let toplevel = output
+        .toplevels
+        .into_iter()
+        .find(|toplevel| toplevel.activated)?;
+    Some(WaylandWindow {
+        process_id: None,
+        app_id: non_empty(toplevel.app_id),
+        title: non_empty(toplevel.title),
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct GnomeWindow {
+    id: u64,
+    #[serde(default)]
+    pid: Option<u32>,
+    #[serde(default)]
+    wm_class: Option<String>,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    focus: bool,
+}
+
+impl GnomeWindow {
+    fn into_window(self) -> WaylandWindow {
+        WaylandWindow {
+            process_id: self.pid.filter(|pid| *pid > 0),
+            app_id: non_empty(self.wm_class),
+            title: non_empty(self.title),
+        }
+    }
+
+    fn matches(&self, target: &TargetAppGuard) -> bool {
+        match (target.process_id, self.pid) {
+            (Some(expected), Some(pid)) => expected == pid,
+            _ => target
+                .native_identity
+                .as_deref()
+                .zip(self.wm_class.as_deref())
+                .is_some_and(|(identity, wm_class)| identity.eq_ignore_ascii_case(wm_class)),
+        }
+    }
+}
+
+fn gnome_windows() -> Option<Vec<GnomeWindow>> {
+    let output = command_output(
+        "gdbus",
+        &[
+            "call",
+            "--session",
+            "--dest",
+            "org.gnome.Shell",
+            "--object-path",
+            GNOME_WINDOWS_PATH,
+            "--method",
+            &format!("{GNOME_WINDOWS_INTERFACE}.List"),
+        ],
+    )?;
+    serde_json::from_str(&parse_gvariant_string(&output)?).ok()
+}
+
+/// Unwraps the single string gdbus prints for a `(s)` reply, e.g.
+/// `('[{"id": 1}]',)`.
+fn parse_gvariant_string(output: &str) -> Option<String> {
+    let inner = output
+        .trim()
+        .strip_prefix('(')?
+        .strip_suffix(')')?
+        .trim_end()
+        .strip_suffix(',')?
+        .trim();
+    let quote = inner
+        .chars()
+        .next()
+        .filter(|quote| matches!(quote, '\'' | '"'))?;
+    let body = inner.strip_prefix(quote)?.strip_suffix(quote)?;
+
+    let mut value = String::with_capacity(body.len());
+    let mut chars = body.chars();
+    while let Some(character) = chars.next() {
+        if character != '\\' {
+            value.push(character);
+            continue;
+        }
+        match chars.next()? {
+            'n' => value.push('\n'),
+            't' => value.push('\t'),
+            other => value.push(other),
+        }
+    }
+    Some(value)
+}
+
+fn kwin_active_window() -> Option<WaylandWindow> {
+    let window_id = command_output("kdotool", &["getactivewindow"])?;
+    let window_id = window_id.trim();
+    let field = |command: &str| {
+        command_output("kdotool", &[command, window_id])
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    Some(WaylandWindow {
+        process_id: field("getwindowpid").and_then(|value| value.parse().ok()),
+        app_id: field("getwindowclassname"),
+        title: field("getwindowname"),
+    })
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+fn command_succeeds(program: &str, args: &[&str]) -> bool {
+    Command::new(program)
+        .args(args)
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backend_order_prefers_the_desktop_interface() {
+        assert_eq!(
+            backend_order("ubuntu:GNOME"),
+            &[Backend::GnomeShell, Backend::ForeignToplevel]
+        );
+        assert_eq!(
+            backend_order("KDE"),
+            &[Backend::KWin, Backend::ForeignToplevel]
+        );
+        assert_eq!(backend_order("sway")[0], Backend::ForeignToplevel);
+        assert_eq!(backend_order("")[0], Backend::ForeignToplevel);
+    }
+
+    #[test]
+    fn lswt_output_yields_the_activated_toplevel() {
+        let json = r#"{
+            "supported-data": {"title": true, "app-id": true, "activated": true},
+            "toplevels": [
+                {"title": "Inbox", "app-id": "thunderbird", "activated": false},
+                {"title": "Rust - Chromium", "app-id": "chromium", "activated": true}
+            ]
+        }"#;
+
+        assert_eq!(
+            parse_lswt_active(json),
+            Some(WaylandWindow {
+                process_id: None,
+                app_id: Some("chromium".to_string()),
+                title: Some("Rust - Chromium".to_string()),
+            })
+        );
+        assert_eq!(parse_lswt_active(r#"{"toplevels": []}"#), None);
+        assert_eq!(parse_lswt_active("not json"), None);
+    }
+
+    #[test]
+    fn gnome_window_list_is_unwrapped_from_the_gvariant_reply() {
+        let reply = r#"('[{"id":42,"pid":1234,"wm_class":"firefox","title":"Bob\'s page","focus":true},{"id":7,"pid":99,"wm_class":"org.gnome.Nautilus","focus":false}]',)"#;
+
+        let windows: Vec<GnomeWindow> =
+            serde_json::from_str(&parse_gvariant_string(reply).unwrap()).unwrap();
+        let focused = windows.into_iter().find(|window| window.focus).unwrap();
+
+        assert_eq!(focused.id, 42);
+        assert!(focused.matches(&TargetAppGuard {
+            process_id: Some(1234),
+            native_identity: Some("firefox".to_string()),
+            ..TargetAppGuard::default()
+        }));
+        assert_eq!(
+            focused.into_window(),
+            WaylandWindow {
+                process_id: Some(1234),
+                app_id: Some("firefox".to_string()),
+                title: Some("Bob's page".to_string()),
+            }
+        );
+        assert_eq!(parse_gvariant_string("Error: no such object"), None);
+    }
+
+    #[test]
+    fn gnome_windows_match_by_class_only_without_a_process_id() {
+        let window = GnomeWindow {
+            id: 1,
+            pid: None,
+            wm_class: Some("Code".to_string()),
+            title: None,
+            focus: false,
+        };
+
+        assert!(window.matches(&TargetAppGuard {
+            process_id: Some(10),
+            native_identity: Some("code".to_string()),
+            ..TargetAppGuard::default()
+        }));
+        assert!(!window.matches(&TargetAppGuard {
+            process_id: None,
+            native_identity: Some("slack".to_string()),
+            ..TargetAppGuard::default()
+        }));
+    }
+
+    #[test]
+    fn proc_command_names_match_app_ids() {
+        assert!(comm_matches_app_id("firefox", "firefox"));
+        assert!(comm_matches_app_id("nautilus", "org.gnome.Nautilus"));
+        assert!(comm_matches_app_id("gnome-text-edit", "gnome-text-editor"));
+        assert!(!comm_matches_app_id("firefox", "chromium"));
+        assert!(!comm_matches_app_id("", "firefox"));
+    }
+}
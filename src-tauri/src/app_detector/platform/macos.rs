@@ -82,6 +82,7 @@ impl ContextSignalSource for MacOsContextSource {
             is_supported_browser: browser.is_some(),
             browser_access_status,
             browser_target: browser.map(|browser| browser.target),
+            toplevel_app_id: None,
         })
     }
 }
@@ -209,6 +210,8 @@ pub(crate) fn request_browser_access(target: BrowserTarget) -> BrowserAccessStat
         BrowserTarget::Edge => "com.microsoft.edgemac",
         BrowserTarget::Brave => "com.brave.Browser",
         BrowserTarget::Arc => "company.thebrowser.Browser",
+        // Firefox has no AppleScript dictionary to read tabs from.
+        BrowserTarget::Firefox => return BrowserAccessStatus::NotApplicable,
     };
     browser_access_status_for_automation_permission(automation_permission_status(bundle_id, true))
 }
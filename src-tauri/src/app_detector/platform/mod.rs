@@ -59,7 +59,12 @@ pub(crate) fn request_browser_access(target: BrowserTarget) -> BrowserAccessStat
     {
         macos::request_browser_access(target)
     }
-    #[cfg(not(target_os = "macos"))]
+    #[cfg(target_os = "linux")]
+    {
+        let _ = target;
+        linux::request_browser_access()
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
     {
         let _ = target;
         BrowserAccessStatus::NotApplicable
@@ -93,6 +93,7 @@ unsafe fn collect_foreground_context() -> Option<ContextSignals> {
         is_supported_browser: supported_browser,
         browser_access_status: BrowserAccessStatus::for_unavailable_url_adapter(supported_browser),
         browser_target: None,
+        toplevel_app_id: None,
     })
 }
 
@@ -1,5 +1,9 @@
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
 use super::types::{
-    AppStyleOverride, ArtifactKind, ContextFamily, Density, Formality, ListBehavior, MarkupPolicy,
+    AppOutputPolicy, AppStyleOverride, ArtifactKind, ContextFamily, Density, Formality,
+    ListBehavior, MarkupPolicy,
 };
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -38,6 +42,7 @@ pub struct ProfileDefinition {
     pub native_identities: Vec<String>,
     pub process_aliases: Vec<String>,
     pub title_markers: Vec<TitleMarkerDefinition>,
+    pub output_policy: AppOutputPolicy,
     pub release_tier: ReleaseTier,
 }
 
@@ -66,6 +71,7 @@ impl ProfileDefinition {
             native_identities: strings(native_identities),
             process_aliases: strings(process_aliases),
             title_markers: Vec::new(),
+            output_policy: AppOutputPolicy::default(),
             release_tier,
         }
     }
@@ -74,6 +80,11 @@ impl ProfileDefinition {
         self.title_markers.push(marker);
         self
     }
+
+    fn with_output_policy(mut self, output_policy: AppOutputPolicy) -> Self {
+        self.output_policy = output_policy;
+        self
+    }
 }
 
 fn strings(values: &[&str]) -> Vec<String> {
@@ -889,10 +900,92 @@ pub fn builtin_profile_definitions() -> Vec<ProfileDefinition> {
             &[],
             &[],
         ),
+        // Terminals treat Ctrl+V as a control character and run pasted lines
+        // as they stream in, so any paste uses Ctrl+Shift+V in one piece. The
+        // strategy stays the user's, since Wayland "auto" types instead.
+        extended(
+            "code.terminal",
+            PromptOrCode,
+            "Terminal",
+            "terminal",
+            &[],
+            &[],
+            &[
+                "com.apple.terminal",
+                "com.googlecode.iterm2",
+                "com.mitchellh.ghostty",
+                "net.kovidgoyal.kitty",
+                "com.github.wez.wezterm",
+                "org.alacritty",
+            ],
+            &[
+                "gnome-terminal-server",
+                "konsole",
+                "xfce4-terminal",
+                "tilix",
+                "alacritty",
+                "kitty",
+                "wezterm-gui",
+                "ghostty",
+                "windowsterminal.exe",
+            ],
+        )
+        .with_output_policy(AppOutputPolicy {
+            paste_shortcut: Some("ctrlShiftV".to_string()),
+            streaming_insert_enabled: Some(false),
+            ..AppOutputPolicy::default()
+        }),
+        // Synthetic keys and paste shortcuts go to the local machine, so
+        // remote sessions only get the text on the shared clipboard.
+        extended(
+            "general.remote_desktop",
+            General,
+            "Remote Desktop",
+            "general",
+            &[],
+            &[],
+            &["com.microsoft.rdc.macos"],
+            &[
+                "mstsc.exe",
+                "msrdc.exe",
+                "remmina",
+                "vncviewer",
+                "vncviewer.exe",
+            ],
+        )
+        .with_output_policy(AppOutputPolicy {
+            insertion_strategy: Some("clipboardCopyOnly".to_string()),
+            streaming_insert_enabled: Some(false),
+            ..AppOutputPolicy::default()
+        }),
     ]
 }
 
+fn pack_style_overrides() -> &'static RwLock<Arc<HashMap<String, AppStyleOverride>>> {
+    static PACK_STYLE_OVERRIDES: OnceLock<RwLock<Arc<HashMap<String, AppStyleOverride>>>> =
+        OnceLock::new();
+    PACK_STYLE_OVERRIDES.get_or_init(|| RwLock::new(Arc::new(HashMap::new())))
+}
+
+/// Replaces the style overrides contributed by profile packs.
+pub fn install_pack_style_overrides(overrides: HashMap<String, AppStyleOverride>) {
+    *pack_style_overrides()
+        .write()
+        .unwrap_or_else(|e| e.into_inner()) = Arc::new(overrides);
+}
+
+/// Built-in override for `id`, or one installed from a profile pack.
 pub fn style_override(id: &str) -> Option<AppStyleOverride> {
+    builtin_style_override(id).or_else(|| {
+        pack_style_overrides()
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(id)
+            .copied()
+    })
+}
+
+pub fn builtin_style_override(id: &str) -> Option<AppStyleOverride> {
     use ArtifactKind::*;
     use Density::*;
     use Formality::*;
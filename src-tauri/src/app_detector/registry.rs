@@ -1,8 +1,9 @@
 use std::collections::{HashMap, HashSet};
 
-use super::profiles::{builtin_profile_definitions, style_override, ProfileDefinition};
+use super::profiles::{builtin_profile_definitions, builtin_style_override, ProfileDefinition};
 use super::types::{
-    is_valid_profile_id, ContextFamily, ContextProfile, ContextSignals, ContextSource,
+    is_valid_profile_id, AppOutputPolicy, AppStyleOverride, ContextFamily, ContextProfile,
+    ContextSignals, ContextSource,
 };
 
 #[derive(Clone, Debug)]
@@ -31,7 +32,16 @@ impl AppRegistry {
     }
 
     pub fn build(definitions: Vec<ProfileDefinition>) -> Result<Self, String> {
-        validate_definitions(&definitions)?;
+        Self::build_with_overrides(definitions, &HashMap::new())
+    }
+
+    /// Builds a registry whose definitions may also use `pack_overrides`,
+    /// the style overrides contributed by profile packs.
+    pub fn build_with_overrides(
+        definitions: Vec<ProfileDefinition>,
+        pack_overrides: &HashMap<String, AppStyleOverride>,
+    ) -> Result<Self, String> {
+        validate_definitions(&definitions, pack_overrides)?;
 
         let profiles = definitions
             .iter()
@@ -105,6 +115,14 @@ impl AppRegistry {
         &self.definitions
     }
 
+    /// Output policy of the profile `id`; empty for unknown ids.
+    pub fn output_policy(&self, id: &str) -> AppOutputPolicy {
+        self.profile_ids
+            .get(id)
+            .map(|index| self.definitions[*index].output_policy.clone())
+            .unwrap_or_default()
+    }
+
     pub fn profile(&self, id: &str) -> Option<ContextProfile> {
         self.profile_ids
             .get(id)
@@ -236,7 +254,10 @@ pub(crate) fn host_matches_suffix(host: &str, suffix: &str) -> bool {
             .is_some_and(|prefix| prefix.ends_with('.'))
 }
 
-fn validate_definitions(definitions: &[ProfileDefinition]) -> Result<(), String> {
+pub(crate) fn validate_definitions(
+    definitions: &[ProfileDefinition],
+    pack_overrides: &HashMap<String, AppStyleOverride>,
+) -> Result<(), String> {
     let mut ids = HashSet::new();
     let mut exact_hosts = HashMap::<String, &str>::new();
     let mut host_suffixes = HashMap::<String, &str>::new();
@@ -270,8 +291,16 @@ fn validate_definitions(definitions: &[ProfileDefinition]) -> Result<(), String>
         {
             return Err(format!("profile {} has no matcher", definition.id));
         }
+        if !definition.output_policy.is_valid() {
+            return Err(format!(
+                "profile {} has an invalid output policy",
+                definition.id
+            ));
+        }
         if let Some(override_id) = definition.override_id.as_deref() {
-            if style_override(override_id).is_none() {
+            if builtin_style_override(override_id).is_none()
+                && !pack_overrides.contains_key(override_id)
+            {
                 return Err(format!(
                     "profile {} uses unknown override {}",
                     definition.id, override_id
@@ -500,6 +529,7 @@ mod tests {
             native_identities: Vec::new(),
             process_aliases: Vec::new(),
             title_markers: Vec::new(),
+            output_policy: AppOutputPolicy::default(),
             release_tier: ReleaseTier::Extended,
         };
         assert!(AppRegistry::build(vec![invalid]).is_err());
@@ -519,6 +549,7 @@ mod tests {
                 native_identities: Vec::new(),
                 process_aliases: Vec::new(),
                 title_markers: Vec::new(),
+                output_policy: AppOutputPolicy::default(),
                 release_tier: ReleaseTier::Extended,
             })
             .collect();
@@ -102,12 +102,16 @@ pub(crate) struct ContextSignals {
     pub(crate) is_supported_browser: bool,
     pub(crate) browser_access_status: BrowserAccessStatus,
     pub(crate) browser_target: Option<BrowserTarget>,
+    /// Unnormalized Wayland toplevel app id, used to refocus the window.
+    pub(crate) toplevel_app_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct TargetAppGuard {
     pub process_id: Option<u32>,
     pub native_identity: Option<String>,
+    /// Raw Wayland app id; only used for activation, never for matching.
+    pub toplevel_app_id: Option<String>,
 }
 
 impl TargetAppGuard {
@@ -142,6 +146,7 @@ impl From<&ContextSignals> for TargetAppGuard {
         Self {
             process_id: signals.process_id,
             native_identity: signals.native_identity.clone(),
+            toplevel_app_id: signals.toplevel_app_id.clone(),
         }
     }
 }
@@ -151,6 +156,7 @@ pub struct RecordingContext {
     pub profile: ContextProfile,
     pub target_guard: TargetAppGuard,
     pub mapped_scene_id: Option<String>,
+    pub output_policy: AppOutputPolicy,
     pub browser_access_status: BrowserAccessStatus,
     pub browser_target: Option<BrowserTarget>,
 }
@@ -172,6 +178,7 @@ pub enum BrowserTarget {
     Edge,
     Brave,
     Arc,
+    Firefox,
 }
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -185,7 +192,7 @@ pub enum BrowserAccessStatus {
 }
 
 impl BrowserAccessStatus {
-    #[cfg(any(target_os = "windows", target_os = "linux", test))]
+    #[cfg(any(target_os = "windows", test))]
     pub(crate) fn for_unavailable_url_adapter(is_supported_browser: bool) -> Self {
         if is_supported_browser {
             Self::Unknown
@@ -285,6 +292,59 @@ pub struct AppStyleOverride {
     pub list_behavior: ListBehavior,
 }
 
+/// Output settings an app needs instead of the global ones, e.g. a terminal
+/// pasting with Ctrl+Shift+V. Unset fields keep the global setting; values
+/// use the same strings as `AppConfig`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppOutputPolicy {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insertion_strategy: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paste_shortcut: Option<String>,
+    /// How Windows SendInput types line breaks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub newline_mode: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub streaming_insert_enabled: Option<bool>,
+}
+
+impl AppOutputPolicy {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        use crate::storage::{
+            is_insertion_strategy_value, is_paste_shortcut_value,
+            is_windows_sendinput_newline_mode_value,
+        };
+        self.insertion_strategy
+            .as_deref()
+            .is_none_or(is_insertion_strategy_value)
+            && self
+                .paste_shortcut
+                .as_deref()
+                .is_none_or(is_paste_shortcut_value)
+            && self
+                .newline_mode
+                .as_deref()
+                .is_none_or(is_windows_sendinput_newline_mode_value)
+    }
+
+    /// Fields set here, falling back to `base` for the rest.
+    pub fn or(self, base: Self) -> Self {
+        Self {
+            insertion_strategy: self.insertion_strategy.or(base.insertion_strategy),
+            paste_shortcut: self.paste_shortcut.or(base.paste_shortcut),
+            newline_mode: self.newline_mode.or(base.newline_mode),
+            streaming_insert_enabled: self
+                .streaming_insert_enabled
+                .or(base.streaming_insert_enabled),
+        }
+    }
+}
+
 pub fn is_valid_profile_id(id: &str) -> bool {
     let mut parts = id.split('.');
     let Some(prefix) = parts.next() else {
@@ -1,3 +1,4 @@
+use std::cmp::Reverse;
 use std::collections::HashSet;
 use std::sync::{Arc, RwLock};
 
@@ -5,13 +6,20 @@ use serde::{Deserialize, Serialize};
 use tauri_plugin_store::StoreExt;
 use uuid::Uuid;
 
-use super::registry::AppRegistry;
-use super::types::{ContextFamily, ContextProfile, ContextSignals, ContextSource};
+use super::registry::{host_matches_suffix, AppRegistry};
+use super::types::{AppOutputPolicy, ContextFamily, ContextProfile, ContextSignals, ContextSource};
 
 const MAPPING_STORE_FILE: &str = "context-mappings.json";
 const MAPPING_STORE_KEY: &str = "mapping_state";
-const MAPPING_STORE_VERSION: u32 = 1;
+/// Version 2 added the host-suffix and pattern matchers. Older builds only
+/// read version 1, so a store stays at version 1 until one of those matchers
+/// is saved; from then on older builds see no mappings.
+const MAPPING_STORE_VERSION: u32 = 2;
 const MAX_MAPPING_LABEL_CHARS: usize = 40;
+const MAX_PATTERN_CHARS: usize = 120;
+/// Patterns need this many non-wildcard characters, so `*` alone cannot
+/// capture every app.
+const MIN_PATTERN_LITERAL_CHARS: usize = 3;
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(tag = "type", content = "value", rename_all = "snake_case")]
@@ -19,6 +27,12 @@ pub enum UserAppMatcher {
     NativeBundleId(String),
     NativeExecutable(String),
     ExactWebHost(String),
+    /// The host itself and any of its subdomains.
+    WebHostSuffix(String),
+    /// `*`/`?` glob over the executable name.
+    ExecutableGlob(String),
+    /// `*`/`?` glob over the whole window title, case-insensitive.
+    WindowTitlePattern(String),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -29,6 +43,8 @@ pub struct CustomAppMapping {
     pub family: ContextFamily,
     pub scene_id: Option<String>,
     pub enabled: bool,
+    #[serde(default, skip_serializing_if = "AppOutputPolicy::is_empty")]
+    pub output_policy: AppOutputPolicy,
 }
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -37,6 +53,9 @@ pub enum UserAppMatcherType {
     NativeBundleId,
     NativeExecutable,
     ExactWebHost,
+    WebHostSuffix,
+    ExecutableGlob,
+    WindowTitlePattern,
 }
 
 #[derive(Clone, Debug, Serialize, PartialEq, Eq)]
@@ -61,6 +80,7 @@ pub struct CustomAppMappingView {
     pub scene_id: Option<String>,
     pub enabled: bool,
     pub icon_key: String,
+    pub output_policy: AppOutputPolicy,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -86,11 +106,123 @@ impl MappingCandidate {
 }
 
 impl UserAppMatcher {
+    pub(crate) fn new(matcher_type: UserAppMatcherType, value: String) -> Self {
+        match matcher_type {
+            UserAppMatcherType::NativeBundleId => Self::NativeBundleId(value),
+            UserAppMatcherType::NativeExecutable => Self::NativeExecutable(value),
+            UserAppMatcherType::ExactWebHost => Self::ExactWebHost(value),
+            UserAppMatcherType::WebHostSuffix => Self::WebHostSuffix(value),
+            UserAppMatcherType::ExecutableGlob => Self::ExecutableGlob(value),
+            UserAppMatcherType::WindowTitlePattern => Self::WindowTitlePattern(value),
+        }
+    }
+
     fn matcher_type(&self) -> UserAppMatcherType {
         match self {
             Self::NativeBundleId(_) => UserAppMatcherType::NativeBundleId,
             Self::NativeExecutable(_) => UserAppMatcherType::NativeExecutable,
             Self::ExactWebHost(_) => UserAppMatcherType::ExactWebHost,
+            Self::WebHostSuffix(_) => UserAppMatcherType::WebHostSuffix,
+            Self::ExecutableGlob(_) => UserAppMatcherType::ExecutableGlob,
+            Self::WindowTitlePattern(_) => UserAppMatcherType::WindowTitlePattern,
+        }
+    }
+
+    fn value(&self) -> &str {
+        match self {
+            Self::NativeBundleId(value)
+            | Self::NativeExecutable(value)
+            | Self::ExactWebHost(value)
+            | Self::WebHostSuffix(value)
+            | Self::ExecutableGlob(value)
+            | Self::WindowTitlePattern(value) => value,
+        }
+    }
+
+    fn is_pattern(&self) -> bool {
+        matches!(self, Self::ExecutableGlob(_) | Self::WindowTitlePattern(_))
+    }
+
+    /// Matchers a version 1 store could already hold.
+    fn is_exact(&self) -> bool {
+        matches!(
+            self,
+            Self::NativeBundleId(_) | Self::NativeExecutable(_) | Self::ExactWebHost(_)
+        )
+    }
+
+    /// Sort key for overlapping mappings; the smallest wins. Web matchers are
+    /// the narrowest, a window title narrows a native app, and a glob only
+    /// applies when nothing exact does. Within a kind, the matcher with more
+    /// literal characters wins, e.g. `jira.acme.dev` over `acme.dev`.
+    fn priority(&self) -> (u8, Reverse<usize>) {
+        let rank = match self {
+            Self::ExactWebHost(_) => 0,
+            Self::WebHostSuffix(_) => 1,
+            Self::WindowTitlePattern(_) => 2,
+            Self::NativeBundleId(_) | Self::NativeExecutable(_) => 3,
+            Self::ExecutableGlob(_) => 4,
+        };
+        (rank, Reverse(literal_chars(self.value())))
+    }
+
+    fn matches(&self, target: &MatchTarget) -> bool {
+        match self {
+            Self::ExactWebHost(value) => target.web_host.as_deref() == Some(value.as_str()),
+            Self::WebHostSuffix(suffix) => target
+                .web_host
+                .as_deref()
+                .is_some_and(|host| host == suffix || host_matches_suffix(host, suffix)),
+            Self::NativeBundleId(value) => target.bundle_id.as_deref() == Some(value.as_str()),
+            Self::NativeExecutable(value) => target.executable.as_deref() == Some(value.as_str()),
+            Self::ExecutableGlob(pattern) => target
+                .executable
+                .as_deref()
+                .is_some_and(|executable| glob_matches(pattern, executable)),
+            Self::WindowTitlePattern(pattern) => target
+                .window_title
+                .as_deref()
+                .is_some_and(|title| glob_matches(pattern, title)),
+        }
+    }
+
+    /// Two patterns of the same kind and weight that some app could satisfy
+    /// both of, leaving the winner down to save order.
+    fn conflicts_with(&self, other: &Self) -> bool {
+        self.is_pattern()
+            && self.matcher_type() == other.matcher_type()
+            && self.priority() == other.priority()
+            && globs_overlap(self.value(), other.value())
+    }
+}
+
+/// Focused-window signals normalized the way stored matchers are.
+struct MatchTarget {
+    web_host: Option<String>,
+    bundle_id: Option<String>,
+    executable: Option<String>,
+    window_title: Option<String>,
+}
+
+impl MatchTarget {
+    fn from_signals(signals: &ContextSignals) -> Self {
+        let identity = signals
+            .native_identity
+            .as_deref()
+            .or(signals.process_alias.as_deref());
+        Self {
+            web_host: signals
+                .browser_host
+                .as_deref()
+                .filter(|_| signals.is_supported_browser)
+                .and_then(|host| normalize_web_host(host).ok()),
+            bundle_id: identity.and_then(|identity| normalize_bundle_id(identity).ok()),
+            executable: identity.and_then(|identity| normalize_executable(identity).ok()),
+            window_title: signals
+                .window_title
+                .as_deref()
+                .map(normalize_title)
+                .filter(|title| !title.is_empty()),
         }
     }
 }
@@ -123,6 +255,9 @@ impl UserAppMappingCollection {
             mapping.label = label;
             mapping.matcher = matcher;
             mapping.scene_id = sanitize_scene_id(mapping.scene_id);
+            if !mapping.output_policy.is_valid() {
+                mapping.output_policy = AppOutputPolicy::default();
+            }
             normalized.push(mapping);
         }
         Self {
@@ -141,7 +276,17 @@ impl UserAppMappingCollection {
         family: ContextFamily,
         scene_id: Option<String>,
     ) -> Result<CustomAppMapping, String> {
-        let matcher = normalize_matcher(candidate.matcher.clone())?;
+        self.save_matcher(candidate.matcher.clone(), label, family, scene_id)
+    }
+
+    pub(crate) fn save_matcher(
+        &mut self,
+        matcher: UserAppMatcher,
+        label: &str,
+        family: ContextFamily,
+        scene_id: Option<String>,
+    ) -> Result<CustomAppMapping, String> {
+        let matcher = normalize_matcher(matcher)?;
         if self
             .mappings
             .iter()
@@ -149,6 +294,13 @@ impl UserAppMappingCollection {
         {
             return Err("custom_app_mapping_duplicate".to_string());
         }
+        if self
+            .mappings
+            .iter()
+            .any(|mapping| matcher.conflicts_with(&mapping.matcher))
+        {
+            return Err("custom_app_mapping_conflict".to_string());
+        }
         let label = sanitize_mapping_label(label);
         if label.is_empty() {
             return Err("custom_app_mapping_label_empty".to_string());
@@ -160,6 +312,7 @@ impl UserAppMappingCollection {
             family,
             scene_id: sanitize_scene_id(scene_id),
             enabled: true,
+            output_policy: AppOutputPolicy::default(),
         };
         self.mappings.push(mapping.clone());
         Ok(mapping)
@@ -199,6 +352,23 @@ impl UserAppMappingCollection {
         Ok(())
     }
 
+    pub(crate) fn set_output_policy(
+        &mut self,
+        id: &str,
+        output_policy: AppOutputPolicy,
+    ) -> Result<CustomAppMapping, String> {
+        if !output_policy.is_valid() {
+            return Err("custom_app_mapping_invalid_output_policy".to_string());
+        }
+        let mapping = self
+            .mappings
+            .iter_mut()
+            .find(|mapping| mapping.id == id)
+            .ok_or_else(|| "custom_app_mapping_not_found".to_string())?;
+        mapping.output_policy = output_policy;
+        Ok(mapping.clone())
+    }
+
     pub(crate) fn delete(&mut self, id: &str) -> Result<(), String> {
         let initial_len = self.mappings.len();
         self.mappings.retain(|mapping| mapping.id != id);
@@ -212,42 +382,14 @@ impl UserAppMappingCollection {
         self.mappings.clear();
     }
 
+    /// The enabled mapping with the best [`UserAppMatcher::priority`] that
+    /// matches; equal priorities keep save order.
     pub(crate) fn find_match(&self, signals: &ContextSignals) -> Option<&CustomAppMapping> {
-        let web_matcher = signals
-            .browser_host
-            .as_deref()
-            .filter(|_| signals.is_supported_browser)
-            .and_then(|host| normalize_web_host(host).ok())
-            .map(UserAppMatcher::ExactWebHost);
-        if let Some(matcher) = web_matcher.as_ref() {
-            if let Some(mapping) = self
-                .mappings
-                .iter()
-                .find(|mapping| mapping.enabled && &mapping.matcher == matcher)
-            {
-                return Some(mapping);
-            }
-        }
-
-        let identity = signals
-            .native_identity
-            .as_deref()
-            .or(signals.process_alias.as_deref());
-        let identity = identity?;
-        self.mappings.iter().find(|mapping| {
-            if !mapping.enabled {
-                return false;
-            }
-            match &mapping.matcher {
-                UserAppMatcher::NativeBundleId(value) => {
-                    normalize_bundle_id(identity).is_ok_and(|identity| identity == *value)
-                }
-                UserAppMatcher::NativeExecutable(value) => {
-                    normalize_executable(identity).is_ok_and(|identity| identity == *value)
-                }
-                UserAppMatcher::ExactWebHost(_) => false,
-            }
-        })
+        let target = MatchTarget::from_signals(signals);
+        self.mappings
+            .iter()
+            .filter(|mapping| mapping.enabled && mapping.matcher.matches(&target))
+            .min_by_key(|mapping| mapping.matcher.priority())
     }
 }
 
@@ -255,6 +397,12 @@ impl UserAppMappingCollection {
 pub(crate) struct ResolvedProfile {
     pub(crate) profile: ContextProfile,
     pub(crate) mapped_scene_id: Option<String>,
+    pub(crate) output_policy: AppOutputPolicy,
+}
+
+/// Profile id a custom mapping reports in its context.
+pub(crate) fn mapping_profile_id(mapping_id: &str) -> String {
+    format!("user.{}", mapping_id.replace('-', ""))
 }
 
 pub(crate) fn resolve_profile(
@@ -263,15 +411,17 @@ pub(crate) fn resolve_profile(
     signals: &ContextSignals,
 ) -> ResolvedProfile {
     let builtin = registry.classify(signals);
+    let builtin_policy = registry.output_policy(&builtin.id);
     let Some(mapping) = mappings.find_match(signals) else {
         return ResolvedProfile {
             profile: builtin,
             mapped_scene_id: None,
+            output_policy: builtin_policy,
         };
     };
     ResolvedProfile {
         profile: ContextProfile {
-            id: format!("user.{}", mapping.id.replace('-', "")),
+            id: mapping_profile_id(&mapping.id),
             family: mapping.family,
             app_label: mapping.label.clone(),
             icon_key: builtin.icon_key,
@@ -280,6 +430,7 @@ pub(crate) fn resolve_profile(
             confidence: 1.0,
         },
         mapped_scene_id: mapping.scene_id.clone(),
+        output_policy: mapping.output_policy.clone().or(builtin_policy),
     }
 }
 
@@ -339,6 +490,15 @@ pub(crate) fn normalize_matcher(matcher: UserAppMatcher) -> Result<UserAppMatche
         UserAppMatcher::ExactWebHost(value) => {
             normalize_web_host(&value).map(UserAppMatcher::ExactWebHost)
         }
+        UserAppMatcher::WebHostSuffix(value) => {
+            normalize_host_suffix(&value).map(UserAppMatcher::WebHostSuffix)
+        }
+        UserAppMatcher::ExecutableGlob(value) => {
+            normalize_executable_glob(&value).map(UserAppMatcher::ExecutableGlob)
+        }
+        UserAppMatcher::WindowTitlePattern(value) => {
+            normalize_title_pattern(&value).map(UserAppMatcher::WindowTitlePattern)
+        }
     }
 }
 
@@ -399,6 +559,134 @@ fn normalize_web_host(value: &str) -> Result<String, String> {
     }
 }
 
+/// Accepts `atlassian.net`, `.atlassian.net` or `*.atlassian.net`. A bare
+/// top-level domain or an IP address is not a suffix.
+fn normalize_host_suffix(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    let value = value
+        .strip_prefix("*.")
+        .or_else(|| value.strip_prefix('.'))
+        .unwrap_or(value);
+    let host = normalize_web_host(value)?;
+    if !host.contains('.') || host.parse::<std::net::Ipv4Addr>().is_ok() {
+        return Err("custom_app_mapping_invalid_host".to_string());
+    }
+    Ok(host)
+}
+
+fn normalize_executable_glob(value: &str) -> Result<String, String> {
+    let value = value.trim().to_ascii_lowercase();
+    if value.chars().count() > MAX_PATTERN_CHARS
+        || value.chars().any(|character| {
+            character.is_control() || matches!(character, '/' | '\\' | ':' | '"' | '<' | '>' | '|')
+        })
+    {
+        return Err("custom_app_mapping_invalid_pattern".to_string());
+    }
+    require_specific_pattern(value)
+}
+
+fn normalize_title_pattern(value: &str) -> Result<String, String> {
+    let value = normalize_title(value);
+    if value.chars().count() > MAX_PATTERN_CHARS {
+        return Err("custom_app_mapping_invalid_pattern".to_string());
+    }
+    require_specific_pattern(value)
+}
+
+fn require_specific_pattern(pattern: String) -> Result<String, String> {
+    if literal_chars(&pattern) < MIN_PATTERN_LITERAL_CHARS {
+        return Err("custom_app_mapping_pattern_too_broad".to_string());
+    }
+    Ok(pattern)
+}
+
+fn normalize_title(value: &str) -> String {
+    value
+        .chars()
+        .map(|character| {
+            if character.is_control() {
+                ' '
+            } else {
+                character
+            }
+        })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+fn literal_chars(pattern: &str) -> usize {
+    pattern
+        .chars()
+        .filter(|character| !matches!(character, '*' | '?'))
+        .count()
+}
+
+/// `*` matches any run of characters and `?` exactly one.
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let text = text.chars().collect::<Vec<_>>();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&character) if character == '?' || character == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, star_text)) => {
+                    p = star + 1;
+                    t = star_text + 1;
+                    backtrack = Some((star, star_text + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|character| *character == '*')
+}
+
+/// Whether some text matches both globs.
+fn globs_overlap(left: &str, right: &str) -> bool {
+    fn overlap(
+        left: &[char],
+        right: &[char],
+        i: usize,
+        j: usize,
+        seen: &mut HashSet<(usize, usize)>,
+    ) -> bool {
+        if !seen.insert((i, j)) {
+            return false;
+        }
+        match (left.get(i), right.get(j)) {
+            (None, None) => true,
+            (Some('*'), _) => {
+                overlap(left, right, i + 1, j, seen)
+                    || (j < right.len() && overlap(left, right, i, j + 1, seen))
+            }
+            (_, Some('*')) => {
+                overlap(left, right, i, j + 1, seen)
+                    || (i < left.len() && overlap(left, right, i + 1, j, seen))
+            }
+            (Some(a), Some(b)) if *a == '?' || *b == '?' || a == b => {
+                overlap(left, right, i + 1, j + 1, seen)
+            }
+            _ => false,
+        }
+    }
+    let left = left.chars().collect::<Vec<_>>();
+    let right = right.chars().collect::<Vec<_>>();
+    overlap(&left, &right, 0, 0, &mut HashSet::new())
+}
+
 pub(crate) fn sanitize_mapping_label(value: &str) -> String {
     value
         .chars()
@@ -434,6 +722,11 @@ fn sanitize_scene_id(scene_id: Option<String>) -> Option<String> {
 fn matcher_display_value(matcher: &UserAppMatcher, native_label: &str) -> String {
     match matcher {
         UserAppMatcher::ExactWebHost(host) => host.clone(),
+        UserAppMatcher::WebHostSuffix(suffix) => format!("*.{suffix}"),
+        UserAppMatcher::ExecutableGlob(pattern) => {
+            format!("{} · {}", pattern, platform_display_name())
+        }
+        UserAppMatcher::WindowTitlePattern(pattern) => pattern.clone(),
         _ => format!("{} · {}", native_label, platform_display_name()),
     }
 }
@@ -453,11 +746,33 @@ struct StoredMappingState {
     mappings: Vec<CustomAppMapping>,
 }
 
+/// Mappings from a stored state. Version 1 only had exact matchers, which
+/// read unchanged.
+fn stored_mappings(value: &serde_json::Value) -> Option<Vec<CustomAppMapping>> {
+    let state = serde_json::from_value::<StoredMappingState>(value.clone()).ok()?;
+    (1..=MAPPING_STORE_VERSION)
+        .contains(&state.version)
+        .then_some(state.mappings)
+}
+
+/// The oldest version that can hold `mappings`, so older builds keep reading
+/// the store until a newer matcher or an output policy is saved.
+fn store_version_for(mappings: &[CustomAppMapping]) -> u32 {
+    if mappings
+        .iter()
+        .all(|mapping| mapping.matcher.is_exact() && mapping.output_policy.is_empty())
+    {
+        1
+    } else {
+        MAPPING_STORE_VERSION
+    }
+}
+
 #[derive(Clone)]
 pub struct UserAppMappingStore {
     app_handle: Option<tauri::AppHandle>,
     collection: Arc<RwLock<UserAppMappingCollection>>,
-    registry: AppRegistry,
+    registry: Arc<RwLock<Arc<AppRegistry>>>,
 }
 
 impl UserAppMappingStore {
@@ -466,14 +781,12 @@ impl UserAppMappingStore {
             .store(MAPPING_STORE_FILE)
             .ok()
             .and_then(|store| store.get(MAPPING_STORE_KEY))
-            .and_then(|value| serde_json::from_value::<StoredMappingState>(value.clone()).ok())
-            .filter(|state| state.version == MAPPING_STORE_VERSION)
-            .map(|state| state.mappings)
+            .and_then(|value| stored_mappings(&value))
             .unwrap_or_default();
         Self {
             app_handle: Some(app_handle),
             collection: Arc::new(RwLock::new(UserAppMappingCollection::from_stored(mappings))),
-            registry,
+            registry: Arc::new(RwLock::new(Arc::new(registry))),
         }
     }
 
@@ -482,16 +795,31 @@ impl UserAppMappingStore {
         Self {
             app_handle: None,
             collection: Arc::new(RwLock::new(UserAppMappingCollection::default())),
-            registry,
+            registry: Arc::new(RwLock::new(Arc::new(registry))),
         }
     }
 
+    fn registry(&self) -> Arc<AppRegistry> {
+        self.registry
+            .read()
+            .unwrap_or_else(|error| error.into_inner())
+            .clone()
+    }
+
+    /// Swaps in a rebuilt registry, e.g. after profile packs changed on disk.
+    pub fn replace_registry(&self, registry: AppRegistry) {
+        *self
+            .registry
+            .write()
+            .unwrap_or_else(|error| error.into_inner()) = Arc::new(registry);
+    }
+
     pub(crate) fn resolve(&self, signals: &ContextSignals) -> ResolvedProfile {
         let collection = self
             .collection
             .read()
             .unwrap_or_else(|error| error.into_inner());
-        resolve_profile(&self.registry, &collection, signals)
+        resolve_profile(&self.registry(), &collection, signals)
     }
 
     pub(crate) fn has_match(&self, signals: &ContextSignals) -> bool {
@@ -525,6 +853,19 @@ impl UserAppMappingStore {
             .map(|mapping| self.mapping_view(&mapping))
     }
 
+    pub fn save_pattern(
+        &self,
+        matcher_type: UserAppMatcherType,
+        value: &str,
+        label: &str,
+        family: ContextFamily,
+        scene_id: Option<String>,
+    ) -> Result<CustomAppMappingView, String> {
+        let matcher = UserAppMatcher::new(matcher_type, value.to_string());
+        self.mutate(|collection| collection.save_matcher(matcher, label, family, scene_id))
+            .map(|mapping| self.mapping_view(&mapping))
+    }
+
     pub fn update(
         &self,
         id: &str,
@@ -541,6 +882,15 @@ impl UserAppMappingStore {
         self.mutate(|collection| collection.set_enabled(id, enabled))
     }
 
+    pub fn set_output_policy(
+        &self,
+        id: &str,
+        output_policy: AppOutputPolicy,
+    ) -> Result<CustomAppMappingView, String> {
+        self.mutate(|collection| collection.set_output_policy(id, output_policy))
+            .map(|mapping| self.mapping_view(&mapping))
+    }
+
     pub fn delete(&self, id: &str) -> Result<(), String> {
         self.mutate(|collection| collection.delete(id))
     }
@@ -554,7 +904,7 @@ impl UserAppMappingStore {
 
     fn mapping_view(&self, mapping: &CustomAppMapping) -> CustomAppMappingView {
         let signals = signals_for_matcher(&mapping.matcher);
-        let base = self.registry.classify(&signals);
+        let base = self.registry().classify(&signals);
         CustomAppMappingView {
             id: mapping.id.clone(),
             label: mapping.label.clone(),
@@ -564,6 +914,7 @@ impl UserAppMappingStore {
             scene_id: mapping.scene_id.clone(),
             enabled: mapping.enabled,
             icon_key: base.icon_key,
+            output_policy: mapping.output_policy.clone(),
         }
     }
 
@@ -592,7 +943,7 @@ impl UserAppMappingStore {
             .store(MAPPING_STORE_FILE)
             .map_err(|_| "custom_app_mapping_store_unavailable".to_string())?;
         let value = serde_json::to_value(StoredMappingState {
-            version: MAPPING_STORE_VERSION,
+            version: store_version_for(&collection.mappings),
             mappings: collection.mappings.clone(),
         })
         .map_err(|_| "custom_app_mapping_store_invalid".to_string())?;
@@ -610,6 +961,11 @@ fn signals_for_matcher(matcher: &UserAppMatcher) -> ContextSignals {
             is_supported_browser: true,
             ..ContextSignals::default()
         },
+        UserAppMatcher::WebHostSuffix(suffix) => ContextSignals {
+            browser_host: Some(suffix.clone()),
+            is_supported_browser: true,
+            ..ContextSignals::default()
+        },
         UserAppMatcher::NativeBundleId(identity) | UserAppMatcher::NativeExecutable(identity) => {
             ContextSignals {
                 native_identity: Some(identity.clone()),
@@ -617,6 +973,9 @@ fn signals_for_matcher(matcher: &UserAppMatcher) -> ContextSignals {
                 ..ContextSignals::default()
             }
         }
+        UserAppMatcher::ExecutableGlob(_) | UserAppMatcher::WindowTitlePattern(_) => {
+            ContextSignals::default()
+        }
     }
 }
 
@@ -772,6 +1131,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn user_app_mapping_output_policy_overrides_the_builtin_profile_policy() {
+        let registry = crate::app_detector::registry::AppRegistry::builtin().unwrap();
+        let mut collection = UserAppMappingCollection::default();
+        let signals = ContextSignals {
+            process_alias: Some("kitty".to_string()),
+            ..native_signals("kitty", "~")
+        };
+
+        let builtin = resolve_profile(&registry, &collection, &signals);
+        assert_eq!(builtin.profile.id, "code.terminal");
+        assert_eq!(
+            builtin.output_policy.paste_shortcut.as_deref(),
+            Some("ctrlShiftV")
+        );
+
+        let mapping = collection
+            .save_matcher(
+                UserAppMatcher::NativeExecutable("kitty".to_string()),
+                "Kitty",
+                ContextFamily::PromptOrCode,
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            collection.set_output_policy(
+                &mapping.id,
+                AppOutputPolicy {
+                    insertion_strategy: Some("typeEverything".to_string()),
+                    ..AppOutputPolicy::default()
+                },
+            ),
+            Err("custom_app_mapping_invalid_output_policy".to_string())
+        );
+        collection
+            .set_output_policy(
+                &mapping.id,
+                AppOutputPolicy {
+                    insertion_strategy: Some("keyboard".to_string()),
+                    ..AppOutputPolicy::default()
+                },
+            )
+            .unwrap();
+
+        let resolved = resolve_profile(&registry, &collection, &signals);
+        assert_eq!(
+            resolved.output_policy,
+            AppOutputPolicy {
+                insertion_strategy: Some("keyboard".to_string()),
+                paste_shortcut: Some("ctrlShiftV".to_string()),
+                newline_mode: None,
+                streaming_insert_enabled: Some(false),
+            }
+        );
+    }
+
     #[test]
     fn user_app_mapping_views_never_serialize_raw_matcher_material() {
         let registry = crate::app_detector::registry::AppRegistry::builtin().unwrap();
@@ -797,6 +1212,204 @@ mod tests {
         assert!(!serialized.to_string().contains("com.example.private"));
     }
 
+    #[test]
+    fn user_app_mapping_normalizes_patterns_and_rejects_broad_ones() {
+        for suffix in ["atlassian.net", ".Atlassian.net", " *.atlassian.net. "] {
+            assert_eq!(
+                normalize_matcher(UserAppMatcher::WebHostSuffix(suffix.to_string())).unwrap(),
+                UserAppMatcher::WebHostSuffix("atlassian.net".to_string())
+            );
+        }
+        for rejected in [
+            "com",
+            "*.com",
+            "10.0.0.1",
+            "*.*.example.com",
+            "atlassian.net/wiki",
+        ] {
+            assert!(
+                normalize_matcher(UserAppMatcher::WebHostSuffix(rejected.to_string())).is_err()
+            );
+        }
+        assert_eq!(
+            normalize_matcher(UserAppMatcher::ExecutableGlob(" Code-*.EXE ".to_string())).unwrap(),
+            UserAppMatcher::ExecutableGlob("code-*.exe".to_string())
+        );
+        assert_eq!(
+            normalize_matcher(UserAppMatcher::WindowTitlePattern(
+                "*  OpenTypeless\t— Zsh*".to_string()
+            ))
+            .unwrap(),
+            UserAppMatcher::WindowTitlePattern("* opentypeless — zsh*".to_string())
+        );
+        assert!(normalize_matcher(UserAppMatcher::ExecutableGlob("bin/*".to_string())).is_err());
+        for broad in ["*", "?*?", "*ab*"] {
+            assert_eq!(
+                normalize_matcher(UserAppMatcher::WindowTitlePattern(broad.to_string())),
+                Err("custom_app_mapping_pattern_too_broad".to_string())
+            );
+        }
+
+        assert!(glob_matches("*-electron", "slack-electron"));
+        assert!(glob_matches("code?insiders*", "code-insiders.exe"));
+        assert!(!glob_matches("code*", "vscode"));
+        assert!(globs_overlap("*code*", "code*"));
+        assert!(globs_overlap("a?c*", "*bc"));
+        assert!(!globs_overlap("code*", "slack*"));
+    }
+
+    #[test]
+    fn user_app_mapping_prefers_the_most_specific_matcher() {
+        let mut collection = UserAppMappingCollection::default();
+        for (matcher, label) in [
+            (
+                UserAppMatcher::WebHostSuffix("atlassian.net".to_string()),
+                "Atlassian",
+            ),
+            (
+                UserAppMatcher::WebHostSuffix("acme.atlassian.net".to_string()),
+                "Acme",
+            ),
+            (
+                UserAppMatcher::ExactWebHost("status.acme.atlassian.net".to_string()),
+                "Status",
+            ),
+            (
+                UserAppMatcher::ExecutableGlob("*term*".to_string()),
+                "Terminals",
+            ),
+            (
+                UserAppMatcher::NativeExecutable("wezterm".to_string()),
+                "WezTerm",
+            ),
+            (
+                UserAppMatcher::WindowTitlePattern("*opentypeless*".to_string()),
+                "Repo",
+            ),
+        ] {
+            collection
+                .save_matcher(matcher, label, ContextFamily::General, None)
+                .unwrap();
+        }
+        let label = |signals: &ContextSignals| {
+            collection
+                .find_match(signals)
+                .map(|mapping| mapping.label.clone())
+        };
+
+        assert_eq!(
+            label(&browser_signals("jira.atlassian.net")).as_deref(),
+            Some("Atlassian")
+        );
+        assert_eq!(
+            label(&browser_signals("acme.atlassian.net")).as_deref(),
+            Some("Acme")
+        );
+        assert_eq!(
+            label(&browser_signals("wiki.acme.atlassian.net")).as_deref(),
+            Some("Acme")
+        );
+        assert_eq!(
+            label(&browser_signals("status.acme.atlassian.net")).as_deref(),
+            Some("Status")
+        );
+        assert_eq!(label(&browser_signals("evilatlassian.net")), None);
+        assert_eq!(
+            label(&native_signals("xterm", "~")).as_deref(),
+            Some("Terminals")
+        );
+        assert_eq!(
+            label(&native_signals("wezterm", "~")).as_deref(),
+            Some("WezTerm")
+        );
+        assert_eq!(
+            label(&native_signals("wezterm", "~/src/OpenTypeless — zsh")).as_deref(),
+            Some("Repo")
+        );
+    }
+
+    #[test]
+    fn user_app_mapping_rejects_patterns_that_tie_with_an_existing_one() {
+        let mut collection = UserAppMappingCollection::default();
+        collection
+            .save_matcher(
+                UserAppMatcher::ExecutableGlob("*code*".to_string()),
+                "Code",
+                ContextFamily::PromptOrCode,
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(
+            collection.save_matcher(
+                UserAppMatcher::ExecutableGlob("code*".to_string()),
+                "Code again",
+                ContextFamily::Document,
+                None,
+            ),
+            Err("custom_app_mapping_conflict".to_string())
+        );
+        for matcher in [
+            UserAppMatcher::ExecutableGlob("team?".to_string()),
+            UserAppMatcher::ExecutableGlob("*code-x*".to_string()),
+            UserAppMatcher::WindowTitlePattern("*code*".to_string()),
+        ] {
+            collection
+                .save_matcher(matcher, "Other", ContextFamily::General, None)
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn user_app_mapping_store_stays_at_version_one_until_a_new_matcher_is_saved() {
+        let mapping = serde_json::json!({
+            "id": "0b6f3f9e-8f3c-4d59-9a55-0f7c8b3f5a11",
+            "label": "Docs",
+            "matcher": { "type": "exact_web_host", "value": "docs.example.com" },
+            "family": "document",
+            "scene_id": null,
+            "enabled": true
+        });
+        let mut mappings =
+            stored_mappings(&serde_json::json!({ "version": 1, "mappings": [mapping] })).unwrap();
+        assert_eq!(
+            mappings[0].matcher,
+            UserAppMatcher::ExactWebHost("docs.example.com".to_string())
+        );
+        assert_eq!(store_version_for(&mappings), 1);
+
+        mappings[0].matcher = UserAppMatcher::WebHostSuffix("example.com".to_string());
+        assert_eq!(store_version_for(&mappings), MAPPING_STORE_VERSION);
+
+        assert!(stored_mappings(&serde_json::json!({ "version": 2, "mappings": [] })).is_some());
+        assert!(stored_mappings(&serde_json::json!({ "version": 3, "mappings": [] })).is_none());
+    }
+
+    #[test]
+    fn user_app_mapping_store_moves_to_version_two_when_an_output_policy_is_saved() {
+        let mut mappings = vec![CustomAppMapping {
+            id: Uuid::new_v4().to_string(),
+            label: "Docs".to_string(),
+            matcher: UserAppMatcher::ExactWebHost("docs.example.com".to_string()),
+            family: ContextFamily::Document,
+            scene_id: None,
+            enabled: true,
+            output_policy: AppOutputPolicy::default(),
+        }];
+        assert_eq!(store_version_for(&mappings), 1);
+
+        mappings[0].output_policy.paste_shortcut = Some("ctrlShiftV".to_string());
+        assert_eq!(store_version_for(&mappings), MAPPING_STORE_VERSION);
+    }
+
+    fn native_signals(executable: &str, title: &str) -> ContextSignals {
+        ContextSignals {
+            native_identity: Some(executable.to_string()),
+            window_title: Some(title.to_string()),
+            ..ContextSignals::default()
+        }
+    }
+
     fn browser_signals(host: &str) -> ContextSignals {
         ContextSignals {
             browser_host: Some(host.to_string()),
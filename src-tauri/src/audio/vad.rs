@@ -0,0 +1,248 @@
+//! Energy-based voice activity detection over 16-bit mono PCM.
+//!
+//! Runs on every capture chunk regardless of STT provider, so speech
+//! boundaries and silence auto-stop behave the same for streaming and
+//! file-upload backends.
+
+/// Never treat audio quieter than this as speech, whatever the noise floor.
+const MIN_SPEECH_DBFS: f32 = -50.0;
+/// Speech must rise this far above the tracked noise floor.
+const NOISE_MARGIN_DB: f32 = 10.0;
+/// Caps the first-frame noise estimate so a user who starts talking
+/// immediately is not mistaken for background noise.
+const INITIAL_NOISE_FLOOR_CAP_DBFS: f32 = -45.0;
+const NOISE_FLOOR_RISE_RATE: f32 = 0.05;
+/// Voiced audio needed before speech is reported, to ignore clicks.
+const SPEECH_START_MS: u32 = 60;
+/// Unvoiced audio needed before speech is reported as ended.
+const SPEECH_HANGOVER_MS: u32 = 400;
+const TRIM_FRAME_MS: u32 = 20;
+const TRIM_PADDING_MS: u32 = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VadEvent {
+    SpeechStarted,
+    SpeechEnded,
+}
+
+#[derive(Debug)]
+pub struct VoiceActivityDetector {
+    sample_rate: u32,
+    noise_floor_dbfs: Option<f32>,
+    voiced_ms: u32,
+    unvoiced_ms: u32,
+    in_speech: bool,
+    heard_speech: bool,
+}
+
+impl VoiceActivityDetector {
+    pub fn new(sample_rate: u32) -> Self {
+        Self {
+            sample_rate,
+            noise_floor_dbfs: None,
+            voiced_ms: 0,
+            unvoiced_ms: 0,
+            in_speech: false,
+            heard_speech: false,
+        }
+    }
+
+    /// Feeds one capture chunk and returns a speech boundary if one was crossed.
+    pub fn push(&mut self, pcm: &[u8]) -> Option<VadEvent> {
+        let duration_ms = pcm_duration_ms(pcm, self.sample_rate);
+        if duration_ms == 0 {
+            return None;
+        }
+        let level = frame_dbfs(pcm);
+        let floor = *self
+            .noise_floor_dbfs
+            .get_or_insert(level.min(INITIAL_NOISE_FLOOR_CAP_DBFS));
+        let voiced = level >= speech_threshold(floor);
+
+        if voiced {
+            self.voiced_ms = self.voiced_ms.saturating_add(duration_ms);
+            self.unvoiced_ms = 0;
+        } else {
+            self.unvoiced_ms = self.unvoiced_ms.saturating_add(duration_ms);
+            self.voiced_ms = 0;
+            let next_floor = if level < floor {
+                level
+            } else {
+                floor + (level - floor) * NOISE_FLOOR_RISE_RATE
+            };
+            self.noise_floor_dbfs = Some(next_floor);
+        }
+
+        if !self.in_speech && self.voiced_ms >= SPEECH_START_MS {
+            self.in_speech = true;
+            self.heard_speech = true;
+            return Some(VadEvent::SpeechStarted);
+        }
+        if self.in_speech && self.unvoiced_ms >= SPEECH_HANGOVER_MS {
+            self.in_speech = false;
+            return Some(VadEvent::SpeechEnded);
+        }
+        None
+    }
+
+    /// Silence since the last speech, or `None` while speaking or before any
+    /// speech was heard.
+    pub fn silence_after_speech_ms(&self) -> Option<u32> {
+        (self.heard_speech && self.voiced_ms == 0).then_some(self.unvoiced_ms)
+    }
+}
+
+/// Whether a recording with `silence_after_speech_ms` of trailing silence
+/// should stop under the given auto-stop threshold.
+pub fn should_auto_stop(silence_after_speech_ms: Option<u32>, threshold_ms: Option<u32>) -> bool {
+    match (silence_after_speech_ms, threshold_ms) {
+        (Some(silence), Some(threshold)) => threshold > 0 && silence >= threshold,
+        _ => false,
+    }
+}
+
+/// Drops leading and trailing silence from a recording, keeping a short pad
+/// around the speech. Audio with no detectable speech is returned unchanged so
+/// the provider still decides what it heard.
+pub fn trim_silence(pcm: &[u8], sample_rate: u32) -> &[u8] {
+    let frame_bytes = ((sample_rate * TRIM_FRAME_MS / 1000) as usize * 2).max(2);
+    let levels: Vec<f32> = pcm.chunks(frame_bytes).map(frame_dbfs).collect();
+    let Some(quietest) = levels.iter().copied().reduce(f32::min) else {
+        return pcm;
+    };
+    let threshold = speech_threshold(quietest);
+    let Some(first) = levels.iter().position(|level| *level >= threshold) else {
+        return pcm;
+    };
+    let last = levels
+        .iter()
+        .rposition(|level| *level >= threshold)
+        .unwrap_or(first);
+
+    let pad_frames = (TRIM_PADDING_MS / TRIM_FRAME_MS) as usize;
+    let start = first.saturating_sub(pad_frames) * frame_bytes;
+    let end = ((last + 1 + pad_frames) * frame_bytes).min(pcm.len() & !1);
+    &pcm[start..end]
+}
+
+fn speech_threshold(noise_floor_dbfs: f32) -> f32 {
+    (noise_floor_dbfs + NOISE_MARGIN_DB).max(MIN_SPEECH_DBFS)
+}
+
+fn pcm_duration_ms(pcm: &[u8], sample_rate: u32) -> u32 {
+    if sample_rate == 0 {
+        return 0;
+    }
+    ((pcm.len() / 2) as u64 * 1000 / u64::from(sample_rate)) as u32
+}
+
+fn frame_dbfs(pcm: &[u8]) -> f32 {
+    let mut sum = 0.0f64;
+    let mut count = 0usize;
+    for sample in pcm.chunks_exact(2) {
+        let value = f64::from(i16::from_le_bytes([sample[0], sample[1]])) / 32_768.0;
+        sum += value * value;
+        count += 1;
+    }
+    if count == 0 {
+        return f32::NEG_INFINITY;
+    }
+    let rms = (sum / count as f64).sqrt();
+    if rms <= 0.0 {
+        return f32::NEG_INFINITY;
+    }
+    (20.0 * rms.log10()) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 16_000;
+
+    /// 20 ms chunk of a square wave at `amplitude`.
+    fn chunk(amplitude: i16) -> Vec<u8> {
+        (0..320)
+            .flat_map(|i| {
+                let sample = if i % 16 < 8 { amplitude } else { -amplitude };
+                sample.to_le_bytes()
+            })
+            .collect()
+    }
+
+    fn feed(vad: &mut VoiceActivityDetector, amplitude: i16, chunks: usize) -> Vec<VadEvent> {
+        (0..chunks)
+            .filter_map(|_| vad.push(&chunk(amplitude)))
+            .collect()
+    }
+
+    #[test]
+    fn reports_speech_boundaries_with_hangover() {
+        let mut vad = VoiceActivityDetector::new(RATE);
+
+        assert!(feed(&mut vad, 30, 25).is_empty());
+        assert_eq!(vad.silence_after_speech_ms(), None);
+        assert!(feed(&mut vad, 8_000, 2).is_empty());
+        assert_eq!(feed(&mut vad, 8_000, 1), vec![VadEvent::SpeechStarted]);
+        assert!(feed(&mut vad, 8_000, 20).is_empty());
+        assert_eq!(vad.silence_after_speech_ms(), None);
+
+        assert!(feed(&mut vad, 30, 19).is_empty());
+        assert_eq!(vad.silence_after_speech_ms(), Some(380));
+        assert_eq!(feed(&mut vad, 30, 1), vec![VadEvent::SpeechEnded]);
+        assert!(feed(&mut vad, 30, 30).is_empty());
+        assert_eq!(vad.silence_after_speech_ms(), Some(1_000));
+    }
+
+    #[test]
+    fn short_clicks_and_steady_background_noise_are_not_speech() {
+        let mut vad = VoiceActivityDetector::new(RATE);
+
+        assert!(feed(&mut vad, 8_000, 2).is_empty());
+        assert!(feed(&mut vad, 30, 10).is_empty());
+        assert_eq!(feed(&mut vad, 800, 3), vec![VadEvent::SpeechStarted]);
+
+        // Steady hiss raises the noise floor, so the same quiet voice no longer counts.
+        let mut noisy = VoiceActivityDetector::new(RATE);
+        assert!(feed(&mut noisy, 300, 200).is_empty());
+        assert!(feed(&mut noisy, 800, 10).is_empty());
+        assert_eq!(feed(&mut noisy, 8_000, 3), vec![VadEvent::SpeechStarted]);
+    }
+
+    #[test]
+    fn auto_stop_requires_speech_and_a_positive_threshold() {
+        assert!(should_auto_stop(Some(1_500), Some(1_500)));
+        assert!(!should_auto_stop(Some(1_480), Some(1_500)));
+        assert!(!should_auto_stop(None, Some(1_500)));
+        assert!(!should_auto_stop(Some(5_000), None));
+        assert!(!should_auto_stop(Some(5_000), Some(0)));
+    }
+
+    #[test]
+    fn trim_silence_keeps_padding_around_speech() {
+        let silence = chunk(0);
+        let speech = chunk(8_000);
+        let mut pcm = Vec::new();
+        for _ in 0..50 {
+            pcm.extend_from_slice(&silence);
+        }
+        for _ in 0..10 {
+            pcm.extend_from_slice(&speech);
+        }
+        for _ in 0..50 {
+            pcm.extend_from_slice(&silence);
+        }
+
+        let trimmed = trim_silence(&pcm, RATE);
+        let chunk_bytes = silence.len();
+        assert_eq!(trimmed.len(), (10 + 2 * 10) * chunk_bytes);
+        assert_eq!(
+            &trimmed[10 * chunk_bytes..11 * chunk_bytes],
+            speech.as_slice()
+        );
+
+        let all_silence = [0u8; 6_400];
+        assert_eq!(trim_silence(&all_silence, RATE).len(), all_silence.len());
+        assert!(trim_silence(&[], RATE).is_empty());
+    }
+}
@@ -1,6 +1,6 @@
-use crate::app_detector::types::ContextFamily;
+use crate::app_detector::types::{AppOutputPolicy, ContextFamily};
 use crate::app_detector::user_mappings::{
-    CustomAppMappingView, MappingCandidateView, UserAppMappingStore,
+    CustomAppMappingView, MappingCandidateView, UserAppMappingStore, UserAppMatcherType,
 };
 use crate::app_detector::ContextDetectorHandle;
 use crate::storage::{AppConfig, FamilySceneAssignment};
@@ -10,12 +10,21 @@ use tauri::Emitter;
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SaveCustomAppMappingInput {
-    pub candidate_generation: u64,
+    pub candidate_generation: Option<u64>,
+    /// Replaces the candidate's exact matcher, e.g. to cover a whole domain.
+    pub pattern: Option<MappingPatternInput>,
     pub label: String,
     pub family: ContextFamily,
     pub scene_id: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MappingPatternInput {
+    pub matcher_type: UserAppMatcherType,
+    pub value: String,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateCustomAppMappingInput {
@@ -92,11 +101,25 @@ pub async fn save_custom_app_mapping(
         .await
         .map_err(|error| error.to_string())?;
     let scene_id = validate_optional_scene_id(&config, input.scene_id)?;
-    let candidate = detector
-        .mapping_candidate_for_generation(input.candidate_generation)
-        .ok_or_else(|| "custom_app_mapping_candidate_expired".to_string())?;
-    let mapping = mapping_store.save_candidate(&candidate, &input.label, input.family, scene_id)?;
-    detector.clear_mapping_candidate(input.candidate_generation);
+    let mapping = match input.pattern {
+        Some(pattern) => mapping_store.save_pattern(
+            pattern.matcher_type,
+            &pattern.value,
+            &input.label,
+            input.family,
+            scene_id,
+        )?,
+        None => {
+            let candidate = input
+                .candidate_generation
+                .and_then(|generation| detector.mapping_candidate_for_generation(generation))
+                .ok_or_else(|| "custom_app_mapping_candidate_expired".to_string())?;
+            mapping_store.save_candidate(&candidate, &input.label, input.family, scene_id)?
+        }
+    };
+    if let Some(generation) = input.candidate_generation {
+        detector.clear_mapping_candidate(generation);
+    }
     detector.notify_focus_changed();
     Ok(mapping)
 }
@@ -136,6 +159,18 @@ pub fn set_custom_app_mapping_enabled(
     Ok(())
 }
 
+#[tauri::command]
+pub fn set_custom_app_mapping_output_policy(
+    detector: tauri::State<'_, ContextDetectorHandle>,
+    mapping_store: tauri::State<'_, UserAppMappingStore>,
+    id: String,
+    output_policy: AppOutputPolicy,
+) -> Result<CustomAppMappingView, String> {
+    let mapping = mapping_store.set_output_policy(id.trim(), output_policy)?;
+    detector.notify_focus_changed();
+    Ok(mapping)
+}
+
 #[tauri::command]
 pub fn delete_custom_app_mapping(
     detector: tauri::State<'_, ContextDetectorHandle>,
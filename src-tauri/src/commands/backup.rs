@@ -1,12 +1,11 @@
 use crate::app_detector::types::{BrowserAccessStatus, ContextFamily};
+use crate::corrections::{AppliedCorrection, MAX_LOCAL_CORRECTION_RULES};
 use crate::storage::{
     self, CorrectionRule, DictionaryEntry, HistoryEntry, HistoryProviderKind,
-    DEFAULT_HISTORY_MAX_ENTRIES,
+    DEFAULT_HISTORY_MAX_ENTRIES, MAX_HISTORY_TEXT_CHARS,
 };
 use serde::{Deserialize, Serialize};
 
-const MAX_HISTORY_TEXT_CHARS: usize = 1_000_000;
-
 #[derive(Debug, Deserialize)]
 pub struct BackupHistoryEntry {
     #[serde(default)]
@@ -45,6 +44,16 @@ pub struct BackupHistoryEntry {
     output_status: Option<String>,
     #[serde(default)]
     output_error: Option<String>,
+    #[serde(default)]
+    applied_corrections: Vec<AppliedCorrection>,
+    #[serde(default)]
+    pinned: bool,
+    #[serde(default)]
+    stt_provider: Option<String>,
+    #[serde(default)]
+    llm_provider: Option<String>,
+    #[serde(default)]
+    scene_rule_id: Option<String>,
     // Pre-context backups used app_name instead of a normalized context label.
     #[serde(default)]
     app_name: Option<String>,
@@ -127,6 +136,25 @@ impl BackupHistoryEntry {
                 2_000,
                 "backup_history_output_error",
             )?,
+            applied_corrections: validated_applied_corrections(self.applied_corrections)?,
+            pinned: self.pinned,
+            // Ids are reassigned on restore, so regeneration links are dropped.
+            parent_id: None,
+            stt_provider: optional_backup_string(
+                self.stt_provider,
+                100,
+                "backup_history_stt_provider",
+            )?,
+            llm_provider: optional_backup_string(
+                self.llm_provider,
+                100,
+                "backup_history_llm_provider",
+            )?,
+            scene_rule_id: optional_backup_string(
+                self.scene_rule_id,
+                200,
+                "backup_history_scene_rule_id",
+            )?,
         })
     }
 }
@@ -274,6 +302,36 @@ fn normalize_timestamp(value: Option<String>, fallback: &str) -> Result<String,
         .map_err(|_| "backup_history_created_at_invalid".to_string())
 }
 
+fn validated_applied_corrections(
+    corrections: Vec<AppliedCorrection>,
+) -> Result<Vec<AppliedCorrection>, String> {
+    // Each rule can fire once per stage, so a valid entry never has more than
+    // two records per enabled rule.
+    if corrections.len() > MAX_LOCAL_CORRECTION_RULES * 2 {
+        return Err("backup_history_applied_corrections_too_large".to_string());
+    }
+    corrections
+        .into_iter()
+        .map(|correction| {
+            Ok(AppliedCorrection {
+                pattern: validated_backup_string(
+                    correction.pattern,
+                    120,
+                    "backup_history_correction_pattern",
+                    false,
+                )?,
+                replacement: validated_backup_string(
+                    correction.replacement,
+                    120,
+                    "backup_history_correction_replacement",
+                    false,
+                )?,
+                ..correction
+            })
+        })
+        .collect()
+}
+
 fn optional_backup_string(
     value: Option<String>,
     max_chars: usize,
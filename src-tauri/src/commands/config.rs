@@ -6,7 +6,7 @@ use crate::HotkeyRegistrationError;
 use crate::HotkeyRoleCache;
 use crate::SessionTokenStore;
 use serde_json::{json, Map, Value};
-use tauri::Emitter;
+use tauri::{Emitter, Manager};
 
 fn config_patch_between(previous: &storage::AppConfig, next: &storage::AppConfig) -> Value {
     let mut patch = Map::new();
@@ -67,9 +67,22 @@ fn prepare_config_for_save(mut config: storage::AppConfig) -> Result<storage::Ap
     config.normalize_values();
     config.clamp_recording_limit_intent_for_save();
     crate::hotkey::validate_hotkey_config(&config.hotkeys).map_err(|e| e.to_string())?;
+    if !config.knowledge_embedding_base_url.is_empty()
+        && !crate::knowledge::embeddings::is_allowed_base_url(&config.knowledge_embedding_base_url)
+    {
+        return Err("knowledge_embedding_base_url_invalid".to_string());
+    }
     Ok(config)
 }
 
+/// Whether the background knowledge indexer should rescan right away.
+fn knowledge_config_changed(previous: &storage::AppConfig, next: &storage::AppConfig) -> bool {
+    previous.knowledge_base_enabled != next.knowledge_base_enabled
+        || previous.knowledge_base_folder != next.knowledge_base_folder
+        || previous.knowledge_embedding_base_url != next.knowledge_embedding_base_url
+        || previous.knowledge_embedding_model != next.knowledge_embedding_model
+}
+
 fn sync_hotkey_fields_before_save(config: &mut storage::AppConfig) {
     let default = storage::AppConfig::default();
     let typed_was_changed = config.hotkeys != default.hotkeys;
@@ -272,6 +285,11 @@ pub async fn update_config(
 
     update_runtime_caches(&cache, &ask_cache, &role_cache, &close_tray_cache, &config);
     emit_config_patch(&app, &patch);
+    if knowledge_config_changed(&previous, &config) {
+        if let Some(indexer) = app.try_state::<crate::knowledge::KnowledgeIndexer>() {
+            indexer.request_refresh();
+        }
+    }
     if patch.get("ui_language").is_some() || patch.get("capsule_auto_hide").is_some() {
         crate::refresh_tray(&app);
     }
@@ -443,6 +461,32 @@ mod tests {
         assert_eq!(prepared.hotkey_mode, "toggle");
     }
 
+    #[test]
+    fn prepare_config_for_save_rejects_remote_plain_http_embeddings() {
+        let config = storage::AppConfig {
+            knowledge_embedding_base_url: "http://embeddings.example/v1".to_string(),
+            ..storage::AppConfig::default()
+        };
+        assert_eq!(
+            prepare_config_for_save(config).unwrap_err(),
+            "knowledge_embedding_base_url_invalid"
+        );
+
+        let config = storage::AppConfig {
+            knowledge_embedding_base_url: " http://localhost:11434/v1 ".to_string(),
+            ..storage::AppConfig::default()
+        };
+        let prepared = prepare_config_for_save(config).unwrap();
+        assert_eq!(
+            prepared.knowledge_embedding_base_url,
+            "http://localhost:11434/v1"
+        );
+        assert!(knowledge_config_changed(
+            &storage::AppConfig::default(),
+            &prepared
+        ));
+    }
+
     #[test]
     fn prepare_config_for_save_preserves_disabled_ask_hotkey() {
         let mut config = storage::AppConfig::default();
@@ -153,7 +153,10 @@ fn validate_credential_target(
     let namespace = namespace.trim().to_string();
     let provider = provider.trim().to_string();
 
-    if namespace != "stt" && namespace != "llm" {
+    if !matches!(
+        namespace.as_str(),
+        "stt" | "llm" | crate::knowledge::EMBEDDING_CREDENTIAL_NAMESPACE
+    ) {
         return Err(format!("unknown credential namespace: {namespace}"));
     }
     if provider.is_empty() {
@@ -237,6 +240,9 @@ mod tests {
             validate_credential_target(" stt ".to_string(), "custom-whisper".to_string()).unwrap(),
             ("stt".to_string(), "custom-whisper".to_string())
         );
+        assert!(
+            validate_credential_target("knowledge".to_string(), "embeddings".to_string()).is_ok()
+        );
     }
 
     #[test]
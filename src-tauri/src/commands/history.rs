@@ -1,4 +1,14 @@
-use crate::storage;
+use crate::app_detector::types::{ContextProfileSummary, TargetAppGuard};
+use crate::app_detector::ContextDetectorHandle;
+use crate::corrections::{apply_correction_rules, CorrectionStage};
+use crate::credentials::{resolve_llm_config_secret, SystemCredentialVault};
+use crate::llm::{self, PolishRequest};
+use crate::output;
+use crate::storage::{self, ActiveScene, AppConfig, HistoryEntry};
+use crate::SessionTokenStore;
+use serde::{Deserialize, Serialize};
+
+const MAX_REPOLISH_MODEL_CHARS: usize = 200;
 
 #[tauri::command]
 pub async fn get_history(
@@ -10,6 +20,570 @@ pub async fn get_history(
 }
 
 #[tauri::command]
-pub async fn clear_history(state: tauri::State<'_, storage::HistoryStore>) -> Result<(), String> {
-    state.clear().await.map_err(|e| e.to_string())
+pub async fn search_history(
+    state: tauri::State<'_, storage::HistoryStore>,
+    query: storage::HistorySearchQuery,
+) -> Result<storage::HistorySearchPage, String> {
+    state.search(&query).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn delete_history_entry(
+    state: tauri::State<'_, storage::HistoryStore>,
+    id: i64,
+) -> Result<(), String> {
+    state.delete(id).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn delete_history_matching(
+    state: tauri::State<'_, storage::HistoryStore>,
+    query: storage::HistorySearchQuery,
+) -> Result<usize, String> {
+    state
+        .delete_matching(&query)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn set_history_entry_pinned(
+    state: tauri::State<'_, storage::HistoryStore>,
+    id: i64,
+    pinned: bool,
+) -> Result<(), String> {
+    state
+        .set_pinned(id, pinned)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn update_history_polished_text(
+    state: tauri::State<'_, storage::HistoryStore>,
+    id: i64,
+    polished_text: String,
+) -> Result<storage::HistoryEntry, String> {
+    state
+        .update_polished_text(id, &polished_text)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn clear_history(
+    state: tauri::State<'_, storage::HistoryStore>,
+    ask_threads: tauri::State<'_, storage::AskThreadStore>,
+    ask_session: tauri::State<'_, crate::commands::ask::AskSessionState>,
+) -> Result<(), String> {
+    state.clear().await.map_err(|e| e.to_string())?;
+    ask_threads.clear().await.map_err(|e| e.to_string())?;
+    ask_session.clear();
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn list_history_regenerations(
+    state: tauri::State<'_, storage::HistoryStore>,
+    id: i64,
+) -> Result<Vec<storage::HistoryEntry>, String> {
+    state.regenerations(id).await.map_err(|e| e.to_string())
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RepolishOutput {
+    #[default]
+    None,
+    Insert,
+    Copy,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepolishHistoryInput {
+    pub id: i64,
+    /// `None` keeps the entry's scene; an empty string polishes without one.
+    #[serde(default)]
+    pub scene_id: Option<String>,
+    #[serde(default)]
+    pub polish_style: Option<String>,
+    #[serde(default)]
+    pub llm_model: Option<String>,
+    #[serde(default)]
+    pub output: RepolishOutput,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepolishHistoryResult {
+    pub polished_text: String,
+    /// The stored child entry, absent when history is disabled.
+    pub entry: Option<HistoryEntry>,
+    pub insert_result: Option<output::InsertResult>,
+}
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn repolish_history_entry(
+    app: tauri::AppHandle,
+    history: tauri::State<'_, storage::HistoryStore>,
+    dictionary: tauri::State<'_, storage::DictionaryStore>,
+    config_state: tauri::State<'_, storage::ConfigManager>,
+    token_store: tauri::State<'_, SessionTokenStore>,
+    client: tauri::State<'_, reqwest::Client>,
+    detector: tauri::State<'_, ContextDetectorHandle>,
+    input: RepolishHistoryInput,
+) -> Result<RepolishHistoryResult, String> {
+    // Captured before the model call so focus moving meanwhile cannot
+    // redirect the insert.
+    let insert_target = if input.output == RepolishOutput::Insert {
+        repolish_insert_target(detector.last_external_target(), std::process::id())
+    } else {
+        None
+    };
+    let parent = history
+        .get(input.id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "history_entry_not_found".to_string())?;
+    let config = config_state.load().await.map_err(|e| e.to_string())?;
+    let config = repolish_config(&config, &parent, &input)?;
+
+    let session_token = token_store
+        .0
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    let llm_api_key = if config.llm_provider == "cloud" {
+        session_token.clone()
+    } else {
+        resolve_llm_config_secret(&config, &SystemCredentialVault).map_err(|e| e.to_string())?
+    };
+    if !llm::has_usable_provider_credentials(&config.llm_provider, &llm_api_key) {
+        return Err("repolish_llm_unavailable".to_string());
+    }
+
+    let correction_rules = dictionary
+        .enabled_correction_rules()
+        .await
+        .into_iter()
+        .map(|rule| llm::CorrectionRule {
+            id: rule.id,
+            pattern: rule.pattern,
+            replacement: rule.replacement,
+            enabled: rule.enabled,
+        })
+        .collect::<Vec<_>>();
+    let transcript = apply_correction_rules(
+        &parent.raw_text,
+        &correction_rules,
+        CorrectionStage::Transcript,
+    );
+    let request = build_repolish_request(
+        &parent,
+        &config,
+        transcript.text,
+        dictionary.words().await,
+        correction_rules.clone(),
+    )?;
+
+    let chain =
+        llm::failover::build_chain(&config, llm_api_key, &session_token, &SystemCredentialVault);
+    let served =
+        llm::failover::polish_with_failover(&chain, client.inner().clone(), &request, None)
+            .await
+            .map_err(|e| e.to_string())?;
+    let polished = apply_correction_rules(
+        &served.response.polished_text,
+        &correction_rules,
+        CorrectionStage::Polished,
+    );
+    let polished_text = polished.text.trim().to_string();
+    if polished_text.is_empty() {
+        return Err("repolish_empty_result".to_string());
+    }
+
+    let (insert_result, output_status, output_error) = match repolish_output(
+        &app,
+        &detector,
+        &config,
+        &polished_text,
+        input.output,
+        insert_target,
+    )
+    .await
+    {
+        Ok(result) => (result, None, None),
+        Err(error) => {
+            tracing::warn!("Re-polish output failed: {error}");
+            (None, Some("fallback".to_string()), Some(error))
+        }
+    };
+
+    let scene = crate::pipeline::active_scene_history_diagnostics(config.active_scene.as_ref());
+    let mut applied_corrections = transcript.applied;
+    applied_corrections.extend(polished.applied);
+    let child = HistoryEntry {
+        id: 0,
+        created_at: chrono::Local::now().format("%Y-%m-%dT%H:%M:%S").to_string(),
+        provider_kind: crate::pipeline::served_history_provider_kind(
+            &config,
+            None,
+            Some(&served.provider),
+        ),
+        polished_text: polished_text.clone(),
+        active_scene_id: scene.id,
+        active_scene_source: scene.source,
+        active_scene_name: scene.name,
+        active_scene_prompt_chars: scene.prompt_chars,
+        active_scene_prompt_truncated: scene.prompt_truncated,
+        output_status,
+        output_error,
+        applied_corrections,
+        pinned: false,
+        parent_id: Some(parent.parent_id.unwrap_or(parent.id)),
+        llm_provider: Some(served.provider),
+        scene_rule_id: None,
+        ..parent
+    };
+    let entry = history
+        .insert_with_policy(child, &config.history_retention_policy())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(RepolishHistoryResult {
+        polished_text,
+        entry,
+        insert_result,
+    })
+}
+
+/// Applies the per-run overrides on top of the saved config. Re-polish is an
+/// explicit request, so it runs even when automatic polish is turned off.
+fn repolish_config(
+    config: &AppConfig,
+    parent: &HistoryEntry,
+    input: &RepolishHistoryInput,
+) -> Result<AppConfig, String> {
+    let mut config = config.clone();
+    config.polish_enabled = true;
+
+    let scene_id = match input.scene_id.as_deref() {
+        Some(scene_id) => Some(scene_id.trim()).filter(|value| !value.is_empty()),
+        None => parent.active_scene_id.as_deref(),
+    };
+    config.active_scene = match scene_id {
+        Some(scene_id) => Some(repolish_scene(&config, parent, scene_id)?),
+        None => None,
+    };
+
+    if let Some(style) = input.polish_style.as_deref() {
+        let style = style.trim();
+        if !matches!(style, "minimal" | "clean" | "structured" | "professional") {
+            return Err("repolish_invalid_polish_style".to_string());
+        }
+        config.polish_style = style.to_string();
+    }
+
+    if let Some(model) = input.llm_model.as_deref() {
+        let model = model.trim();
+        if model.is_empty() || model.chars().count() > MAX_REPOLISH_MODEL_CHARS {
+            return Err("repolish_invalid_llm_model".to_string());
+        }
+        config.llm_model = model.to_string();
+    }
+    Ok(config)
+}
+
+fn repolish_scene(
+    config: &AppConfig,
+    parent: &HistoryEntry,
+    scene_id: &str,
+) -> Result<ActiveScene, String> {
+    let prompt_template = storage::scene_prompt_for_id(config, scene_id)
+        .ok_or_else(|| "repolish_scene_not_found".to_string())?;
+    let custom = config
+        .custom_scenes
+        .iter()
+        .find(|scene| scene.id == scene_id);
+    let name = custom
+        .map(|scene| scene.name.clone())
+        .or_else(|| {
+            (parent.active_scene_id.as_deref() == Some(scene_id))
+                .then(|| parent.active_scene_name.clone())
+                .flatten()
+        })
+        .unwrap_or_else(|| scene_id.to_string());
+    Ok(ActiveScene {
+        id: scene_id.to_string(),
+        source: if custom.is_some() {
+            "custom"
+        } else {
+            "builtin"
+        }
+        .to_string(),
+        name,
+        prompt_template,
+    })
+}
+
+fn build_repolish_request(
+    parent: &HistoryEntry,
+    config: &AppConfig,
+    raw_text: String,
+    dictionary: Vec<String>,
+    correction_rules: Vec<llm::CorrectionRule>,
+) -> Result<PolishRequest, String> {
+    if raw_text.trim().is_empty() {
+        return Err("repolish_raw_text_empty".to_string());
+    }
+    let voice_intent = crate::voice_intent::VoiceIntent::from_parts(
+        crate::voice_intent::VoiceIntentKind::DictateInsert,
+        crate::voice_intent::VoiceOutputPlacement::InsertAtCursor,
+        1.0,
+        None,
+        None,
+        None,
+        None,
+    )
+    .map_err(|e| e.to_string())?;
+
+    Ok(PolishRequest {
+        raw_text,
+        context: ContextProfileSummary {
+            profile_id: parent.context_profile_id.clone(),
+            family: parent.context_family,
+            app_label: parent.context_label.clone(),
+            icon_key: parent.context_icon_key.clone(),
+            override_id: None,
+            browser_access_status: parent.browser_access_status,
+            browser_target: None,
+        },
+        dictionary,
+        correction_rules,
+        polish_style: config.polish_style.clone(),
+        mapped_scene_prompt: storage::automatic_scene_prompt(config, parent.context_family, None)
+            .unwrap_or_default(),
+        active_scene_prompt: config
+            .active_scene
+            .as_ref()
+            .map(|scene| scene.prompt_template.clone())
+            .unwrap_or_default(),
+        polish_custom_prompt: config.polish_custom_prompt.clone(),
+        translate_enabled: config.translate_enabled,
+        target_lang: config.translation.active_target.clone(),
+        selected_text: None,
+        operation_id: None,
+        voice_intent,
+    })
+}
+
+/// Re-polish is started from our own window, so the insert goes to the last
+/// app that had focus before it. Our own window never receives the insert;
+/// without another app the text is copied instead.
+fn repolish_insert_target(
+    last_external: Option<TargetAppGuard>,
+    own_process_id: u32,
+) -> Option<TargetAppGuard> {
+    last_external.filter(|guard| !guard.is_empty() && guard.process_id != Some(own_process_id))
+}
+
+async fn repolish_output(
+    app: &tauri::AppHandle,
+    detector: &ContextDetectorHandle,
+    config: &AppConfig,
+    text: &str,
+    mode: RepolishOutput,
+    insert_target: Option<TargetAppGuard>,
+) -> Result<Option<output::InsertResult>, String> {
+    let mode = match (mode, insert_target) {
+        (RepolishOutput::Insert, Some(target)) => {
+            let detector = detector.clone();
+            let restored =
+                tokio::task::spawn_blocking(move || detector.restore_target_application(&target))
+                    .await
+                    .map_err(|e| e.to_string())?;
+            if restored {
+                RepolishOutput::Insert
+            } else {
+                tracing::warn!("Re-polish insert target could not be restored; copying instead");
+                RepolishOutput::Copy
+            }
+        }
+        (RepolishOutput::Insert, None) => RepolishOutput::Copy,
+        (mode, _) => mode,
+    };
+    let (output_mode, auto_paste) = match mode {
+        RepolishOutput::None => return Ok(None),
+        RepolishOutput::Insert if config.output_mode == "clipboard" => {
+            (output::OutputMode::Clipboard, true)
+        }
+        RepolishOutput::Insert => (output::OutputMode::Keyboard, true),
+        RepolishOutput::Copy => (output::OutputMode::Clipboard, false),
+    };
+    let clipboard_options = output::clipboard::ClipboardOutputOptions {
+        restore_after_paste: auto_paste && config.restore_clipboard_after_paste,
+        paste_shortcut: output::clipboard::PasteShortcut::from_config_value(&config.paste_shortcut),
+        auto_paste,
+    };
+    let outcome = output::output_with_fallback(app, text, output_mode, clipboard_options).await?;
+    Ok(Some(outcome.insert_result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app_detector::types::{BrowserAccessStatus, ContextFamily};
+
+    fn parent_entry() -> HistoryEntry {
+        HistoryEntry {
+            id: 7,
+            created_at: "2026-07-01T00:00:00".to_string(),
+            context_profile_id: "email.gmail".to_string(),
+            context_label: "Gmail".to_string(),
+            context_icon_key: "gmail".to_string(),
+            context_family: ContextFamily::Email,
+            browser_access_status: BrowserAccessStatus::Available,
+            provider_kind: storage::HistoryProviderKind::Byok,
+            raw_text: "hi sam can we move the sync".to_string(),
+            polished_text: "Hi Sam, can we move the sync?".to_string(),
+            language: None,
+            duration_ms: Some(1_200),
+            active_scene_id: Some("builtin_meeting_notes".to_string()),
+            active_scene_source: Some("builtin".to_string()),
+            active_scene_name: Some("Meeting Notes".to_string()),
+            active_scene_prompt_chars: Some(120),
+            active_scene_prompt_truncated: false,
+            output_status: None,
+            output_error: None,
+            applied_corrections: Vec::new(),
+            pinned: false,
+            parent_id: None,
+            stt_provider: Some("groq-whisper".to_string()),
+            llm_provider: Some("openrouter".to_string()),
+            scene_rule_id: None,
+        }
+    }
+
+    fn input(scene_id: Option<&str>) -> RepolishHistoryInput {
+        RepolishHistoryInput {
+            id: 7,
+            scene_id: scene_id.map(str::to_string),
+            polish_style: None,
+            llm_model: None,
+            output: RepolishOutput::None,
+        }
+    }
+
+    #[test]
+    fn repolish_insert_goes_to_the_last_external_app_or_nowhere() {
+        let other = TargetAppGuard {
+            process_id: Some(42),
+            native_identity: Some("com.apple.mail".to_string()),
+            ..TargetAppGuard::default()
+        };
+        assert_eq!(repolish_insert_target(Some(other.clone()), 7), Some(other));
+        assert_eq!(
+            repolish_insert_target(
+                Some(TargetAppGuard {
+                    process_id: Some(7),
+                    ..TargetAppGuard::default()
+                }),
+                7
+            ),
+            None
+        );
+        assert_eq!(
+            repolish_insert_target(Some(TargetAppGuard::default()), 7),
+            None
+        );
+        assert_eq!(repolish_insert_target(None, 7), None);
+    }
+
+    #[test]
+    fn repolish_keeps_the_entry_scene_unless_overridden() {
+        let config = AppConfig::default();
+        let parent = parent_entry();
+
+        let kept = repolish_config(&config, &parent, &input(None)).unwrap();
+        let scene = kept.active_scene.unwrap();
+        assert_eq!(scene.id, "builtin_meeting_notes");
+        assert_eq!(scene.name, "Meeting Notes");
+        assert!(!scene.prompt_template.is_empty());
+        assert!(kept.polish_enabled);
+
+        let cleared = repolish_config(&config, &parent, &input(Some(" "))).unwrap();
+        assert!(cleared.active_scene.is_none());
+
+        let switched =
+            repolish_config(&config, &parent, &input(Some("builtin_support_reply"))).unwrap();
+        assert_eq!(
+            switched.active_scene.unwrap().id,
+            "builtin_support_reply".to_string()
+        );
+
+        assert_eq!(
+            repolish_config(&config, &parent, &input(Some("missing"))).unwrap_err(),
+            "repolish_scene_not_found"
+        );
+    }
+
+    #[test]
+    fn repolish_validates_style_and_model_overrides() {
+        let config = AppConfig::default();
+        let parent = parent_entry();
+        let mut overrides = input(Some(""));
+        overrides.polish_style = Some("structured".to_string());
+        overrides.llm_model = Some(" gpt-4.1-mini ".to_string());
+
+        let config_with_overrides = repolish_config(&config, &parent, &overrides).unwrap();
+        assert_eq!(config_with_overrides.polish_style, "structured");
+        assert_eq!(config_with_overrides.llm_model, "gpt-4.1-mini");
+
+        overrides.polish_style = Some("marketplace-pack".to_string());
+        assert_eq!(
+            repolish_config(&config, &parent, &overrides).unwrap_err(),
+            "repolish_invalid_polish_style"
+        );
+        overrides.polish_style = None;
+        overrides.llm_model = Some("   ".to_string());
+        assert_eq!(
+            repolish_config(&config, &parent, &overrides).unwrap_err(),
+            "repolish_invalid_llm_model"
+        );
+    }
+
+    #[test]
+    fn repolish_request_rebuilds_the_stored_context() {
+        let parent = parent_entry();
+        let config = repolish_config(&AppConfig::default(), &parent, &input(None)).unwrap();
+
+        let request = build_repolish_request(
+            &parent,
+            &config,
+            parent.raw_text.clone(),
+            vec!["Sam".to_string()],
+            Vec::new(),
+        )
+        .unwrap();
+
+        assert_eq!(request.raw_text, "hi sam can we move the sync");
+        assert_eq!(request.context.profile_id, "email.gmail");
+        assert_eq!(request.context.family, ContextFamily::Email);
+        assert_eq!(request.dictionary, vec!["Sam".to_string()]);
+        assert!(request.mapped_scene_prompt.is_empty());
+        assert!(!request.active_scene_prompt.is_empty());
+        assert!(request.selected_text.is_none());
+        assert_eq!(
+            request.voice_intent.kind,
+            crate::voice_intent::VoiceIntentKind::DictateInsert
+        );
+
+        assert_eq!(
+            build_repolish_request(&parent, &config, "  ".to_string(), Vec::new(), Vec::new())
+                .unwrap_err(),
+            "repolish_raw_text_empty"
+        );
+    }
 }
@@ -0,0 +1,39 @@
+use crate::knowledge::{self, KnowledgeStatus, KnowledgeStore};
+use crate::storage;
+use tauri::Manager;
+
+#[tauri::command]
+pub async fn get_knowledge_base_status(
+    store: tauri::State<'_, KnowledgeStore>,
+) -> Result<KnowledgeStatus, String> {
+    store.status().await.map_err(|e| e.to_string())
+}
+
+/// Rescans the configured folder and, when an embeddings endpoint is set,
+/// embeds any new passages so the next question does not wait on them.
+/// Indexing blocks on file reads and the store's indexing lock, so it runs
+/// on a blocking thread rather than an async worker.
+#[tauri::command]
+pub async fn reindex_knowledge_base(
+    app: tauri::AppHandle,
+    config_state: tauri::State<'_, storage::ConfigManager>,
+    store: tauri::State<'_, KnowledgeStore>,
+) -> Result<KnowledgeStatus, String> {
+    let config = config_state.load().await.map_err(|e| e.to_string())?;
+    tokio::task::spawn_blocking(move || {
+        tauri::async_runtime::block_on(knowledge::refresh_index(
+            &app.state::<KnowledgeStore>(),
+            &app.state::<reqwest::Client>(),
+            &config,
+        ))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| e.to_string())?;
+    store.status().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn clear_knowledge_base(store: tauri::State<'_, KnowledgeStore>) -> Result<(), String> {
+    store.clear().await.map_err(|e| e.to_string())
+}
@@ -1,4 +1,5 @@
 use crate::credentials::{resolve_config_secret, SystemCredentialVault};
+use crate::llm::LlmApi;
 use crate::SessionTokenStore;
 use crate::{api_base_url, with_desktop_client_version};
 
@@ -109,16 +110,7 @@ pub async fn test_llm_connection(
         return Err("Base URL must use http or https scheme".to_string());
     }
 
-    let url = format!("{}/chat/completions", base_url.trim_end_matches('/'));
-    let body = serde_json::json!({
-        "model": model,
-        "messages": [{"role": "user", "content": "hi"}],
-        "max_tokens": 1
-    });
-
-    let request = client.post(&url).header("Content-Type", "application/json");
-    let resp = crate::llm::apply_provider_auth_header(request, &provider, &api_key)
-        .json(&body)
+    let resp = build_ping_request(&client, &provider, &base_url, &model, &api_key)
         .timeout(std::time::Duration::from_secs(15))
         .send()
         .await
@@ -127,13 +119,97 @@ pub async fn test_llm_connection(
     Ok(resp.status().is_success())
 }
 
+/// Smallest possible completion request in the provider's own wire format.
+fn build_ping_request(
+    client: &reqwest::Client,
+    provider: &str,
+    base_url: &str,
+    model: &str,
+    api_key: &str,
+) -> reqwest::RequestBuilder {
+    let base_url = base_url.trim_end_matches('/');
+    let api = crate::llm::provider_api(provider, base_url);
+    let (url, body) = match api {
+        LlmApi::AnthropicMessages => (
+            format!("{base_url}/messages"),
+            serde_json::json!({
+                "model": model,
+                "messages": [{"role": "user", "content": "hi"}],
+                "max_tokens": 1
+            }),
+        ),
+        LlmApi::GeminiGenerateContent => (
+            format!(
+                "{base_url}/models/{}:generateContent",
+                model.trim_start_matches("models/")
+            ),
+            serde_json::json!({
+                "contents": [{"role": "user", "parts": [{"text": "hi"}]}],
+                "generationConfig": {"maxOutputTokens": 1}
+            }),
+        ),
+        LlmApi::OpenAiCompatible | LlmApi::ManagedCloud => (
+            format!("{base_url}/chat/completions"),
+            serde_json::json!({
+                "model": model,
+                "messages": [{"role": "user", "content": "hi"}],
+                "max_tokens": 1
+            }),
+        ),
+    };
+    let request = client.post(url).header("Content-Type", "application/json");
+    crate::llm::apply_api_auth_header(request, api, provider, api_key).json(&body)
+}
+
 fn build_fetch_models_request(
     client: &reqwest::Client,
     provider: &str,
     api_key: &str,
-    url: &str,
+    base_url: &str,
 ) -> reqwest::RequestBuilder {
-    crate::llm::apply_provider_auth_header(client.get(url), provider, api_key)
+    let base_url = base_url.trim_end_matches('/');
+    let api = crate::llm::provider_api(provider, base_url);
+    let url = match api {
+        // Anthropic pages at 20 and Gemini at 50 models by default.
+        LlmApi::AnthropicMessages => format!("{base_url}/models?limit=1000"),
+        LlmApi::GeminiGenerateContent => format!("{base_url}/models?pageSize=1000"),
+        LlmApi::OpenAiCompatible | LlmApi::ManagedCloud => format!("{base_url}/models"),
+    };
+    crate::llm::apply_api_auth_header(client.get(url), api, provider, api_key)
+}
+
+/// Model ids from a models listing. Gemini lists every model family, so only
+/// those that can serve `generateContent` are kept.
+fn parse_model_ids(body: &serde_json::Value) -> Vec<String> {
+    // OpenAI-compatible and Anthropic: { data: [{ id: "model-name" }] }
+    // Ollama-compatible: { models: [{ name: "model-name" }] }
+    // Gemini: { models: [{ name: "models/gemini-2.0-flash", supportedGenerationMethods: [..] }] }
+    let mut models: Vec<String> = Vec::new();
+
+    if let Some(data) = body.get("data").and_then(|d| d.as_array()) {
+        for item in data {
+            if let Some(id) = item.get("id").and_then(|v| v.as_str()) {
+                models.push(id.to_string());
+            }
+        }
+    } else if let Some(data) = body.get("models").and_then(|d| d.as_array()) {
+        for item in data {
+            if let Some(methods) = item
+                .get("supportedGenerationMethods")
+                .and_then(|v| v.as_array())
+            {
+                if !methods.iter().any(|m| m == "generateContent") {
+                    continue;
+                }
+            }
+            if let Some(name) = item.get("name").and_then(|v| v.as_str()) {
+                models.push(name.trim_start_matches("models/").to_string());
+            }
+        }
+    }
+
+    models.sort();
+    models
 }
 
 #[tauri::command]
@@ -156,9 +232,7 @@ pub async fn fetch_llm_models(
     }
 
     let client = reqwest::Client::new();
-    let url = format!("{}/models", base_url.trim_end_matches('/'));
-
-    let resp = build_fetch_models_request(&client, &provider, &api_key, &url)
+    let resp = build_fetch_models_request(&client, &provider, &api_key, &base_url)
         .timeout(std::time::Duration::from_secs(10))
         .send()
         .await
@@ -169,27 +243,7 @@ pub async fn fetch_llm_models(
     }
 
     let body: serde_json::Value = resp.json().await.map_err(|e| e.to_string())?;
-
-    // OpenAI-compatible: { data: [{ id: "model-name" }] }
-    // Ollama-compatible: { models: [{ name: "model-name" }] }
-    let mut models: Vec<String> = Vec::new();
-
-    if let Some(data) = body.get("data").and_then(|d| d.as_array()) {
-        for item in data {
-            if let Some(id) = item.get("id").and_then(|v| v.as_str()) {
-                models.push(id.to_string());
-            }
-        }
-    } else if let Some(data) = body.get("models").and_then(|d| d.as_array()) {
-        for item in data {
-            if let Some(name) = item.get("name").and_then(|v| v.as_str()) {
-                models.push(name.to_string());
-            }
-        }
-    }
-
-    models.sort();
-    Ok(models)
+    Ok(parse_model_ids(&body))
 }
 
 #[cfg(test)]
@@ -245,7 +299,7 @@ mod tests {
             &reqwest::Client::new(),
             "ollama",
             "",
-            "http://localhost:11434/v1/models",
+            "http://localhost:11434/v1",
         )
         .build()
         .unwrap();
@@ -259,7 +313,7 @@ mod tests {
             &reqwest::Client::new(),
             "openai",
             "sk-test",
-            "https://api.openai.com/v1/models",
+            "https://api.openai.com/v1",
         )
         .build()
         .unwrap();
@@ -268,6 +322,95 @@ mod tests {
             request.headers().get("Authorization").unwrap(),
             "Bearer sk-test"
         );
+        assert_eq!(request.url().as_str(), "https://api.openai.com/v1/models");
+    }
+
+    #[test]
+    fn native_model_requests_use_provider_headers_and_page_sizes() {
+        let anthropic = build_fetch_models_request(
+            &reqwest::Client::new(),
+            "anthropic",
+            "sk-ant",
+            "https://api.anthropic.com/v1/",
+        )
+        .build()
+        .unwrap();
+        assert_eq!(anthropic.headers().get("x-api-key").unwrap(), "sk-ant");
+        assert_eq!(
+            anthropic.url().as_str(),
+            "https://api.anthropic.com/v1/models?limit=1000"
+        );
+
+        let gemini = build_fetch_models_request(
+            &reqwest::Client::new(),
+            "gemini",
+            "AIza",
+            "https://generativelanguage.googleapis.com/v1beta",
+        )
+        .build()
+        .unwrap();
+        assert_eq!(gemini.headers().get("x-goog-api-key").unwrap(), "AIza");
+        assert!(gemini.headers().get("Authorization").is_none());
+    }
+
+    #[test]
+    fn gemini_model_list_keeps_generate_content_models_without_prefix() {
+        let body = serde_json::json!({
+            "models": [
+                { "name": "models/text-embedding-004", "supportedGenerationMethods": ["embedContent"] },
+                { "name": "models/gemini-2.0-flash", "supportedGenerationMethods": ["generateContent", "countTokens"] }
+            ]
+        });
+        assert_eq!(parse_model_ids(&body), vec!["gemini-2.0-flash".to_string()]);
+
+        let ollama = serde_json::json!({ "models": [{ "name": "qwen3:8b" }] });
+        assert_eq!(parse_model_ids(&ollama), vec!["qwen3:8b".to_string()]);
+    }
+
+    #[test]
+    fn ping_requests_follow_each_wire_format() {
+        let client = reqwest::Client::new();
+        let anthropic = build_ping_request(
+            &client,
+            "anthropic",
+            "https://api.anthropic.com/v1",
+            "claude-3-5-haiku-latest",
+            "sk-ant",
+        )
+        .build()
+        .unwrap();
+        assert_eq!(
+            anthropic.url().as_str(),
+            "https://api.anthropic.com/v1/messages"
+        );
+
+        let gemini = build_ping_request(
+            &client,
+            "gemini",
+            "https://generativelanguage.googleapis.com/v1beta",
+            "gemini-2.0-flash",
+            "AIza",
+        )
+        .build()
+        .unwrap();
+        assert_eq!(
+            gemini.url().as_str(),
+            "https://generativelanguage.googleapis.com/v1beta/models/gemini-2.0-flash:generateContent"
+        );
+
+        let openai = build_ping_request(
+            &client,
+            "openai",
+            "https://api.openai.com/v1/",
+            "gpt-4o-mini",
+            "sk",
+        )
+        .build()
+        .unwrap();
+        assert_eq!(
+            openai.url().as_str(),
+            "https://api.openai.com/v1/chat/completions"
+        );
     }
 }
 
@@ -337,17 +480,8 @@ pub async fn bench_llm_connection(
         return Err("Base URL must use http or https scheme".to_string());
     }
 
-    let url = format!("{}/chat/completions", base_url.trim_end_matches('/'));
-    let body = serde_json::json!({
-        "model": model,
-        "messages": [{"role": "user", "content": "hi"}],
-        "max_tokens": 1
-    });
-
     let t0 = std::time::Instant::now();
-    let request = client.post(&url).header("Content-Type", "application/json");
-    let resp = crate::llm::apply_provider_auth_header(request, &provider, &api_key)
-        .json(&body)
+    let resp = build_ping_request(&client, &provider, &base_url, &model, &api_key)
         .timeout(std::time::Duration::from_secs(15))
         .send()
         .await
@@ -722,10 +722,33 @@ fn insertion_diagnostic_row(
     caps: &platform::PlatformCapabilities,
     checked_at: &str,
 ) -> DiagnosticRow {
+    let is_linux_wayland = caps.os == "linux" && caps.session_type == "wayland";
+    let uses_wayland_virtual_keyboard = config.insertion_strategy == "waylandVirtualKeyboard"
+        || (config.insertion_strategy == "auto" && config.output_mode != "clipboard");
+    if is_linux_wayland && uses_wayland_virtual_keyboard {
+        return match &caps.wayland_virtual_keyboard {
+            Some(backend) => diagnostic_row(
+                "insertion",
+                DiagnosticStatus::Ok,
+                format!("Typing through the Wayland virtual keyboard ({})", backend),
+                None,
+                checked_at,
+            ),
+            None => diagnostic_row(
+                "insertion",
+                DiagnosticStatus::Warning,
+                "Install wtype or ydotool to type on Wayland; text is copied to the clipboard instead",
+                None,
+                checked_at,
+            ),
+        };
+    }
+
     let uses_clipboard_paste = config.insertion_strategy == "clipboardPaste"
         || (config.insertion_strategy == "auto" && config.output_mode == "clipboard");
     let uses_keyboard = config.insertion_strategy == "keyboard"
         || config.insertion_strategy == "windowsSendInput"
+        || config.insertion_strategy == "waylandVirtualKeyboard"
         || (config.insertion_strategy == "auto" && config.output_mode == "keyboard");
 
     if uses_clipboard_paste && !caps.clipboard_auto_paste_reliable {
@@ -780,6 +803,58 @@ fn platform_diagnostic_row(
     )
 }
 
+fn llm_diagnostic_row(
+    config: &storage::AppConfig,
+    open_providers: &[String],
+    last_served: Option<&str>,
+    checked_at: &str,
+) -> DiagnosticRow {
+    if !config.polish_enabled {
+        return diagnostic_row(
+            "llm",
+            DiagnosticStatus::NotApplicable,
+            "AI polish is off",
+            None,
+            checked_at,
+        );
+    }
+
+    let chain = std::iter::once(config.llm_provider.as_str())
+        .chain(
+            config
+                .llm_fallback_chain
+                .iter()
+                .map(|entry| entry.provider.trim())
+                .filter(|provider| !provider.is_empty()),
+        )
+        .collect::<Vec<_>>()
+        .join(" → ");
+    let served = last_served
+        .map(|provider| format!("; last polish served by {provider}"))
+        .unwrap_or_default();
+
+    if !open_providers.is_empty() {
+        return diagnostic_row(
+            "llm",
+            DiagnosticStatus::Warning,
+            format!(
+                "Paused after repeated failures: {} (chain: {chain}{served})",
+                open_providers.join(", ")
+            ),
+            None,
+            checked_at,
+        );
+    }
+
+    diagnostic_row(
+        "llm",
+        DiagnosticStatus::Ok,
+        format!("Chain: {chain}{served}"),
+        None,
+        checked_at,
+    )
+}
+
 fn build_system_diagnostics_report(
     config: &storage::AppConfig,
     caps: platform::PlatformCapabilities,
@@ -930,7 +1005,7 @@ pub async fn get_system_diagnostics(
     let accessibility_trusted = pipeline::is_accessibility_trusted();
     let checked_at = current_diagnostics_timestamp();
 
-    Ok(build_system_diagnostics_report(
+    let mut report = build_system_diagnostics_report(
         &config,
         caps,
         hotkey_status,
@@ -938,7 +1013,23 @@ pub async fn get_system_diagnostics(
         probe_microphone_input(),
         probe_clipboard_write_restore(),
         &checked_at,
-    ))
+    );
+    let (open_providers, last_served) = {
+        let health = crate::llm::failover::chain_health()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        (
+            health.open_providers(std::time::Instant::now()),
+            health.last_served().map(str::to_string),
+        )
+    };
+    report.rows.push(llm_diagnostic_row(
+        &config,
+        &open_providers,
+        last_served.as_deref(),
+        &checked_at,
+    ));
+    Ok(report)
 }
 
 #[tauri::command]
@@ -1156,6 +1247,7 @@ mod tests {
             global_hotkey_reliable: true,
             keyboard_output_reliable: true,
             clipboard_auto_paste_reliable: true,
+            wayland_virtual_keyboard: None,
         };
 
         let status = hotkey_status_for_with_capability(&config, None, caps);
@@ -1184,6 +1276,7 @@ mod tests {
             global_hotkey_reliable: true,
             keyboard_output_reliable: true,
             clipboard_auto_paste_reliable: true,
+            wayland_virtual_keyboard: None,
         };
         let windows = platform::PlatformCapabilities {
             os: "windows".to_string(),
@@ -1191,6 +1284,7 @@ mod tests {
             global_hotkey_reliable: true,
             keyboard_output_reliable: true,
             clipboard_auto_paste_reliable: true,
+            wayland_virtual_keyboard: None,
         };
 
         let macos_capability = hotkey_capability_for(&macos);
@@ -1221,6 +1315,7 @@ mod tests {
             global_hotkey_reliable: true,
             keyboard_output_reliable: true,
             clipboard_auto_paste_reliable: true,
+            wayland_virtual_keyboard: None,
         };
 
         let status = hotkey_status_for_with_capability(&config, None, caps);
@@ -1259,6 +1354,7 @@ mod tests {
             global_hotkey_reliable: true,
             keyboard_output_reliable: true,
             clipboard_auto_paste_reliable: true,
+            wayland_virtual_keyboard: None,
         };
 
         let status = hotkey_status_for_with_capability(&config, None, caps);
@@ -1293,6 +1389,7 @@ mod tests {
             global_hotkey_reliable: true,
             keyboard_output_reliable: true,
             clipboard_auto_paste_reliable: true,
+            wayland_virtual_keyboard: None,
         };
 
         let status = hotkey_status_for_with_capability(&config, None, caps);
@@ -1331,6 +1428,7 @@ mod tests {
             global_hotkey_reliable: true,
             keyboard_output_reliable: true,
             clipboard_auto_paste_reliable: true,
+            wayland_virtual_keyboard: None,
         };
 
         let status = hotkey_status_for_with_capability(
@@ -1366,6 +1464,7 @@ mod tests {
             session_type: "unknown".to_string(),
             keyboard_output_reliable: true,
             clipboard_auto_paste_reliable: true,
+            wayland_virtual_keyboard: None,
             global_hotkey_reliable: true,
         };
 
@@ -1400,6 +1499,7 @@ mod tests {
             session_type: "unknown".to_string(),
             keyboard_output_reliable: true,
             clipboard_auto_paste_reliable: true,
+            wayland_virtual_keyboard: None,
             global_hotkey_reliable: true,
         };
         let status = hotkey_status_for_with_capability_and_supervisor(
@@ -1430,6 +1530,7 @@ mod tests {
             global_hotkey_reliable: true,
             keyboard_output_reliable: true,
             clipboard_auto_paste_reliable: true,
+            wayland_virtual_keyboard: None,
         };
         let status = hotkey_status_for_with_capability(&config, None, caps.clone());
 
@@ -1447,6 +1548,7 @@ mod tests {
             global_hotkey_reliable: true,
             keyboard_output_reliable: true,
             clipboard_auto_paste_reliable: true,
+            wayland_virtual_keyboard: None,
         };
         let status = HotkeyStatus {
             dictation: HotkeyBindingStatus {
@@ -1500,6 +1602,7 @@ mod tests {
             global_hotkey_reliable: false,
             keyboard_output_reliable: false,
             clipboard_auto_paste_reliable: false,
+            wayland_virtual_keyboard: None,
         };
 
         let status = hotkey_status_for_with_capability(&config, None, caps);
@@ -1535,6 +1638,7 @@ mod tests {
             global_hotkey_reliable: false,
             keyboard_output_reliable: false,
             clipboard_auto_paste_reliable: false,
+            wayland_virtual_keyboard: None,
         };
         let hotkey_status = hotkey_status_for(&config, Some("already registered".to_string()));
 
@@ -1566,6 +1670,39 @@ mod tests {
         assert_eq!(report.rows[4].status, DiagnosticStatus::Warning);
     }
 
+    #[test]
+    fn insertion_row_reports_wayland_virtual_keyboard_backend() {
+        let config = storage::AppConfig {
+            insertion_strategy: "auto".to_string(),
+            ..storage::AppConfig::default()
+        };
+        let mut caps = platform::PlatformCapabilities {
+            os: "linux".to_string(),
+            session_type: "wayland".to_string(),
+            global_hotkey_reliable: false,
+            keyboard_output_reliable: true,
+            clipboard_auto_paste_reliable: false,
+            wayland_virtual_keyboard: Some("wtype".to_string()),
+        };
+
+        let row = insertion_diagnostic_row(&config, &caps, "2026-07-06T00:00:00");
+        assert_eq!(row.status, DiagnosticStatus::Ok);
+        assert!(row.message.contains("wtype"));
+
+        caps.wayland_virtual_keyboard = None;
+        caps.keyboard_output_reliable = false;
+        let row = insertion_diagnostic_row(&config, &caps, "2026-07-06T00:00:00");
+        assert_eq!(row.status, DiagnosticStatus::Warning);
+        assert!(row.message.contains("ydotool"));
+
+        let legacy_clipboard = storage::AppConfig {
+            output_mode: "clipboard".to_string(),
+            ..config
+        };
+        let row = insertion_diagnostic_row(&legacy_clipboard, &caps, "2026-07-06T00:00:00");
+        assert!(!row.message.contains("ydotool"));
+    }
+
     #[test]
     fn diagnostics_marks_macos_accessibility_as_error_when_missing() {
         let config = storage::AppConfig::default();
@@ -1575,6 +1712,7 @@ mod tests {
             global_hotkey_reliable: true,
             keyboard_output_reliable: true,
             clipboard_auto_paste_reliable: true,
+            wayland_virtual_keyboard: None,
         };
         let hotkey_status = hotkey_status_for(&config, None);
 
@@ -1617,6 +1755,7 @@ mod tests {
             global_hotkey_reliable: true,
             keyboard_output_reliable: true,
             clipboard_auto_paste_reliable: true,
+            wayland_virtual_keyboard: None,
         };
         let hotkey_status = hotkey_status_for_with_capability(&config, None, caps.clone());
 
@@ -1652,4 +1791,38 @@ mod tests {
         assert_eq!(row.status, DiagnosticStatus::Ok);
         assert!(row.message.contains("text-only"));
     }
+
+    #[test]
+    fn llm_diagnostics_report_chain_open_circuits_and_serving_provider() {
+        let mut config = storage::AppConfig {
+            llm_provider: "openrouter".to_string(),
+            llm_fallback_chain: vec![storage::LlmFallbackEntry {
+                provider: "groq".to_string(),
+                ..storage::LlmFallbackEntry::default()
+            }],
+            ..storage::AppConfig::default()
+        };
+
+        let row = llm_diagnostic_row(&config, &[], Some("groq"), "2026-07-06T00:00:00");
+        assert_eq!(row.status, DiagnosticStatus::Ok);
+        assert_eq!(
+            row.message,
+            "Chain: openrouter → groq; last polish served by groq"
+        );
+
+        let row = llm_diagnostic_row(
+            &config,
+            &["openrouter".to_string()],
+            None,
+            "2026-07-06T00:00:00",
+        );
+        assert_eq!(row.status, DiagnosticStatus::Warning);
+        assert!(row
+            .message
+            .contains("Paused after repeated failures: openrouter"));
+
+        config.polish_enabled = false;
+        let row = llm_diagnostic_row(&config, &[], None, "2026-07-06T00:00:00");
+        assert_eq!(row.status, DiagnosticStatus::NotApplicable);
+    }
 }
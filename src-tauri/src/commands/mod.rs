@@ -5,7 +5,9 @@ pub mod config;
 pub mod credentials;
 pub mod dictionary;
 pub mod history;
+pub mod knowledge;
 pub mod llm;
 pub mod misc;
+pub mod scenes;
 pub mod stt;
 pub mod translation;
@@ -0,0 +1,73 @@
+use crate::scene_io::{self, SceneConflictResolution, ScenePackImportReport, ScenePackPreview};
+use crate::storage::{ConfigManager, CustomScene, SystemSceneOverride};
+use serde::Serialize;
+use tauri::Emitter;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScenePackImportResult {
+    pub report: ScenePackImportReport,
+    pub custom_scenes: Vec<CustomScene>,
+    pub system_scene_overrides: Vec<SystemSceneOverride>,
+}
+
+#[tauri::command]
+pub async fn preview_scene_pack_import(
+    config_manager: tauri::State<'_, ConfigManager>,
+    bytes: Vec<u8>,
+) -> Result<ScenePackPreview, String> {
+    let parsed = scene_io::parse_scene_pack(&bytes).map_err(|error| error.to_string())?;
+    let config = config_manager
+        .load()
+        .await
+        .map_err(|error| error.to_string())?;
+    Ok(scene_io::preview_scene_pack(&config, &parsed))
+}
+
+#[tauri::command]
+pub async fn import_scene_pack(
+    app: tauri::AppHandle,
+    config_manager: tauri::State<'_, ConfigManager>,
+    bytes: Vec<u8>,
+    resolution: SceneConflictResolution,
+) -> Result<ScenePackImportResult, String> {
+    let parsed = scene_io::parse_scene_pack(&bytes).map_err(|error| error.to_string())?;
+    let mut config = config_manager
+        .load()
+        .await
+        .map_err(|error| error.to_string())?;
+    let now = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+    let report = scene_io::apply_scene_pack(&mut config, parsed, resolution, &now, || {
+        format!("custom_{}", uuid::Uuid::new_v4())
+    });
+    config_manager
+        .save(&config)
+        .await
+        .map_err(|error| error.to_string())?;
+    let _ = app.emit(
+        "config:patch",
+        serde_json::json!({
+            "custom_scenes": config.custom_scenes,
+            "system_scene_overrides": config.system_scene_overrides,
+        }),
+    );
+    Ok(ScenePackImportResult {
+        report,
+        custom_scenes: config.custom_scenes,
+        system_scene_overrides: config.system_scene_overrides,
+    })
+}
+
+#[tauri::command]
+pub async fn export_scene_pack(
+    config_manager: tauri::State<'_, ConfigManager>,
+    scene_ids: Vec<String>,
+) -> Result<String, String> {
+    let config = config_manager
+        .load()
+        .await
+        .map_err(|error| error.to_string())?;
+    let exported_at = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+    scene_io::export_scene_pack(&config, &scene_ids, &exported_at)
+        .map_err(|error| error.to_string())
+}
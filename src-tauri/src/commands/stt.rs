@@ -94,6 +94,8 @@ async fn check_volcengine_doubao_connection(
         resource_id,
         operation_id: None,
         managed_audio: None,
+        vocabulary: Vec::new(),
+        local_model_path: None,
     };
     provider.connect(&config).await.map_err(|e| e.to_string())?;
     let _ = provider.disconnect().await;
@@ -234,11 +236,47 @@ fn build_apple_speech_diagnostics(
     }
 }
 
+fn build_local_whisper_diagnostics(
+    provider: &str,
+    local_model_path: Option<&str>,
+) -> SttProviderDiagnostics {
+    let mut issues = Vec::new();
+    if !stt::local_whisper::is_available_in_build() {
+        issues.push(diagnostic_issue(
+            "local_whisper_not_built",
+            "Offline Whisper is not included in this build",
+        ));
+    }
+    let model = match stt::local_whisper::validate_model_path(local_model_path.unwrap_or_default())
+    {
+        Ok(path) => Some(path.display().to_string()),
+        Err(err) => {
+            issues.push(diagnostic_issue("invalid_local_whisper_model", err));
+            local_model_path
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(ToString::to_string)
+        }
+    };
+
+    SttProviderDiagnostics {
+        provider: provider.to_string(),
+        kind: "builtinLocal".to_string(),
+        endpoint: None,
+        model,
+        requires_api_key: false,
+        api_key_configured: false,
+        ready: issues.is_empty(),
+        issues,
+    }
+}
+
 fn build_stt_provider_diagnostics(
     provider: &str,
     api_key: &str,
     custom_base_url: Option<&str>,
     custom_model: Option<&str>,
+    local_model_path: Option<&str>,
 ) -> SttProviderDiagnostics {
     match provider {
         "" => SttProviderDiagnostics {
@@ -268,6 +306,9 @@ fn build_stt_provider_diagnostics(
             provider,
             stt::apple_speech::apple_speech_availability(None),
         ),
+        stt::config::LOCAL_WHISPER_PROVIDER => {
+            build_local_whisper_diagnostics(provider, local_model_path)
+        }
         stt::config::CUSTOM_WHISPER_PROVIDER => {
             let api_key_configured = !api_key.trim().is_empty();
             match stt::config::build_custom_whisper_config(
@@ -343,6 +384,7 @@ pub fn get_stt_provider_diagnostics(
     provider: String,
     custom_base_url: Option<String>,
     custom_model: Option<String>,
+    local_model_path: Option<String>,
 ) -> Result<SttProviderDiagnostics, String> {
     let resolved_api_key = if provider == "cloud" {
         String::new()
@@ -356,6 +398,7 @@ pub fn get_stt_provider_diagnostics(
         &resolved_api_key,
         custom_base_url.as_deref(),
         custom_model.as_deref(),
+        local_model_path.as_deref(),
     ))
 }
 
@@ -366,6 +409,7 @@ pub async fn test_stt_connection(
     custom_base_url: Option<String>,
     custom_model: Option<String>,
     volcengine_resource_id: Option<String>,
+    local_model_path: Option<String>,
     token_store: tauri::State<'_, SessionTokenStore>,
     client: tauri::State<'_, reqwest::Client>,
 ) -> Result<bool, String> {
@@ -441,6 +485,11 @@ pub async fn test_stt_connection(
             }
             Ok(stt::apple_speech::apple_speech_availability(None).ready)
         }
+        stt::config::LOCAL_WHISPER_PROVIDER => Ok(stt::local_whisper::is_available_in_build()
+            && stt::local_whisper::validate_model_path(
+                local_model_path.as_deref().unwrap_or_default(),
+            )
+            .is_ok()),
         "openai-whisper" => Ok(check_openai_whisper_model(&client, &api_key).await.is_ok()),
         _ => {
             let cfg = resolve_whisper_test_config(&provider, custom_base_url, custom_model)?;
@@ -511,6 +560,7 @@ mod tests {
             "",
             Some("http://localhost:8000/v1"),
             Some("Systran/faster-whisper-large-v3"),
+            None,
         );
 
         assert_eq!(diagnostics.provider, stt::config::CUSTOM_WHISPER_PROVIDER);
@@ -535,6 +585,7 @@ mod tests {
             "",
             Some("file:///tmp/server"),
             Some(" "),
+            None,
         );
 
         assert_eq!(diagnostics.kind, "localCompatible");
@@ -543,9 +594,32 @@ mod tests {
         assert_eq!(diagnostics.issues[0].code, "invalid_custom_whisper_config");
     }
 
+    #[test]
+    fn offline_whisper_diagnostics_report_missing_model() {
+        let diagnostics = build_stt_provider_diagnostics(
+            stt::config::LOCAL_WHISPER_PROVIDER,
+            "",
+            None,
+            None,
+            Some("/nonexistent/ggml-base.bin"),
+        );
+
+        assert_eq!(diagnostics.kind, "builtinLocal");
+        assert!(!diagnostics.requires_api_key);
+        assert!(!diagnostics.ready);
+        assert_eq!(
+            diagnostics.model.as_deref(),
+            Some("/nonexistent/ggml-base.bin")
+        );
+        assert!(diagnostics
+            .issues
+            .iter()
+            .any(|issue| issue.code == "invalid_local_whisper_model"));
+    }
+
     #[test]
     fn remote_stt_diagnostics_requires_api_key() {
-        let diagnostics = build_stt_provider_diagnostics("deepgram", "", None, None);
+        let diagnostics = build_stt_provider_diagnostics("deepgram", "", None, None, None);
 
         assert_eq!(diagnostics.kind, "byokRemote");
         assert!(diagnostics.requires_api_key);
@@ -556,7 +630,7 @@ mod tests {
 
     #[test]
     fn apple_speech_diagnostics_are_platform_gated_builtin_local() {
-        let diagnostics = build_stt_provider_diagnostics("apple-speech", "", None, None);
+        let diagnostics = build_stt_provider_diagnostics("apple-speech", "", None, None, None);
 
         assert_eq!(diagnostics.provider, "apple-speech");
         assert_eq!(diagnostics.kind, "builtinLocal");
@@ -650,6 +724,7 @@ pub async fn bench_stt_connection(
     custom_base_url: Option<String>,
     custom_model: Option<String>,
     volcengine_resource_id: Option<String>,
+    local_model_path: Option<String>,
     token_store: tauri::State<'_, SessionTokenStore>,
     client: tauri::State<'_, reqwest::Client>,
 ) -> Result<u32, String> {
@@ -737,6 +812,14 @@ pub async fn bench_stt_connection(
                 Err("Apple Speech is only available on macOS".to_string())
             }
         }
+        stt::config::LOCAL_WHISPER_PROVIDER => {
+            let diagnostics =
+                build_local_whisper_diagnostics(&provider, local_model_path.as_deref());
+            match diagnostics.issues.into_iter().next() {
+                Some(issue) => Err(issue.message),
+                None => Ok(0),
+            }
+        }
         "openai-whisper" => {
             let t0 = std::time::Instant::now();
             check_openai_whisper_model(&client, &api_key).await?;
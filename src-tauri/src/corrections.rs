@@ -0,0 +1,328 @@
+use crate::llm::CorrectionRule;
+use serde::{Deserialize, Serialize};
+
+pub const MAX_LOCAL_CORRECTION_RULES: usize = 100;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CorrectionStage {
+    Transcript,
+    Polished,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AppliedCorrection {
+    pub rule_id: i64,
+    pub stage: CorrectionStage,
+    pub pattern: String,
+    pub replacement: String,
+    pub count: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorrectionOutcome {
+    pub text: String,
+    pub applied: Vec<AppliedCorrection>,
+}
+
+struct CompiledRule<'a> {
+    rule: &'a CorrectionRule,
+    folded_pattern: Vec<char>,
+    pattern_has_upper: bool,
+    replacement: String,
+}
+
+/// Applies enabled correction rules locally so they take effect even when
+/// polish is disabled or the model ignores the prompt hint.
+///
+/// Matching is case-insensitive and runs in a single left-to-right pass, so a
+/// replacement is never rewritten again by another rule. Longer patterns win
+/// over shorter ones at the same position. Latin words only match on word
+/// boundaries; CJK, kana and Hangul have no spaces between words, so they
+/// match anywhere.
+pub fn apply_correction_rules(
+    text: &str,
+    rules: &[CorrectionRule],
+    stage: CorrectionStage,
+) -> CorrectionOutcome {
+    let mut compiled = rules
+        .iter()
+        .filter(|rule| rule.enabled)
+        .take(MAX_LOCAL_CORRECTION_RULES)
+        .filter_map(compile_rule)
+        .collect::<Vec<_>>();
+    if compiled.is_empty() || text.is_empty() {
+        return CorrectionOutcome {
+            text: text.to_string(),
+            applied: Vec::new(),
+        };
+    }
+    // Stable sort keeps rule id order for equally long patterns.
+    compiled.sort_by(|left, right| right.folded_pattern.len().cmp(&left.folded_pattern.len()));
+
+    let chars = text.chars().collect::<Vec<_>>();
+    let folded = chars.iter().copied().map(fold_char).collect::<Vec<_>>();
+    let mut counts = vec![0u32; compiled.len()];
+    let mut output = String::with_capacity(text.len());
+    let mut index = 0;
+
+    while index < chars.len() {
+        let matched = compiled.iter().enumerate().find_map(|(rule_index, rule)| {
+            match_rule_at(rule, &chars, &folded, index).map(|end| (rule_index, end))
+        });
+        let Some((rule_index, end)) = matched else {
+            output.push(chars[index]);
+            index += 1;
+            continue;
+        };
+
+        let original = chars[index..end].iter().collect::<String>();
+        let replacement = case_adjusted_replacement(&original, &compiled[rule_index]);
+        if replacement != original {
+            counts[rule_index] = counts[rule_index].saturating_add(1);
+        }
+        output.push_str(&replacement);
+        index = end;
+    }
+
+    let mut applied = compiled
+        .iter()
+        .zip(counts)
+        .filter(|(_, count)| *count > 0)
+        .map(|(compiled, count)| AppliedCorrection {
+            rule_id: compiled.rule.id,
+            stage,
+            pattern: compiled.rule.pattern.trim().to_string(),
+            replacement: compiled.replacement.clone(),
+            count,
+        })
+        .collect::<Vec<_>>();
+    applied.sort_by_key(|correction| correction.rule_id);
+
+    CorrectionOutcome {
+        text: output,
+        applied,
+    }
+}
+
+fn compile_rule(rule: &CorrectionRule) -> Option<CompiledRule<'_>> {
+    let mut folded_pattern = Vec::new();
+    for character in rule.pattern.trim().chars() {
+        if character.is_whitespace() {
+            if folded_pattern.last() != Some(&' ') {
+                folded_pattern.push(' ');
+            }
+        } else {
+            folded_pattern.push(fold_char(character));
+        }
+    }
+    let replacement = rule.replacement.trim().to_string();
+    if folded_pattern.is_empty() || replacement.is_empty() {
+        return None;
+    }
+
+    Some(CompiledRule {
+        rule,
+        folded_pattern,
+        pattern_has_upper: rule.pattern.chars().any(char::is_uppercase),
+        replacement,
+    })
+}
+
+/// Returns the exclusive end index when `rule` matches at `start`. A single
+/// space in the pattern matches any run of whitespace in the text.
+fn match_rule_at(
+    rule: &CompiledRule<'_>,
+    chars: &[char],
+    folded: &[char],
+    start: usize,
+) -> Option<usize> {
+    let first = *rule.folded_pattern.first()?;
+    if is_word_char(first) && start > 0 && is_word_char(chars[start - 1]) {
+        return None;
+    }
+
+    let mut index = start;
+    for expected in &rule.folded_pattern {
+        if *expected == ' ' {
+            if !chars.get(index).is_some_and(|value| value.is_whitespace()) {
+                return None;
+            }
+            while chars.get(index).is_some_and(|value| value.is_whitespace()) {
+                index += 1;
+            }
+            continue;
+        }
+        if folded.get(index) != Some(expected) {
+            return None;
+        }
+        index += 1;
+    }
+
+    let last = *rule.folded_pattern.last()?;
+    if is_word_char(last) && chars.get(index).copied().is_some_and(is_word_char) {
+        return None;
+    }
+    Some(index)
+}
+
+fn case_adjusted_replacement(original: &str, rule: &CompiledRule<'_>) -> String {
+    if rule.pattern_has_upper {
+        return rule.replacement.clone();
+    }
+
+    let letters = original
+        .chars()
+        .filter(|character| character.is_alphabetic())
+        .collect::<Vec<_>>();
+    let has_cased_letters = letters
+        .iter()
+        .any(|character| character.is_uppercase() || character.is_lowercase());
+    if !has_cased_letters {
+        return rule.replacement.clone();
+    }
+
+    if letters.len() > 1 && letters.iter().all(|character| !character.is_lowercase()) {
+        return rule.replacement.to_uppercase();
+    }
+    if letters
+        .first()
+        .is_some_and(|character| character.is_uppercase())
+    {
+        let mut replacement = rule.replacement.chars();
+        return match replacement.next() {
+            Some(first) => first.to_uppercase().chain(replacement).collect(),
+            None => String::new(),
+        };
+    }
+    rule.replacement.clone()
+}
+
+fn fold_char(character: char) -> char {
+    let mut lower = character.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(folded), None) => folded,
+        _ => character,
+    }
+}
+
+fn is_word_char(character: char) -> bool {
+    (character.is_alphanumeric() || character == '_') && !is_boundaryless_script(character)
+}
+
+fn is_boundaryless_script(character: char) -> bool {
+    matches!(
+        character as u32,
+        0x3040..=0x30FF
+            | 0x3400..=0x4DBF
+            | 0x4E00..=0x9FFF
+            | 0xAC00..=0xD7AF
+            | 0xF900..=0xFAFF
+            | 0xFF66..=0xFF9F
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(id: i64, pattern: &str, replacement: &str) -> CorrectionRule {
+        CorrectionRule {
+            id,
+            pattern: pattern.to_string(),
+            replacement: replacement.to_string(),
+            enabled: true,
+        }
+    }
+
+    fn apply(text: &str, rules: &[CorrectionRule]) -> CorrectionOutcome {
+        apply_correction_rules(text, rules, CorrectionStage::Transcript)
+    }
+
+    #[test]
+    fn latin_rules_respect_word_boundaries() {
+        let rules = [rule(1, "token", "Token")];
+
+        let outcome = apply("tokens and token, tokenize token", &rules);
+
+        assert_eq!(outcome.text, "tokens and Token, tokenize Token");
+        assert_eq!(outcome.applied.len(), 1);
+        assert_eq!(outcome.applied[0].rule_id, 1);
+        assert_eq!(outcome.applied[0].count, 2);
+        assert_eq!(outcome.applied[0].stage, CorrectionStage::Transcript);
+    }
+
+    #[test]
+    fn lowercase_patterns_preserve_sentence_and_shouting_case() {
+        let rules = [rule(1, "open typeless", "opentypeless")];
+
+        let outcome = apply("Open typeless rocks. OPEN TYPELESS!", &rules);
+
+        assert_eq!(outcome.text, "Opentypeless rocks. OPENTYPELESS!");
+    }
+
+    #[test]
+    fn cased_patterns_use_the_replacement_verbatim() {
+        let rules = [rule(1, "Get Hub", "GitHub")];
+
+        let outcome = apply("push to get hub today", &rules);
+
+        assert_eq!(outcome.text, "push to GitHub today");
+    }
+
+    #[test]
+    fn whitespace_in_patterns_matches_any_whitespace_run() {
+        let rules = [rule(1, "talk more", "TalkMore")];
+
+        let outcome = apply("use talk   more\nnow and talk\tmore", &rules);
+
+        assert_eq!(outcome.text, "use TalkMore\nnow and TalkMore");
+        assert_eq!(outcome.applied[0].count, 2);
+    }
+
+    #[test]
+    fn cjk_patterns_match_without_spaces_and_keep_char_boundaries() {
+        let rules = [rule(1, "拓肯", "Token"), rule(2, "克劳德", "Claude")];
+
+        let outcome = apply("这个拓肯来自克劳德的接口，拓肯很贵", &rules);
+
+        assert_eq!(outcome.text, "这个Token来自Claude的接口，Token很贵");
+        assert_eq!(
+            outcome
+                .applied
+                .iter()
+                .map(|correction| (correction.rule_id, correction.count))
+                .collect::<Vec<_>>(),
+            vec![(1, 2), (2, 1)]
+        );
+    }
+
+    #[test]
+    fn replacements_are_not_rewritten_by_later_rules_and_longest_match_wins() {
+        let rules = [
+            rule(1, "open ai", "OpenAI"),
+            rule(2, "openai", "ClosedAI"),
+            rule(3, "ai", "AI"),
+        ];
+
+        let outcome = apply("open ai and ai", &rules);
+
+        assert_eq!(outcome.text, "OpenAI and AI");
+        assert!(outcome
+            .applied
+            .iter()
+            .all(|correction| correction.rule_id != 2));
+    }
+
+    #[test]
+    fn unchanged_matches_and_disabled_rules_are_not_recorded() {
+        let mut disabled = rule(2, "foo", "bar");
+        disabled.enabled = false;
+        let rules = [rule(1, "github", "GitHub"), disabled];
+
+        let outcome = apply("GitHub foo", &rules);
+
+        assert_eq!(outcome.text, "GitHub foo");
+        assert!(outcome.applied.is_empty());
+    }
+}
@@ -324,7 +324,7 @@ fn optional_json_array(
     }
 }
 
-fn string_alias<'a>(
+pub(crate) fn string_alias<'a>(
     object: &'a serde_json::Map<String, serde_json::Value>,
     aliases: &[&str],
 ) -> Option<&'a str> {
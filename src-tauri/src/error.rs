@@ -68,7 +68,7 @@ impl AppError {
         match self {
             AppError::Network(_) => true,
             AppError::Timeout(_) => true,
-            AppError::Api { status, .. } => *status >= 500,
+            AppError::Api { status, .. } => *status == 429 || *status >= 500,
             AppError::Auth(_) => false,
             AppError::Quota(_) => false,
             AppError::LlmQuota(_) => false,
@@ -223,6 +223,15 @@ mod tests {
         assert!(err.is_retryable());
     }
 
+    #[test]
+    fn test_429_is_retryable() {
+        let err = AppError::Api {
+            status: 429,
+            body: "rate limited".to_string(),
+        };
+        assert!(err.is_retryable());
+    }
+
     #[test]
     fn test_401_is_not_retryable() {
         let err = AppError::Api {
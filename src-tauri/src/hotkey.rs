@@ -925,6 +925,7 @@ pub fn handle_hotkey_role_event(
                 pipeline_state,
                 pipeline::PipelineStartOptions {
                     force_translate: true,
+                    ..pipeline::PipelineStartOptions::default()
                 },
             );
             handle_recording_shortcut(handle, action);
@@ -1530,11 +1531,13 @@ mod tests {
                 pipeline::PipelineState::Idle,
                 pipeline::PipelineStartOptions {
                     force_translate: true,
+                    ..pipeline::PipelineStartOptions::default()
                 },
             ),
             RecordingShortcutAction::Start {
                 options: pipeline::PipelineStartOptions {
                     force_translate: true,
+                    ..pipeline::PipelineStartOptions::default()
                 },
             }
         );
@@ -1546,6 +1549,7 @@ mod tests {
                 pipeline::PipelineState::Recording,
                 pipeline::PipelineStartOptions {
                     force_translate: true,
+                    ..pipeline::PipelineStartOptions::default()
                 },
             ),
             RecordingShortcutAction::Stop
@@ -0,0 +1,96 @@
+//! Okapi BM25 scoring and rank fusion for knowledge-base retrieval.
+
+use std::collections::HashMap;
+
+pub const K1: f64 = 1.2;
+pub const B: f64 = 0.75;
+
+/// Corpus-wide statistics needed to score one query.
+#[derive(Debug, Clone, Default)]
+pub struct CorpusStats {
+    pub chunk_count: u64,
+    pub average_length: f64,
+    /// Number of chunks containing each query term.
+    pub document_frequency: HashMap<String, u64>,
+}
+
+/// Non-negative BM25 idf; terms in more than half the corpus still add a little.
+pub fn idf(chunk_count: u64, document_frequency: u64) -> f64 {
+    let n = chunk_count as f64;
+    let df = document_frequency as f64;
+    (1.0 + (n - df + 0.5) / (df + 0.5)).ln()
+}
+
+/// BM25 score of one chunk given the term frequencies of the query terms it contains.
+pub fn score(stats: &CorpusStats, chunk_length: u64, term_frequencies: &[(&str, u64)]) -> f64 {
+    let average_length = if stats.average_length > 0.0 {
+        stats.average_length
+    } else {
+        1.0
+    };
+    let length_norm = 1.0 - B + B * (chunk_length as f64 / average_length);
+    term_frequencies
+        .iter()
+        .map(|(term, tf)| {
+            let df = stats.document_frequency.get(*term).copied().unwrap_or(0);
+            let tf = *tf as f64;
+            idf(stats.chunk_count, df) * tf * (K1 + 1.0) / (tf + K1 * length_norm)
+        })
+        .sum()
+}
+
+/// Reciprocal-rank fusion of several best-first rankings of chunk ids.
+pub fn reciprocal_rank_fusion(rankings: &[Vec<i64>], k: f64) -> Vec<(i64, f64)> {
+    let mut fused: HashMap<i64, f64> = HashMap::new();
+    for ranking in rankings {
+        for (rank, id) in ranking.iter().enumerate() {
+            *fused.entry(*id).or_default() += 1.0 / (k + rank as f64 + 1.0);
+        }
+    }
+    let mut fused: Vec<_> = fused.into_iter().collect();
+    fused.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+    fused
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats() -> CorpusStats {
+        CorpusStats {
+            chunk_count: 100,
+            average_length: 50.0,
+            document_frequency: HashMap::from([
+                ("rotate".to_string(), 2),
+                ("keys".to_string(), 40),
+            ]),
+        }
+    }
+
+    #[test]
+    fn rare_terms_and_shorter_chunks_score_higher() {
+        let stats = stats();
+        assert!(idf(100, 2) > idf(100, 40));
+        assert!(idf(100, 100) > 0.0);
+
+        let rare = score(&stats, 50, &[("rotate", 1)]);
+        let common = score(&stats, 50, &[("keys", 1)]);
+        assert!(rare > common);
+
+        let short = score(&stats, 20, &[("rotate", 1)]);
+        let long = score(&stats, 200, &[("rotate", 1)]);
+        assert!(short > long);
+
+        // Term frequency saturates instead of growing linearly.
+        let once = score(&stats, 50, &[("rotate", 1)]);
+        let ten = score(&stats, 50, &[("rotate", 10)]);
+        assert!(ten < once * (K1 + 1.0));
+    }
+
+    #[test]
+    fn rank_fusion_rewards_agreement_between_rankings() {
+        let fused = reciprocal_rank_fusion(&[vec![1, 2, 3], vec![2, 4, 1]], 60.0);
+        let ids: Vec<_> = fused.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, [2, 1, 4, 3]);
+    }
+}
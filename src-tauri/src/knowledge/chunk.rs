@@ -0,0 +1,245 @@
+//! Splits documents into retrieval passages and tokenizes them for BM25.
+
+/// Soft upper bound on passage length; paragraphs are packed up to this size.
+pub const MAX_CHUNK_CHARS: usize = 1_200;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chunk {
+    /// Nearest Markdown heading above the passage.
+    pub heading: Option<String>,
+    pub text: String,
+}
+
+/// Splits `text` into passages. Markdown sections start at headings so a
+/// passage never spans two sections; plain text is split on blank lines only.
+pub fn chunk_document(text: &str, markdown: bool) -> Vec<Chunk> {
+    let mut chunks = Vec::new();
+    let mut heading: Option<String> = None;
+    let mut paragraphs: Vec<String> = Vec::new();
+    let mut paragraph = String::new();
+    let mut in_fence = false;
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if markdown && (trimmed.starts_with("```") || trimmed.starts_with("~~~")) {
+            in_fence = !in_fence;
+        }
+        let heading_text = (markdown && !in_fence)
+            .then(|| markdown_heading(trimmed))
+            .flatten();
+        if let Some(next_heading) = heading_text {
+            push_paragraph(&mut paragraphs, &mut paragraph);
+            pack_paragraphs(&mut chunks, heading.as_deref(), &mut paragraphs);
+            heading = Some(next_heading.to_string());
+            continue;
+        }
+        if trimmed.is_empty() && !in_fence {
+            push_paragraph(&mut paragraphs, &mut paragraph);
+            continue;
+        }
+        if !paragraph.is_empty() {
+            paragraph.push('\n');
+        }
+        paragraph.push_str(line.trim_end());
+    }
+    push_paragraph(&mut paragraphs, &mut paragraph);
+    pack_paragraphs(&mut chunks, heading.as_deref(), &mut paragraphs);
+    chunks
+}
+
+fn markdown_heading(line: &str) -> Option<&str> {
+    let hashes = line.chars().take_while(|c| *c == '#').count();
+    if !(1..=6).contains(&hashes) {
+        return None;
+    }
+    let rest = &line[hashes..];
+    if !rest.starts_with(' ') {
+        return None;
+    }
+    let title = rest.trim().trim_end_matches('#').trim();
+    (!title.is_empty()).then_some(title)
+}
+
+fn push_paragraph(paragraphs: &mut Vec<String>, paragraph: &mut String) {
+    let text = paragraph.trim();
+    if !text.is_empty() {
+        paragraphs.push(text.to_string());
+    }
+    paragraph.clear();
+}
+
+fn pack_paragraphs(chunks: &mut Vec<Chunk>, heading: Option<&str>, paragraphs: &mut Vec<String>) {
+    let mut current = String::new();
+    for paragraph in paragraphs.drain(..) {
+        for piece in split_long(&paragraph) {
+            if !current.is_empty()
+                && current.chars().count() + piece.chars().count() + 2 > MAX_CHUNK_CHARS
+            {
+                chunks.push(Chunk {
+                    heading: heading.map(str::to_string),
+                    text: std::mem::take(&mut current),
+                });
+            }
+            if !current.is_empty() {
+                current.push_str("\n\n");
+            }
+            current.push_str(piece);
+        }
+    }
+    if !current.is_empty() {
+        chunks.push(Chunk {
+            heading: heading.map(str::to_string),
+            text: current,
+        });
+    }
+}
+
+/// Cuts an oversized paragraph at whitespace near `MAX_CHUNK_CHARS`.
+fn split_long(paragraph: &str) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut rest = paragraph;
+    while rest.chars().count() > MAX_CHUNK_CHARS {
+        let limit = rest
+            .char_indices()
+            .nth(MAX_CHUNK_CHARS)
+            .map_or(rest.len(), |(index, _)| index);
+        let cut = rest[..limit]
+            .rfind(char::is_whitespace)
+            .filter(|index| *index > limit / 2)
+            .unwrap_or(limit);
+        pieces.push(rest[..cut].trim_end());
+        rest = rest[cut..].trim_start();
+    }
+    if !rest.is_empty() {
+        pieces.push(rest);
+    }
+    pieces
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c as u32,
+        0x3040..=0x30FF     // Hiragana, Katakana
+        | 0x3400..=0x4DBF   // CJK Extension A
+        | 0x4E00..=0x9FFF   // CJK Unified Ideographs
+        | 0xAC00..=0xD7AF   // Hangul syllables
+        | 0xF900..=0xFAFF) // CJK Compatibility Ideographs
+}
+
+/// Lowercased index terms. Space-separated scripts yield words; CJK runs,
+/// which have no word boundaries, yield overlapping character bigrams.
+pub fn tokenize(text: &str) -> Vec<String> {
+    let mut terms = Vec::new();
+    let mut word = String::new();
+    let mut cjk_run: Vec<char> = Vec::new();
+
+    let flush_word = |word: &mut String, terms: &mut Vec<String>| {
+        // Single letters carry no signal, but lone digits ("step 3") do.
+        let keep = word.chars().count() >= 2 || word.chars().all(|c| c.is_ascii_digit());
+        if keep && !word.is_empty() && !is_stopword(word) {
+            terms.push(std::mem::take(word));
+        }
+        word.clear();
+    };
+    let flush_cjk = |run: &mut Vec<char>, terms: &mut Vec<String>| {
+        match run.len() {
+            0 => {}
+            1 => terms.push(run[0].to_string()),
+            _ => terms.extend(run.windows(2).map(|pair| pair.iter().collect::<String>())),
+        }
+        run.clear();
+    };
+
+    for c in text.chars() {
+        if is_cjk(c) {
+            flush_word(&mut word, &mut terms);
+            cjk_run.push(c);
+        } else if c.is_alphanumeric() {
+            flush_cjk(&mut cjk_run, &mut terms);
+            word.extend(c.to_lowercase());
+        } else {
+            flush_word(&mut word, &mut terms);
+            flush_cjk(&mut cjk_run, &mut terms);
+        }
+    }
+    flush_word(&mut word, &mut terms);
+    flush_cjk(&mut cjk_run, &mut terms);
+    terms
+}
+
+fn is_stopword(word: &str) -> bool {
+    matches!(
+        word,
+        "a" | "an"
+            | "and"
+            | "are"
+            | "as"
+            | "at"
+            | "be"
+            | "by"
+            | "do"
+            | "does"
+            | "for"
+            | "from"
+            | "how"
+            | "in"
+            | "is"
+            | "it"
+            | "of"
+            | "on"
+            | "or"
+            | "our"
+            | "the"
+            | "to"
+            | "we"
+            | "what"
+            | "when"
+            | "where"
+            | "which"
+            | "who"
+            | "why"
+            | "with"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn markdown_sections_start_new_chunks_and_keep_their_heading() {
+        let doc = "Intro line.\n\n# Setup\n\nInstall the CLI.\n\n```sh\n# not a heading\n\nrun it\n```\n\n## Deploy ##\nShip it.";
+        let chunks = chunk_document(doc, true);
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0].heading, None);
+        assert_eq!(chunks[1].heading.as_deref(), Some("Setup"));
+        assert!(chunks[1].text.contains("# not a heading"));
+        assert_eq!(chunks[2].heading.as_deref(), Some("Deploy"));
+        assert_eq!(chunks[2].text, "Ship it.");
+    }
+
+    #[test]
+    fn long_paragraphs_are_split_near_the_size_limit() {
+        let paragraph = "word ".repeat(700);
+        let chunks = chunk_document(&paragraph, false);
+
+        assert!(chunks.len() >= 3);
+        for chunk in &chunks {
+            assert!(chunk.text.chars().count() <= MAX_CHUNK_CHARS);
+            assert!(!chunk.text.starts_with(' '));
+        }
+    }
+
+    #[test]
+    fn tokenizer_lowercases_words_drops_stopwords_and_bigrams_cjk() {
+        assert_eq!(
+            tokenize("How do we rotate the API-Keys in v2?"),
+            ["rotate", "api", "keys", "v2"]
+        );
+        assert_eq!(
+            tokenize("部署流程 uses k8s"),
+            ["部署", "署流", "流程", "uses", "k8s"]
+        );
+        assert_eq!(tokenize("step 3"), ["step", "3"]);
+    }
+}
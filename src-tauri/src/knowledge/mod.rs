@@ -0,0 +1,307 @@
+//! Local knowledge base for Ask: a user-chosen folder of Markdown and text
+//! files, chunked into passages and searched with BM25, optionally fused
+//! with embedding similarity from an OpenAI-compatible endpoint.
+
+pub mod bm25;
+pub mod chunk;
+pub mod embeddings;
+pub mod store;
+
+use std::path::Path;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::Manager;
+
+use crate::credentials::{resolve_config_secret, SystemCredentialVault};
+use crate::storage::{AppConfig, ConfigManager};
+
+pub use embeddings::EmbeddingConfig;
+pub use store::{KnowledgeStatus, KnowledgeStore};
+
+/// Vault namespace and provider holding the embeddings API key.
+pub const EMBEDDING_CREDENTIAL_NAMESPACE: &str = "knowledge";
+pub const EMBEDDING_CREDENTIAL_PROVIDER: &str = "embeddings";
+
+/// Passages handed to the model per question.
+pub const MAX_PASSAGES: usize = 4;
+/// Candidates taken from each ranking before fusion.
+const CANDIDATES_PER_RANKING: usize = 20;
+const RRF_K: f64 = 60.0;
+/// Vector hits below this cosine similarity are treated as unrelated.
+const MIN_VECTOR_SIMILARITY: f32 = 0.3;
+/// Longest an Ask waits for passages before answering without them.
+pub const RETRIEVAL_TIMEOUT: Duration = Duration::from_secs(3);
+/// How often the indexer checks the folder and settings for changes.
+const WATCH_INTERVAL: Duration = Duration::from_secs(15);
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct KnowledgePassage {
+    /// Path relative to the knowledge folder, always `/`-separated.
+    pub path: String,
+    pub heading: Option<String>,
+    pub text: String,
+    pub score: f64,
+}
+
+impl KnowledgePassage {
+    /// `path#heading`, or just the path for passages above the first heading.
+    pub fn source(&self) -> String {
+        match &self.heading {
+            Some(heading) => format!("{}#{}", self.path, heading),
+            None => self.path.clone(),
+        }
+    }
+}
+
+/// The embeddings endpoint configured for the knowledge base, if any.
+pub fn embedding_config(config: &AppConfig) -> Option<EmbeddingConfig> {
+    let api_key = resolve_config_secret(
+        "",
+        EMBEDDING_CREDENTIAL_NAMESPACE,
+        EMBEDDING_CREDENTIAL_PROVIDER,
+        &SystemCredentialVault,
+    )
+    .unwrap_or_else(|e| {
+        tracing::warn!("Failed to read knowledge embeddings key: {e}");
+        String::new()
+    });
+    EmbeddingConfig::from_parts(
+        &config.knowledge_embedding_base_url,
+        &config.knowledge_embedding_model,
+        api_key,
+    )
+}
+
+/// Embeds every chunk that has no vector yet. Stops at the first failed batch
+/// so an unreachable endpoint costs one request, not one per batch.
+pub async fn embed_missing(
+    store: &KnowledgeStore,
+    client: &reqwest::Client,
+    config: &EmbeddingConfig,
+) -> anyhow::Result<usize> {
+    let mut embedded = 0;
+    loop {
+        let batch = store
+            .chunks_missing_embeddings(&config.model, embeddings::EMBEDDING_BATCH_SIZE)
+            .await?;
+        if batch.is_empty() {
+            return Ok(embedded);
+        }
+        let inputs: Vec<String> = batch.iter().map(|(_, text)| text.clone()).collect();
+        let vectors = embeddings::embed(client, config, &inputs)
+            .await
+            .map_err(|e| anyhow::anyhow!("knowledge embeddings failed: {e:?}"))?;
+        let rows: Vec<_> = batch.iter().map(|(id, _)| *id).zip(vectors).collect();
+        store.set_embeddings(&rows).await?;
+        embedded += rows.len();
+    }
+}
+
+/// Rescans the configured folder and, when an embeddings endpoint is set,
+/// embeds any passages that have no vector yet.
+pub async fn refresh_index(
+    store: &KnowledgeStore,
+    client: &reqwest::Client,
+    config: &AppConfig,
+) -> anyhow::Result<store::IndexReport> {
+    if config.knowledge_base_folder.is_empty() {
+        anyhow::bail!("knowledge_folder_missing");
+    }
+    let report = store
+        .index_folder(Path::new(&config.knowledge_base_folder))
+        .await?;
+    tracing::info!(
+        "Knowledge base indexed: {} indexed, {} unchanged, {} removed, {} skipped",
+        report.indexed,
+        report.unchanged,
+        report.removed,
+        report.skipped
+    );
+    if let Some(embedding) = embedding_config(config) {
+        embed_missing(store, client, &embedding).await?;
+    }
+    Ok(report)
+}
+
+/// Everything a refresh depends on; a change in any of it triggers one.
+#[derive(PartialEq)]
+struct IndexInputs {
+    folder: String,
+    embedding_base_url: String,
+    embedding_model: String,
+    files: Vec<(String, i64, u64)>,
+}
+
+/// Background job that keeps the index current so Ask only has to search.
+/// It polls the folder and the knowledge settings, and refreshes right away
+/// when `request_refresh` is called.
+#[derive(Clone)]
+pub struct KnowledgeIndexer {
+    refresh_tx: mpsc::SyncSender<()>,
+}
+
+impl KnowledgeIndexer {
+    /// Spawns the indexer. Reads the config manager, store and HTTP client
+    /// from `app`, so call it after those are managed.
+    pub fn start(app: tauri::AppHandle) -> Self {
+        let (refresh_tx, refresh_rx) = mpsc::sync_channel(1);
+        let spawned = std::thread::Builder::new()
+            .name("knowledge-indexer".to_string())
+            .spawn(move || {
+                let mut last: Option<IndexInputs> = None;
+                loop {
+                    let requested = match refresh_rx.recv_timeout(WATCH_INTERVAL) {
+                        Ok(()) => true,
+                        Err(mpsc::RecvTimeoutError::Timeout) => false,
+                        Err(mpsc::RecvTimeoutError::Disconnected) => return,
+                    };
+                    let config =
+                        match tauri::async_runtime::block_on(app.state::<ConfigManager>().load()) {
+                            Ok(config) => config,
+                            Err(error) => {
+                                tracing::warn!("Knowledge indexer could not load config: {error}");
+                                continue;
+                            }
+                        };
+                    if !config.knowledge_base_enabled || config.knowledge_base_folder.is_empty() {
+                        last = None;
+                        continue;
+                    }
+                    let inputs = IndexInputs {
+                        files: store::folder_fingerprint(Path::new(&config.knowledge_base_folder)),
+                        folder: config.knowledge_base_folder.clone(),
+                        embedding_base_url: config.knowledge_embedding_base_url.clone(),
+                        embedding_model: config.knowledge_embedding_model.clone(),
+                    };
+                    if !requested && last.as_ref() == Some(&inputs) {
+                        continue;
+                    }
+                    let store = app.state::<KnowledgeStore>();
+                    let client = app.state::<reqwest::Client>();
+                    if let Err(error) =
+                        tauri::async_runtime::block_on(refresh_index(&store, &client, &config))
+                    {
+                        tracing::warn!("Knowledge base refresh failed: {error}");
+                    }
+                    // Failures are not retried until something changes; the
+                    // settings page can still ask for a reindex.
+                    last = Some(inputs);
+                }
+            });
+        if let Err(error) = spawned {
+            tracing::warn!("Knowledge base background indexing unavailable: {error}");
+        }
+        let indexer = Self { refresh_tx };
+        indexer.request_refresh();
+        indexer
+    }
+
+    /// Asks for a refresh on the next loop turn. A pending request already
+    /// covers this one, so a full queue is fine.
+    pub fn request_refresh(&self) {
+        let _ = self.refresh_tx.try_send(());
+    }
+}
+
+/// Passages most relevant to `query` from the current index. Indexing and
+/// passage embeddings belong to `KnowledgeIndexer`; only the query is
+/// embedded here, and embedding failures degrade to lexical-only retrieval.
+pub async fn retrieve(
+    store: &KnowledgeStore,
+    client: &reqwest::Client,
+    embedding: Option<&EmbeddingConfig>,
+    query: &str,
+) -> anyhow::Result<Vec<KnowledgePassage>> {
+    let lexical = store.lexical_search(query, CANDIDATES_PER_RANKING).await?;
+    let mut rankings = vec![lexical.iter().map(|(id, _)| *id).collect::<Vec<_>>()];
+    if let Some(embedding) = embedding {
+        match vector_ranking(store, client, embedding, query).await {
+            Ok(ranking) => rankings.push(ranking),
+            Err(e) => tracing::warn!("Knowledge vector search skipped: {e}"),
+        }
+    }
+
+    let mut fused = bm25::reciprocal_rank_fusion(&rankings, RRF_K);
+    fused.truncate(MAX_PASSAGES);
+    store.passages(&fused).await
+}
+
+async fn vector_ranking(
+    store: &KnowledgeStore,
+    client: &reqwest::Client,
+    embedding: &EmbeddingConfig,
+    query: &str,
+) -> anyhow::Result<Vec<i64>> {
+    let query_vector = embeddings::embed(client, embedding, &[query.to_string()])
+        .await
+        .map_err(|e| anyhow::anyhow!("query embedding failed: {e:?}"))?
+        .pop()
+        .unwrap_or_default();
+    Ok(store
+        .vector_search(&query_vector, MIN_VECTOR_SIMILARITY, CANDIDATES_PER_RANKING)
+        .await?
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect())
+}
+
+/// Distinct source files of `passages`, in first-cited order.
+pub fn source_files(passages: &[KnowledgePassage]) -> Vec<String> {
+    let mut files: Vec<String> = Vec::new();
+    for passage in passages {
+        if !files.contains(&passage.path) {
+            files.push(passage.path.clone());
+        }
+    }
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn retrieves_indexed_passages_with_their_sources() {
+        let root = std::env::temp_dir().join(format!(
+            "opentypeless-knowledge-retrieve-{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        std::fs::create_dir_all(root.join("ops")).unwrap();
+        std::fs::write(
+            root.join("ops/runbook.md"),
+            "# Rotating keys\n\nRotate the signing keys every 90 days.\n\n# Deploys\n\nDeploys go out on Tuesdays.",
+        )
+        .unwrap();
+        std::fs::write(root.join("notes.txt"), "Lunch is at noon.").unwrap();
+        let store = KnowledgeStore::new(root.with_extension("sqlite")).unwrap();
+        let client = reqwest::Client::new();
+
+        // Retrieval never indexes on its own.
+        let passages = retrieve(&store, &client, None, "rotate signing keys")
+            .await
+            .unwrap();
+        assert!(passages.is_empty());
+
+        store.index_folder(&root).await.unwrap();
+        let passages = retrieve(
+            &store,
+            &client,
+            None,
+            "how often do we rotate signing keys?",
+        )
+        .await
+        .unwrap();
+        assert_eq!(passages[0].source(), "ops/runbook.md#Rotating keys");
+        assert_eq!(source_files(&passages)[0], "ops/runbook.md");
+        assert!(passages.len() <= MAX_PASSAGES);
+
+        assert!(store::folder_fingerprint(&root)
+            .iter()
+            .any(|(path, _, _)| path == "ops/runbook.md"));
+    }
+}
@@ -0,0 +1,603 @@
+//! SQLite persistence for the knowledge base: documents, chunks, BM25
+//! postings and optional embeddings, sharing the app database.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::Result;
+use rusqlite::{Connection, OptionalExtension};
+use serde::Serialize;
+
+use super::bm25::{self, CorpusStats};
+use super::chunk;
+use super::embeddings;
+use super::KnowledgePassage;
+
+/// Files larger than this are skipped rather than partially indexed.
+pub const MAX_FILE_BYTES: u64 = 512 * 1024;
+/// Walking stops once this many files have been indexed.
+pub const MAX_FILES: usize = 2_000;
+const MAX_DEPTH: usize = 12;
+const INDEXED_EXTENSIONS: &[&str] = &["md", "markdown", "mdx", "txt", "text"];
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IndexReport {
+    pub indexed: usize,
+    pub unchanged: usize,
+    pub removed: usize,
+    pub skipped: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KnowledgeStatus {
+    pub folder: String,
+    pub document_count: u64,
+    pub chunk_count: u64,
+    pub embedded_chunk_count: u64,
+    /// RFC 3339 UTC time of the last completed scan.
+    pub indexed_at: Option<String>,
+}
+
+struct FoundFile {
+    relative: String,
+    absolute: PathBuf,
+    modified_ms: i64,
+    size: u64,
+}
+
+pub struct KnowledgeStore {
+    conn: Mutex<Connection>,
+    /// Serializes scans so the background job and a manual reindex never
+    /// interleave their reads and writes.
+    indexing: Mutex<()>,
+}
+
+impl KnowledgeStore {
+    pub fn new(db_path: PathBuf) -> Result<Self> {
+        let conn = Connection::open(&db_path)?;
+        conn.execute_batch("PRAGMA journal_mode=WAL;")?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS kb_meta (
+                key TEXT PRIMARY KEY,
+                value TEXT NOT NULL
+            );
+            CREATE TABLE IF NOT EXISTS kb_documents (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                path TEXT NOT NULL UNIQUE,
+                modified_ms INTEGER NOT NULL,
+                size INTEGER NOT NULL
+            );
+            CREATE TABLE IF NOT EXISTS kb_chunks (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                document_id INTEGER NOT NULL,
+                ordinal INTEGER NOT NULL,
+                heading TEXT,
+                text TEXT NOT NULL,
+                length INTEGER NOT NULL,
+                embedding BLOB
+            );
+            CREATE INDEX IF NOT EXISTS idx_kb_chunks_document ON kb_chunks(document_id);
+            CREATE TABLE IF NOT EXISTS kb_postings (
+                term TEXT NOT NULL,
+                chunk_id INTEGER NOT NULL,
+                tf INTEGER NOT NULL,
+                PRIMARY KEY (term, chunk_id)
+            ) WITHOUT ROWID;
+            CREATE INDEX IF NOT EXISTS idx_kb_postings_chunk ON kb_postings(chunk_id);",
+        )?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+            indexing: Mutex::new(()),
+        })
+    }
+
+    pub async fn status(&self) -> Result<KnowledgeStatus> {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let count = |sql: &str| conn.query_row(sql, [], |row| row.get::<_, i64>(0));
+        Ok(KnowledgeStatus {
+            folder: meta(&conn, "folder")?.unwrap_or_default(),
+            document_count: count("SELECT COUNT(*) FROM kb_documents")? as u64,
+            chunk_count: count("SELECT COUNT(*) FROM kb_chunks")? as u64,
+            embedded_chunk_count: count(
+                "SELECT COUNT(*) FROM kb_chunks WHERE embedding IS NOT NULL",
+            )? as u64,
+            indexed_at: meta(&conn, "indexed_at")?,
+        })
+    }
+
+    /// Brings the index in line with `folder`. Unchanged files (same size and
+    /// modification time) are skipped, and changed files are read before the
+    /// connection is locked so searches only wait for the writes. Pointing at
+    /// a different folder drops the previous index.
+    pub async fn index_folder(&self, folder: &Path) -> Result<IndexReport> {
+        if !folder.is_absolute() || !folder.is_dir() {
+            anyhow::bail!("knowledge_folder_missing");
+        }
+        let _indexing = self.indexing.lock().unwrap_or_else(|e| e.into_inner());
+        let folder_key = folder.to_string_lossy().to_string();
+        let mut report = IndexReport::default();
+        let mut files = Vec::new();
+        walk(folder, folder, 0, &mut files, &mut report.skipped);
+
+        let known = {
+            let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+            if meta(&conn, "folder")?.as_deref() == Some(folder_key.as_str()) {
+                known_documents(&conn)?
+            } else {
+                HashMap::new()
+            }
+        };
+
+        let mut seen = HashSet::new();
+        let mut changed = Vec::new();
+        for file in files {
+            seen.insert(file.relative.clone());
+            let existing = known.get(&file.relative).copied();
+            if let Some((_, modified_ms, size)) = existing {
+                if modified_ms == file.modified_ms && size == file.size as i64 {
+                    report.unchanged += 1;
+                    continue;
+                }
+            }
+            let Some(text) = std::fs::read(&file.absolute)
+                .ok()
+                .and_then(|bytes| String::from_utf8(bytes).ok())
+            else {
+                report.skipped += 1;
+                continue;
+            };
+            changed.push((file, existing.map(|(id, _, _)| id), text));
+        }
+
+        let mut conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let tx = conn.transaction()?;
+        if meta(&tx, "folder")?.as_deref() != Some(folder_key.as_str()) {
+            clear_tables(&tx)?;
+            set_meta(&tx, "folder", &folder_key)?;
+        }
+        for (file, existing, text) in &changed {
+            let document_id = match existing {
+                Some(id) => {
+                    delete_document_chunks(&tx, *id)?;
+                    tx.execute(
+                        "UPDATE kb_documents SET modified_ms = ?2, size = ?3 WHERE id = ?1",
+                        rusqlite::params![id, file.modified_ms, file.size as i64],
+                    )?;
+                    *id
+                }
+                None => {
+                    tx.execute(
+                        "INSERT INTO kb_documents (path, modified_ms, size) VALUES (?1, ?2, ?3)",
+                        rusqlite::params![file.relative, file.modified_ms, file.size as i64],
+                    )?;
+                    tx.last_insert_rowid()
+                }
+            };
+            insert_chunks(&tx, document_id, text, is_markdown(&file.absolute))?;
+            report.indexed += 1;
+        }
+
+        for (path, (id, _, _)) in &known {
+            if !seen.contains(path) {
+                delete_document_chunks(&tx, *id)?;
+                tx.execute("DELETE FROM kb_documents WHERE id = ?1", [id])?;
+                report.removed += 1;
+            }
+        }
+        set_meta(&tx, "indexed_at", &chrono::Utc::now().to_rfc3339())?;
+        tx.commit()?;
+        Ok(report)
+    }
+
+    /// Chunks that still need a vector for `model`. Switching models discards
+    /// vectors produced by the previous one.
+    pub async fn chunks_missing_embeddings(
+        &self,
+        model: &str,
+        limit: usize,
+    ) -> Result<Vec<(i64, String)>> {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        if meta(&conn, "embedding_model")?.as_deref() != Some(model) {
+            conn.execute("UPDATE kb_chunks SET embedding = NULL", [])?;
+            set_meta(&conn, "embedding_model", model)?;
+        }
+        let mut stmt = conn.prepare(
+            "SELECT id, heading, text FROM kb_chunks WHERE embedding IS NULL ORDER BY id LIMIT ?1",
+        )?;
+        let rows = stmt.query_map([limit as i64], |row| {
+            let heading: Option<String> = row.get(1)?;
+            let text: String = row.get(2)?;
+            Ok((row.get(0)?, indexed_text(heading.as_deref(), &text)))
+        })?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    pub async fn set_embeddings(&self, vectors: &[(i64, Vec<f32>)]) -> Result<()> {
+        let mut conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let tx = conn.transaction()?;
+        for (id, vector) in vectors {
+            tx.execute(
+                "UPDATE kb_chunks SET embedding = ?2 WHERE id = ?1",
+                rusqlite::params![id, embeddings::encode(vector)],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Best-first chunk ids by BM25 over the query's terms.
+    pub async fn lexical_search(&self, query: &str, limit: usize) -> Result<Vec<(i64, f64)>> {
+        let mut terms = chunk::tokenize(query);
+        terms.sort();
+        terms.dedup();
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let (chunk_count, average_length): (i64, f64) = conn.query_row(
+            "SELECT COUNT(*), COALESCE(AVG(length), 0) FROM kb_chunks",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+        let mut stats = CorpusStats {
+            chunk_count: chunk_count as u64,
+            average_length,
+            document_frequency: HashMap::new(),
+        };
+
+        let mut matches: HashMap<i64, (u64, Vec<(String, u64)>)> = HashMap::new();
+        let mut stmt = conn.prepare(
+            "SELECT p.chunk_id, p.tf, c.length FROM kb_postings p
+             JOIN kb_chunks c ON c.id = p.chunk_id
+             WHERE p.term = ?1",
+        )?;
+        for term in &terms {
+            let rows = stmt.query_map([term], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, i64>(2)?,
+                ))
+            })?;
+            let mut df = 0;
+            for row in rows {
+                let (chunk_id, tf, length) = row?;
+                df += 1;
+                let entry = matches
+                    .entry(chunk_id)
+                    .or_insert_with(|| (length as u64, Vec::new()));
+                entry.1.push((term.clone(), tf as u64));
+            }
+            stats.document_frequency.insert(term.clone(), df);
+        }
+
+        let mut scored: Vec<(i64, f64)> = matches
+            .into_iter()
+            .map(|(chunk_id, (length, tfs))| {
+                let tfs: Vec<(&str, u64)> = tfs.iter().map(|(t, tf)| (t.as_str(), *tf)).collect();
+                (chunk_id, bm25::score(&stats, length, &tfs))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        scored.truncate(limit);
+        Ok(scored)
+    }
+
+    /// Best-first chunk ids by cosine similarity, ignoring weak matches.
+    pub async fn vector_search(
+        &self,
+        query: &[f32],
+        min_similarity: f32,
+        limit: usize,
+    ) -> Result<Vec<(i64, f32)>> {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let mut stmt =
+            conn.prepare("SELECT id, embedding FROM kb_chunks WHERE embedding IS NOT NULL")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, Vec<u8>>(1)?))
+        })?;
+        let mut scored = Vec::new();
+        for row in rows {
+            let (id, blob) = row?;
+            let similarity = embeddings::cosine_similarity(query, &embeddings::decode(&blob));
+            if similarity >= min_similarity {
+                scored.push((id, similarity));
+            }
+        }
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        scored.truncate(limit);
+        Ok(scored)
+    }
+
+    /// Passages for `ids`, in the order given.
+    pub async fn passages(&self, ids: &[(i64, f64)]) -> Result<Vec<KnowledgePassage>> {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let mut stmt = conn.prepare(
+            "SELECT d.path, c.heading, c.text FROM kb_chunks c
+             JOIN kb_documents d ON d.id = c.document_id
+             WHERE c.id = ?1",
+        )?;
+        let mut passages = Vec::new();
+        for (id, score) in ids {
+            let passage = stmt
+                .query_row([id], |row| {
+                    Ok(KnowledgePassage {
+                        path: row.get(0)?,
+                        heading: row.get(1)?,
+                        text: row.get(2)?,
+                        score: *score,
+                    })
+                })
+                .optional()?;
+            passages.extend(passage);
+        }
+        Ok(passages)
+    }
+
+    pub async fn clear(&self) -> Result<()> {
+        let _indexing = self.indexing.lock().unwrap_or_else(|e| e.into_inner());
+        let mut conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let tx = conn.transaction()?;
+        clear_tables(&tx)?;
+        tx.execute("DELETE FROM kb_meta", [])?;
+        tx.commit()?;
+        Ok(())
+    }
+}
+
+/// Relative path, modification time and size of every file `index_folder`
+/// would look at, to notice changes without touching the database.
+pub fn folder_fingerprint(folder: &Path) -> Vec<(String, i64, u64)> {
+    let mut files = Vec::new();
+    walk(folder, folder, 0, &mut files, &mut 0);
+    files
+        .into_iter()
+        .map(|file| (file.relative, file.modified_ms, file.size))
+        .collect()
+}
+
+/// Indexed documents by path: id, modification time and size.
+fn known_documents(conn: &Connection) -> Result<HashMap<String, (i64, i64, i64)>> {
+    let mut stmt = conn.prepare("SELECT id, path, modified_ms, size FROM kb_documents")?;
+    let rows = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, String>(1)?,
+            (row.get(0)?, row.get(2)?, row.get(3)?),
+        ))
+    })?;
+    Ok(rows.collect::<rusqlite::Result<_>>()?)
+}
+
+fn meta(conn: &Connection, key: &str) -> Result<Option<String>> {
+    Ok(conn
+        .query_row("SELECT value FROM kb_meta WHERE key = ?1", [key], |row| {
+            row.get(0)
+        })
+        .optional()?)
+}
+
+fn set_meta(conn: &Connection, key: &str, value: &str) -> Result<()> {
+    conn.execute(
+        "INSERT INTO kb_meta (key, value) VALUES (?1, ?2)
+         ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        [key, value],
+    )?;
+    Ok(())
+}
+
+fn clear_tables(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "DELETE FROM kb_postings;
+         DELETE FROM kb_chunks;
+         DELETE FROM kb_documents;",
+    )?;
+    Ok(())
+}
+
+fn delete_document_chunks(conn: &Connection, document_id: i64) -> Result<()> {
+    conn.execute(
+        "DELETE FROM kb_postings WHERE chunk_id IN
+            (SELECT id FROM kb_chunks WHERE document_id = ?1)",
+        [document_id],
+    )?;
+    conn.execute(
+        "DELETE FROM kb_chunks WHERE document_id = ?1",
+        [document_id],
+    )?;
+    Ok(())
+}
+
+/// Headings are indexed with their passage so a query naming a section finds it.
+fn indexed_text(heading: Option<&str>, text: &str) -> String {
+    match heading {
+        Some(heading) => format!("{heading}\n{text}"),
+        None => text.to_string(),
+    }
+}
+
+fn insert_chunks(conn: &Connection, document_id: i64, text: &str, markdown: bool) -> Result<()> {
+    let mut insert_chunk = conn.prepare(
+        "INSERT INTO kb_chunks (document_id, ordinal, heading, text, length)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+    )?;
+    let mut insert_posting =
+        conn.prepare("INSERT INTO kb_postings (term, chunk_id, tf) VALUES (?1, ?2, ?3)")?;
+    for (ordinal, chunk) in chunk::chunk_document(text, markdown).iter().enumerate() {
+        let terms = chunk::tokenize(&indexed_text(chunk.heading.as_deref(), &chunk.text));
+        if terms.is_empty() {
+            continue;
+        }
+        insert_chunk.execute(rusqlite::params![
+            document_id,
+            ordinal as i64,
+            chunk.heading,
+            chunk.text,
+            terms.len() as i64
+        ])?;
+        let chunk_id = conn.last_insert_rowid();
+        let mut frequencies: HashMap<&str, i64> = HashMap::new();
+        for term in &terms {
+            *frequencies.entry(term.as_str()).or_default() += 1;
+        }
+        for (term, tf) in frequencies {
+            insert_posting.execute(rusqlite::params![term, chunk_id, tf])?;
+        }
+    }
+    Ok(())
+}
+
+fn is_markdown(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| matches!(ext.to_ascii_lowercase().as_str(), "md" | "markdown" | "mdx"))
+}
+
+/// Collects indexable files below `dir`. Hidden entries and symlinks are
+/// skipped so dot-folders like `.git` and link cycles never get walked.
+fn walk(root: &Path, dir: &Path, depth: usize, files: &mut Vec<FoundFile>, skipped: &mut usize) {
+    if depth > MAX_DEPTH {
+        return;
+    }
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let mut entries: Vec<_> = entries.flatten().collect();
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        if files.len() >= MAX_FILES {
+            return;
+        }
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        let path = entry.path();
+        if file_type.is_dir() {
+            walk(root, &path, depth + 1, files, skipped);
+            continue;
+        }
+        if !file_type.is_file() {
+            continue;
+        }
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase);
+        if !extension.is_some_and(|ext| INDEXED_EXTENSIONS.contains(&ext.as_str())) {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.len() > MAX_FILE_BYTES {
+            *skipped += 1;
+            continue;
+        }
+        let modified_ms = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+            .map_or(0, |duration| duration.as_millis() as i64);
+        let Ok(relative) = path.strip_prefix(root) else {
+            continue;
+        };
+        let relative = relative
+            .components()
+            .map(|part| part.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        files.push(FoundFile {
+            relative,
+            absolute: path,
+            modified_ms,
+            size: metadata.len(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "opentypeless-knowledge-test-{}-{}",
+            name,
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ))
+    }
+
+    #[tokio::test]
+    async fn indexes_folder_incrementally_and_ranks_matching_passages() {
+        let root = temp_dir("folder");
+        std::fs::create_dir_all(root.join("ops/.git")).unwrap();
+        std::fs::write(
+            root.join("ops/runbook.md"),
+            "# Rotating keys\n\nRotate the signing keys every 90 days with `kms rotate`.\n\n# Deploys\n\nDeploys go out on Tuesdays.",
+        )
+        .unwrap();
+        std::fs::write(root.join("notes.txt"), "Lunch is at noon.").unwrap();
+        std::fs::write(root.join("ops/.git/config"), "rotate rotate rotate").unwrap();
+        std::fs::write(root.join("image.png"), [0u8, 1, 2]).unwrap();
+
+        let store = KnowledgeStore::new(root.with_extension("sqlite")).unwrap();
+        let report = store.index_folder(&root).await.unwrap();
+        assert_eq!(report.indexed, 2);
+
+        let hits = store
+            .lexical_search("how often do we rotate keys?", 5)
+            .await
+            .unwrap();
+        let passages = store.passages(&hits).await.unwrap();
+        assert_eq!(passages[0].path, "ops/runbook.md");
+        assert_eq!(passages[0].heading.as_deref(), Some("Rotating keys"));
+        assert!(passages.iter().all(|p| p.path != "notes.txt"));
+
+        let report = store.index_folder(&root).await.unwrap();
+        assert_eq!((report.indexed, report.unchanged), (0, 2));
+
+        std::fs::remove_file(root.join("notes.txt")).unwrap();
+        let report = store.index_folder(&root).await.unwrap();
+        assert_eq!(report.removed, 1);
+        let status = store.status().await.unwrap();
+        assert_eq!(status.document_count, 1);
+        assert_eq!(status.chunk_count, 2);
+        assert!(status.indexed_at.is_some());
+
+        let missing = store
+            .chunks_missing_embeddings("test-model", 10)
+            .await
+            .unwrap();
+        assert_eq!(missing.len(), 2);
+        store
+            .set_embeddings(&[
+                (missing[0].0, vec![1.0, 0.0]),
+                (missing[1].0, vec![0.0, 1.0]),
+            ])
+            .await
+            .unwrap();
+        let nearest = store.vector_search(&[0.9, 0.1], 0.3, 5).await.unwrap();
+        assert_eq!(nearest.len(), 1);
+        assert_eq!(nearest[0].0, missing[0].0);
+        assert_eq!(
+            store
+                .chunks_missing_embeddings("other-model", 10)
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+
+        store.clear().await.unwrap();
+        assert_eq!(store.status().await.unwrap().chunk_count, 0);
+        std::fs::remove_dir_all(&root).ok();
+    }
+}
@@ -1,10 +1,12 @@
 pub mod app_detector;
 pub mod audio;
 pub mod commands;
+pub mod corrections;
 pub mod credentials;
 pub mod dictionary_io;
 pub mod error;
 pub mod hotkey;
+pub mod knowledge;
 #[cfg(target_os = "linux")]
 mod linux_x11;
 pub mod llm;
@@ -13,7 +15,9 @@ pub mod output;
 pub mod pipeline;
 pub mod platform;
 pub mod recording_deadline;
+pub mod scene_io;
 pub mod selection;
+pub mod spoken_formatting;
 pub mod storage;
 pub mod stt;
 pub mod tray;
@@ -765,9 +769,33 @@ pub fn run() {
             let config_manager = storage::ConfigManager::new(app_handle.clone());
             let history_store = storage::HistoryStore::new(db_path.clone())
                 .map_err(|e| anyhow::anyhow!("Failed to init history store: {}", e))?;
+            let ask_thread_store = storage::AskThreadStore::new(db_path.clone())
+                .map_err(|e| anyhow::anyhow!("Failed to init Ask thread store: {}", e))?;
+            let knowledge_store = knowledge::KnowledgeStore::new(db_path.clone())
+                .map_err(|e| anyhow::anyhow!("Failed to init knowledge store: {}", e))?;
             let dictionary_store = storage::DictionaryStore::new(db_path)
                 .map_err(|e| anyhow::anyhow!("Failed to init dictionary store: {}", e))?;
 
+            // User voice command grammar; a broken file must not block startup.
+            match voice_intent::grammar::load_user_grammar(
+                &data_dir.join(voice_intent::grammar::USER_GRAMMAR_FILE),
+            ) {
+                Ok(load) => {
+                    for rejected in &load.rejected {
+                        tracing::warn!("Skipped voice grammar {rejected}");
+                    }
+                    if !load.grammar.is_empty() {
+                        tracing::info!(
+                            "Loaded {} user voice grammar rules and {} search providers",
+                            load.grammar.len(),
+                            load.grammar.search_provider_count()
+                        );
+                    }
+                    voice_intent::grammar::install_user_grammar(load.grammar);
+                }
+                Err(error) => tracing::warn!("Ignoring user voice grammar: {error}"),
+            }
+
             let shared_client = reqwest::Client::builder()
                 .pool_max_idle_per_host(2)
                 .pool_idle_timeout(std::time::Duration::from_secs(30))
@@ -775,7 +803,14 @@ pub fn run() {
                 .build()
                 .expect("Failed to create HTTP client");
 
-            let app_registry = app_detector::registry::AppRegistry::builtin()
+            // Context profile packs extend the built-in registry; a broken pack
+            // is skipped instead of blocking startup.
+            let profile_pack_dir = data_dir.join(app_detector::packs::PROFILE_PACK_DIR);
+            let app_registry = app_detector::packs::load_registry(&profile_pack_dir)
+                .or_else(|error| {
+                    tracing::warn!("Ignoring context profile packs: {error}");
+                    app_detector::registry::AppRegistry::builtin()
+                })
                 .map_err(|error| anyhow::anyhow!("Failed to init app registry: {error}"))?;
             let app_mapping_store = app_detector::user_mappings::UserAppMappingStore::new(
                 app_handle.clone(),
@@ -783,6 +818,11 @@ pub fn run() {
             );
             let context_detector =
                 app_detector::ContextDetectorHandle::start_default(app_mapping_store.clone());
+            app_detector::packs::watch_profile_packs(
+                profile_pack_dir,
+                app_mapping_store.clone(),
+                context_detector.clone(),
+            );
             let pipeline_handle = pipeline::PipelineHandle::new(
                 app_handle.clone(),
                 shared_client.clone(),
@@ -795,12 +835,15 @@ pub fn run() {
             sync_auto_start_preference(&app_handle, &config_manager, &mut initial_config);
             app.manage(config_manager);
             app.manage(history_store);
+            app.manage(ask_thread_store);
+            app.manage(knowledge_store);
             app.manage(dictionary_store);
             app.manage(app_mapping_store);
             app.manage(shared_client);
             app.manage(context_detector);
             app.manage(pipeline_handle);
             app.manage(commands::ask::AskDictationState::default());
+            app.manage(commands::ask::AskSessionState::default());
             app.manage(HotkeyModeCache(Arc::new(Mutex::new(
                 initial_config.hotkey_mode.clone(),
             ))));
@@ -820,6 +863,7 @@ pub fn run() {
                 initial_config.close_to_tray,
             ))));
             app.manage(SessionTokenStore(Arc::new(Mutex::new(String::new()))));
+            app.manage(knowledge::KnowledgeIndexer::start(app_handle.clone()));
 
             // Register global shortcut from config
             let handler = hotkey::build_shortcut_handler(app_handle.clone());
@@ -1060,15 +1104,21 @@ pub fn run() {
             commands::ask::stop_ask_flow,
             commands::ask::abort_ask_dictation,
             commands::ask::take_pending_ask_message,
+            commands::ask::get_ask_session,
+            commands::ask::reset_ask_session,
             commands::translation::set_active_translation_target,
             commands::app_mappings::get_latest_mapping_candidate,
             commands::app_mappings::list_custom_app_mappings,
             commands::app_mappings::save_custom_app_mapping,
             commands::app_mappings::update_custom_app_mapping,
             commands::app_mappings::set_custom_app_mapping_enabled,
+            commands::app_mappings::set_custom_app_mapping_output_policy,
             commands::app_mappings::delete_custom_app_mapping,
             commands::app_mappings::reset_custom_app_mappings,
             commands::app_mappings::set_family_scene_assignment,
+            commands::scenes::preview_scene_pack_import,
+            commands::scenes::import_scene_pack,
+            commands::scenes::export_scene_pack,
             show_ask_window,
             commands::misc::check_accessibility_permission,
             commands::misc::request_accessibility_permission,
@@ -1091,7 +1141,17 @@ pub fn run() {
             commands::stt::bench_stt_connection,
             commands::llm::fetch_llm_models,
             commands::history::get_history,
+            commands::history::search_history,
+            commands::history::delete_history_entry,
+            commands::history::delete_history_matching,
+            commands::history::set_history_entry_pinned,
+            commands::history::update_history_polished_text,
             commands::history::clear_history,
+            commands::history::list_history_regenerations,
+            commands::history::repolish_history_entry,
+            commands::knowledge::get_knowledge_base_status,
+            commands::knowledge::reindex_knowledge_base,
+            commands::knowledge::clear_knowledge_base,
             commands::backup::restore_backup_data,
             commands::dictionary::get_dictionary,
             commands::dictionary::add_dictionary_entry,
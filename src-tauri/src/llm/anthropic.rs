@@ -0,0 +1,278 @@
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use reqwest::Client;
+
+use crate::error::AppError;
+
+use super::{
+    prompt, sse, ChunkCallback, LlmApi, LlmConfig, LlmProvider, PolishRequest, PolishResponse,
+};
+
+/// Value of the `anthropic-version` header sent with every Messages API call.
+pub const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+pub struct AnthropicProvider {
+    client: Client,
+}
+
+impl Default for AnthropicProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnthropicProvider {
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+        }
+    }
+
+    pub fn with_client(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+fn build_messages_body(config: &LlmConfig, req: &PolishRequest, stream: bool) -> serde_json::Value {
+    let prompt = prompt::build_polish_prompt(req);
+    let content: Vec<_> = prompt
+        .user_parts
+        .iter()
+        .map(|text| serde_json::json!({ "type": "text", "text": text }))
+        .collect();
+    serde_json::json!({
+        "model": config.model,
+        "max_tokens": config.max_tokens,
+        "temperature": config.temperature,
+        "system": prompt.system,
+        "messages": [{ "role": "user", "content": content }],
+        "stream": stream
+    })
+}
+
+pub(super) fn messages_url(config: &LlmConfig) -> String {
+    format!("{}/messages", config.base_url.trim_end_matches('/'))
+}
+
+/// Messages body for OpenAI-style chat `messages`: system messages move to the
+/// top-level `system` field.
+pub(super) fn build_chat_body(
+    config: &LlmConfig,
+    messages: &[serde_json::Value],
+) -> serde_json::Value {
+    let system = messages
+        .iter()
+        .filter(|message| message["role"] == "system")
+        .filter_map(|message| message["content"].as_str())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let messages: Vec<_> = messages
+        .iter()
+        .filter(|message| message["role"] != "system")
+        .map(
+            |message| serde_json::json!({ "role": message["role"], "content": message["content"] }),
+        )
+        .collect();
+    serde_json::json!({
+        "model": config.model,
+        "max_tokens": config.max_tokens,
+        "temperature": config.temperature,
+        "system": system,
+        "messages": messages,
+        "stream": false
+    })
+}
+
+/// Text of every `text` block in a non-streaming Messages response.
+pub(super) fn response_text(v: &serde_json::Value) -> String {
+    v["content"]
+        .as_array()
+        .map(|blocks| {
+            blocks
+                .iter()
+                .filter(|block| block["type"] == "text")
+                .filter_map(|block| block["text"].as_str())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Interprets one streamed event: text deltas are returned, `error` events
+/// become an `AppError`, and everything else is ignored.
+fn stream_event_text(v: &serde_json::Value) -> Result<Option<&str>, AppError> {
+    match v["type"].as_str() {
+        Some("content_block_delta") if v["delta"]["type"] == "text_delta" => {
+            Ok(v["delta"]["text"].as_str())
+        }
+        Some("error") => {
+            let kind = v["error"]["type"].as_str().unwrap_or_default();
+            // overloaded_error is Anthropic's 529; surface it as retryable.
+            let status = if kind == "overloaded_error" { 529 } else { 500 };
+            Err(super::api_error(status, &v["error"].to_string()))
+        }
+        _ => Ok(None),
+    }
+}
+
+#[async_trait]
+impl LlmProvider for AnthropicProvider {
+    async fn polish(
+        &self,
+        config: &LlmConfig,
+        req: &PolishRequest,
+        on_chunk: Option<&ChunkCallback>,
+    ) -> Result<PolishResponse, AppError> {
+        let body = build_messages_body(config, req, on_chunk.is_some());
+
+        let request = self
+            .client
+            .post(messages_url(config))
+            .timeout(config.request_timeout())
+            .header("Content-Type", "application/json");
+        let response = super::apply_api_auth_header(
+            request,
+            LlmApi::AnthropicMessages,
+            &config.provider,
+            &config.api_key,
+        )
+        .json(&body)
+        .send()
+        .await?;
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            return Err(super::api_error(status.as_u16(), &text));
+        }
+
+        let Some(callback) = on_chunk else {
+            let v: serde_json::Value = response.json().await?;
+            let text = response_text(&v);
+            if text.is_empty() {
+                tracing::warn!(
+                    "Anthropic returned no text blocks, stop_reason: {}",
+                    v["stop_reason"]
+                );
+            }
+            return Ok(PolishResponse {
+                polished_text: text,
+            });
+        };
+
+        let mut full_text = String::new();
+        let mut stream = response.bytes_stream();
+        let mut buffer = String::new();
+        while let Some(chunk) = stream.next().await {
+            buffer.push_str(&String::from_utf8_lossy(&chunk?));
+            for data in sse::take_data_lines(&mut buffer) {
+                let Ok(v) = serde_json::from_str::<serde_json::Value>(&data) else {
+                    continue;
+                };
+                if let Some(text) = stream_event_text(&v)? {
+                    if !text.is_empty() {
+                        full_text.push_str(text);
+                        callback(text);
+                    }
+                }
+            }
+        }
+
+        if full_text.is_empty() {
+            tracing::error!("Anthropic streaming returned no text");
+        }
+        Ok(PolishResponse {
+            polished_text: full_text,
+        })
+    }
+
+    fn name(&self) -> &str {
+        "Anthropic"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app_detector::types::ContextProfile;
+    use crate::voice_intent::{VoiceIntent, VoiceIntentKind, VoiceOutputPlacement};
+
+    fn request(selected_text: Option<&str>) -> PolishRequest {
+        PolishRequest {
+            raw_text: "hello world".to_string(),
+            context: ContextProfile::general_native().summary(),
+            dictionary: Vec::new(),
+            correction_rules: Vec::new(),
+            polish_style: "clean".to_string(),
+            mapped_scene_prompt: String::new(),
+            active_scene_prompt: String::new(),
+            polish_custom_prompt: String::new(),
+            translate_enabled: false,
+            target_lang: "en".to_string(),
+            selected_text: selected_text.map(str::to_string),
+            operation_id: None,
+            voice_intent: VoiceIntent::from_parts(
+                VoiceIntentKind::DictateInsert,
+                VoiceOutputPlacement::InsertAtCursor,
+                1.0,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap(),
+        }
+    }
+
+    #[test]
+    fn messages_body_keeps_system_prompt_separate_from_user_blocks() {
+        let config = LlmConfig {
+            provider: "anthropic".to_string(),
+            model: "claude-3-5-haiku-latest".to_string(),
+            ..LlmConfig::default()
+        };
+        let body = build_messages_body(&config, &request(Some("draft")), true);
+
+        assert_eq!(body["model"], "claude-3-5-haiku-latest");
+        assert_eq!(body["stream"], true);
+        assert!(body["system"]
+            .as_str()
+            .unwrap()
+            .contains("TRUSTED OPERATION"));
+        let blocks = body["messages"][0]["content"].as_array().unwrap();
+        assert_eq!(body["messages"][0]["role"], "user");
+        assert_eq!(blocks.len(), 2);
+        assert!(blocks[0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("<selected_text>"));
+        assert!(blocks[1]["text"].as_str().unwrap().contains("hello world"));
+    }
+
+    #[test]
+    fn parses_text_blocks_and_stream_events() {
+        let response = serde_json::json!({
+            "content": [
+                { "type": "text", "text": "Hello, " },
+                { "type": "tool_use", "id": "x" },
+                { "type": "text", "text": "world." }
+            ]
+        });
+        assert_eq!(response_text(&response), "Hello, world.");
+
+        let delta = serde_json::json!({
+            "type": "content_block_delta",
+            "index": 0,
+            "delta": { "type": "text_delta", "text": "Hi" }
+        });
+        assert_eq!(stream_event_text(&delta).unwrap(), Some("Hi"));
+        let ping = serde_json::json!({ "type": "ping" });
+        assert_eq!(stream_event_text(&ping).unwrap(), None);
+
+        let overloaded = serde_json::json!({
+            "type": "error",
+            "error": { "type": "overloaded_error", "message": "Overloaded" }
+        });
+        let error = stream_event_text(&overloaded).unwrap_err();
+        assert!(error.is_retryable());
+        assert!(matches!(error, AppError::Api { status: 529, .. }));
+    }
+}
@@ -185,104 +185,43 @@ impl LlmProvider for CloudLlmProvider {
             "voiceIntentMetadata": cloud_voice_intent_metadata(&req.voice_intent)
         });
 
-        // Retry the initial connection (not once streaming starts)
-        #[allow(unused_assignments)]
-        let mut response = None;
-        let mut last_error: Option<AppError> = None;
-        let mut attempt = 0u32;
-
-        loop {
-            match with_desktop_client_version(
-                self.client.post(format!("{}/api/proxy/llm", api_base_url)),
-            )
-            .header("Authorization", format!("Bearer {}", config.api_key))
-            .header("Content-Type", "application/json")
-            .json(&body)
-            .timeout(std::time::Duration::from_secs(15))
-            .send()
-            .await
-            {
-                Ok(resp) => {
-                    let status = resp.status();
-                    if status.is_success() {
-                        response = Some(resp);
-                        break;
-                    } else if status.as_u16() == 401 {
-                        let text = resp.text().await.unwrap_or_default();
-                        if let Some(error) = managed_cloud_error(status.as_u16(), &text) {
-                            return Err(error);
-                        }
-                        return Err(AppError::Api {
-                            status: status.as_u16(),
-                            body: text,
-                        });
-                    } else if status.as_u16() == 403 {
-                        let text = resp.text().await.unwrap_or_default();
-                        return Err(cloud_llm_forbidden_error(&text));
-                    } else if status.as_u16() >= 500 && attempt < 2 {
-                        let body_text = resp.text().await.unwrap_or_default();
-                        tracing::warn!(
-                            "Cloud LLM server error {} (attempt {}/3), retrying",
-                            status,
-                            attempt + 1
-                        );
-                        last_error = Some(AppError::Api {
-                            status: status.as_u16(),
-                            body: body_text,
-                        });
-                        attempt += 1;
-                        tokio::time::sleep(std::time::Duration::from_millis(
-                            1000 * 2u64.pow(attempt - 1),
-                        ))
-                        .await;
-                        continue;
-                    } else {
-                        let text = resp.text().await.unwrap_or_default();
-                        let truncate_at = text
-                            .char_indices()
-                            .take_while(|&(i, _)| i < 200)
-                            .last()
-                            .map(|(i, c)| i + c.len_utf8())
-                            .unwrap_or(text.len());
-                        let sanitized = &text[..truncate_at];
-                        return Err(AppError::Api {
-                            status: status.as_u16(),
-                            body: sanitized.to_string(),
-                        });
-                    }
-                }
-                Err(e) if e.is_timeout() && attempt < 2 => {
-                    tracing::warn!(
-                        "Cloud LLM connection timeout (attempt {}/3), retrying",
-                        attempt + 1
-                    );
-                    last_error = Some(e.into());
-                    attempt += 1;
-                    tokio::time::sleep(std::time::Duration::from_millis(
-                        1000 * 2u64.pow(attempt - 1),
-                    ))
-                    .await;
-                    continue;
-                }
-                Err(e) if e.is_connect() && attempt < 2 => {
-                    tracing::warn!(
-                        "Cloud LLM connection failed (attempt {}/3), retrying",
-                        attempt + 1
-                    );
-                    last_error = Some(e.into());
-                    attempt += 1;
-                    tokio::time::sleep(std::time::Duration::from_millis(
-                        1000 * 2u64.pow(attempt - 1),
-                    ))
-                    .await;
-                    continue;
-                }
-                Err(e) => return Err(e.into()),
+        let response = with_desktop_client_version(
+            self.client.post(format!("{}/api/proxy/llm", api_base_url)),
+        )
+        .header("Authorization", format!("Bearer {}", config.api_key))
+        .header("Content-Type", "application/json")
+        .timeout(config.request_timeout())
+        .json(&body)
+        .send()
+        .await?;
+        let status = response.status();
+        if status.as_u16() == 401 {
+            let text = response.text().await.unwrap_or_default();
+            if let Some(error) = managed_cloud_error(status.as_u16(), &text) {
+                return Err(error);
             }
+            return Err(AppError::Api {
+                status: status.as_u16(),
+                body: text,
+            });
+        } else if status.as_u16() == 403 {
+            let text = response.text().await.unwrap_or_default();
+            return Err(cloud_llm_forbidden_error(&text));
+        } else if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            let truncate_at = text
+                .char_indices()
+                .take_while(|&(i, _)| i < 200)
+                .last()
+                .map(|(i, c)| i + c.len_utf8())
+                .unwrap_or(text.len());
+            let sanitized = &text[..truncate_at];
+            return Err(AppError::Api {
+                status: status.as_u16(),
+                body: sanitized.to_string(),
+            });
         }
 
-        let response = response.ok_or_else(|| last_error.unwrap())?;
-
         if let Some(callback) = on_chunk {
             let mut full_text = String::new();
             let mut stream = response.bytes_stream();
@@ -0,0 +1,687 @@
+//! Ordered LLM provider chain with jittered retries and circuit breaking.
+//!
+//! Each entry is retried on retryable errors before the next one is tried.
+//! Non-retryable errors from the primary (a bad main key) end the chain; a
+//! fallback failing that way only hands over to the next entry.
+//! Providers that keep failing are skipped for a cooldown so a dead endpoint
+//! does not add its full timeout to every polish.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use crate::credentials::{resolve_config_secret, CredentialSecretReader};
+use crate::error::AppError;
+use crate::storage::AppConfig;
+
+use super::{ChunkCallback, LlmConfig, LlmProvider, PolishRequest, PolishResponse};
+
+pub const DEFAULT_LLM_TIMEOUT_MS: u32 = 15_000;
+const RETRIES_PER_PROVIDER: u32 = 2;
+const RETRY_BASE_DELAY_MS: u64 = 500;
+const RETRY_MAX_DELAY_MS: u64 = 4_000;
+/// Consecutive failed polishes before a provider is skipped.
+const BREAKER_FAILURE_THRESHOLD: u32 = 3;
+const BREAKER_COOLDOWN: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone)]
+pub struct LlmChainEntry {
+    pub config: LlmConfig,
+    pub timeout: Duration,
+}
+
+impl LlmChainEntry {
+    fn breaker_key(&self) -> String {
+        format!("{}@{}", self.config.provider, self.config.base_url)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ServedPolish {
+    pub response: PolishResponse,
+    /// Provider id of the chain entry that produced the response.
+    pub provider: String,
+}
+
+pub(super) fn entry_timeout(timeout_ms: u32) -> Duration {
+    let timeout_ms = if timeout_ms == 0 {
+        DEFAULT_LLM_TIMEOUT_MS
+    } else {
+        timeout_ms
+    };
+    Duration::from_millis(u64::from(timeout_ms))
+}
+
+/// Whether the managed Cloud session token is needed anywhere in the chain.
+pub fn chain_uses_cloud(config: &AppConfig) -> bool {
+    config.llm_provider == "cloud"
+        || config
+            .llm_fallback_chain
+            .iter()
+            .any(|entry| entry.provider.trim() == "cloud")
+}
+
+/// Primary provider followed by the configured fallbacks. Fallbacks without
+/// usable credentials and exact duplicates are left out.
+pub fn build_chain<V: CredentialSecretReader>(
+    config: &AppConfig,
+    primary_api_key: String,
+    session_token: &str,
+    vault: &V,
+) -> Vec<LlmChainEntry> {
+    let mut chain = vec![LlmChainEntry {
+        config: LlmConfig {
+            provider: config.llm_provider.clone(),
+            api_key: primary_api_key,
+            model: config.llm_model.clone(),
+            base_url: config.llm_base_url.clone(),
+            max_tokens: 4096,
+            temperature: 0.3,
+            timeout_ms: config.llm_timeout_ms,
+        },
+        timeout: entry_timeout(config.llm_timeout_ms),
+    }];
+
+    for fallback in &config.llm_fallback_chain {
+        let provider = fallback.provider.trim();
+        if provider.is_empty()
+            || chain.iter().any(|entry| {
+                entry.config.provider == provider
+                    && entry.config.model == fallback.model
+                    && entry.config.base_url == fallback.base_url
+            })
+        {
+            continue;
+        }
+        let api_key = if provider == "cloud" {
+            session_token.to_string()
+        } else {
+            match resolve_config_secret("", "llm", provider, vault) {
+                Ok(secret) => secret,
+                Err(error) => {
+                    tracing::warn!(
+                        "Failed to read LLM fallback credential for {provider}: {error}"
+                    );
+                    String::new()
+                }
+            }
+        };
+        if !super::has_usable_provider_credentials(provider, &api_key) {
+            tracing::debug!("Skipping LLM fallback {provider} without credentials");
+            continue;
+        }
+        chain.push(LlmChainEntry {
+            config: LlmConfig {
+                provider: provider.to_string(),
+                api_key,
+                model: fallback.model.clone(),
+                base_url: fallback.base_url.clone(),
+                max_tokens: 4096,
+                temperature: 0.3,
+                timeout_ms: fallback.timeout_ms,
+            },
+            timeout: entry_timeout(fallback.timeout_ms),
+        });
+    }
+    chain
+}
+
+#[derive(Debug, Default)]
+struct BreakerEntry {
+    provider: String,
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+}
+
+#[derive(Debug, Default)]
+pub struct LlmChainHealth {
+    breakers: HashMap<String, BreakerEntry>,
+    last_served: Option<String>,
+}
+
+impl LlmChainHealth {
+    fn allows(&self, key: &str, now: Instant) -> bool {
+        self.breakers
+            .get(key)
+            .and_then(|entry| entry.open_until)
+            .is_none_or(|until| now >= until)
+    }
+
+    fn record_success(&mut self, key: &str, provider: &str) {
+        self.breakers.remove(key);
+        self.last_served = Some(provider.to_string());
+    }
+
+    /// Past the threshold every failure re-opens the circuit, so a provider
+    /// that fails its post-cooldown trial is skipped again straight away.
+    fn record_failure(&mut self, key: &str, provider: &str, now: Instant) {
+        let entry = self
+            .breakers
+            .entry(key.to_string())
+            .or_insert_with(|| BreakerEntry {
+                provider: provider.to_string(),
+                ..BreakerEntry::default()
+            });
+        entry.consecutive_failures = entry.consecutive_failures.saturating_add(1);
+        if entry.consecutive_failures >= BREAKER_FAILURE_THRESHOLD {
+            entry.open_until = Some(now + BREAKER_COOLDOWN);
+        }
+    }
+
+    /// Providers currently skipped by an open circuit.
+    pub fn open_providers(&self, now: Instant) -> Vec<String> {
+        let mut providers: Vec<String> = self
+            .breakers
+            .values()
+            .filter(|entry| entry.open_until.is_some_and(|until| now < until))
+            .map(|entry| entry.provider.clone())
+            .collect();
+        providers.sort();
+        providers.dedup();
+        providers
+    }
+
+    pub fn last_served(&self) -> Option<&str> {
+        self.last_served.as_deref()
+    }
+}
+
+pub fn chain_health() -> &'static Mutex<LlmChainHealth> {
+    static HEALTH: OnceLock<Mutex<LlmChainHealth>> = OnceLock::new();
+    HEALTH.get_or_init(|| Mutex::new(LlmChainHealth::default()))
+}
+
+/// Exponential backoff capped at `RETRY_MAX_DELAY_MS`, jittered into the
+/// upper half of the window so concurrent retries spread out.
+fn backoff_delay(attempt: u32, jitter: f64) -> Duration {
+    let ceiling = RETRY_BASE_DELAY_MS
+        .saturating_mul(1u64 << attempt.min(16))
+        .min(RETRY_MAX_DELAY_MS);
+    let half = ceiling / 2;
+    Duration::from_millis(half + (half as f64 * jitter.clamp(0.0, 1.0)) as u64)
+}
+
+fn jitter_fraction() -> f64 {
+    (uuid::Uuid::new_v4().as_u128() % 1_000) as f64 / 1_000.0
+}
+
+pub async fn polish_with_failover(
+    chain: &[LlmChainEntry],
+    client: reqwest::Client,
+    req: &PolishRequest,
+    on_chunk: Option<ChunkCallback>,
+) -> Result<ServedPolish, AppError> {
+    polish_chain(chain, req, on_chunk, chain_health(), |config| {
+        super::create_provider(&config.provider, &config.base_url, Some(client.clone()))
+    })
+    .await
+}
+
+async fn polish_chain<F>(
+    chain: &[LlmChainEntry],
+    req: &PolishRequest,
+    on_chunk: Option<ChunkCallback>,
+    health: &Mutex<LlmChainHealth>,
+    create_provider: F,
+) -> Result<ServedPolish, AppError>
+where
+    F: Fn(&LlmConfig) -> Box<dyn LlmProvider>,
+{
+    let on_chunk = on_chunk.map(Arc::new);
+    let streamed = Arc::new(AtomicBool::new(false));
+    let mut last_error: Option<AppError> = None;
+
+    for (index, entry) in chain.iter().enumerate() {
+        let key = entry.breaker_key();
+        let allowed = health
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .allows(&key, Instant::now());
+        if !allowed {
+            tracing::info!(
+                "Skipping LLM provider {} while its circuit is open",
+                entry.config.provider
+            );
+            continue;
+        }
+
+        let provider = create_provider(&entry.config);
+        let mut attempt = 0u32;
+        let error = loop {
+            let callback = on_chunk.as_ref().map(|callback| {
+                let callback = callback.clone();
+                let streamed = streamed.clone();
+                Box::new(move |chunk: &str| {
+                    streamed.store(true, Ordering::Relaxed);
+                    callback(chunk);
+                }) as ChunkCallback
+            });
+            let result = match tokio::time::timeout(
+                entry.timeout,
+                provider.polish(&entry.config, req, callback.as_ref()),
+            )
+            .await
+            {
+                Ok(result) => result,
+                Err(_) => Err(AppError::Timeout(entry.timeout)),
+            };
+            let error = match result {
+                Ok(response) => {
+                    health
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .record_success(&key, &entry.config.provider);
+                    return Ok(ServedPolish {
+                        response,
+                        provider: entry.config.provider.clone(),
+                    });
+                }
+                Err(error) => error,
+            };
+            // Chunks already shown or inserted would be duplicated by a retry.
+            if streamed.load(Ordering::Relaxed)
+                || !error.is_retryable()
+                || attempt >= RETRIES_PER_PROVIDER
+            {
+                break error;
+            }
+            let delay = backoff_delay(attempt, jitter_fraction());
+            tracing::warn!(
+                "LLM provider {} failed (attempt {}/{}): {}, retrying in {}ms",
+                entry.config.provider,
+                attempt + 1,
+                RETRIES_PER_PROVIDER + 1,
+                error,
+                delay.as_millis()
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        };
+
+        if index == 0 && !error.is_retryable() {
+            return Err(error);
+        }
+        health
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .record_failure(&key, &entry.config.provider, Instant::now());
+        if streamed.load(Ordering::Relaxed) {
+            return Err(error);
+        }
+        tracing::warn!(
+            "LLM provider {} unavailable, trying next in chain: {}",
+            entry.config.provider,
+            error
+        );
+        last_error = Some(error);
+    }
+
+    Err(last_error.unwrap_or_else(|| {
+        AppError::Network("All LLM providers are paused after repeated failures".to_string())
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::LlmFallbackEntry;
+    use async_trait::async_trait;
+    use std::collections::VecDeque;
+
+    struct ScriptedProvider {
+        results: Mutex<VecDeque<Result<&'static str, AppError>>>,
+        calls: Arc<Mutex<Vec<String>>>,
+        id: String,
+    }
+
+    #[async_trait]
+    impl LlmProvider for ScriptedProvider {
+        async fn polish(
+            &self,
+            _config: &LlmConfig,
+            _req: &PolishRequest,
+            on_chunk: Option<&ChunkCallback>,
+        ) -> Result<PolishResponse, AppError> {
+            self.calls.lock().unwrap().push(self.id.clone());
+            let next = self.results.lock().unwrap().pop_front();
+            match next.unwrap_or(Ok("ok")) {
+                Ok(text) => {
+                    if let Some(callback) = on_chunk {
+                        callback(text);
+                    }
+                    Ok(PolishResponse {
+                        polished_text: text.to_string(),
+                    })
+                }
+                Err(error) => Err(error),
+            }
+        }
+
+        fn name(&self) -> &str {
+            &self.id
+        }
+    }
+
+    type Scripts = HashMap<&'static str, Vec<Result<&'static str, AppError>>>;
+
+    fn factory(
+        scripts: Scripts,
+        calls: Arc<Mutex<Vec<String>>>,
+    ) -> impl Fn(&LlmConfig) -> Box<dyn LlmProvider> {
+        let scripts = Mutex::new(scripts);
+        move |config: &LlmConfig| {
+            let results = scripts
+                .lock()
+                .unwrap()
+                .remove(config.provider.as_str())
+                .unwrap_or_default();
+            Box::new(ScriptedProvider {
+                results: Mutex::new(results.into()),
+                calls: calls.clone(),
+                id: config.provider.clone(),
+            }) as Box<dyn LlmProvider>
+        }
+    }
+
+    fn entry(provider: &str) -> LlmChainEntry {
+        LlmChainEntry {
+            config: LlmConfig {
+                provider: provider.to_string(),
+                base_url: format!("https://{provider}.example/v1"),
+                ..LlmConfig::default()
+            },
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    fn request() -> PolishRequest {
+        PolishRequest {
+            raw_text: "hello".to_string(),
+            context: crate::app_detector::types::ContextProfile::general_native().summary(),
+            dictionary: Vec::new(),
+            correction_rules: Vec::new(),
+            polish_style: "clean".to_string(),
+            mapped_scene_prompt: String::new(),
+            active_scene_prompt: String::new(),
+            polish_custom_prompt: String::new(),
+            translate_enabled: false,
+            target_lang: "en".to_string(),
+            selected_text: None,
+            operation_id: None,
+            voice_intent: crate::voice_intent::VoiceIntent::from_parts(
+                crate::voice_intent::VoiceIntentKind::DictateInsert,
+                crate::voice_intent::VoiceOutputPlacement::InsertAtCursor,
+                1.0,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap(),
+        }
+    }
+
+    fn rate_limited() -> AppError {
+        AppError::Api {
+            status: 429,
+            body: "rate limited".to_string(),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retries_then_fails_over_and_reports_the_serving_provider() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let scripts = Scripts::from([
+            (
+                "openrouter",
+                vec![
+                    Err(rate_limited()),
+                    Err(rate_limited()),
+                    Err(rate_limited()),
+                ],
+            ),
+            ("groq", vec![Ok("polished")]),
+        ]);
+        let health = Mutex::new(LlmChainHealth::default());
+
+        let served = polish_chain(
+            &[entry("openrouter"), entry("groq")],
+            &request(),
+            None,
+            &health,
+            factory(scripts, calls.clone()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(served.provider, "groq");
+        assert_eq!(served.response.polished_text, "polished");
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec!["openrouter", "openrouter", "openrouter", "groq"]
+        );
+        assert_eq!(health.lock().unwrap().last_served(), Some("groq"));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn non_retryable_errors_and_streamed_output_stop_the_chain() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let scripts = Scripts::from([
+            (
+                "openrouter",
+                vec![Err(AppError::Auth("bad key".to_string()))],
+            ),
+            ("groq", vec![Ok("unused")]),
+        ]);
+        let result = polish_chain(
+            &[entry("openrouter"), entry("groq")],
+            &request(),
+            None,
+            &Mutex::new(LlmChainHealth::default()),
+            factory(scripts, calls.clone()),
+        )
+        .await;
+        assert!(matches!(result, Err(AppError::Auth(_))));
+        assert_eq!(*calls.lock().unwrap(), vec!["openrouter"]);
+
+        struct StreamThenFail;
+        #[async_trait]
+        impl LlmProvider for StreamThenFail {
+            async fn polish(
+                &self,
+                _config: &LlmConfig,
+                _req: &PolishRequest,
+                on_chunk: Option<&ChunkCallback>,
+            ) -> Result<PolishResponse, AppError> {
+                if let Some(callback) = on_chunk {
+                    callback("partial");
+                }
+                Err(AppError::Network("stream reset".to_string()))
+            }
+
+            fn name(&self) -> &str {
+                "stream-then-fail"
+            }
+        }
+
+        let chunks = Arc::new(Mutex::new(Vec::new()));
+        let sink = chunks.clone();
+        let result = polish_chain(
+            &[entry("openrouter"), entry("groq")],
+            &request(),
+            Some(
+                Box::new(move |chunk: &str| sink.lock().unwrap().push(chunk.to_string()))
+                    as ChunkCallback,
+            ),
+            &Mutex::new(LlmChainHealth::default()),
+            |_: &LlmConfig| Box::new(StreamThenFail) as Box<dyn LlmProvider>,
+        )
+        .await;
+        assert!(matches!(result, Err(AppError::Network(_))));
+        assert_eq!(*chunks.lock().unwrap(), vec!["partial"]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn non_retryable_fallback_errors_move_on_to_the_next_entry() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let scripts = Scripts::from([
+            (
+                "openrouter",
+                vec![
+                    Err(rate_limited()),
+                    Err(rate_limited()),
+                    Err(rate_limited()),
+                ],
+            ),
+            ("groq", vec![Err(AppError::Auth("bad key".to_string()))]),
+            ("deepseek", vec![Ok("polished")]),
+        ]);
+
+        let served = polish_chain(
+            &[entry("openrouter"), entry("groq"), entry("deepseek")],
+            &request(),
+            None,
+            &Mutex::new(LlmChainHealth::default()),
+            factory(scripts, calls.clone()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(served.provider, "deepseek");
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec!["openrouter", "openrouter", "openrouter", "groq", "deepseek"]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn per_provider_timeout_counts_as_a_retryable_failure() {
+        struct Hangs;
+        #[async_trait]
+        impl LlmProvider for Hangs {
+            async fn polish(
+                &self,
+                _config: &LlmConfig,
+                _req: &PolishRequest,
+                _on_chunk: Option<&ChunkCallback>,
+            ) -> Result<PolishResponse, AppError> {
+                std::future::pending().await
+            }
+
+            fn name(&self) -> &str {
+                "hangs"
+            }
+        }
+
+        let served = polish_chain(
+            &[entry("slow"), entry("groq")],
+            &request(),
+            None,
+            &Mutex::new(LlmChainHealth::default()),
+            |config: &LlmConfig| {
+                if config.provider == "slow" {
+                    Box::new(Hangs) as Box<dyn LlmProvider>
+                } else {
+                    factory(Scripts::new(), Arc::default())(config)
+                }
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(served.provider, "groq");
+    }
+
+    #[test]
+    fn circuit_opens_after_repeated_failures_and_recovers_after_cooldown() {
+        let mut health = LlmChainHealth::default();
+        let key = entry("openrouter").breaker_key();
+        let now = Instant::now();
+
+        for _ in 0..BREAKER_FAILURE_THRESHOLD - 1 {
+            health.record_failure(&key, "openrouter", now);
+        }
+        assert!(health.allows(&key, now));
+        health.record_failure(&key, "openrouter", now);
+        assert!(!health.allows(&key, now));
+        assert_eq!(health.open_providers(now), vec!["openrouter".to_string()]);
+
+        let later = now + BREAKER_COOLDOWN;
+        assert!(health.allows(&key, later));
+        assert!(health.open_providers(later).is_empty());
+        health.record_failure(&key, "openrouter", later);
+        assert!(!health.allows(&key, later));
+
+        health.record_success(&key, "openrouter");
+        assert!(health.allows(&key, later));
+    }
+
+    #[test]
+    fn backoff_grows_with_jitter_and_is_capped() {
+        assert_eq!(backoff_delay(0, 0.0), Duration::from_millis(250));
+        assert_eq!(backoff_delay(0, 1.0), Duration::from_millis(500));
+        assert_eq!(backoff_delay(1, 0.5), Duration::from_millis(750));
+        assert_eq!(
+            backoff_delay(10, 1.0),
+            Duration::from_millis(RETRY_MAX_DELAY_MS)
+        );
+    }
+
+    #[test]
+    fn chain_keeps_credentialed_fallbacks_in_order() {
+        struct Vault;
+        impl CredentialSecretReader for Vault {
+            fn get_secret(
+                &self,
+                _namespace: &str,
+                provider: &str,
+            ) -> anyhow::Result<Option<String>> {
+                Ok((provider == "groq").then(|| "gsk-test".to_string()))
+            }
+        }
+
+        let config = AppConfig {
+            llm_provider: "openrouter".to_string(),
+            llm_timeout_ms: 8_000,
+            llm_fallback_chain: vec![
+                LlmFallbackEntry {
+                    provider: "deepseek".to_string(),
+                    model: "deepseek-chat".to_string(),
+                    base_url: "https://api.deepseek.com/v1".to_string(),
+                    timeout_ms: 0,
+                },
+                LlmFallbackEntry {
+                    provider: "groq".to_string(),
+                    model: "llama-3.3-70b-versatile".to_string(),
+                    base_url: "https://api.groq.com/openai/v1".to_string(),
+                    timeout_ms: 4_000,
+                },
+                LlmFallbackEntry {
+                    provider: "ollama".to_string(),
+                    model: "qwen2.5".to_string(),
+                    base_url: "http://localhost:11434/v1".to_string(),
+                    timeout_ms: 0,
+                },
+            ],
+            ..AppConfig::default()
+        };
+
+        let chain = build_chain(&config, "sk-or".to_string(), "", &Vault);
+        let providers: Vec<&str> = chain
+            .iter()
+            .map(|entry| entry.config.provider.as_str())
+            .collect();
+        assert_eq!(providers, vec!["openrouter", "groq", "ollama"]);
+        assert_eq!(chain[0].timeout, Duration::from_millis(8_000));
+        assert_eq!(chain[1].config.api_key, "gsk-test");
+        assert_eq!(chain[1].timeout, Duration::from_millis(4_000));
+        assert_eq!(
+            chain[2].timeout,
+            Duration::from_millis(u64::from(DEFAULT_LLM_TIMEOUT_MS))
+        );
+        // Providers apply the same deadline to their own requests.
+        assert!(chain
+            .iter()
+            .all(|entry| entry.config.request_timeout() == entry.timeout));
+    }
+}
@@ -0,0 +1,270 @@
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use reqwest::Client;
+
+use crate::error::AppError;
+
+use super::{
+    prompt, sse, ChunkCallback, LlmApi, LlmConfig, LlmProvider, PolishRequest, PolishResponse,
+};
+
+pub struct GeminiProvider {
+    client: Client,
+}
+
+impl Default for GeminiProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GeminiProvider {
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+        }
+    }
+
+    pub fn with_client(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+pub(super) fn generate_content_url(config: &LlmConfig, stream: bool) -> String {
+    let base_url = config.base_url.trim_end_matches('/');
+    let model = config.model.trim().trim_start_matches("models/");
+    if stream {
+        format!("{base_url}/models/{model}:streamGenerateContent?alt=sse")
+    } else {
+        format!("{base_url}/models/{model}:generateContent")
+    }
+}
+
+fn build_generate_content_body(config: &LlmConfig, req: &PolishRequest) -> serde_json::Value {
+    let prompt = prompt::build_polish_prompt(req);
+    let parts: Vec<_> = prompt
+        .user_parts
+        .iter()
+        .map(|text| serde_json::json!({ "text": text }))
+        .collect();
+    serde_json::json!({
+        "systemInstruction": { "parts": [{ "text": prompt.system }] },
+        "contents": [{ "role": "user", "parts": parts }],
+        "generationConfig": {
+            "maxOutputTokens": config.max_tokens,
+            "temperature": config.temperature
+        }
+    })
+}
+
+/// generateContent body for OpenAI-style chat `messages`: system messages
+/// become the system instruction and assistant turns use the `model` role.
+pub(super) fn build_chat_body(
+    config: &LlmConfig,
+    messages: &[serde_json::Value],
+) -> serde_json::Value {
+    let system: Vec<_> = messages
+        .iter()
+        .filter(|message| message["role"] == "system")
+        .map(|message| serde_json::json!({ "text": message["content"] }))
+        .collect();
+    let contents: Vec<_> = messages
+        .iter()
+        .filter(|message| message["role"] != "system")
+        .map(|message| {
+            let role = if message["role"] == "assistant" {
+                "model"
+            } else {
+                "user"
+            };
+            serde_json::json!({ "role": role, "parts": [{ "text": message["content"] }] })
+        })
+        .collect();
+    serde_json::json!({
+        "systemInstruction": { "parts": system },
+        "contents": contents,
+        "generationConfig": {
+            "maxOutputTokens": config.max_tokens,
+            "temperature": config.temperature
+        }
+    })
+}
+
+/// Answer text of the first candidate. Thinking models mark their reasoning
+/// parts with `thought: true`; those never reach the output.
+pub(super) fn candidate_text(v: &serde_json::Value) -> Result<String, AppError> {
+    if let Some(error) = v.get("error") {
+        let status = error["code"].as_u64().unwrap_or(500) as u16;
+        return Err(super::api_error(status, &error.to_string()));
+    }
+    Ok(v["candidates"][0]["content"]["parts"]
+        .as_array()
+        .map(|parts| {
+            parts
+                .iter()
+                .filter(|part| part["thought"].as_bool() != Some(true))
+                .filter_map(|part| part["text"].as_str())
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+#[async_trait]
+impl LlmProvider for GeminiProvider {
+    async fn polish(
+        &self,
+        config: &LlmConfig,
+        req: &PolishRequest,
+        on_chunk: Option<&ChunkCallback>,
+    ) -> Result<PolishResponse, AppError> {
+        let body = build_generate_content_body(config, req);
+
+        let request = self
+            .client
+            .post(generate_content_url(config, on_chunk.is_some()))
+            .timeout(config.request_timeout())
+            .header("Content-Type", "application/json");
+        let response = super::apply_api_auth_header(
+            request,
+            LlmApi::GeminiGenerateContent,
+            &config.provider,
+            &config.api_key,
+        )
+        .json(&body)
+        .send()
+        .await?;
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            return Err(super::api_error(status.as_u16(), &text));
+        }
+
+        let Some(callback) = on_chunk else {
+            let v: serde_json::Value = response.json().await?;
+            let text = candidate_text(&v)?;
+            if text.is_empty() {
+                tracing::warn!(
+                    "Gemini returned no answer text, finishReason: {}",
+                    v["candidates"][0]["finishReason"]
+                );
+            }
+            return Ok(PolishResponse {
+                polished_text: text,
+            });
+        };
+
+        let mut full_text = String::new();
+        let mut stream = response.bytes_stream();
+        let mut buffer = String::new();
+        while let Some(chunk) = stream.next().await {
+            buffer.push_str(&String::from_utf8_lossy(&chunk?));
+            for data in sse::take_data_lines(&mut buffer) {
+                let Ok(v) = serde_json::from_str::<serde_json::Value>(&data) else {
+                    continue;
+                };
+                let text = candidate_text(&v)?;
+                if !text.is_empty() {
+                    full_text.push_str(&text);
+                    callback(&text);
+                }
+            }
+        }
+
+        if full_text.is_empty() {
+            tracing::error!("Gemini streaming returned no answer text");
+        }
+        Ok(PolishResponse {
+            polished_text: full_text,
+        })
+    }
+
+    fn name(&self) -> &str {
+        "Gemini"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> LlmConfig {
+        LlmConfig {
+            provider: "gemini".to_string(),
+            model: "models/gemini-2.0-flash".to_string(),
+            base_url: "https://generativelanguage.googleapis.com/v1beta/".to_string(),
+            ..LlmConfig::default()
+        }
+    }
+
+    #[test]
+    fn generate_content_urls_normalize_model_and_base() {
+        assert_eq!(
+            generate_content_url(&config(), false),
+            "https://generativelanguage.googleapis.com/v1beta/models/gemini-2.0-flash:generateContent"
+        );
+        assert_eq!(
+            generate_content_url(&config(), true),
+            "https://generativelanguage.googleapis.com/v1beta/models/gemini-2.0-flash:streamGenerateContent?alt=sse"
+        );
+    }
+
+    #[test]
+    fn candidate_text_skips_thought_parts_and_surfaces_errors() {
+        let response = serde_json::json!({
+            "candidates": [{
+                "content": {
+                    "role": "model",
+                    "parts": [
+                        { "text": "Let me think.", "thought": true },
+                        { "text": "Hello, " },
+                        { "text": "world." }
+                    ]
+                }
+            }]
+        });
+        assert_eq!(candidate_text(&response).unwrap(), "Hello, world.");
+        assert_eq!(candidate_text(&serde_json::json!({})).unwrap(), "");
+
+        let error = serde_json::json!({
+            "error": { "code": 429, "message": "Resource exhausted", "status": "RESOURCE_EXHAUSTED" }
+        });
+        let error = candidate_text(&error).unwrap_err();
+        assert!(matches!(error, AppError::Api { status: 429, .. }));
+    }
+
+    #[test]
+    fn body_uses_system_instruction_and_generation_config() {
+        let req = PolishRequest {
+            raw_text: "hello".to_string(),
+            context: crate::app_detector::types::ContextProfile::general_native().summary(),
+            dictionary: Vec::new(),
+            correction_rules: Vec::new(),
+            polish_style: "clean".to_string(),
+            mapped_scene_prompt: String::new(),
+            active_scene_prompt: String::new(),
+            polish_custom_prompt: String::new(),
+            translate_enabled: false,
+            target_lang: "en".to_string(),
+            selected_text: None,
+            operation_id: None,
+            voice_intent: crate::voice_intent::VoiceIntent::from_parts(
+                crate::voice_intent::VoiceIntentKind::DictateInsert,
+                crate::voice_intent::VoiceOutputPlacement::InsertAtCursor,
+                1.0,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap(),
+        };
+        let body = build_generate_content_body(&config(), &req);
+
+        assert!(body["systemInstruction"]["parts"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("TRUSTED OPERATION"));
+        assert_eq!(body["contents"][0]["parts"].as_array().unwrap().len(), 1);
+        assert_eq!(body["generationConfig"]["maxOutputTokens"], 4096);
+    }
+}
@@ -1,8 +1,12 @@
+pub mod anthropic;
 pub mod cloud;
 pub mod context_policy;
+pub mod failover;
+pub mod gemini;
 pub mod model_capabilities;
 pub mod openai;
 pub mod prompt;
+pub mod sse;
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
@@ -18,6 +22,8 @@ pub struct LlmConfig {
     pub base_url: String,
     pub max_tokens: u32,
     pub temperature: f64,
+    /// Deadline for a single request; zero uses the default LLM timeout.
+    pub timeout_ms: u32,
 }
 
 impl Default for LlmConfig {
@@ -29,10 +35,17 @@ impl Default for LlmConfig {
             base_url: "https://open.bigmodel.cn/api/paas/v4".to_string(),
             max_tokens: 4096,
             temperature: 0.3,
+            timeout_ms: failover::DEFAULT_LLM_TIMEOUT_MS,
         }
     }
 }
 
+impl LlmConfig {
+    pub fn request_timeout(&self) -> std::time::Duration {
+        failover::entry_timeout(self.timeout_ms)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PolishRequest {
     pub raw_text: String,
@@ -109,15 +122,110 @@ pub fn apply_provider_auth_header(
     }
 }
 
+/// Wire format spoken by a provider endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LlmApi {
+    OpenAiCompatible,
+    AnthropicMessages,
+    GeminiGenerateContent,
+    ManagedCloud,
+}
+
+pub fn provider_api(provider: &str, base_url: &str) -> LlmApi {
+    match provider.trim().to_ascii_lowercase().as_str() {
+        "cloud" => LlmApi::ManagedCloud,
+        "anthropic" => LlmApi::AnthropicMessages,
+        // Gemini configs saved before the native provider point at Google's
+        // OpenAI-compatible shim and keep using it.
+        "gemini" if !base_url.trim().trim_end_matches('/').ends_with("/openai") => {
+            LlmApi::GeminiGenerateContent
+        }
+        _ => LlmApi::OpenAiCompatible,
+    }
+}
+
+/// Adds the credential header each wire format expects.
+pub fn apply_api_auth_header(
+    request: reqwest::RequestBuilder,
+    api: LlmApi,
+    provider: &str,
+    api_key: &str,
+) -> reqwest::RequestBuilder {
+    match api {
+        LlmApi::AnthropicMessages => request
+            .header("x-api-key", api_key.trim())
+            .header("anthropic-version", anthropic::ANTHROPIC_VERSION),
+        LlmApi::GeminiGenerateContent => request.header("x-goog-api-key", api_key.trim()),
+        LlmApi::OpenAiCompatible | LlmApi::ManagedCloud => {
+            apply_provider_auth_header(request, provider, api_key)
+        }
+    }
+}
+
+/// Non-streaming chat request for callers outside the polish flow, such as
+/// Ask. `messages` use OpenAI chat roles and are mapped to the provider's wire
+/// format with the same URLs and auth headers polish uses.
+pub(crate) fn build_chat_request(
+    client: &reqwest::Client,
+    config: &LlmConfig,
+    messages: &[serde_json::Value],
+) -> Result<reqwest::RequestBuilder, AppError> {
+    let api = provider_api(&config.provider, &config.base_url);
+    let (url, body) = match api {
+        LlmApi::OpenAiCompatible => (
+            openai::chat_completions_url(config),
+            openai::build_chat_body(config, messages, false),
+        ),
+        LlmApi::AnthropicMessages => (
+            anthropic::messages_url(config),
+            anthropic::build_chat_body(config, messages),
+        ),
+        LlmApi::GeminiGenerateContent => (
+            gemini::generate_content_url(config, false),
+            gemini::build_chat_body(config, messages),
+        ),
+        LlmApi::ManagedCloud => {
+            return Err(AppError::Config(
+                "managed cloud has no direct chat endpoint".to_string(),
+            ))
+        }
+    };
+    let request = client
+        .post(url)
+        .timeout(config.request_timeout())
+        .header("Content-Type", "application/json");
+    Ok(apply_api_auth_header(request, api, &config.provider, &config.api_key).json(&body))
+}
+
+/// Answer text of a response to `build_chat_request`.
+pub(crate) fn chat_response_text(
+    config: &LlmConfig,
+    v: &serde_json::Value,
+) -> Result<String, AppError> {
+    match provider_api(&config.provider, &config.base_url) {
+        LlmApi::AnthropicMessages => Ok(anthropic::response_text(v)),
+        LlmApi::GeminiGenerateContent => gemini::candidate_text(v),
+        LlmApi::OpenAiCompatible | LlmApi::ManagedCloud => Ok(openai::message_text(v)),
+    }
+}
+
+/// Error for a non-success response, keeping at most 200 characters of body.
+pub(crate) fn api_error(status: u16, body: &str) -> AppError {
+    let body: String = body.chars().take(200).collect();
+    AppError::Api { status, body }
+}
+
 pub fn create_provider(
     provider_name: &str,
+    base_url: &str,
     client: Option<reqwest::Client>,
 ) -> Box<dyn LlmProvider> {
-    match (provider_name, client) {
-        ("cloud", Some(c)) => Box::new(cloud::CloudLlmProvider::with_client(c)),
-        ("cloud", None) => Box::new(cloud::CloudLlmProvider::new()),
-        (_, Some(c)) => Box::new(openai::OpenAiProvider::with_client(c)),
-        (_, None) => Box::new(openai::OpenAiProvider::new()),
+    let client = client.unwrap_or_default();
+    match provider_api(provider_name, base_url) {
+        LlmApi::ManagedCloud => Box::new(cloud::CloudLlmProvider::with_client(client)),
+        LlmApi::AnthropicMessages => Box::new(anthropic::AnthropicProvider::with_client(client)),
+        LlmApi::GeminiGenerateContent => Box::new(gemini::GeminiProvider::with_client(client)),
+        LlmApi::OpenAiCompatible => Box::new(openai::OpenAiProvider::with_client(client)),
     }
 }
 
@@ -141,6 +249,67 @@ mod provider_capability_tests {
         assert!(!has_usable_provider_credentials("openai", "   "));
         assert!(has_usable_provider_credentials("openai", "sk-test"));
     }
+
+    #[test]
+    fn native_apis_are_chosen_by_provider_and_legacy_gemini_shim_stays_compatible() {
+        assert_eq!(
+            provider_api("anthropic", "https://api.anthropic.com/v1"),
+            LlmApi::AnthropicMessages
+        );
+        assert_eq!(
+            provider_api("gemini", "https://generativelanguage.googleapis.com/v1beta"),
+            LlmApi::GeminiGenerateContent
+        );
+        assert_eq!(
+            provider_api(
+                "gemini",
+                "https://generativelanguage.googleapis.com/v1beta/openai/"
+            ),
+            LlmApi::OpenAiCompatible
+        );
+        assert_eq!(
+            provider_api("claude", "https://openrouter.ai/api/v1"),
+            LlmApi::OpenAiCompatible
+        );
+        assert_eq!(provider_api("cloud", ""), LlmApi::ManagedCloud);
+        assert_eq!(create_provider("anthropic", "", None).name(), "Anthropic");
+        assert_eq!(
+            create_provider(
+                "gemini",
+                "https://generativelanguage.googleapis.com/v1beta",
+                None
+            )
+            .name(),
+            "Gemini"
+        );
+    }
+
+    #[test]
+    fn native_api_auth_headers() {
+        let client = reqwest::Client::new();
+        let anthropic = apply_api_auth_header(
+            client.get("https://api.anthropic.com/v1/models"),
+            LlmApi::AnthropicMessages,
+            "anthropic",
+            "sk-ant",
+        )
+        .build()
+        .unwrap();
+        assert_eq!(anthropic.headers().get("x-api-key").unwrap(), "sk-ant");
+        assert!(anthropic.headers().get("anthropic-version").is_some());
+        assert!(anthropic.headers().get("Authorization").is_none());
+
+        let gemini = apply_api_auth_header(
+            client.get("https://generativelanguage.googleapis.com/v1beta/models"),
+            LlmApi::GeminiGenerateContent,
+            "gemini",
+            "AIza",
+        )
+        .build()
+        .unwrap();
+        assert_eq!(gemini.headers().get("x-goog-api-key").unwrap(), "AIza");
+        assert!(gemini.headers().get("Authorization").is_none());
+    }
 }
 
 #[cfg(test)]
@@ -141,4 +141,35 @@ mod tests {
             ModelCapability::Unknown
         );
     }
+
+    #[test]
+    fn model_capabilities_certifies_native_anthropic_and_gemini() {
+        assert_eq!(
+            model_capability(
+                "anthropic",
+                "https://api.anthropic.com/v1/",
+                "claude-3-5-haiku-latest",
+                CONTEXT_PROMPT_VERSION,
+            ),
+            ModelCapability::Certified
+        );
+        assert_eq!(
+            model_capability(
+                "gemini",
+                "https://generativelanguage.googleapis.com/v1beta",
+                "gemini-2.0-flash",
+                CONTEXT_PROMPT_VERSION,
+            ),
+            ModelCapability::Certified
+        );
+        assert_eq!(
+            model_capability(
+                "gemini",
+                "https://generativelanguage.googleapis.com/v1beta",
+                "gemini-2.5-pro",
+                CONTEXT_PROMPT_VERSION,
+            ),
+            ModelCapability::BestEffort
+        );
+    }
 }
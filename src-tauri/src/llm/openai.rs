@@ -28,6 +28,53 @@ impl OpenAiProvider {
     }
 }
 
+pub(super) fn chat_completions_url(config: &LlmConfig) -> String {
+    format!("{}/chat/completions", config.base_url.trim_end_matches('/'))
+}
+
+pub(crate) fn build_chat_body(
+    config: &LlmConfig,
+    messages: &[serde_json::Value],
+    stream: bool,
+) -> serde_json::Value {
+    let mut body = serde_json::json!({
+        "model": config.model,
+        "messages": messages,
+        "max_tokens": config.max_tokens,
+        "temperature": config.temperature,
+        "stream": stream
+    });
+
+    // GLM-4.7/4.5/5 default to thinking mode, but without explicitly enabling it
+    // the API may return content in reasoning_content only, leaving content empty.
+    // Explicitly enable thinking so both fields are properly populated.
+    // Thinking mode also requires temperature >= 0.6 (recommended 1.0).
+    if config.model.starts_with("glm-") {
+        if let Some(obj) = body.as_object_mut() {
+            obj.insert(
+                "thinking".to_string(),
+                serde_json::json!({"type": "enabled"}),
+            );
+            obj.insert("temperature".to_string(), serde_json::json!(1.0));
+            obj.insert("top_p".to_string(), serde_json::json!(0.95));
+        }
+    }
+    body
+}
+
+/// Message text of a non-streaming response, falling back to
+/// `reasoning_content` for thinking-mode models.
+pub(super) fn message_text(v: &serde_json::Value) -> String {
+    let message = &v["choices"][0]["message"];
+    match message["content"].as_str() {
+        Some(content) if !content.trim().is_empty() => content.to_string(),
+        _ => message["reasoning_content"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+    }
+}
+
 #[async_trait]
 impl LlmProvider for OpenAiProvider {
     async fn polish(
@@ -68,117 +115,35 @@ impl LlmProvider for OpenAiProvider {
             "content": format!("<transcription>\n{}\n</transcription>", req.raw_text)
         }));
 
-        let mut body = serde_json::json!({
-            "model": config.model,
-            "messages": messages,
-            "max_tokens": config.max_tokens,
-            "temperature": config.temperature,
-            "stream": on_chunk.is_some()
-        });
+        let body = build_chat_body(config, &messages, on_chunk.is_some());
 
-        // GLM-4.7/4.5/5 default to thinking mode, but without explicitly enabling it
-        // the API may return content in reasoning_content only, leaving content empty.
-        // Explicitly enable thinking so both fields are properly populated.
-        // Thinking mode also requires temperature >= 0.6 (recommended 1.0).
-        if config.model.starts_with("glm-") {
-            if let Some(obj) = body.as_object_mut() {
-                obj.insert(
-                    "thinking".to_string(),
-                    serde_json::json!({"type": "enabled"}),
-                );
-                obj.insert("temperature".to_string(), serde_json::json!(1.0));
-                obj.insert("top_p".to_string(), serde_json::json!(0.95));
-            }
-        }
-
-        // Retry the initial connection (not once streaming starts)
-        #[allow(unused_assignments)]
-        let mut response = None;
-        let mut last_error: Option<AppError> = None;
-        let mut attempt = 0u32;
-
-        loop {
-            let request = self
-                .client
-                .post(format!("{}/chat/completions", config.base_url))
-                .header("Content-Type", "application/json");
-            match super::apply_provider_auth_header(request, &config.provider, &config.api_key)
+        let request = self
+            .client
+            .post(chat_completions_url(config))
+            .timeout(config.request_timeout())
+            .header("Content-Type", "application/json");
+        let response =
+            super::apply_provider_auth_header(request, &config.provider, &config.api_key)
                 .json(&body)
-                .timeout(std::time::Duration::from_secs(15))
                 .send()
-                .await
-            {
-                Ok(resp) => {
-                    let status = resp.status();
-                    if status.is_success() {
-                        response = Some(resp);
-                        break;
-                    } else if status.as_u16() >= 500 && attempt < 2 {
-                        let body_text = resp.text().await.unwrap_or_default();
-                        tracing::warn!(
-                            "LLM server error {} (attempt {}/3), retrying",
-                            status,
-                            attempt + 1
-                        );
-                        last_error = Some(AppError::Api {
-                            status: status.as_u16(),
-                            body: body_text,
-                        });
-                        attempt += 1;
-                        tokio::time::sleep(std::time::Duration::from_millis(
-                            1000 * 2u64.pow(attempt - 1),
-                        ))
-                        .await;
-                        continue;
-                    } else {
-                        let status = resp.status();
-                        let text = resp.text().await.unwrap_or_default();
-                        // Truncate at a valid UTF-8 char boundary to avoid panic on multi-byte chars
-                        let truncate_at = text
-                            .char_indices()
-                            .take_while(|&(i, _)| i < 200)
-                            .last()
-                            .map(|(i, c)| i + c.len_utf8())
-                            .unwrap_or(text.len());
-                        let sanitized = &text[..truncate_at];
-                        return Err(AppError::Api {
-                            status: status.as_u16(),
-                            body: sanitized.to_string(),
-                        });
-                    }
-                }
-                Err(e) if e.is_timeout() && attempt < 2 => {
-                    tracing::warn!(
-                        "LLM connection timeout (attempt {}/3), retrying",
-                        attempt + 1
-                    );
-                    last_error = Some(e.into());
-                    attempt += 1;
-                    tokio::time::sleep(std::time::Duration::from_millis(
-                        1000 * 2u64.pow(attempt - 1),
-                    ))
-                    .await;
-                    continue;
-                }
-                Err(e) if e.is_connect() && attempt < 2 => {
-                    tracing::warn!(
-                        "LLM connection failed (attempt {}/3), retrying",
-                        attempt + 1
-                    );
-                    last_error = Some(e.into());
-                    attempt += 1;
-                    tokio::time::sleep(std::time::Duration::from_millis(
-                        1000 * 2u64.pow(attempt - 1),
-                    ))
-                    .await;
-                    continue;
-                }
-                Err(e) => return Err(e.into()),
-            }
+                .await?;
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            // Truncate at a valid UTF-8 char boundary to avoid panic on multi-byte chars
+            let truncate_at = text
+                .char_indices()
+                .take_while(|&(i, _)| i < 200)
+                .last()
+                .map(|(i, c)| i + c.len_utf8())
+                .unwrap_or(text.len());
+            let sanitized = &text[..truncate_at];
+            return Err(AppError::Api {
+                status: status.as_u16(),
+                body: sanitized.to_string(),
+            });
         }
 
-        let response = response.ok_or_else(|| last_error.unwrap())?;
-
         if let Some(callback) = on_chunk {
             // Streaming mode
             let mut full_text = String::new();
@@ -3,7 +3,7 @@ use crate::app_detector::types::{ContextFamily, ContextProfileSummary};
 use crate::voice_intent::{VoiceIntent, VoiceIntentKind};
 
 use super::context_policy::ContextPolicy;
-use super::{AppType, CorrectionRule};
+use super::{AppType, CorrectionRule, PolishRequest};
 
 pub const CONTEXT_PROMPT_VERSION: &str = "context-v1";
 
@@ -242,6 +242,46 @@ pub fn build_context_system_prompt(options: ContextPromptOptions<'_>) -> String
     prompt
 }
 
+/// System prompt plus the user turns for a polish request, for providers
+/// whose wire format does not take OpenAI-style message lists.
+pub struct PolishPrompt {
+    pub system: String,
+    pub user_parts: Vec<String>,
+}
+
+pub fn build_polish_prompt(req: &PolishRequest) -> PolishPrompt {
+    let selected_text = req
+        .selected_text
+        .as_deref()
+        .filter(|text| !text.trim().is_empty());
+    let system = build_context_system_prompt(ContextPromptOptions {
+        context: &req.context,
+        dictionary: &req.dictionary,
+        correction_rules: &req.correction_rules,
+        polish_style: &req.polish_style,
+        personal_style_prompt: "",
+        mapped_scene_prompt: &req.mapped_scene_prompt,
+        active_scene_prompt: &req.active_scene_prompt,
+        polish_custom_prompt: &req.polish_custom_prompt,
+        translate_enabled: req.translate_enabled,
+        target_lang: &req.target_lang,
+        has_selected_text: selected_text.is_some(),
+        voice_intent: Some(&req.voice_intent),
+    });
+
+    let mut user_parts = Vec::with_capacity(2);
+    if let Some(selected_text) = selected_text {
+        user_parts.push(format!(
+            "<selected_text>\n{selected_text}\n</selected_text>"
+        ));
+    }
+    user_parts.push(format!(
+        "<transcription>\n{}\n</transcription>",
+        req.raw_text
+    ));
+    PolishPrompt { system, user_parts }
+}
+
 fn append_voice_operation_prompt(
     prompt: &mut String,
     intent: &VoiceIntent,
@@ -284,6 +324,14 @@ fn append_voice_operation_prompt(
         VoiceIntentKind::Search => prompt.push_str(
             "\nSearch routing must bypass the language model. Return no generated content.",
         ),
+        VoiceIntentKind::UndoInsertion
+        | VoiceIntentKind::DeleteLastSentence
+        | VoiceIntentKind::DeleteLastWord
+        | VoiceIntentKind::NewLine
+        | VoiceIntentKind::NewParagraph
+        | VoiceIntentKind::SelectInsertion => prompt.push_str(
+            "\nEditing commands must bypass the language model. Return no generated content.",
+        ),
     }
 }
 
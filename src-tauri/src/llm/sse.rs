@@ -0,0 +1,35 @@
+//! Minimal server-sent events framing shared by the streaming providers.
+
+/// Removes every complete line from `buffer` and returns the payloads of its
+/// `data:` lines. A trailing partial line stays buffered for the next chunk.
+pub fn take_data_lines(buffer: &mut String) -> Vec<String> {
+    let Some(last_newline) = buffer.rfind('\n') else {
+        return Vec::new();
+    };
+    let rest = buffer.split_off(last_newline + 1);
+    let complete = std::mem::replace(buffer, rest);
+    complete
+        .lines()
+        .filter_map(|line| line.trim_end_matches('\r').strip_prefix("data:"))
+        .map(|data| data.trim_start().to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_partial_lines_and_ignores_event_names() {
+        let mut buffer =
+            "event: content_block_delta\r\ndata: {\"a\":1}\r\n\ndata:{\"b\"".to_string();
+
+        assert_eq!(take_data_lines(&mut buffer), vec!["{\"a\":1}".to_string()]);
+        assert_eq!(buffer, "data:{\"b\"");
+
+        buffer.push_str(":2}\n");
+        assert_eq!(take_data_lines(&mut buffer), vec!["{\"b\":2}".to_string()]);
+        assert!(buffer.is_empty());
+        assert!(take_data_lines(&mut buffer).is_empty());
+    }
+}
@@ -9,6 +9,8 @@ use super::{InsertResult, InsertionStrategy, OutputMode, TextOutput};
 const TYPE_CHUNK_SIZE: usize = 200;
 /// Delay between typing chunks.
 const TYPE_CHUNK_DELAY_MS: u64 = 5;
+/// Key presses sent between pauses when editing, so the target keeps up.
+const EDIT_KEY_CHUNK_SIZE: usize = 50;
 /// Base timeout for macOS main-thread keyboard output.
 #[cfg(target_os = "macos")]
 const MACOS_TYPE_BASE_TIMEOUT_SECS: u64 = 30;
@@ -39,6 +41,14 @@ pub fn check_keyboard_available() -> std::result::Result<(), String> {
     Ok(())
 }
 
+/// Key presses for voice editing commands, counted in characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EditKeys {
+    Backspace(usize),
+    SelectLeft(usize),
+    LineBreaks(usize),
+}
+
 pub struct KeyboardOutput {
     #[cfg(target_os = "macos")]
     app_handle: tauri::AppHandle,
@@ -60,16 +70,50 @@ impl KeyboardOutput {
         }
     }
 
+    /// Sends editing keys to the focused app.
+    pub async fn press_edit_keys(&self, keys: EditKeys) -> Result<(), AppError> {
+        #[cfg(target_os = "macos")]
+        {
+            let count = match keys {
+                EditKeys::Backspace(count)
+                | EditKeys::SelectLeft(count)
+                | EditKeys::LineBreaks(count) => count,
+            };
+            self.run_on_main_thread(macos_key_timeout(count), move || press_edit_keys_sync(keys))
+                .await
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        {
+            tokio::task::spawn_blocking(move || press_edit_keys_sync(keys))
+                .await
+                .map_err(|e| AppError::Output(format!("Spawn blocking error: {}", e)))?
+        }
+    }
+
     #[cfg(target_os = "macos")]
     async fn type_text_on_main_thread(&self, text: &str) -> Result<(), AppError> {
         let text = text.to_string();
         let timeout = macos_type_timeout(&text);
+        self.run_on_main_thread(timeout, move || type_text_sync(&text))
+            .await
+    }
+
+    #[cfg(target_os = "macos")]
+    async fn run_on_main_thread<F>(
+        &self,
+        timeout: std::time::Duration,
+        job: F,
+    ) -> Result<(), AppError>
+    where
+        F: FnOnce() -> Result<(), AppError> + Send + 'static,
+    {
         let app_handle = self.app_handle.clone();
         let (tx, rx) = tokio::sync::oneshot::channel();
 
         app_handle
             .run_on_main_thread(move || {
-                let result = type_text_sync(&text);
+                let result = job();
                 let _ = tx.send(result);
             })
             .map_err(|e| {
@@ -157,6 +201,52 @@ fn type_text_sync(text: &str) -> Result<(), AppError> {
     Ok(())
 }
 
+fn press_edit_keys_sync(keys: EditKeys) -> Result<(), AppError> {
+    super::windows_modifier_guard::wait_for_modifier_release()?;
+
+    let mut enigo = Enigo::new(&Settings::default())
+        .map_err(|e| AppError::Output(format!("Failed to create Enigo: {:?}", e)))?;
+    let (count, modifier, key) = match keys {
+        EditKeys::Backspace(count) => (count, None, Key::Backspace),
+        EditKeys::SelectLeft(count) => (count, Some(Key::Shift), Key::LeftArrow),
+        // Shift+Return, like dictated newlines, so chat apps do not send.
+        EditKeys::LineBreaks(count) => (count, Some(Key::Shift), Key::Return),
+    };
+
+    if let Some(modifier) = modifier {
+        enigo
+            .key(modifier, Direction::Press)
+            .map_err(|e| AppError::Output(format!("Key error: {:?}", e)))?;
+    }
+    let mut result = Ok(());
+    for pressed in 0..count {
+        if let Err(e) = enigo.key(key, Direction::Click) {
+            result = Err(AppError::Output(format!("Key error: {:?}", e)));
+            break;
+        }
+        if (pressed + 1) % EDIT_KEY_CHUNK_SIZE == 0 {
+            std::thread::sleep(std::time::Duration::from_millis(TYPE_CHUNK_DELAY_MS));
+        }
+    }
+    // Always release the modifier, even when a press failed midway.
+    if let Some(modifier) = modifier {
+        let released = enigo
+            .key(modifier, Direction::Release)
+            .map_err(|e| AppError::Output(format!("Key error: {:?}", e)));
+        result = result.and(released);
+    }
+    result
+}
+
+#[cfg(target_os = "macos")]
+fn macos_key_timeout(count: usize) -> std::time::Duration {
+    let chunk_count = count.div_ceil(EDIT_KEY_CHUNK_SIZE);
+    let seconds =
+        (MACOS_TYPE_BASE_TIMEOUT_SECS + chunk_count as u64).min(MACOS_TYPE_MAX_TIMEOUT_SECS);
+
+    std::time::Duration::from_secs(seconds)
+}
+
 #[cfg(target_os = "macos")]
 fn macos_type_timeout(text: &str) -> std::time::Duration {
     let char_count = text.chars().count();
@@ -1,5 +1,6 @@
 pub mod clipboard;
 pub mod keyboard;
+pub mod wayland_keyboard;
 pub mod windows_modifier_guard;
 pub mod windows_sendinput;
 
@@ -21,15 +22,30 @@ pub enum InsertionStrategy {
     ClipboardPaste,
     ClipboardCopyOnly,
     WindowsSendInput,
+    WaylandVirtualKeyboard,
 }
 
 impl InsertionStrategy {
-    pub fn from_config_value(value: &str) -> Self {
+    pub fn from_config_value(value: &str, output_mode: &str) -> Self {
+        Self::from_config_value_for_session(
+            value,
+            output_mode,
+            crate::platform::is_wayland_session(),
+        )
+    }
+
+    /// On Wayland, "auto" types through a virtual keyboard because enigo
+    /// cannot synthesize input there, unless the legacy output mode asked
+    /// for the clipboard.
+    fn from_config_value_for_session(value: &str, output_mode: &str, wayland: bool) -> Self {
         match value {
             "keyboard" => Self::Keyboard,
             "clipboardPaste" => Self::ClipboardPaste,
             "clipboardCopyOnly" => Self::ClipboardCopyOnly,
             "windowsSendInput" => Self::WindowsSendInput,
+            "waylandVirtualKeyboard" => Self::WaylandVirtualKeyboard,
+            _ if wayland && output_mode == "clipboard" => Self::ClipboardPaste,
+            _ if wayland => Self::WaylandVirtualKeyboard,
             _ => Self::Auto,
         }
     }
@@ -38,10 +54,21 @@ impl InsertionStrategy {
         match self {
             Self::Auto | Self::Keyboard => Some(Self::Keyboard),
             Self::WindowsSendInput => Some(Self::WindowsSendInput),
+            Self::WaylandVirtualKeyboard => Some(Self::WaylandVirtualKeyboard),
             Self::ClipboardPaste | Self::ClipboardCopyOnly => None,
         }
     }
 
+    /// How voice editing keys reach text that went in with this strategy.
+    /// Wayland virtual keyboard text is edited through the same helper;
+    /// everything else is edited with regular key presses.
+    pub fn edit_keys_strategy(self) -> Self {
+        match self {
+            Self::WaylandVirtualKeyboard => Self::WaylandVirtualKeyboard,
+            _ => Self::Keyboard,
+        }
+    }
+
     pub fn needs_keyboard_access(self) -> bool {
         matches!(self, Self::Auto | Self::Keyboard | Self::WindowsSendInput)
     }
@@ -197,7 +224,11 @@ pub async fn output_with_strategy(
     clipboard_options: clipboard::ClipboardOutputOptions,
     windows_sendinput_options: windows_sendinput::WindowsSendInputOptions,
 ) -> Result<OutputOutcome, String> {
-    let keyboard = create_output(OutputMode::Keyboard, app_handle);
+    let keyboard: Box<dyn TextOutput> = if strategy == InsertionStrategy::WaylandVirtualKeyboard {
+        Box::new(wayland_keyboard::WaylandKeyboardOutput::detect())
+    } else {
+        create_output(OutputMode::Keyboard, app_handle)
+    };
     let windows_sendinput =
         windows_sendinput::WindowsSendInputOutput::new(windows_sendinput_options);
     let clipboard_paste =
@@ -240,6 +271,12 @@ pub async fn output_stream_chunk(
                 .await
                 .map_err(|e| e.to_string())
         }
+        Some(InsertionStrategy::WaylandVirtualKeyboard) => {
+            wayland_keyboard::WaylandKeyboardOutput::detect()
+                .type_text(text)
+                .await
+                .map_err(|e| e.to_string())
+        }
         _ => Err(format!(
             "Insertion strategy {:?} does not support streaming chunks",
             strategy
@@ -247,6 +284,24 @@ pub async fn output_stream_chunk(
     }
 }
 
+pub async fn press_edit_keys(
+    app_handle: &tauri::AppHandle,
+    keys: keyboard::EditKeys,
+    strategy: InsertionStrategy,
+) -> Result<(), String> {
+    if strategy.edit_keys_strategy() == InsertionStrategy::WaylandVirtualKeyboard {
+        return wayland_keyboard::WaylandKeyboardOutput::detect()
+            .press_edit_keys(keys)
+            .await
+            .map_err(|e| e.to_string());
+    }
+    keyboard::check_keyboard_available()?;
+    keyboard::KeyboardOutput::new(app_handle)
+        .press_edit_keys(keys)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[cfg(test)]
 async fn output_with_fallback_using(
     text: &str,
@@ -378,7 +433,7 @@ async fn output_with_strategy_using(
             });
     }
 
-    // Try keyboard first
+    // Try keyboard (or the Wayland virtual keyboard) first
     match keyboard.type_text(text).await {
         Ok(insert_result) => Ok(OutputOutcome {
             insert_result,
@@ -462,6 +517,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn edit_keys_follow_text_typed_through_the_wayland_virtual_keyboard() {
+        assert_eq!(
+            InsertionStrategy::WaylandVirtualKeyboard.edit_keys_strategy(),
+            InsertionStrategy::WaylandVirtualKeyboard
+        );
+        for strategy in [
+            InsertionStrategy::Auto,
+            InsertionStrategy::Keyboard,
+            InsertionStrategy::ClipboardPaste,
+            InsertionStrategy::WindowsSendInput,
+        ] {
+            assert_eq!(strategy.edit_keys_strategy(), InsertionStrategy::Keyboard);
+        }
+    }
+
     #[test]
     fn direct_streaming_strategy_allows_only_direct_insert_paths() {
         assert_eq!(
@@ -476,6 +547,10 @@ mod tests {
             InsertionStrategy::WindowsSendInput.direct_streaming_strategy(),
             Some(InsertionStrategy::WindowsSendInput)
         );
+        assert_eq!(
+            InsertionStrategy::WaylandVirtualKeyboard.direct_streaming_strategy(),
+            Some(InsertionStrategy::WaylandVirtualKeyboard)
+        );
         assert_eq!(
             InsertionStrategy::ClipboardPaste.direct_streaming_strategy(),
             None
@@ -584,6 +659,79 @@ mod tests {
         assert!(outcome.insert_result.warning_code.is_none());
     }
 
+    #[test]
+    fn auto_strategy_types_through_virtual_keyboard_on_wayland() {
+        assert_eq!(
+            InsertionStrategy::from_config_value_for_session("auto", "keyboard", true),
+            InsertionStrategy::WaylandVirtualKeyboard
+        );
+        assert_eq!(
+            InsertionStrategy::from_config_value_for_session("auto", "clipboard", true),
+            InsertionStrategy::ClipboardPaste
+        );
+        assert_eq!(
+            InsertionStrategy::from_config_value_for_session("auto", "keyboard", false),
+            InsertionStrategy::Auto
+        );
+        assert_eq!(
+            InsertionStrategy::from_config_value_for_session("clipboardPaste", "keyboard", true),
+            InsertionStrategy::ClipboardPaste
+        );
+        assert!(!InsertionStrategy::WaylandVirtualKeyboard.needs_keyboard_access());
+    }
+
+    #[test]
+    fn terminal_profile_keeps_wayland_auto_on_the_virtual_keyboard() {
+        let registry = crate::app_detector::registry::AppRegistry::builtin().unwrap();
+        let mut config = crate::storage::AppConfig::default();
+        config.apply_output_policy(&registry.output_policy("code.terminal"));
+
+        assert_eq!(
+            InsertionStrategy::from_config_value_for_session(
+                &config.insertion_strategy,
+                &config.output_mode,
+                true,
+            ),
+            InsertionStrategy::WaylandVirtualKeyboard
+        );
+        assert_eq!(config.paste_shortcut, "ctrlShiftV");
+        assert!(!config.streaming_insert_enabled);
+    }
+
+    #[tokio::test]
+    async fn wayland_virtual_keyboard_failure_falls_back_to_clipboard() {
+        let virtual_keyboard = FakeOutput::err(OutputMode::Keyboard, "wtype missing");
+        let windows_sendinput =
+            FakeOutput::err(OutputMode::Keyboard, "sendinput should not be used");
+        let clipboard_paste = FakeOutput::ok(
+            OutputMode::Clipboard,
+            InsertResult::copied_fallback(InsertionStrategy::ClipboardCopyOnly, 4),
+        );
+        let clipboard_copy = FakeOutput::err(OutputMode::Clipboard, "copy should not be used");
+
+        let outcome = output_with_strategy_using(
+            "text",
+            InsertionStrategy::WaylandVirtualKeyboard,
+            &virtual_keyboard,
+            &windows_sendinput,
+            &clipboard_paste,
+            &clipboard_copy,
+            Some(UserError {
+                code: "output_wayland_clipboard_copy_only".to_string(),
+                details: None,
+                retry_count: 0,
+            }),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(outcome.insert_result.status, InsertStatus::CopiedFallback);
+        assert_eq!(
+            outcome.insert_result.warning_code.as_deref(),
+            Some("output_wayland_clipboard_copy_only")
+        );
+    }
+
     #[tokio::test]
     async fn windows_sendinput_success_uses_sendinput_without_clipboard() {
         let keyboard = FakeOutput::err(OutputMode::Keyboard, "keyboard should not be used");
@@ -0,0 +1,335 @@
+//! Text insertion on Wayland through a virtual keyboard.
+//!
+//! Wayland compositors refuse synthetic input from ordinary clients, so enigo
+//! cannot type there. Instead we drive helpers that speak the input protocols
+//! for us: `wtype` creates a `zwp_virtual_keyboard_v1` device (wlroots, KDE)
+//! and `ydotool` writes to uinput through `ydotoold`, which also works on
+//! GNOME where the virtual keyboard protocol is not exposed.
+
+use super::keyboard::EditKeys;
+use super::{InsertResult, InsertionStrategy, OutputMode, TextOutput};
+use crate::error::AppError;
+use async_trait::async_trait;
+use std::process::Command;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+const YDOTOOL_DEFAULT_SOCKET: &str = "/tmp/.ydotool_socket";
+/// How long a detection result is reused; short enough that installing a
+/// helper or starting `ydotoold` is picked up without a restart.
+const DETECTION_TTL: Duration = Duration::from_secs(30);
+/// Linux input event codes for Shift+Return, pressed and released in order.
+const YDOTOOL_SHIFT_RETURN: [&str; 4] = ["42:1", "28:1", "28:0", "42:0"];
+const YDOTOOL_SHIFT: u16 = 42;
+const YDOTOOL_BACKSPACE: u16 = 14;
+const YDOTOOL_RETURN: u16 = 28;
+const YDOTOOL_LEFT: u16 = 105;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VirtualKeyboardBackend {
+    Wtype,
+    Ydotool,
+}
+
+impl VirtualKeyboardBackend {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Wtype => "wtype",
+            Self::Ydotool => "ydotool",
+        }
+    }
+
+    /// `ydotool type` maps characters through a US keymap, so it can only be
+    /// trusted with ASCII; anything else goes through the clipboard fallback.
+    fn supports(self, text: &str) -> bool {
+        match self {
+            Self::Wtype => true,
+            Self::Ydotool => text.is_ascii(),
+        }
+    }
+}
+
+/// Candidate backends for a desktop, in preference order. Mutter does not
+/// implement the virtual keyboard protocol, so GNOME only gets uinput.
+fn backend_order(desktop: &str) -> &'static [VirtualKeyboardBackend] {
+    if desktop
+        .split(':')
+        .any(|part| part.eq_ignore_ascii_case("gnome"))
+    {
+        &[VirtualKeyboardBackend::Ydotool]
+    } else {
+        &[
+            VirtualKeyboardBackend::Wtype,
+            VirtualKeyboardBackend::Ydotool,
+        ]
+    }
+}
+
+/// The first usable virtual keyboard backend in this Wayland session, if any.
+/// Cached for `DETECTION_TTL` so streaming chunks do not spawn `which` each.
+pub fn detect_backend() -> Option<VirtualKeyboardBackend> {
+    static DETECTED: OnceLock<Mutex<Option<(Instant, Option<VirtualKeyboardBackend>)>>> =
+        OnceLock::new();
+    let mut detected = DETECTED
+        .get_or_init(|| Mutex::new(None))
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+    if let Some((checked_at, backend)) = *detected {
+        if checked_at.elapsed() < DETECTION_TTL {
+            return backend;
+        }
+    }
+    let backend = probe_backend();
+    *detected = Some((Instant::now(), backend));
+    backend
+}
+
+fn probe_backend() -> Option<VirtualKeyboardBackend> {
+    if !crate::platform::is_wayland_session() {
+        return None;
+    }
+    let desktop = std::env::var("XDG_CURRENT_DESKTOP").unwrap_or_default();
+    backend_order(&desktop)
+        .iter()
+        .copied()
+        .find(|backend| backend_available(*backend))
+}
+
+fn backend_available(backend: VirtualKeyboardBackend) -> bool {
+    let installed = Command::new("which")
+        .arg(backend.as_str())
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false);
+    match backend {
+        VirtualKeyboardBackend::Wtype => installed,
+        VirtualKeyboardBackend::Ydotool => {
+            let socket = std::env::var("YDOTOOL_SOCKET")
+                .unwrap_or_else(|_| YDOTOOL_DEFAULT_SOCKET.to_string());
+            installed && std::path::Path::new(&socket).exists()
+        }
+    }
+}
+
+/// Helper invocations that type `text`, with Shift+Return between lines to
+/// match what keyboard output does in chat inputs.
+fn commands_for(backend: VirtualKeyboardBackend, text: &str) -> Vec<Vec<String>> {
+    let lines: Vec<&str> = text.split('\n').collect();
+    let mut commands = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if !line.is_empty() {
+            let mut args = match backend {
+                VirtualKeyboardBackend::Wtype => vec![],
+                VirtualKeyboardBackend::Ydotool => vec!["type".to_string()],
+            };
+            args.push("--".to_string());
+            args.push(line.to_string());
+            commands.push(args);
+        }
+        if i < lines.len() - 1 {
+            commands.push(match backend {
+                VirtualKeyboardBackend::Wtype => ["-M", "shift", "-k", "Return", "-m", "shift"]
+                    .iter()
+                    .map(|arg| arg.to_string())
+                    .collect(),
+                VirtualKeyboardBackend::Ydotool => std::iter::once("key")
+                    .chain(YDOTOOL_SHIFT_RETURN)
+                    .map(|arg| arg.to_string())
+                    .collect(),
+            });
+        }
+    }
+    commands
+}
+
+/// One helper invocation pressing the keys of a voice editing command, with
+/// Shift held around the presses that need it.
+fn edit_command_for(backend: VirtualKeyboardBackend, keys: EditKeys) -> Vec<String> {
+    let (count, shift, wtype_key, ydotool_key) = match keys {
+        EditKeys::Backspace(count) => (count, false, "BackSpace", YDOTOOL_BACKSPACE),
+        EditKeys::SelectLeft(count) => (count, true, "Left", YDOTOOL_LEFT),
+        EditKeys::LineBreaks(count) => (count, true, "Return", YDOTOOL_RETURN),
+    };
+    let mut args = Vec::new();
+    match backend {
+        VirtualKeyboardBackend::Wtype => {
+            if shift {
+                args.extend(["-M".to_string(), "shift".to_string()]);
+            }
+            for _ in 0..count {
+                args.extend(["-k".to_string(), wtype_key.to_string()]);
+            }
+            if shift {
+                args.extend(["-m".to_string(), "shift".to_string()]);
+            }
+        }
+        VirtualKeyboardBackend::Ydotool => {
+            args.push("key".to_string());
+            if shift {
+                args.push(format!("{YDOTOOL_SHIFT}:1"));
+            }
+            for _ in 0..count {
+                args.push(format!("{ydotool_key}:1"));
+                args.push(format!("{ydotool_key}:0"));
+            }
+            if shift {
+                args.push(format!("{YDOTOOL_SHIFT}:0"));
+            }
+        }
+    }
+    args
+}
+
+fn type_text_sync(backend: VirtualKeyboardBackend, text: &str) -> Result<(), AppError> {
+    run_commands(backend, commands_for(backend, text))
+}
+
+fn run_commands(
+    backend: VirtualKeyboardBackend,
+    commands: Vec<Vec<String>>,
+) -> Result<(), AppError> {
+    for args in commands {
+        let output = Command::new(backend.as_str())
+            .args(&args)
+            .output()
+            .map_err(|e| AppError::Output(format!("Failed to run {}: {}", backend.as_str(), e)))?;
+        if !output.status.success() {
+            return Err(AppError::Output(format!(
+                "{} exited with {}: {}",
+                backend.as_str(),
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+    }
+    Ok(())
+}
+
+pub struct WaylandKeyboardOutput {
+    backend: Option<VirtualKeyboardBackend>,
+}
+
+impl WaylandKeyboardOutput {
+    pub fn detect() -> Self {
+        Self {
+            backend: detect_backend(),
+        }
+    }
+
+    fn backend(&self) -> Result<VirtualKeyboardBackend, AppError> {
+        self.backend.ok_or_else(|| {
+            AppError::Output("No Wayland virtual keyboard found (install wtype or ydotool)".into())
+        })
+    }
+
+    pub async fn press_edit_keys(&self, keys: EditKeys) -> Result<(), AppError> {
+        let backend = self.backend()?;
+        tokio::task::spawn_blocking(move || {
+            run_commands(backend, vec![edit_command_for(backend, keys)])
+        })
+        .await
+        .map_err(|e| AppError::Output(format!("Spawn blocking error: {}", e)))?
+    }
+}
+
+#[async_trait]
+impl TextOutput for WaylandKeyboardOutput {
+    async fn type_text(&self, text: &str) -> Result<InsertResult, AppError> {
+        let backend = self.backend()?;
+        if !backend.supports(text) {
+            return Err(AppError::Output(format!(
+                "{} cannot type non-ASCII text",
+                backend.as_str()
+            )));
+        }
+
+        let chars_inserted = text.chars().count();
+        let text = text.to_string();
+        tokio::task::spawn_blocking(move || type_text_sync(backend, &text))
+            .await
+            .map_err(|e| AppError::Output(format!("Spawn blocking error: {}", e)))??;
+        Ok(InsertResult::inserted(
+            InsertionStrategy::WaylandVirtualKeyboard,
+            chars_inserted,
+        ))
+    }
+
+    fn mode(&self) -> OutputMode {
+        OutputMode::Keyboard
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn gnome_skips_the_virtual_keyboard_protocol() {
+        assert_eq!(
+            backend_order("ubuntu:GNOME"),
+            &[VirtualKeyboardBackend::Ydotool]
+        );
+        assert_eq!(
+            backend_order("sway"),
+            &[
+                VirtualKeyboardBackend::Wtype,
+                VirtualKeyboardBackend::Ydotool
+            ]
+        );
+    }
+
+    #[test]
+    fn commands_type_lines_with_shift_return_between() {
+        assert_eq!(
+            commands_for(VirtualKeyboardBackend::Wtype, "-a\r\n\nb"),
+            vec![
+                args(&["--", "-a"]),
+                args(&["-M", "shift", "-k", "Return", "-m", "shift"]),
+                args(&["-M", "shift", "-k", "Return", "-m", "shift"]),
+                args(&["--", "b"]),
+            ]
+        );
+        assert_eq!(
+            commands_for(VirtualKeyboardBackend::Ydotool, "hi\n"),
+            vec![
+                args(&["type", "--", "hi"]),
+                args(&["key", "42:1", "28:1", "28:0", "42:0"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn edit_keys_hold_shift_only_around_selection_and_line_breaks() {
+        assert_eq!(
+            edit_command_for(VirtualKeyboardBackend::Wtype, EditKeys::Backspace(2)),
+            args(&["-k", "BackSpace", "-k", "BackSpace"])
+        );
+        assert_eq!(
+            edit_command_for(VirtualKeyboardBackend::Wtype, EditKeys::SelectLeft(1)),
+            args(&["-M", "shift", "-k", "Left", "-m", "shift"])
+        );
+        assert_eq!(
+            edit_command_for(VirtualKeyboardBackend::Ydotool, EditKeys::Backspace(2)),
+            args(&["key", "14:1", "14:0", "14:1", "14:0"])
+        );
+        assert_eq!(
+            edit_command_for(VirtualKeyboardBackend::Ydotool, EditKeys::LineBreaks(1)),
+            args(&["key", "42:1", "28:1", "28:0", "42:0"])
+        );
+    }
+
+    #[tokio::test]
+    async fn ydotool_rejects_non_ascii_so_output_falls_back() {
+        let output = WaylandKeyboardOutput {
+            backend: Some(VirtualKeyboardBackend::Ydotool),
+        };
+
+        assert!(output.type_text("你好").await.is_err());
+        assert!(VirtualKeyboardBackend::Wtype.supports("你好"));
+    }
+}
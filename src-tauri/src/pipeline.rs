@@ -8,10 +8,11 @@ use tokio::sync::{mpsc, Notify};
 use crate::app_detector;
 use crate::app_detector::types::{RecordingContext, TargetAppGuard};
 use crate::audio::{AudioCaptureHandle, AudioConfig};
+use crate::corrections::{apply_correction_rules, AppliedCorrection, CorrectionStage};
 use crate::credentials::{
     resolve_llm_config_secret, resolve_stt_config_secret, SystemCredentialVault,
 };
-use crate::llm::{self, LlmConfig, PolishRequest};
+use crate::llm::{self, PolishRequest};
 use crate::output;
 use crate::storage;
 use crate::stt::{self, SttConfig, TranscriptEvent};
@@ -122,6 +123,40 @@ const SELECTED_TEXT_CAPTURE_DELAY_MS: u64 = 60;
 const VOLUME_POLL_INTERVAL_MS: u64 = 50;
 /// Timeout for STT finalization after recording stops.
 const STT_FINALIZE_TIMEOUT_SECS: u64 = 120;
+/// How long voice editing commands may still act on the last insertion.
+const LAST_INSERTION_TTL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// The last insertion, with what is needed to tell whether it is still the
+/// text right before the cursor.
+struct LastInsertion {
+    record: crate::voice_intent::executor::VoiceInsertionRecord,
+    /// How the text went in; editing keys must reach the app the same way.
+    strategy: output::InsertionStrategy,
+    recorded_at: std::time::Instant,
+    focus_generation: u64,
+}
+
+impl LastInsertion {
+    fn is_current(&self, focus_generation: u64) -> bool {
+        self.recorded_at.elapsed() < LAST_INSERTION_TTL && self.focus_generation == focus_generation
+    }
+}
+
+/// Strategy the voice editing keys go through: whatever typed the last
+/// insertion, or the configured one when nothing was typed yet.
+fn edit_keys_strategy(
+    last_insertion: Option<output::InsertionStrategy>,
+    config: &storage::AppConfig,
+) -> output::InsertionStrategy {
+    last_insertion
+        .unwrap_or_else(|| {
+            output::InsertionStrategy::from_config_value(
+                &config.insertion_strategy,
+                &config.output_mode,
+            )
+        })
+        .edit_keys_strategy()
+}
 
 fn generate_cloud_operation_id() -> String {
     static COUNTER: AtomicU64 = AtomicU64::new(1);
@@ -199,6 +234,15 @@ fn should_finalize_stt_task(
         && active_session_id.load(Ordering::SeqCst) == task_session_id
 }
 
+fn emit_speech_event(app_handle: &tauri::AppHandle, event: &TranscriptEvent) {
+    let name = match event {
+        TranscriptEvent::SpeechStarted => "stt:speech_started",
+        TranscriptEvent::SpeechEnded => "stt:speech_ended",
+        _ => return,
+    };
+    let _ = app_handle.emit(name, ());
+}
+
 fn no_speech_user_error() -> crate::error::UserError {
     crate::error::UserError {
         code: "stt_no_speech_detected".to_string(),
@@ -254,6 +298,7 @@ fn strategy_uses_automated_input(strategy: output::InsertionStrategy) -> bool {
             | output::InsertionStrategy::Keyboard
             | output::InsertionStrategy::ClipboardPaste
             | output::InsertionStrategy::WindowsSendInput
+            | output::InsertionStrategy::WaylandVirtualKeyboard
     )
 }
 
@@ -292,10 +337,45 @@ fn voice_intent_requires_generated_output(kind: crate::voice_intent::VoiceIntent
         kind,
         crate::voice_intent::VoiceIntentKind::DictateInsert
             | crate::voice_intent::VoiceIntentKind::Search
-    )
+    ) && !kind.is_edit()
+}
+
+/// Applies spoken punctuation to a transcript that skips the language model.
+fn format_raw_transcript(text: &str, config: &storage::AppConfig) -> String {
+    if !config.spoken_formatting_enabled {
+        return text.to_string();
+    }
+    match crate::spoken_formatting::formatting_locale(&config.stt_language, text) {
+        Some(locale) => crate::spoken_formatting::apply_spoken_formatting(text, locale),
+        None => text.to_string(),
+    }
 }
 
-fn history_provider_kind(config: &storage::AppConfig) -> storage::HistoryProviderKind {
+fn voice_execution_history_status(
+    execution: &crate::voice_intent::executor::VoiceExecutionResult,
+) -> (Option<String>, Option<String>) {
+    match execution.status {
+        crate::voice_intent::executor::VoiceExecutionStatus::Completed => (None, None),
+        crate::voice_intent::executor::VoiceExecutionStatus::CopiedFallback => (
+            Some("clipboard_fallback".to_string()),
+            Some(format!(
+                "Voice output fallback: {:?}",
+                execution.fallback_reason
+            )),
+        ),
+        crate::voice_intent::executor::VoiceExecutionStatus::PopupFallback
+        | crate::voice_intent::executor::VoiceExecutionStatus::Prevented
+        | crate::voice_intent::executor::VoiceExecutionStatus::Failed => (
+            Some("fallback".to_string()),
+            Some(format!(
+                "Voice output fallback: {:?}",
+                execution.fallback_reason
+            )),
+        ),
+    }
+}
+
+pub(crate) fn history_provider_kind(config: &storage::AppConfig) -> storage::HistoryProviderKind {
     let provider = if config.polish_enabled {
         config.llm_provider.as_str()
     } else {
@@ -306,6 +386,7 @@ fn history_provider_kind(config: &storage::AppConfig) -> storage::HistoryProvide
     }
     if provider == "ollama"
         || provider == "apple-speech"
+        || provider == stt::config::LOCAL_WHISPER_PROVIDER
         || (provider == "custom-whisper"
             && (config.stt_custom_base_url.contains("localhost")
                 || config.stt_custom_base_url.contains("127.0.0.1")))
@@ -315,6 +396,25 @@ fn history_provider_kind(config: &storage::AppConfig) -> storage::HistoryProvide
     storage::HistoryProviderKind::Byok
 }
 
+/// Classifies the providers that actually served the recording, which differ
+/// from the configured ones after a failover.
+pub(crate) fn served_history_provider_kind(
+    config: &storage::AppConfig,
+    stt_provider: Option<&str>,
+    llm_provider: Option<&str>,
+) -> storage::HistoryProviderKind {
+    let stt_provider = stt_provider.unwrap_or(&config.stt_provider);
+    let llm_provider = llm_provider.unwrap_or(&config.llm_provider);
+    if stt_provider == config.stt_provider && llm_provider == config.llm_provider {
+        return history_provider_kind(config);
+    }
+    history_provider_kind(&storage::AppConfig {
+        stt_provider: stt_provider.to_string(),
+        llm_provider: llm_provider.to_string(),
+        ..config.clone()
+    })
+}
+
 fn route_pipeline_voice_intent(
     mode: crate::voice_intent::VoiceMode,
     raw_text: &str,
@@ -345,20 +445,53 @@ fn streaming_insert_strategy_for_config(
         return None;
     }
 
-    let strategy = output::InsertionStrategy::from_config_value(&config.insertion_strategy)
-        .direct_streaming_strategy()?;
-    if strategy == output::InsertionStrategy::Keyboard && !keyboard_available {
+    let strategy = output::InsertionStrategy::from_config_value(
+        &config.insertion_strategy,
+        &config.output_mode,
+    )
+    .direct_streaming_strategy()?;
+    if matches!(
+        strategy,
+        output::InsertionStrategy::Keyboard | output::InsertionStrategy::WaylandVirtualKeyboard
+    ) && !keyboard_available
+    {
         return None;
     }
 
     Some(strategy)
 }
 
+/// Polished-stage corrections rewrite the model output, which is no longer
+/// possible once it has been typed chunk by chunk, so enabled correction rules
+/// keep streaming insert off for the recording.
+fn streaming_insert_allowed_with_corrections(rules: &[llm::CorrectionRule]) -> bool {
+    !rules.iter().any(|rule| rule.enabled)
+}
+
+/// Local correction pass over the model output, so rules still win when the
+/// model ignores the prompt hint.
+fn correct_polished_text(
+    polished_text: &mut String,
+    rules: &[llm::CorrectionRule],
+) -> Vec<AppliedCorrection> {
+    let corrected = apply_correction_rules(polished_text, rules, CorrectionStage::Polished);
+    *polished_text = corrected.text;
+    corrected.applied
+}
+
 fn streaming_insert_strategy_for_runtime(
     config: &storage::AppConfig,
     selected_text: Option<&str>,
 ) -> Option<output::InsertionStrategy> {
-    let keyboard_available = output::keyboard::check_keyboard_available().is_ok();
+    let keyboard_available = if output::InsertionStrategy::from_config_value(
+        &config.insertion_strategy,
+        &config.output_mode,
+    ) == output::InsertionStrategy::WaylandVirtualKeyboard
+    {
+        output::wayland_keyboard::detect_backend().is_some()
+    } else {
+        output::keyboard::check_keyboard_available().is_ok()
+    };
     streaming_insert_strategy_for_config(
         config,
         selected_text_has_content(selected_text),
@@ -367,16 +500,117 @@ fn streaming_insert_strategy_for_runtime(
     )
 }
 
+/// Builds the configured fallback chain. Fallbacks that cannot be set up
+/// right now (missing key or config) are skipped instead of failing the
+/// recording.
+pub(crate) fn stt_fallback_candidates(
+    config: &storage::AppConfig,
+    primary: &SttConfig,
+    cloud_session_token: &str,
+    client: &reqwest::Client,
+) -> Vec<stt::failover::FailoverCandidate> {
+    stt::failover::fallback_provider_ids(config)
+        .into_iter()
+        .filter_map(|provider_id| {
+            let source = stt::failover::fallback_source_config(config, &provider_id);
+            let api_key = if provider_id == "cloud" {
+                cloud_session_token.to_string()
+            } else {
+                match resolve_stt_config_secret(&source, &SystemCredentialVault) {
+                    Ok(secret) => secret,
+                    Err(error) => {
+                        tracing::warn!("Skipping STT fallback {provider_id}: {error}");
+                        return None;
+                    }
+                }
+            };
+            if stt::config::stt_provider_requires_api_key(&provider_id) && api_key.is_empty() {
+                tracing::warn!("Skipping STT fallback {provider_id}: API key is not configured");
+                return None;
+            }
+            let custom_whisper_config = if provider_id == stt::config::CUSTOM_WHISPER_PROVIDER {
+                match stt::config::build_custom_whisper_config(
+                    &config.stt_custom_base_url,
+                    &config.stt_custom_model,
+                ) {
+                    Ok(cfg) => Some(cfg),
+                    Err(error) => {
+                        tracing::warn!("Skipping STT fallback {provider_id}: {error}");
+                        return None;
+                    }
+                }
+            } else {
+                None
+            };
+            let provider = match stt::create_provider(
+                &provider_id,
+                custom_whisper_config,
+                Some(client.clone()),
+            ) {
+                Ok(provider) => provider,
+                Err(error) => {
+                    tracing::warn!("Skipping STT fallback {provider_id}: {error}");
+                    return None;
+                }
+            };
+            let stt_config = SttConfig {
+                api_key,
+                resource_id: None,
+                managed_audio: stt::capabilities::managed_audio_encoding_config(
+                    &source,
+                    chrono::Utc::now().timestamp(),
+                ),
+                local_model_path: (provider_id == stt::config::LOCAL_WHISPER_PROVIDER)
+                    .then(|| config.stt_local_model_path.clone()),
+                ..primary.clone()
+            };
+            Some(stt::failover::FailoverCandidate {
+                provider_id,
+                provider,
+                config: stt_config,
+            })
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct ActiveSceneHistoryDiagnostics {
-    id: Option<String>,
-    source: Option<String>,
-    name: Option<String>,
-    prompt_chars: Option<i64>,
-    prompt_truncated: bool,
+pub(crate) struct ActiveSceneHistoryDiagnostics {
+    pub(crate) id: Option<String>,
+    pub(crate) source: Option<String>,
+    pub(crate) name: Option<String>,
+    pub(crate) prompt_chars: Option<i64>,
+    pub(crate) prompt_truncated: bool,
+}
+
+/// Scene picked by the scene rules for this recording, evaluated against the
+/// local clock.
+fn scene_rule_scene(
+    config: &storage::AppConfig,
+    app_ctx: &RecordingContext,
+    raw_text: &str,
+    selected_text: Option<&str>,
+) -> Option<storage::SceneRuleMatch> {
+    use chrono::{Datelike, Timelike};
+
+    if config.scene_rules.is_empty() {
+        return None;
+    }
+    let now = chrono::Local::now();
+    let language = storage::speech_language(&config.stt_language, raw_text);
+    storage::scene_rule_scene(
+        config,
+        &storage::SceneRuleInput {
+            family: app_ctx.profile.family,
+            profile_id: &app_ctx.profile.id,
+            language: language.as_deref(),
+            weekday: now.weekday().number_from_monday() as u8,
+            minute_of_day: (now.hour() * 60 + now.minute()) as u16,
+            has_selected_text: selected_text_has_content(selected_text),
+        },
+    )
 }
 
-fn active_scene_history_diagnostics(
+pub(crate) fn active_scene_history_diagnostics(
     active_scene: Option<&storage::ActiveScene>,
 ) -> ActiveSceneHistoryDiagnostics {
     match active_scene {
@@ -633,6 +867,8 @@ fn take_matching_stt_error(
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct PipelineStartOptions {
     pub force_translate: bool,
+    /// Stop the recording once this much silence follows detected speech.
+    pub auto_stop_silence_ms: Option<u32>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -678,6 +914,18 @@ fn apply_pipeline_start_options(
     config
 }
 
+/// Explicit start options win; otherwise toggle-mode dictation falls back to the
+/// configured silence timeout, since hold-to-talk already ends on key release.
+fn resolve_auto_stop_silence_ms(
+    options: PipelineStartOptions,
+    config: &storage::AppConfig,
+) -> Option<u32> {
+    options
+        .auto_stop_silence_ms
+        .or_else(|| (config.hotkey_mode == "toggle").then_some(config.auto_stop_silence_ms))
+        .filter(|ms| *ms > 0)
+}
+
 #[derive(Clone)]
 pub struct PipelineHandle {
     app_handle: tauri::AppHandle,
@@ -689,6 +937,13 @@ pub struct PipelineHandle {
     stt_session: Arc<Mutex<Option<SttTaskControl>>>,
     stt_error: Arc<Mutex<Option<(u64, crate::error::UserError)>>>,
     active_stt_session_id: Arc<AtomicU64>,
+    active_stt_provider: stt::failover::ActiveSttProvider,
+    /// LLM provider that served the current polish, after any failover.
+    active_llm_provider: Arc<Mutex<Option<String>>>,
+    /// Scene rule that supplied the current polish's scene, for history.
+    matched_scene_rule: Arc<Mutex<Option<storage::SceneRuleMatch>>>,
+    /// Text the last dictation typed, for voice editing commands.
+    last_insertion: Arc<Mutex<Option<LastInsertion>>>,
     active_deadline_session_id: Arc<AtomicU64>,
     abort_flag: Arc<AtomicBool>,
     preloaded_config: Arc<Mutex<Option<storage::AppConfig>>>,
@@ -729,6 +984,7 @@ struct PolishTextOutcome {
     history_output_status: Option<String>,
     history_output_error: Option<String>,
     voice_execution: Option<crate::voice_intent::executor::VoiceExecutionResult>,
+    applied_corrections: Vec<AppliedCorrection>,
 }
 
 pub(crate) struct AskVoiceDraftOutcome {
@@ -739,6 +995,9 @@ pub(crate) struct AskVoiceDraftOutcome {
 struct HistoryOutputMetadata {
     status: Option<String>,
     error: Option<String>,
+    applied_corrections: Vec<AppliedCorrection>,
+    llm_provider: Option<String>,
+    scene_rule: Option<storage::SceneRuleMatch>,
 }
 
 struct PipelineVoiceExecutionBackend<'a> {
@@ -750,6 +1009,7 @@ struct PipelineVoiceExecutionBackend<'a> {
     config: &'a storage::AppConfig,
     already_copied: bool,
     popup_fallback_enabled: bool,
+    edit_strategy: output::InsertionStrategy,
 }
 
 #[async_trait::async_trait]
@@ -838,6 +1098,33 @@ impl crate::voice_intent::executor::VoiceExecutionBackend for PipelineVoiceExecu
     ) -> std::result::Result<(), String> {
         Err("search is not available in the dictation pipeline".to_string())
     }
+
+    async fn delete_before_cursor(&mut self, chars: usize) -> std::result::Result<(), String> {
+        self.pipeline
+            .press_edit_keys(
+                output::keyboard::EditKeys::Backspace(chars),
+                self.edit_strategy,
+            )
+            .await
+    }
+
+    async fn select_before_cursor(&mut self, chars: usize) -> std::result::Result<(), String> {
+        self.pipeline
+            .press_edit_keys(
+                output::keyboard::EditKeys::SelectLeft(chars),
+                self.edit_strategy,
+            )
+            .await
+    }
+
+    async fn insert_line_breaks(&mut self, count: usize) -> std::result::Result<(), String> {
+        self.pipeline
+            .press_edit_keys(
+                output::keyboard::EditKeys::LineBreaks(count),
+                self.edit_strategy,
+            )
+            .await
+    }
 }
 
 impl PolishTextOutcome {
@@ -848,6 +1135,7 @@ impl PolishTextOutcome {
             history_output_status: None,
             history_output_error: None,
             voice_execution: None,
+            applied_corrections: Vec::new(),
         }
     }
 
@@ -863,9 +1151,15 @@ impl PolishTextOutcome {
             history_output_status: Some(status.to_string()),
             history_output_error: Some(error.into()),
             voice_execution: None,
+            applied_corrections: Vec::new(),
         }
     }
 
+    fn with_applied_corrections(mut self, applied_corrections: Vec<AppliedCorrection>) -> Self {
+        self.applied_corrections = applied_corrections;
+        self
+    }
+
     fn with_execution(
         final_text: String,
         llm_elapsed: std::time::Duration,
@@ -879,6 +1173,7 @@ impl PolishTextOutcome {
             history_output_status,
             history_output_error,
             voice_execution: Some(execution),
+            applied_corrections: Vec::new(),
         }
     }
 }
@@ -899,6 +1194,10 @@ impl PipelineHandle {
             stt_session: Arc::new(Mutex::new(None)),
             stt_error: Arc::new(Mutex::new(None)),
             active_stt_session_id: Arc::new(AtomicU64::new(0)),
+            active_stt_provider: Arc::new(Mutex::new(None)),
+            active_llm_provider: Arc::new(Mutex::new(None)),
+            matched_scene_rule: Arc::new(Mutex::new(None)),
+            last_insertion: Arc::new(Mutex::new(None)),
             active_deadline_session_id: Arc::new(AtomicU64::new(0)),
             abort_flag: Arc::new(AtomicBool::new(false)),
             preloaded_config: Arc::new(Mutex::new(None)),
@@ -1099,6 +1398,9 @@ impl PipelineHandle {
         );
         let dictionary_store = self.app_handle.state::<storage::DictionaryStore>();
         let dict_words = dictionary_store.words().await;
+        let stt_vocabulary = stt::vocabulary::hints_from_dictionary(
+            &dictionary_store.list().await.unwrap_or_default(),
+        );
         let correction_rules = dictionary_store
             .enabled_correction_rules()
             .await
@@ -1252,6 +1554,9 @@ impl PipelineHandle {
                 &config_data,
                 chrono::Utc::now().timestamp(),
             ),
+            vocabulary: stt_vocabulary,
+            local_model_path: (config_data.stt_provider == stt::config::LOCAL_WHISPER_PROVIDER)
+                .then(|| config_data.stt_local_model_path.clone()),
         };
         let managed_cloud_session_token =
             (config_data.stt_provider == "cloud").then(|| stt_config.api_key.clone());
@@ -1259,7 +1564,7 @@ impl PipelineHandle {
             stt::cloud::warm_managed_cloud_on_intent(self.shared_client.clone(), session_token);
         }
 
-        let mut provider = match stt::create_provider(
+        let primary_provider = match stt::create_provider(
             &config_data.stt_provider,
             custom_whisper_config,
             Some(self.shared_client.clone()),
@@ -1290,10 +1595,29 @@ impl PipelineHandle {
                 return Ok(());
             }
         };
+        let mut provider: Box<dyn stt::SttProvider> =
+            Box::new(stt::failover::FailoverSttProvider::new(
+                config_data.stt_provider.clone(),
+                primary_provider,
+                stt_fallback_candidates(
+                    &config_data,
+                    &stt_config,
+                    &self
+                        .app_handle
+                        .state::<SessionTokenStore>()
+                        .0
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .clone(),
+                    &self.shared_client,
+                ),
+                self.active_stt_provider.clone(),
+            ));
         // Start the platform audio backend before connecting STT. Both readiness
         // operations are then polled concurrently, so speech captured while a
         // network provider connects remains queued instead of being clipped.
         let config = AudioConfig::default();
+        let mut vad = crate::audio::vad::VoiceActivityDetector::new(config.sample_rate);
         let (mut handle, mut audio_rx) = match AudioCaptureHandle::start(config) {
             Ok(result) => result,
             Err(e) => {
@@ -1524,6 +1848,9 @@ impl PipelineHandle {
         let abort_flag_ref = self.abort_flag.clone();
         let active_session_id_ref = self.active_stt_session_id.clone();
         let stt_error_ref = self.stt_error.clone();
+        let auto_stop_silence_ms = resolve_auto_stop_silence_ms(options, &config_data);
+        let auto_stop_pipeline = self.clone();
+        let mut auto_stop_requested = false;
 
         tokio::spawn(async move {
             // Forward audio to STT and receive transcripts
@@ -1544,6 +1871,36 @@ impl PipelineHandle {
                     chunk = audio_rx.recv() => {
                         match chunk {
                             Some(data) => {
+                                if let Some(event) = vad.push(&data) {
+                                    let event = match event {
+                                        crate::audio::vad::VadEvent::SpeechStarted => {
+                                            TranscriptEvent::SpeechStarted
+                                        }
+                                        crate::audio::vad::VadEvent::SpeechEnded => {
+                                            TranscriptEvent::SpeechEnded
+                                        }
+                                    };
+                                    emit_speech_event(&app_handle, &event);
+                                }
+                                if !auto_stop_requested
+                                    && crate::audio::vad::should_auto_stop(
+                                        vad.silence_after_speech_ms(),
+                                        auto_stop_silence_ms,
+                                    )
+                                {
+                                    auto_stop_requested = true;
+                                    tracing::info!("Silence auto-stop after {:?} ms", auto_stop_silence_ms);
+                                    let pipeline = auto_stop_pipeline.clone();
+                                    let session_id = stt_control.id;
+                                    tokio::spawn(async move {
+                                        if pipeline.active_stt_session_id.load(Ordering::SeqCst) != session_id {
+                                            return;
+                                        }
+                                        if let Err(error) = pipeline.stop().await {
+                                            tracing::error!("Failed to stop recording after silence: {error}");
+                                        }
+                                    });
+                                }
                                 let _ = provider.send_audio(&data).await;
                             }
                             None => {
@@ -1657,7 +2014,10 @@ impl PipelineHandle {
                                 }
                                 break;
                             }
-                            _ => {}
+                            // Speech boundaries come from the local VAD so every
+                            // provider reports them the same way.
+                            Ok(Some(TranscriptEvent::SpeechStarted | TranscriptEvent::SpeechEnded))
+                            | Ok(None) => {}
                         }
                     }
                 }
@@ -1818,6 +2178,7 @@ impl PipelineHandle {
                 self.context_detector
                     .snapshot_for_recording_enabled(config.context_adaptation_enabled)
             });
+        config.apply_output_policy(&app_ctx.output_policy);
         let dictionary_words = self
             .preloaded_dictionary
             .lock()
@@ -1848,7 +2209,7 @@ impl PipelineHandle {
             .unwrap_or(crate::voice_intent::VoiceMode::Dictate);
 
         // Extract session token before releasing guard (for cloud LLM)
-        let session_token = if config.llm_provider == "cloud" {
+        let session_token = if llm::failover::chain_uses_cloud(&config) {
             self.app_handle
                 .state::<SessionTokenStore>()
                 .0
@@ -1873,8 +2234,14 @@ impl PipelineHandle {
                 return Ok(());
             } // aborted or no speech detected
         };
-        let voice_intent =
-            route_pipeline_voice_intent(voice_mode, &raw_text, selected_text.as_deref(), &config);
+        let transcript_corrections =
+            apply_correction_rules(&raw_text, &correction_rules, CorrectionStage::Transcript);
+        let voice_intent = route_pipeline_voice_intent(
+            voice_mode,
+            &transcript_corrections.text,
+            selected_text.as_deref(),
+            &config,
+        );
         let stt_elapsed = stop_start.elapsed();
         tracing::info!(
             "[Pipeline Timing] STT finalize: {}ms",
@@ -1893,7 +2260,7 @@ impl PipelineHandle {
         // ── Phase 2: LLM polish + output ───────────────────────────────
         let polish_outcome = self
             .polish_text(PolishTextInput {
-                raw_text: &raw_text,
+                raw_text: &transcript_corrections.text,
                 voice_mode,
                 config: &config,
                 app_ctx: &app_ctx,
@@ -1908,6 +2275,8 @@ impl PipelineHandle {
             .await;
         let final_text = polish_outcome.final_text;
         let llm_elapsed = polish_outcome.llm_elapsed;
+        let mut applied_corrections = transcript_corrections.applied;
+        applied_corrections.extend(polish_outcome.applied_corrections);
 
         // ── Phase 3: Timing, history, cleanup ──────────────────────────
         let total_elapsed = stop_start.elapsed();
@@ -1951,7 +2320,22 @@ impl PipelineHandle {
             HistoryOutputMetadata {
                 status: polish_outcome.history_output_status,
                 error: polish_outcome.history_output_error,
+                applied_corrections,
+                llm_provider: self
+                    .active_llm_provider
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .clone(),
+                scene_rule: self
+                    .matched_scene_rule
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .take(),
             },
+            self.active_stt_provider
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .clone(),
         )
         .await;
 
@@ -2031,8 +2415,24 @@ impl PipelineHandle {
             voice_intent,
             popup_fallback_enabled,
         } = input;
+        *self
+            .active_llm_provider
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = None;
+        *self
+            .matched_scene_rule
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = None;
         let provider_plan =
             crate::voice_intent::plan_voice_provider_work(voice_mode, raw_text, &voice_intent);
+        if voice_intent.kind.is_edit() {
+            return self
+                .execute_voice_edit(raw_text, config, app_ctx, &voice_intent)
+                .await;
+        }
+        // Whatever this dictation does, the previous insertion is no longer
+        // reliably the text before the cursor.
+        self.store_last_insertion(None, output::InsertionStrategy::Auto);
         let Some(provider_text) = provider_plan.provider_input.as_deref() else {
             let message = "Search must bypass the language model and use the safe search executor.";
             let _ = self.app_handle.emit(
@@ -2051,7 +2451,7 @@ impl PipelineHandle {
             );
         };
         let llm_api_key = if config.llm_provider == "cloud" {
-            session_token
+            session_token.clone()
         } else {
             match resolve_llm_config_secret(config, &SystemCredentialVault) {
                 Ok(secret) => secret,
@@ -2096,10 +2496,11 @@ impl PipelineHandle {
                 );
             }
 
-            // No polishing — output raw text directly
+            // No polishing — output the raw text with spoken punctuation applied
+            let raw_output = format_raw_transcript(provider_text, config);
             if let Err(e) = self
                 .output_text(
-                    provider_text,
+                    &raw_output,
                     &app_ctx.profile.app_label,
                     &app_ctx.target_guard,
                     config,
@@ -2111,24 +2512,26 @@ impl PipelineHandle {
                     .app_handle
                     .emit("pipeline:error", output_user_error(&e));
             }
-            return PolishTextOutcome::normal(provider_text.to_string(), std::time::Duration::ZERO);
+            return PolishTextOutcome::normal(raw_output, std::time::Duration::ZERO);
         }
 
         self.set_state(PipelineState::Polishing);
         let llm_start = std::time::Instant::now();
 
-        let llm_config = LlmConfig {
-            provider: config.llm_provider.clone(),
-            api_key: llm_api_key,
-            model: config.llm_model.clone(),
-            base_url: config.llm_base_url.clone(),
-            max_tokens: 4096,
-            temperature: 0.3,
-        };
-        let provider = llm::create_provider(&config.llm_provider, Some(self.shared_client.clone()));
+        let llm_chain =
+            llm::failover::build_chain(config, llm_api_key, &session_token, &SystemCredentialVault);
 
-        let streaming_strategy = provider_plan
-            .allow_streaming
+        let corrections_allow_streaming =
+            streaming_insert_allowed_with_corrections(&correction_rules);
+        if provider_plan.allow_streaming
+            && config.streaming_insert_enabled
+            && !corrections_allow_streaming
+        {
+            tracing::info!(
+                "Streaming insert is off for this recording because correction rules are enabled"
+            );
+        }
+        let streaming_strategy = (provider_plan.allow_streaming && corrections_allow_streaming)
             .then(|| streaming_insert_strategy_for_runtime(config, selected_text.as_deref()))
             .flatten();
         let mut streaming_worker = streaming_strategy.map(|strategy| {
@@ -2162,12 +2565,21 @@ impl PipelineHandle {
         });
 
         let selected_text_for_execution = selected_text.clone();
-        let mapped_scene_prompt = storage::automatic_scene_prompt(
-            config,
-            app_ctx.profile.family,
-            app_ctx.mapped_scene_id.as_deref(),
-        )
-        .unwrap_or_default();
+        let local_correction_rules = correction_rules.clone();
+        let scene_rule = scene_rule_scene(config, app_ctx, raw_text, selected_text.as_deref());
+        let mapped_scene_prompt = match &scene_rule {
+            Some(matched) => matched.scene.prompt_template.clone(),
+            None => storage::automatic_scene_prompt(
+                config,
+                app_ctx.profile.family,
+                app_ctx.mapped_scene_id.as_deref(),
+            )
+            .unwrap_or_default(),
+        };
+        *self
+            .matched_scene_rule
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = scene_rule;
         let req = PolishRequest {
             raw_text: provider_text.to_string(),
             context: app_ctx.summary(),
@@ -2188,16 +2600,38 @@ impl PipelineHandle {
             voice_intent: voice_intent.clone(),
         };
 
-        let polish_result = provider.polish(&llm_config, &req, Some(&on_chunk)).await;
-        drop(on_chunk);
+        let polish_result = llm::failover::polish_with_failover(
+            &llm_chain,
+            self.shared_client.clone(),
+            &req,
+            Some(on_chunk),
+        )
+        .await
+        .map(|served| {
+            *self
+                .active_llm_provider
+                .lock()
+                .unwrap_or_else(|e| e.into_inner()) = Some(served.provider);
+            served.response
+        });
         let streaming_report = match streaming_worker.take() {
             Some(worker) => worker.finish().await,
             None => None,
         };
 
         let polish_outcome = match polish_result {
-            Ok(response) => {
+            Ok(mut response) => {
                 let elapsed = llm_start.elapsed();
+                // Streaming insert is off whenever rules are enabled, so this
+                // only skips text typed with no rules to apply.
+                let polished_corrections = if streaming_report
+                    .as_ref()
+                    .is_some_and(StreamingInsertReport::has_inserted_text)
+                {
+                    Vec::new()
+                } else {
+                    correct_polished_text(&mut response.polished_text, &local_correction_rules)
+                };
                 if let Some(report) = streaming_report.as_ref() {
                     if report.has_inserted_text() {
                         let mut streaming_history_status: Option<(&'static str, String)> = None;
@@ -2208,10 +2642,7 @@ impl PipelineHandle {
                             !report.failed && !report.target_lost,
                         ) {
                             StreamingRecoveryAction::AlreadyComplete => {
-                                self.emit_streaming_insert_result(
-                                    report,
-                                    &app_ctx.profile.app_label,
-                                );
+                                self.emit_streaming_insert_result(report, app_ctx);
                             }
                             StreamingRecoveryAction::InsertSuffix { suffix } => {
                                 let mut recovered_report = report.clone();
@@ -2239,10 +2670,7 @@ impl PipelineHandle {
                                             recovered_report.error_message = None;
                                             recovered_report.attempted_chunks =
                                                 recovered_report.attempted_chunks.saturating_add(1);
-                                            self.emit_streaming_insert_result(
-                                                &recovered_report,
-                                                &app_ctx.profile.app_label,
-                                            );
+                                            self.emit_streaming_insert_result(&recovered_report, app_ctx);
                                         }
                                         Ok(insert_result) => {
                                             let reason = format!(
@@ -2253,10 +2681,7 @@ impl PipelineHandle {
                                             recovered_report.error_message = Some(reason.clone());
                                             streaming_history_status =
                                                 Some(("clipboard_fallback", reason.clone()));
-                                            self.emit_streaming_insert_result(
-                                                &recovered_report,
-                                                &app_ctx.profile.app_label,
-                                            );
+                                            self.emit_streaming_insert_result(&recovered_report, app_ctx);
                                             self.copy_streaming_recovery_to_clipboard(
                                                 &response.polished_text,
                                                 reason,
@@ -2270,10 +2695,7 @@ impl PipelineHandle {
                                             recovered_report.error_message = Some(error.clone());
                                             streaming_history_status =
                                                 Some(("clipboard_fallback", error.clone()));
-                                            self.emit_streaming_insert_result(
-                                                &recovered_report,
-                                                &app_ctx.profile.app_label,
-                                            );
+                                            self.emit_streaming_insert_result(&recovered_report, app_ctx);
                                             self.copy_streaming_recovery_to_clipboard(
                                                 &response.polished_text,
                                                 error,
@@ -2284,10 +2706,7 @@ impl PipelineHandle {
                                         }
                                     }
                                 } else {
-                                    self.emit_streaming_insert_result(
-                                        &recovered_report,
-                                        &app_ctx.profile.app_label,
-                                    );
+                                    self.emit_streaming_insert_result(&recovered_report, app_ctx);
                                 }
                             }
                             StreamingRecoveryAction::CopyFullToClipboard { reason } => {
@@ -2296,10 +2715,7 @@ impl PipelineHandle {
                                 partial_report.error_message = Some(reason.clone());
                                 streaming_history_status =
                                     Some(("clipboard_fallback", reason.clone()));
-                                self.emit_streaming_insert_result(
-                                    &partial_report,
-                                    &app_ctx.profile.app_label,
-                                );
+                                self.emit_streaming_insert_result(&partial_report, app_ctx);
                                 self.copy_streaming_recovery_to_clipboard(
                                     &response.polished_text,
                                     reason,
@@ -2313,10 +2729,7 @@ impl PipelineHandle {
                                 partial_report.failed = true;
                                 partial_report.error_message = Some(reason.clone());
                                 streaming_history_status = Some(("partial", reason.clone()));
-                                self.emit_streaming_insert_result(
-                                    &partial_report,
-                                    &app_ctx.profile.app_label,
-                                );
+                                self.emit_streaming_insert_result(&partial_report, app_ctx);
                                 self.copy_streaming_recovery_to_clipboard(
                                     &partial_report.inserted_text,
                                     reason,
@@ -2353,7 +2766,8 @@ impl PipelineHandle {
                             elapsed,
                             "clipboard_fallback",
                             "Target app changed before streaming insert; copied full result to clipboard",
-                        );
+                        )
+                        .with_applied_corrections(polished_corrections);
                     }
                     if report.failed {
                         tracing::warn!(
@@ -2389,6 +2803,7 @@ impl PipelineHandle {
                     config,
                     already_copied: false,
                     popup_fallback_enabled,
+                    edit_strategy: edit_keys_strategy(None, config),
                 };
                 let execution = crate::voice_intent::executor::execute_voice_intent(
                     crate::voice_intent::executor::VoiceExecutionRequest {
@@ -2398,31 +2813,14 @@ impl PipelineHandle {
                         selected_text_available,
                         restore_target_before_insert: provider_plan.restore_target_before_insert,
                         flags: config.voice_routing_flags,
+                        last_insertion: None,
                     },
                     &mut backend,
                 )
                 .await;
                 let _ = self.app_handle.emit("pipeline:voice_execution", &execution);
 
-                let (history_status, history_error) = match execution.status {
-                    crate::voice_intent::executor::VoiceExecutionStatus::Completed => (None, None),
-                    crate::voice_intent::executor::VoiceExecutionStatus::CopiedFallback => (
-                        Some("clipboard_fallback".to_string()),
-                        Some(format!(
-                            "Voice output fallback: {:?}",
-                            execution.fallback_reason
-                        )),
-                    ),
-                    crate::voice_intent::executor::VoiceExecutionStatus::PopupFallback
-                    | crate::voice_intent::executor::VoiceExecutionStatus::Prevented
-                    | crate::voice_intent::executor::VoiceExecutionStatus::Failed => (
-                        Some("fallback".to_string()),
-                        Some(format!(
-                            "Voice output fallback: {:?}",
-                            execution.fallback_reason
-                        )),
-                    ),
-                };
+                let (history_status, history_error) = voice_execution_history_status(&execution);
                 PolishTextOutcome::with_execution(
                     response.polished_text,
                     elapsed,
@@ -2430,6 +2828,7 @@ impl PipelineHandle {
                     history_status,
                     history_error,
                 )
+                .with_applied_corrections(polished_corrections)
             }
             Err(e) => {
                 crate::error::emit_cloud_session_invalid(&self.app_handle, &e);
@@ -2443,10 +2842,7 @@ impl PipelineHandle {
                         let mut partial_report = report.clone();
                         partial_report.failed = true;
                         partial_report.error_message = Some(format!("LLM polish failed: {}", e));
-                        self.emit_streaming_insert_result(
-                            &partial_report,
-                            &app_ctx.profile.app_label,
-                        );
+                        self.emit_streaming_insert_result(&partial_report, app_ctx);
                         if let StreamingRecoveryAction::CopyPartialToClipboard { reason } =
                             streaming_recovery_action(&partial_report, None, false, false)
                         {
@@ -2487,9 +2883,10 @@ impl PipelineHandle {
                         "LLM generation failed; no application text was changed",
                     );
                 }
+                let raw_output = format_raw_transcript(provider_text, config);
                 if let Err(e) = self
                     .output_text(
-                        provider_text,
+                        &raw_output,
                         &app_ctx.profile.app_label,
                         &app_ctx.target_guard,
                         config,
@@ -2502,7 +2899,7 @@ impl PipelineHandle {
                         .emit("pipeline:error", output_user_error(&e));
                 }
                 PolishTextOutcome::with_history_status(
-                    provider_text.to_string(),
+                    raw_output,
                     elapsed,
                     "fallback",
                     format!("LLM polish failed; output raw text: {e}"),
@@ -2547,7 +2944,7 @@ impl PipelineHandle {
                 enabled: rule.enabled,
             })
             .collect::<Vec<_>>();
-        let session_token = if config.llm_provider == "cloud" {
+        let session_token = if llm::failover::chain_uses_cloud(config) {
             self.app_handle
                 .state::<SessionTokenStore>()
                 .0
@@ -2596,6 +2993,7 @@ impl PipelineHandle {
         duration_ms: Option<i64>,
         config: &storage::AppConfig,
         output: HistoryOutputMetadata,
+        stt_provider: Option<String>,
     ) {
         let policy = config.history_retention_policy();
         if !policy.enabled {
@@ -2604,7 +3002,17 @@ impl PipelineHandle {
         }
 
         let now = chrono::Local::now().format("%Y-%m-%dT%H:%M:%S").to_string();
-        let scene_diagnostics = active_scene_history_diagnostics(config.active_scene.as_ref());
+        let scene_diagnostics = active_scene_history_diagnostics(
+            config
+                .active_scene
+                .as_ref()
+                .or(output.scene_rule.as_ref().map(|matched| &matched.scene)),
+        );
+        let provider_kind = served_history_provider_kind(
+            config,
+            stt_provider.as_deref(),
+            output.llm_provider.as_deref(),
+        );
         let entry = storage::HistoryEntry {
             id: 0, // auto-increment
             created_at: now,
@@ -2613,7 +3021,7 @@ impl PipelineHandle {
             context_icon_key: app_ctx.profile.icon_key.clone(),
             context_family: app_ctx.profile.family,
             browser_access_status: app_ctx.browser_access_status,
-            provider_kind: history_provider_kind(config),
+            provider_kind,
             raw_text: raw_text.to_string(),
             polished_text: final_text.to_string(),
             language: None,
@@ -2625,6 +3033,12 @@ impl PipelineHandle {
             active_scene_prompt_truncated: scene_diagnostics.prompt_truncated,
             output_status: output.status,
             output_error: output.error,
+            applied_corrections: output.applied_corrections,
+            pinned: false,
+            parent_id: None,
+            stt_provider,
+            llm_provider: output.llm_provider,
+            scene_rule_id: output.scene_rule.map(|matched| matched.rule_id),
         };
         if let Err(e) = self
             .app_handle
@@ -2636,13 +3050,19 @@ impl PipelineHandle {
         }
     }
 
-    fn emit_streaming_insert_result(&self, report: &StreamingInsertReport, app_name: &str) {
+    fn emit_streaming_insert_result(
+        &self,
+        report: &StreamingInsertReport,
+        app_ctx: &RecordingContext,
+    ) {
+        let app_name = app_ctx.profile.app_label.as_str();
         let chars_inserted = report.chars_inserted();
         let mut insert_result = if report.failed {
             output::InsertResult::partially_inserted(report.strategy, chars_inserted)
         } else {
             output::InsertResult::inserted(report.strategy, chars_inserted)
         };
+        self.record_insertion(&report.inserted_text, &insert_result, &app_ctx.target_guard);
 
         let warning = report
             .failed
@@ -2729,6 +3149,123 @@ impl PipelineHandle {
         }
     }
 
+    /// Remembers typed text so "delete last sentence" and friends can edit
+    /// it. Copies and failed inserts leave nothing to edit.
+    fn record_insertion(
+        &self,
+        text: &str,
+        insert_result: &output::InsertResult,
+        target_guard: &TargetAppGuard,
+    ) {
+        let record = crate::voice_intent::executor::VoiceInsertionRecord::from_insert_result(
+            text,
+            insert_result,
+            target_guard.clone(),
+        );
+        self.store_last_insertion(record, insert_result.strategy_used);
+    }
+
+    fn store_last_insertion(
+        &self,
+        record: Option<crate::voice_intent::executor::VoiceInsertionRecord>,
+        strategy: output::InsertionStrategy,
+    ) {
+        let focus_generation = self.context_detector.focus_generation();
+        *self
+            .last_insertion
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = record.map(|record| LastInsertion {
+            record,
+            strategy,
+            recorded_at: std::time::Instant::now(),
+            focus_generation,
+        });
+    }
+
+    /// The last insertion if voice editing may still act on it: recorded
+    /// within `LAST_INSERTION_TTL` and with focus never having left the app.
+    fn current_last_insertion(
+        &self,
+    ) -> Option<(
+        crate::voice_intent::executor::VoiceInsertionRecord,
+        output::InsertionStrategy,
+    )> {
+        let focus_generation = self.context_detector.focus_generation();
+        let mut last = self
+            .last_insertion
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if !last
+            .as_ref()
+            .is_some_and(|last| last.is_current(focus_generation))
+        {
+            *last = None;
+        }
+        last.as_ref()
+            .map(|last| (last.record.clone(), last.strategy))
+    }
+
+    async fn press_edit_keys(
+        &self,
+        keys: output::keyboard::EditKeys,
+        strategy: output::InsertionStrategy,
+    ) -> std::result::Result<(), String> {
+        self.set_state(PipelineState::Outputting);
+        output::press_edit_keys(&self.app_handle, keys, strategy).await
+    }
+
+    /// Runs a voice editing command against the last recorded insertion.
+    /// Editing never reaches the language model.
+    async fn execute_voice_edit(
+        &self,
+        raw_text: &str,
+        config: &storage::AppConfig,
+        app_ctx: &RecordingContext,
+        voice_intent: &crate::voice_intent::VoiceIntent,
+    ) -> PolishTextOutcome {
+        let (last_insertion, last_strategy) = self.current_last_insertion().unzip();
+        let mut backend = PipelineVoiceExecutionBackend {
+            pipeline: self,
+            app_name: &app_ctx.profile.app_label,
+            question: raw_text,
+            intent_kind: voice_intent.kind,
+            target_guard: &app_ctx.target_guard,
+            config,
+            already_copied: false,
+            popup_fallback_enabled: false,
+            edit_strategy: edit_keys_strategy(last_strategy, config),
+        };
+        let execution = crate::voice_intent::executor::execute_voice_intent(
+            crate::voice_intent::executor::VoiceExecutionRequest {
+                intent: voice_intent,
+                generated_output: "",
+                target_guard: &app_ctx.target_guard,
+                selected_text_available: false,
+                restore_target_before_insert: false,
+                flags: config.voice_routing_flags,
+                last_insertion: last_insertion.as_ref(),
+            },
+            &mut backend,
+        )
+        .await;
+        let _ = self.app_handle.emit("pipeline:voice_execution", &execution);
+
+        if execution.status == crate::voice_intent::executor::VoiceExecutionStatus::Completed {
+            self.store_last_insertion(
+                last_insertion.and_then(|record| record.after_edit(voice_intent.kind)),
+                last_strategy.unwrap_or(backend.edit_strategy),
+            );
+        }
+        let (history_status, history_error) = voice_execution_history_status(&execution);
+        PolishTextOutcome::with_execution(
+            String::new(),
+            std::time::Duration::ZERO,
+            execution,
+            history_status,
+            history_error,
+        )
+    }
+
     async fn output_text(
         &self,
         text: &str,
@@ -2752,7 +3289,10 @@ impl PipelineHandle {
         let requested_strategy = if target_warning.is_some() {
             output::InsertionStrategy::ClipboardCopyOnly
         } else {
-            output::InsertionStrategy::from_config_value(&config.insertion_strategy)
+            output::InsertionStrategy::from_config_value(
+                &config.insertion_strategy,
+                &config.output_mode,
+            )
         };
         let (strategy, accessibility_warning) =
             effective_strategy_for_accessibility(requested_strategy, is_accessibility_trusted());
@@ -2820,6 +3360,7 @@ impl PipelineHandle {
             output_outcome.insert_result.chars_inserted
         );
         let insert_result = output_outcome.insert_result.clone();
+        self.record_insertion(text, &insert_result, target_guard);
         let _ = self
             .app_handle
             .emit("pipeline:insert_result", &insert_result);
@@ -3070,6 +3611,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn local_corrections_cover_the_transcript_and_the_polished_text() {
+        let rules = vec![llm::CorrectionRule {
+            id: 7,
+            pattern: "open type less".to_string(),
+            replacement: "OpenTypeless".to_string(),
+            enabled: true,
+        }];
+
+        let transcript = apply_correction_rules(
+            "try open type less today",
+            &rules,
+            CorrectionStage::Transcript,
+        );
+        assert_eq!(transcript.text, "try OpenTypeless today");
+
+        // The model may reintroduce the misheard phrase while rewriting.
+        let mut polished = "Try open type less today.".to_string();
+        let applied = correct_polished_text(&mut polished, &rules);
+        assert_eq!(polished, "Try OpenTypeless today.");
+        assert_eq!(applied[0].rule_id, 7);
+        assert_eq!(applied[0].stage, CorrectionStage::Polished);
+
+        assert!(!streaming_insert_allowed_with_corrections(&rules));
+        let disabled = vec![llm::CorrectionRule {
+            enabled: false,
+            ..rules[0].clone()
+        }];
+        assert!(streaming_insert_allowed_with_corrections(&disabled));
+        assert!(streaming_insert_allowed_with_corrections(&[]));
+    }
+
     fn streaming_report_for_test(inserted_text: &str, failed: bool) -> StreamingInsertReport {
         StreamingInsertReport {
             strategy: output::InsertionStrategy::Keyboard,
@@ -3086,11 +3659,13 @@ mod tests {
         let expected = TargetAppGuard {
             process_id: Some(42),
             native_identity: Some("com.example.notes".to_string()),
+            ..TargetAppGuard::default()
         };
         assert!(expected.matches(&expected));
         assert!(!expected.matches(&TargetAppGuard {
             process_id: Some(99),
             native_identity: Some("com.example.browser".to_string()),
+            ..TargetAppGuard::default()
         }));
     }
 
@@ -3146,6 +3721,7 @@ mod tests {
             config.clone(),
             PipelineStartOptions {
                 force_translate: true,
+                ..PipelineStartOptions::default()
             },
         );
 
@@ -3154,6 +3730,77 @@ mod tests {
         assert!(!config.translate_enabled);
     }
 
+    #[test]
+    fn raw_transcripts_get_spoken_formatting_only_when_enabled() {
+        let off_by_default = storage::AppConfig {
+            stt_language: "multi".to_string(),
+            ..storage::AppConfig::default()
+        };
+        assert_eq!(
+            format_raw_transcript("see you soon period", &off_by_default),
+            "see you soon period"
+        );
+
+        let config = storage::AppConfig {
+            spoken_formatting_enabled: true,
+            ..off_by_default
+        };
+        assert_eq!(
+            format_raw_transcript("see you soon period", &config),
+            "see you soon."
+        );
+        assert_eq!(format_raw_transcript("你好逗号世界", &config), "你好，世界");
+
+        let french = storage::AppConfig {
+            stt_language: "fr".to_string(),
+            ..config.clone()
+        };
+        assert_eq!(
+            format_raw_transcript("see you soon period", &french),
+            "see you soon period"
+        );
+        let disabled = storage::AppConfig {
+            spoken_formatting_enabled: false,
+            ..config
+        };
+        assert_eq!(
+            format_raw_transcript("see you soon period", &disabled),
+            "see you soon period"
+        );
+    }
+
+    #[test]
+    fn silence_auto_stop_prefers_start_options_and_only_defaults_in_toggle_mode() {
+        let toggle = storage::AppConfig {
+            hotkey_mode: "toggle".to_string(),
+            auto_stop_silence_ms: 1_500,
+            ..storage::AppConfig::default()
+        };
+        let hold = storage::AppConfig {
+            hotkey_mode: "hold".to_string(),
+            ..toggle.clone()
+        };
+
+        assert_eq!(
+            resolve_auto_stop_silence_ms(PipelineStartOptions::default(), &toggle),
+            Some(1_500)
+        );
+        assert_eq!(
+            resolve_auto_stop_silence_ms(PipelineStartOptions::default(), &hold),
+            None
+        );
+        let explicit = PipelineStartOptions {
+            auto_stop_silence_ms: Some(800),
+            ..PipelineStartOptions::default()
+        };
+        assert_eq!(resolve_auto_stop_silence_ms(explicit, &hold), Some(800));
+        let disabled = PipelineStartOptions {
+            auto_stop_silence_ms: Some(0),
+            ..PipelineStartOptions::default()
+        };
+        assert_eq!(resolve_auto_stop_silence_ms(disabled, &toggle), None);
+    }
+
     #[test]
     fn switch_translation_target_updates_capture_without_restart_and_freezes_at_finalization() {
         let mut operation = TranslationOperationState::new("ja".to_string());
@@ -3411,5 +4058,82 @@ mod tests {
             history_provider_kind(&config),
             storage::HistoryProviderKind::Local
         );
+
+        config.polish_enabled = false;
+        config.stt_provider = "local-whisper".to_string();
+        assert_eq!(
+            history_provider_kind(&config),
+            storage::HistoryProviderKind::Local
+        );
+    }
+
+    #[test]
+    fn last_insertion_expires_and_is_dropped_on_focus_change() {
+        let record = crate::voice_intent::executor::VoiceInsertionRecord::from_insert_result(
+            "Ship it.",
+            &output::InsertResult::inserted(output::InsertionStrategy::Keyboard, 8),
+            TargetAppGuard::default(),
+        )
+        .unwrap();
+        let fresh = LastInsertion {
+            record: record.clone(),
+            strategy: output::InsertionStrategy::Keyboard,
+            recorded_at: std::time::Instant::now(),
+            focus_generation: 3,
+        };
+        assert!(fresh.is_current(3));
+        assert!(!fresh.is_current(4));
+
+        let expired = LastInsertion {
+            record,
+            strategy: output::InsertionStrategy::Keyboard,
+            recorded_at: std::time::Instant::now() - LAST_INSERTION_TTL,
+            focus_generation: 3,
+        };
+        assert!(!expired.is_current(3));
+    }
+
+    #[test]
+    fn edit_keys_follow_the_strategy_that_typed_the_last_insertion() {
+        let config = storage::AppConfig {
+            insertion_strategy: "clipboardPaste".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            edit_keys_strategy(
+                Some(output::InsertionStrategy::WaylandVirtualKeyboard),
+                &config
+            ),
+            output::InsertionStrategy::WaylandVirtualKeyboard
+        );
+        assert_eq!(
+            edit_keys_strategy(Some(output::InsertionStrategy::Keyboard), &config),
+            output::InsertionStrategy::Keyboard
+        );
+        assert_eq!(
+            edit_keys_strategy(None, &config),
+            output::InsertionStrategy::Keyboard
+        );
+    }
+
+    #[test]
+    fn served_history_provider_kind_follows_failover() {
+        let config = storage::AppConfig {
+            polish_enabled: true,
+            llm_provider: "openrouter".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            served_history_provider_kind(&config, None, None),
+            storage::HistoryProviderKind::Byok
+        );
+        assert_eq!(
+            served_history_provider_kind(&config, None, Some("ollama")),
+            storage::HistoryProviderKind::Local
+        );
+        assert_eq!(
+            served_history_provider_kind(&config, None, Some("cloud")),
+            storage::HistoryProviderKind::ManagedCloud
+        );
     }
 }
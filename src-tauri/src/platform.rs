@@ -8,6 +8,8 @@ pub struct PlatformCapabilities {
     pub global_hotkey_reliable: bool,
     pub keyboard_output_reliable: bool,
     pub clipboard_auto_paste_reliable: bool,
+    /// Helper used to type on Wayland (`wtype` or `ydotool`), when one is usable.
+    pub wayland_virtual_keyboard: Option<String>,
 }
 
 pub fn current_os() -> &'static str {
@@ -47,7 +49,12 @@ pub fn is_wayland_session() -> bool {
 }
 
 pub fn capabilities() -> PlatformCapabilities {
-    capabilities_for(current_os(), &current_session_type())
+    let virtual_keyboard = crate::output::wayland_keyboard::detect_backend();
+    capabilities_for(
+        current_os(),
+        &current_session_type(),
+        virtual_keyboard.map(|backend| backend.as_str()),
+    )
 }
 
 fn normalize_session_type(session_type: &str) -> &'static str {
@@ -60,16 +67,22 @@ fn normalize_session_type(session_type: &str) -> &'static str {
     }
 }
 
-fn capabilities_for(os: &str, session_type: &str) -> PlatformCapabilities {
+fn capabilities_for(
+    os: &str,
+    session_type: &str,
+    virtual_keyboard: Option<&str>,
+) -> PlatformCapabilities {
     let normalized_session = normalize_session_type(session_type);
     let is_linux_wayland = os == "linux" && normalized_session == "wayland";
+    let virtual_keyboard = virtual_keyboard.filter(|_| is_linux_wayland);
 
     PlatformCapabilities {
         os: os.to_string(),
         session_type: normalized_session.to_string(),
         global_hotkey_reliable: !is_linux_wayland,
-        keyboard_output_reliable: !is_linux_wayland,
+        keyboard_output_reliable: !is_linux_wayland || virtual_keyboard.is_some(),
         clipboard_auto_paste_reliable: !is_linux_wayland,
+        wayland_virtual_keyboard: virtual_keyboard.map(str::to_string),
     }
 }
 
@@ -79,7 +92,7 @@ mod tests {
 
     #[test]
     fn wayland_linux_marks_input_automation_unreliable() {
-        let caps = capabilities_for("linux", "wayland");
+        let caps = capabilities_for("linux", "wayland", None);
 
         assert_eq!(caps.session_type, "wayland");
         assert!(!caps.global_hotkey_reliable);
@@ -89,17 +102,27 @@ mod tests {
 
     #[test]
     fn x11_linux_keeps_input_automation_reliable() {
-        let caps = capabilities_for("linux", "x11");
+        let caps = capabilities_for("linux", "x11", Some("wtype"));
 
         assert_eq!(caps.session_type, "x11");
         assert!(caps.global_hotkey_reliable);
         assert!(caps.keyboard_output_reliable);
         assert!(caps.clipboard_auto_paste_reliable);
+        assert_eq!(caps.wayland_virtual_keyboard, None);
+    }
+
+    #[test]
+    fn wayland_virtual_keyboard_makes_keyboard_output_reliable() {
+        let caps = capabilities_for("linux", "wayland", Some("ydotool"));
+
+        assert!(caps.keyboard_output_reliable);
+        assert!(!caps.clipboard_auto_paste_reliable);
+        assert_eq!(caps.wayland_virtual_keyboard.as_deref(), Some("ydotool"));
     }
 
     #[test]
     fn unknown_session_normalizes_to_unknown() {
-        let caps = capabilities_for("linux", "mir");
+        let caps = capabilities_for("linux", "mir", None);
 
         assert_eq!(caps.session_type, "unknown");
         assert!(caps.global_hotkey_reliable);
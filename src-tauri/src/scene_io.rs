@@ -0,0 +1,714 @@
+use crate::dictionary_io::{string_alias, ImportRowError, MAX_IMPORT_BYTES};
+use crate::storage::{
+    system_scene_prompt, AppConfig, CustomScene, SystemSceneOverride, CUSTOM_SCENES_MAX_COUNT,
+    SCENE_DESCRIPTION_MAX_CHARS, SCENE_ID_MAX_CHARS, SCENE_NAME_MAX_CHARS, SCENE_PROMPT_MAX_CHARS,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+
+pub const SCENE_PACK_FORMAT: &str = "opentypeless_scene_pack";
+pub const SCENE_PACK_VERSION: u64 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScenePackScene {
+    /// Empty when the pack did not carry an id; one is generated on import.
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub prompt_template: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScenePackSystemOverride {
+    pub id: String,
+    pub prompt_template: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedScenePack {
+    pub version: u64,
+    pub scenes: Vec<ScenePackScene>,
+    pub system_overrides: Vec<ScenePackSystemOverride>,
+    pub skipped_invalid: usize,
+    pub errors: Vec<ImportRowError>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ScenePackError {
+    FileTooLarge,
+    InvalidUtf8,
+    UnsupportedVersion,
+    TooManyScenes,
+    InvalidStructure,
+}
+
+impl fmt::Display for ScenePackError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::FileTooLarge => "scene_pack_file_too_large",
+            Self::InvalidUtf8 => "scene_pack_invalid_utf8",
+            Self::UnsupportedVersion => "scene_pack_unsupported_version",
+            Self::TooManyScenes => "scene_pack_too_many_scenes",
+            Self::InvalidStructure => "scene_pack_invalid_structure",
+        })
+    }
+}
+
+impl std::error::Error for ScenePackError {}
+
+/// What to do with a pack scene that matches an existing custom scene.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SceneConflictResolution {
+    Skip,
+    Replace,
+    /// Imports under a fresh id, and a numbered name when the name clashes.
+    /// System scenes have a single prompt, so their conflicts are skipped.
+    KeepBoth,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SceneConflict {
+    None,
+    Id,
+    Name,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScenePackPreviewScene {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub conflict: SceneConflict,
+    pub existing_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScenePackPreviewOverride {
+    pub id: String,
+    /// The system scene already has a different edited prompt.
+    pub conflict: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScenePackPreview {
+    pub version: u64,
+    pub scenes: Vec<ScenePackPreviewScene>,
+    pub system_overrides: Vec<ScenePackPreviewOverride>,
+    pub skipped_invalid: usize,
+    pub errors: Vec<ImportRowError>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScenePackImportReport {
+    pub imported: usize,
+    pub replaced: usize,
+    pub renamed: usize,
+    pub skipped_conflicts: usize,
+    pub skipped_limit: usize,
+    pub skipped_invalid: usize,
+    pub errors: Vec<ImportRowError>,
+}
+
+/// Parses a scene pack. Bare scene lists and the unmarked `{ version, scenes }`
+/// files written by earlier releases are read as version 1 packs.
+pub fn parse_scene_pack(bytes: &[u8]) -> Result<ParsedScenePack, ScenePackError> {
+    if bytes.len() > MAX_IMPORT_BYTES {
+        return Err(ScenePackError::FileTooLarge);
+    }
+    let text = std::str::from_utf8(bytes).map_err(|_| ScenePackError::InvalidUtf8)?;
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let value: serde_json::Value =
+        serde_json::from_str(text).map_err(|_| ScenePackError::InvalidStructure)?;
+
+    let (version, scenes, overrides) = match &value {
+        serde_json::Value::Array(rows) => (SCENE_PACK_VERSION, Some(rows), None),
+        serde_json::Value::Object(object) => {
+            let version = object.get("version").and_then(serde_json::Value::as_u64);
+            let version = match object.get("format") {
+                Some(format) if format.as_str() == Some(SCENE_PACK_FORMAT) => {
+                    version.ok_or(ScenePackError::InvalidStructure)?
+                }
+                Some(_) => return Err(ScenePackError::InvalidStructure),
+                None => version.unwrap_or(SCENE_PACK_VERSION),
+            };
+            if version == 0 {
+                return Err(ScenePackError::InvalidStructure);
+            }
+            if version > SCENE_PACK_VERSION {
+                return Err(ScenePackError::UnsupportedVersion);
+            }
+            let overrides = optional_json_array(
+                object
+                    .get("systemSceneOverrides")
+                    .or_else(|| object.get("system_scene_overrides")),
+            )?;
+            (
+                version,
+                optional_json_array(object.get("scenes"))?,
+                overrides,
+            )
+        }
+        _ => return Err(ScenePackError::InvalidStructure),
+    };
+    if scenes.is_none() && overrides.is_none() {
+        return Err(ScenePackError::InvalidStructure);
+    }
+    if scenes.map_or(0, Vec::len) > CUSTOM_SCENES_MAX_COUNT
+        || overrides.map_or(0, Vec::len) > CUSTOM_SCENES_MAX_COUNT
+    {
+        return Err(ScenePackError::TooManyScenes);
+    }
+
+    let mut parsed = ParsedScenePack {
+        version,
+        scenes: Vec::new(),
+        system_overrides: Vec::new(),
+        skipped_invalid: 0,
+        errors: Vec::new(),
+    };
+    let mut row_number = 0;
+    let mut scene_ids = HashSet::new();
+    for row in scenes.into_iter().flatten() {
+        row_number += 1;
+        match validate_scene_row(row) {
+            Ok(scene) if !scene.id.is_empty() && !scene_ids.insert(scene.id.clone()) => {
+                push_row_error(&mut parsed, row_number, "scene_id_duplicate")
+            }
+            Ok(scene) => parsed.scenes.push(scene),
+            Err(code) => push_row_error(&mut parsed, row_number, code),
+        }
+    }
+    let mut override_ids = HashSet::new();
+    for row in overrides.into_iter().flatten() {
+        row_number += 1;
+        match validate_system_override_row(row) {
+            Ok(item) if !override_ids.insert(item.id.clone()) => {
+                push_row_error(&mut parsed, row_number, "system_scene_duplicate")
+            }
+            Ok(item) => parsed.system_overrides.push(item),
+            Err(code) => push_row_error(&mut parsed, row_number, code),
+        }
+    }
+    Ok(parsed)
+}
+
+fn optional_json_array(
+    value: Option<&serde_json::Value>,
+) -> Result<Option<&Vec<serde_json::Value>>, ScenePackError> {
+    match value {
+        None => Ok(None),
+        Some(value) => value
+            .as_array()
+            .map(Some)
+            .ok_or(ScenePackError::InvalidStructure),
+    }
+}
+
+fn validate_scene_row(row: &serde_json::Value) -> Result<ScenePackScene, &'static str> {
+    let entry = row.as_object().ok_or("scene_row_invalid")?;
+    Ok(ScenePackScene {
+        id: clean_text(
+            string_alias(entry, &["id"]),
+            SCENE_ID_MAX_CHARS,
+            None,
+            "scene_id_too_long",
+        )?,
+        name: clean_text(
+            string_alias(entry, &["name"]),
+            SCENE_NAME_MAX_CHARS,
+            Some("scene_name_empty"),
+            "scene_name_too_long",
+        )?,
+        description: clean_text(
+            string_alias(entry, &["description"]),
+            SCENE_DESCRIPTION_MAX_CHARS,
+            None,
+            "scene_description_too_long",
+        )?,
+        prompt_template: clean_text(
+            string_alias(entry, &["promptTemplate", "prompt_template"]),
+            SCENE_PROMPT_MAX_CHARS,
+            Some("scene_prompt_empty"),
+            "scene_prompt_too_long",
+        )?,
+    })
+}
+
+fn validate_system_override_row(
+    row: &serde_json::Value,
+) -> Result<ScenePackSystemOverride, &'static str> {
+    let entry = row.as_object().ok_or("system_scene_row_invalid")?;
+    let id = string_alias(entry, &["id"])
+        .map(str::trim)
+        .unwrap_or_default();
+    if system_scene_prompt(id).is_none() {
+        return Err("system_scene_unknown");
+    }
+    Ok(ScenePackSystemOverride {
+        id: id.to_string(),
+        prompt_template: clean_text(
+            string_alias(entry, &["promptTemplate", "prompt_template"]),
+            SCENE_PROMPT_MAX_CHARS,
+            Some("scene_prompt_empty"),
+            "scene_prompt_too_long",
+        )?,
+    })
+}
+
+/// Trims a field, rejecting it when it is too long or, if `empty_code` is
+/// given, missing.
+fn clean_text(
+    value: Option<&str>,
+    max_chars: usize,
+    empty_code: Option<&'static str>,
+    too_long_code: &'static str,
+) -> Result<String, &'static str> {
+    let value = value.unwrap_or_default().trim();
+    if value.is_empty() {
+        return match empty_code {
+            Some(code) => Err(code),
+            None => Ok(String::new()),
+        };
+    }
+    if value.contains('\0') {
+        return Err("invalid_null_character");
+    }
+    if value.chars().count() > max_chars {
+        return Err(too_long_code);
+    }
+    Ok(value.to_string())
+}
+
+fn push_row_error(parsed: &mut ParsedScenePack, row: usize, code: &str) {
+    parsed.skipped_invalid += 1;
+    parsed.errors.push(ImportRowError {
+        row,
+        code: code.to_string(),
+    });
+}
+
+/// Existing custom scene a pack scene collides with: the same id first, then
+/// the same name ignoring case.
+fn scene_conflict(
+    existing: &[CustomScene],
+    scene: &ScenePackScene,
+) -> Option<(SceneConflict, usize)> {
+    if !scene.id.is_empty() {
+        if let Some(index) = existing.iter().position(|item| item.id == scene.id) {
+            return Some((SceneConflict::Id, index));
+        }
+    }
+    let name = scene.name.to_lowercase();
+    existing
+        .iter()
+        .position(|item| item.name.trim().to_lowercase() == name)
+        .map(|index| (SceneConflict::Name, index))
+}
+
+pub fn preview_scene_pack(config: &AppConfig, parsed: &ParsedScenePack) -> ScenePackPreview {
+    ScenePackPreview {
+        version: parsed.version,
+        scenes: parsed
+            .scenes
+            .iter()
+            .map(|scene| {
+                let conflict = scene_conflict(&config.custom_scenes, scene);
+                ScenePackPreviewScene {
+                    id: scene.id.clone(),
+                    name: scene.name.clone(),
+                    description: scene.description.clone(),
+                    conflict: conflict.map_or(SceneConflict::None, |(kind, _)| kind),
+                    existing_name: conflict
+                        .map(|(_, index)| config.custom_scenes[index].name.clone()),
+                }
+            })
+            .collect(),
+        system_overrides: parsed
+            .system_overrides
+            .iter()
+            .map(|item| ScenePackPreviewOverride {
+                id: item.id.clone(),
+                conflict: config.system_scene_overrides.iter().any(|existing| {
+                    existing.id == item.id && existing.prompt_template != item.prompt_template
+                }),
+            })
+            .collect(),
+        skipped_invalid: parsed.skipped_invalid,
+        errors: parsed.errors.clone(),
+    }
+}
+
+/// Merges a parsed pack into `config`. `create_id` supplies ids for scenes
+/// that arrive without one or are kept beside a scene with the same id.
+pub fn apply_scene_pack(
+    config: &mut AppConfig,
+    parsed: ParsedScenePack,
+    resolution: SceneConflictResolution,
+    now: &str,
+    mut create_id: impl FnMut() -> String,
+) -> ScenePackImportReport {
+    let mut report = ScenePackImportReport {
+        skipped_invalid: parsed.skipped_invalid,
+        errors: parsed.errors,
+        ..Default::default()
+    };
+
+    for scene in parsed.scenes {
+        let conflict = scene_conflict(&config.custom_scenes, &scene);
+        match (conflict, resolution) {
+            (Some(_), SceneConflictResolution::Skip) => report.skipped_conflicts += 1,
+            (Some((_, index)), SceneConflictResolution::Replace) => {
+                let existing = &mut config.custom_scenes[index];
+                existing.name = scene.name;
+                existing.description = scene.description;
+                existing.prompt_template = scene.prompt_template;
+                existing.updated_at = now.to_string();
+                report.replaced += 1;
+            }
+            _ if config.custom_scenes.len() >= CUSTOM_SCENES_MAX_COUNT => {
+                report.skipped_limit += 1;
+            }
+            _ => {
+                let mut id = scene.id;
+                while id.is_empty() || config.custom_scenes.iter().any(|item| item.id == id) {
+                    id = create_id();
+                }
+                let name = unique_scene_name(&config.custom_scenes, &scene.name);
+                if conflict.is_some() {
+                    report.renamed += 1;
+                }
+                config.custom_scenes.push(CustomScene {
+                    id,
+                    name,
+                    description: scene.description,
+                    prompt_template: scene.prompt_template,
+                    created_at: now.to_string(),
+                    updated_at: now.to_string(),
+                });
+                report.imported += 1;
+            }
+        }
+    }
+
+    for item in parsed.system_overrides {
+        let existing = config
+            .system_scene_overrides
+            .iter_mut()
+            .find(|existing| existing.id == item.id);
+        match (existing, resolution) {
+            (None, _) => {
+                config.system_scene_overrides.push(SystemSceneOverride {
+                    id: item.id,
+                    prompt_template: item.prompt_template,
+                });
+                report.imported += 1;
+            }
+            (Some(existing), _) if existing.prompt_template == item.prompt_template => {}
+            (Some(existing), SceneConflictResolution::Replace) => {
+                existing.prompt_template = item.prompt_template;
+                report.replaced += 1;
+            }
+            (Some(_), _) => report.skipped_conflicts += 1,
+        }
+    }
+    report
+}
+
+/// `name`, or `name (2)`, `name (3)`… when a custom scene already uses it.
+fn unique_scene_name(existing: &[CustomScene], name: &str) -> String {
+    let taken = |candidate: &str| {
+        let candidate = candidate.to_lowercase();
+        existing
+            .iter()
+            .any(|item| item.name.trim().to_lowercase() == candidate)
+    };
+    if !taken(name) {
+        return name.to_string();
+    }
+    (2..)
+        .map(|number| {
+            let suffix = format!(" ({number})");
+            let base: String = name
+                .chars()
+                .take(SCENE_NAME_MAX_CHARS - suffix.chars().count())
+                .collect();
+            format!("{}{suffix}", base.trim_end())
+        })
+        .find(|candidate| !taken(candidate))
+        .unwrap_or_else(|| name.to_string())
+}
+
+/// Writes the selected custom scenes and edited system scenes as a pack.
+pub fn export_scene_pack(
+    config: &AppConfig,
+    scene_ids: &[String],
+    exported_at: &str,
+) -> Result<String, ScenePackError> {
+    let selected = scene_ids.iter().map(String::as_str).collect::<HashSet<_>>();
+    let scenes = config
+        .custom_scenes
+        .iter()
+        .filter(|scene| selected.contains(scene.id.as_str()))
+        .map(|scene| {
+            serde_json::json!({
+                "id": scene.id,
+                "name": scene.name,
+                "description": scene.description,
+                "promptTemplate": scene.prompt_template,
+            })
+        })
+        .collect::<Vec<_>>();
+    let overrides = config
+        .system_scene_overrides
+        .iter()
+        .filter(|item| selected.contains(item.id.as_str()))
+        .map(|item| {
+            serde_json::json!({
+                "id": item.id,
+                "promptTemplate": item.prompt_template,
+            })
+        })
+        .collect::<Vec<_>>();
+    if scenes.is_empty() && overrides.is_empty() {
+        return Err(ScenePackError::InvalidStructure);
+    }
+    serde_json::to_string_pretty(&serde_json::json!({
+        "format": SCENE_PACK_FORMAT,
+        "version": SCENE_PACK_VERSION,
+        "exportedAt": exported_at,
+        "scenes": scenes,
+        "systemSceneOverrides": overrides,
+    }))
+    .map_err(|_| ScenePackError::InvalidStructure)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: &str = "2026-10-18T09:00:00.000Z";
+
+    fn custom_scene(id: &str, name: &str) -> CustomScene {
+        CustomScene {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: String::new(),
+            prompt_template: format!("{name} prompt."),
+            created_at: "2026-07-01T00:00:00.000Z".to_string(),
+            updated_at: "2026-07-01T00:00:00.000Z".to_string(),
+        }
+    }
+
+    fn config_with(scenes: Vec<CustomScene>) -> AppConfig {
+        AppConfig {
+            custom_scenes: scenes,
+            ..AppConfig::default()
+        }
+    }
+
+    fn ids() -> impl FnMut() -> String {
+        let mut next = 0;
+        move || {
+            next += 1;
+            format!("custom_generated_{next}")
+        }
+    }
+
+    #[test]
+    fn scene_pack_rejects_oversized_foreign_and_future_files() {
+        assert_eq!(
+            parse_scene_pack(&vec![b' '; MAX_IMPORT_BYTES + 1]),
+            Err(ScenePackError::FileTooLarge)
+        );
+        assert_eq!(
+            parse_scene_pack(&[0xff, 0xfe]),
+            Err(ScenePackError::InvalidUtf8)
+        );
+        assert_eq!(
+            parse_scene_pack(br#"{"format":"opentypeless_dictionary","version":1,"scenes":[]}"#),
+            Err(ScenePackError::InvalidStructure)
+        );
+        assert_eq!(
+            parse_scene_pack(br#"{"format":"opentypeless_scene_pack","version":2,"scenes":[]}"#),
+            Err(ScenePackError::UnsupportedVersion)
+        );
+        let too_many = serde_json::json!({
+            "scenes": vec![serde_json::json!({"name": "A", "promptTemplate": "B"}); CUSTOM_SCENES_MAX_COUNT + 1],
+        });
+        assert_eq!(
+            parse_scene_pack(too_many.to_string().as_bytes()),
+            Err(ScenePackError::TooManyScenes)
+        );
+    }
+
+    #[test]
+    fn scene_pack_reads_legacy_exports_and_reports_invalid_rows() {
+        let input = serde_json::json!({
+            "version": 1,
+            "exportedAt": "2026-07-06T00:00:00.000Z",
+            "scenes": [
+                {"id": "custom_a", "name": " Notes ", "promptTemplate": " Use bullets. ", "source": "custom"},
+                {"id": "custom_a", "name": "Again", "prompt_template": "Twice."},
+                {"name": "   ", "promptTemplate": "No name."},
+                {"name": "Long", "promptTemplate": "x".repeat(SCENE_PROMPT_MAX_CHARS + 1)},
+            ],
+            "systemSceneOverrides": [
+                {"id": "system_email", "promptTemplate": "Warm emails."},
+                {"id": "system_unknown", "promptTemplate": "Nope."},
+            ],
+        });
+        let parsed = parse_scene_pack(input.to_string().as_bytes()).unwrap();
+
+        assert_eq!(parsed.version, 1);
+        assert_eq!(
+            parsed.scenes,
+            vec![ScenePackScene {
+                id: "custom_a".to_string(),
+                name: "Notes".to_string(),
+                description: String::new(),
+                prompt_template: "Use bullets.".to_string(),
+            }]
+        );
+        assert_eq!(parsed.system_overrides.len(), 1);
+        assert_eq!(parsed.skipped_invalid, 4);
+        assert_eq!(
+            parsed
+                .errors
+                .iter()
+                .map(|error| (error.row, error.code.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (2, "scene_id_duplicate"),
+                (3, "scene_name_empty"),
+                (4, "scene_prompt_too_long"),
+                (6, "system_scene_unknown"),
+            ]
+        );
+
+        let bare = parse_scene_pack(br#"[{"name":"Bare","promptTemplate":"Ok."}]"#).unwrap();
+        assert_eq!(bare.scenes[0].id, "");
+    }
+
+    #[test]
+    fn scene_pack_preview_flags_id_and_name_conflicts() {
+        let config = config_with(vec![
+            custom_scene("custom_support", "Support Reply"),
+            custom_scene("custom_notes", "Notes"),
+        ]);
+        let parsed = parse_scene_pack(
+            br#"{"scenes":[
+                {"id":"custom_support","name":"Support v2","promptTemplate":"A."},
+                {"id":"custom_other","name":"notes","promptTemplate":"B."},
+                {"name":"Fresh","promptTemplate":"C."}
+            ]}"#,
+        )
+        .unwrap();
+
+        let preview = preview_scene_pack(&config, &parsed);
+        assert_eq!(
+            preview
+                .scenes
+                .iter()
+                .map(|scene| (scene.conflict, scene.existing_name.as_deref()))
+                .collect::<Vec<_>>(),
+            vec![
+                (SceneConflict::Id, Some("Support Reply")),
+                (SceneConflict::Name, Some("Notes")),
+                (SceneConflict::None, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn scene_pack_conflicts_are_skipped_replaced_or_kept_beside_existing_scenes() {
+        let pack = br#"{"scenes":[
+            {"id":"custom_support","name":"Support Reply","promptTemplate":"New support."},
+            {"name":"Fresh","promptTemplate":"Fresh prompt."}
+        ]}"#;
+        let existing = || config_with(vec![custom_scene("custom_support", "Support Reply")]);
+
+        let mut config = existing();
+        let report = apply_scene_pack(
+            &mut config,
+            parse_scene_pack(pack).unwrap(),
+            SceneConflictResolution::Skip,
+            NOW,
+            ids(),
+        );
+        assert_eq!((report.imported, report.skipped_conflicts), (1, 1));
+        assert_eq!(
+            config.custom_scenes[0].prompt_template,
+            "Support Reply prompt."
+        );
+        assert_eq!(config.custom_scenes[1].id, "custom_generated_1");
+
+        let mut config = existing();
+        let report = apply_scene_pack(
+            &mut config,
+            parse_scene_pack(pack).unwrap(),
+            SceneConflictResolution::Replace,
+            NOW,
+            ids(),
+        );
+        assert_eq!((report.imported, report.replaced), (1, 1));
+        assert_eq!(config.custom_scenes[0].prompt_template, "New support.");
+        assert_eq!(
+            config.custom_scenes[0].created_at,
+            "2026-07-01T00:00:00.000Z"
+        );
+        assert_eq!(config.custom_scenes[0].updated_at, NOW);
+
+        let mut config = existing();
+        let report = apply_scene_pack(
+            &mut config,
+            parse_scene_pack(pack).unwrap(),
+            SceneConflictResolution::KeepBoth,
+            NOW,
+            ids(),
+        );
+        assert_eq!((report.imported, report.renamed), (2, 1));
+        assert_eq!(config.custom_scenes[1].id, "custom_generated_1");
+        assert_eq!(config.custom_scenes[1].name, "Support Reply (2)");
+        assert_eq!(config.custom_scenes[1].prompt_template, "New support.");
+    }
+
+    #[test]
+    fn scene_pack_exports_only_the_selected_scenes() {
+        let mut config = config_with(vec![
+            custom_scene("custom_support", "Support Reply"),
+            custom_scene("custom_notes", "Notes"),
+        ]);
+        config.system_scene_overrides.push(SystemSceneOverride {
+            id: "system_email".to_string(),
+            prompt_template: "Warm emails.".to_string(),
+        });
+
+        let exported = export_scene_pack(
+            &config,
+            &["custom_notes".to_string(), "system_email".to_string()],
+            NOW,
+        )
+        .unwrap();
+        let parsed = parse_scene_pack(exported.as_bytes()).unwrap();
+        assert_eq!(parsed.scenes.len(), 1);
+        assert_eq!(parsed.scenes[0].name, "Notes");
+        assert_eq!(parsed.system_overrides[0].prompt_template, "Warm emails.");
+        assert!(exported.contains(r#""format": "opentypeless_scene_pack""#));
+
+        assert_eq!(
+            export_scene_pack(&config, &[], NOW),
+            Err(ScenePackError::InvalidStructure)
+        );
+    }
+}
@@ -0,0 +1,404 @@
+use crate::voice_intent::CommandLocale;
+
+/// How a spoken mark joins the words around it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Spacing {
+    /// Sentence punctuation: attaches to the previous word and replaces any
+    /// punctuation the recognizer already put around the spoken word.
+    Punctuation,
+    /// Closing quote or bracket: attaches to the previous word.
+    Close,
+    /// Opening quote or bracket: attaches to the next word.
+    Open,
+    /// Line and paragraph breaks: no spaces on either side.
+    Break,
+}
+
+struct SpokenMark {
+    phrase: &'static str,
+    symbol: &'static str,
+    spacing: Spacing,
+}
+
+const fn mark(phrase: &'static str, symbol: &'static str, spacing: Spacing) -> SpokenMark {
+    SpokenMark {
+        phrase,
+        symbol,
+        spacing,
+    }
+}
+
+const EN_MARKS: &[SpokenMark] = &[
+    mark("comma", ",", Spacing::Punctuation),
+    mark("period", ".", Spacing::Punctuation),
+    mark("full stop", ".", Spacing::Punctuation),
+    mark("question mark", "?", Spacing::Punctuation),
+    mark("exclamation mark", "!", Spacing::Punctuation),
+    mark("exclamation point", "!", Spacing::Punctuation),
+    mark("colon", ":", Spacing::Punctuation),
+    mark("semicolon", ";", Spacing::Punctuation),
+    mark("open quote", "\"", Spacing::Open),
+    mark("close quote", "\"", Spacing::Close),
+    mark("end quote", "\"", Spacing::Close),
+    mark("unquote", "\"", Spacing::Close),
+    mark("open paren", "(", Spacing::Open),
+    mark("open parenthesis", "(", Spacing::Open),
+    mark("close paren", ")", Spacing::Close),
+    mark("close parenthesis", ")", Spacing::Close),
+    mark("new line", "\n", Spacing::Break),
+    mark("newline", "\n", Spacing::Break),
+    mark("new paragraph", "\n\n", Spacing::Break),
+];
+
+const ZH_HANS_MARKS: &[SpokenMark] = &[
+    mark("逗号", "，", Spacing::Punctuation),
+    mark("句号", "。", Spacing::Punctuation),
+    mark("问号", "？", Spacing::Punctuation),
+    mark("感叹号", "！", Spacing::Punctuation),
+    mark("冒号", "：", Spacing::Punctuation),
+    mark("分号", "；", Spacing::Punctuation),
+    mark("顿号", "、", Spacing::Punctuation),
+    mark("左引号", "“", Spacing::Open),
+    mark("右引号", "”", Spacing::Close),
+    mark("左括号", "（", Spacing::Open),
+    mark("右括号", "）", Spacing::Close),
+    mark("换行", "\n", Spacing::Break),
+    mark("新段落", "\n\n", Spacing::Break),
+    mark("另起一段", "\n\n", Spacing::Break),
+];
+
+const ZH_HANT_MARKS: &[SpokenMark] = &[
+    mark("逗號", "，", Spacing::Punctuation),
+    mark("句號", "。", Spacing::Punctuation),
+    mark("問號", "？", Spacing::Punctuation),
+    mark("驚嘆號", "！", Spacing::Punctuation),
+    mark("感嘆號", "！", Spacing::Punctuation),
+    mark("冒號", "：", Spacing::Punctuation),
+    mark("分號", "；", Spacing::Punctuation),
+    mark("頓號", "、", Spacing::Punctuation),
+    mark("左引號", "「", Spacing::Open),
+    mark("右引號", "」", Spacing::Close),
+    mark("左括號", "（", Spacing::Open),
+    mark("右括號", "）", Spacing::Close),
+    mark("換行", "\n", Spacing::Break),
+    mark("新段落", "\n\n", Spacing::Break),
+    mark("另起一段", "\n\n", Spacing::Break),
+];
+
+/// Saying the escape word before a mark keeps the mark's words as text.
+const EN_ESCAPE: &str = "literal";
+const ZH_ESCAPE: &str = "字面";
+
+/// Han text right after a mark that turns it into an ordinary word, e.g.
+/// 换行业 ("switch industries") or 换行符 ("the newline character").
+const ZH_BLOCKED_FOLLOWERS: &[(&str, &str)] = &[
+    ("换行", "业符"),
+    ("換行", "業符"),
+    ("句号", "码"),
+    ("句號", "碼"),
+];
+
+/// Punctuation a recognizer may already have put next to a spoken mark.
+const RECOGNIZER_PUNCTUATION: &str = ",.;:!?，。；：！？、";
+
+/// Picks the spoken-formatting locale from the STT language, or from the
+/// transcript's script when the language is automatic.
+pub fn formatting_locale(stt_language: &str, text: &str) -> Option<CommandLocale> {
+    match stt_language.trim().to_ascii_lowercase().as_str() {
+        "en" | "en-us" | "en-gb" | "english" => Some(CommandLocale::En),
+        "zh" | "zh-cn" | "zh-hans" | "zh_hans" => Some(CommandLocale::ZhHans),
+        "zh-tw" | "zh-hk" | "zh-hant" | "zh_hant" => Some(CommandLocale::ZhHant),
+        "multi" | "auto" | "automatic" => detect_locale(text),
+        _ => None,
+    }
+}
+
+fn detect_locale(text: &str) -> Option<CommandLocale> {
+    if text
+        .chars()
+        .any(|character| is_kana(character) || is_hangul(character))
+    {
+        return None;
+    }
+    if text.chars().any(is_han) {
+        // Only the traditional marks use these characters.
+        let traditional = text.chars().any(|character| "號換嘆驚".contains(character));
+        return Some(if traditional {
+            CommandLocale::ZhHant
+        } else {
+            CommandLocale::ZhHans
+        });
+    }
+    text.chars()
+        .any(|character| character.is_ascii_alphabetic())
+        .then_some(CommandLocale::En)
+}
+
+/// Turns spoken punctuation and line breaks ("comma", "new line", "句号")
+/// into symbols in a raw transcript. Runs locally, without a language model,
+/// so unpolished dictation still comes out formatted.
+///
+/// English marks only match whole words; Chinese marks match anywhere. The
+/// escape word ("literal comma", "字面逗号") keeps a mark as text. Locales
+/// without marks are returned unchanged.
+pub fn apply_spoken_formatting(text: &str, locale: CommandLocale) -> String {
+    let (marks, escape, word_bounded) = match locale {
+        CommandLocale::En => (EN_MARKS, EN_ESCAPE, true),
+        CommandLocale::ZhHans => (ZH_HANS_MARKS, ZH_ESCAPE, false),
+        CommandLocale::ZhHant => (ZH_HANT_MARKS, ZH_ESCAPE, false),
+        _ => return text.to_string(),
+    };
+    let mut marks = marks
+        .iter()
+        .map(|mark| (mark, mark.phrase.chars().collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+    marks.sort_by_key(|(_, phrase)| std::cmp::Reverse(phrase.len()));
+    let escape = escape.chars().collect::<Vec<_>>();
+
+    let chars = text.chars().collect::<Vec<_>>();
+    let folded = chars
+        .iter()
+        .map(|character| character.to_lowercase().next().unwrap_or(*character))
+        .collect::<Vec<_>>();
+    let find_mark = |start: usize| {
+        marks.iter().find_map(|(mark, phrase)| {
+            match_phrase(phrase, &chars, &folded, start, word_bounded)
+                .filter(|end| !blocked_follower(mark.phrase, &chars[*end..]))
+                .map(|end| (*mark, end))
+        })
+    };
+
+    let mut output = String::with_capacity(text.len());
+    let mut capitalize_next = false;
+    let mut index = 0;
+    while index < chars.len() {
+        if let Some(escape_end) = match_phrase(&escape, &chars, &folded, index, word_bounded) {
+            let mark_start = if word_bounded {
+                skip_whitespace(&chars, escape_end)
+            } else {
+                escape_end
+            };
+            if mark_start > escape_end || !word_bounded {
+                if let Some((_, end)) = find_mark(mark_start) {
+                    output.extend(&chars[mark_start..end]);
+                    capitalize_next = false;
+                    index = end;
+                    continue;
+                }
+            }
+        }
+
+        let Some((mark, end)) = find_mark(index) else {
+            let character = chars[index];
+            if capitalize_next && character.is_alphanumeric() {
+                output.extend(character.to_uppercase());
+                capitalize_next = false;
+            } else {
+                output.push(character);
+            }
+            index += 1;
+            continue;
+        };
+
+        index = end;
+        match mark.spacing {
+            Spacing::Punctuation => {
+                trim_end_matching(&mut output, |character| {
+                    character.is_whitespace() || RECOGNIZER_PUNCTUATION.contains(character)
+                });
+                output.push_str(mark.symbol);
+                index = skip_recognizer_punctuation(&chars, index);
+            }
+            Spacing::Close => {
+                trim_end_matching(&mut output, char::is_whitespace);
+                output.push_str(mark.symbol);
+            }
+            Spacing::Open => {
+                output.push_str(mark.symbol);
+                index = skip_whitespace(&chars, index);
+            }
+            Spacing::Break => {
+                trim_end_matching(&mut output, |character| {
+                    character.is_whitespace() && character != '\n'
+                });
+                output.push_str(mark.symbol);
+                index = skip_whitespace(&chars, skip_recognizer_punctuation(&chars, index));
+            }
+        }
+        if !word_bounded {
+            // Chinese puts no spaces around full-width punctuation.
+            index = skip_whitespace(&chars, index);
+        } else {
+            capitalize_next = matches!(mark.symbol, "." | "?" | "!")
+                || (mark.spacing == Spacing::Break)
+                || (matches!(mark.spacing, Spacing::Open | Spacing::Close) && capitalize_next);
+        }
+    }
+
+    output
+}
+
+/// Returns the exclusive end index when `phrase` matches at `start`. A space
+/// in the phrase matches any run of whitespace in the text.
+fn match_phrase(
+    phrase: &[char],
+    chars: &[char],
+    folded: &[char],
+    start: usize,
+    word_bounded: bool,
+) -> Option<usize> {
+    if word_bounded && start > 0 && is_word_char(chars[start - 1]) {
+        return None;
+    }
+    let mut index = start;
+    for expected in phrase {
+        if *expected == ' ' {
+            if !chars.get(index).is_some_and(|value| value.is_whitespace()) {
+                return None;
+            }
+            index = skip_whitespace(chars, index);
+            continue;
+        }
+        if folded.get(index) != Some(expected) {
+            return None;
+        }
+        index += 1;
+    }
+    if word_bounded && chars.get(index).copied().is_some_and(is_word_char) {
+        return None;
+    }
+    Some(index)
+}
+
+fn blocked_follower(phrase: &str, rest: &[char]) -> bool {
+    rest.first().is_some_and(|next| {
+        ZH_BLOCKED_FOLLOWERS
+            .iter()
+            .any(|(blocked, followers)| *blocked == phrase && followers.contains(*next))
+    })
+}
+
+fn skip_whitespace(chars: &[char], mut index: usize) -> usize {
+    while chars.get(index).is_some_and(|value| value.is_whitespace()) {
+        index += 1;
+    }
+    index
+}
+
+fn skip_recognizer_punctuation(chars: &[char], mut index: usize) -> usize {
+    while chars
+        .get(index)
+        .is_some_and(|value| RECOGNIZER_PUNCTUATION.contains(*value))
+    {
+        index += 1;
+    }
+    index
+}
+
+fn trim_end_matching(output: &mut String, matches: impl Fn(char) -> bool) {
+    while output.chars().next_back().is_some_and(&matches) {
+        output.pop();
+    }
+}
+
+fn is_word_char(character: char) -> bool {
+    character.is_alphanumeric() || character == '_' || character == '\''
+}
+
+pub(crate) fn is_han(character: char) -> bool {
+    matches!(character as u32, 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF)
+}
+
+pub(crate) fn is_kana(character: char) -> bool {
+    matches!(character as u32, 0x3040..=0x30FF | 0x31F0..=0x31FF)
+}
+
+pub(crate) fn is_hangul(character: char) -> bool {
+    matches!(character as u32, 0x1100..=0x11FF | 0x3130..=0x318F | 0xAC00..=0xD7AF)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct CorpusCase {
+        id: String,
+        locale: CommandLocale,
+        input: String,
+        expected: String,
+    }
+
+    fn run_corpus(name: &str, source: &str) {
+        let cases: Vec<CorpusCase> = serde_json::from_str(source).unwrap();
+        assert!(cases.len() >= 30, "{name} has only {} cases", cases.len());
+        for case in &cases {
+            assert_eq!(
+                apply_spoken_formatting(&case.input, case.locale),
+                case.expected,
+                "case {}",
+                case.id
+            );
+        }
+    }
+
+    #[test]
+    fn spoken_formatting_corpus_en() {
+        run_corpus(
+            "en",
+            include_str!("../tests/fixtures/spoken_formatting_en.json"),
+        );
+    }
+
+    #[test]
+    fn spoken_formatting_corpus_zh_hans() {
+        run_corpus(
+            "zh_hans",
+            include_str!("../tests/fixtures/spoken_formatting_zh_hans.json"),
+        );
+    }
+
+    #[test]
+    fn spoken_formatting_corpus_zh_hant() {
+        run_corpus(
+            "zh_hant",
+            include_str!("../tests/fixtures/spoken_formatting_zh_hant.json"),
+        );
+    }
+
+    #[test]
+    fn formatting_locale_follows_stt_language_then_script() {
+        assert_eq!(
+            formatting_locale("en-US", "你好逗号"),
+            Some(CommandLocale::En)
+        );
+        assert_eq!(
+            formatting_locale("zh-TW", "hello"),
+            Some(CommandLocale::ZhHant)
+        );
+        assert_eq!(formatting_locale("ja", "hello comma"), None);
+        assert_eq!(
+            formatting_locale("multi", "hello comma"),
+            Some(CommandLocale::En)
+        );
+        assert_eq!(
+            formatting_locale("multi", "你好逗号世界"),
+            Some(CommandLocale::ZhHans)
+        );
+        assert_eq!(
+            formatting_locale("multi", "你好逗號世界"),
+            Some(CommandLocale::ZhHant)
+        );
+        assert_eq!(formatting_locale("multi", "こんにちは句号"), None);
+        assert_eq!(formatting_locale("multi", "123"), None);
+    }
+
+    #[test]
+    fn unsupported_locales_are_returned_unchanged() {
+        assert_eq!(
+            apply_spoken_formatting("bonjour virgule", CommandLocale::Fr),
+            "bonjour virgule"
+        );
+    }
+}
@@ -1,8 +1,11 @@
 use crate::app_detector::registry::AppRegistry;
-use crate::app_detector::types::{BrowserAccessStatus, ContextFamily, ContextProfile};
+use crate::app_detector::types::{
+    AppOutputPolicy, BrowserAccessStatus, ContextFamily, ContextProfile,
+};
+use crate::corrections::AppliedCorrection;
 use crate::credentials::{migrate_legacy_config_secrets, SystemCredentialVault};
 use anyhow::Result;
-use rusqlite::Connection;
+use rusqlite::{Connection, OptionalExtension};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::path::PathBuf;
@@ -10,11 +13,16 @@ use std::sync::Mutex;
 use tauri_plugin_store::StoreExt;
 use unicode_normalization::UnicodeNormalization;
 
-const CUSTOM_SCENES_MAX_COUNT: usize = 100;
-const SCENE_ID_MAX_CHARS: usize = 120;
+mod scene_rules;
+
+pub(crate) use scene_rules::speech_language;
+pub use scene_rules::{SceneRule, SceneRuleConditions, SceneRuleInput, SceneTimeWindow};
+
+pub(crate) const CUSTOM_SCENES_MAX_COUNT: usize = 100;
+pub(crate) const SCENE_ID_MAX_CHARS: usize = 120;
 const SCENE_SOURCE_MAX_CHARS: usize = 24;
-const SCENE_NAME_MAX_CHARS: usize = 80;
-const SCENE_DESCRIPTION_MAX_CHARS: usize = 240;
+pub(crate) const SCENE_NAME_MAX_CHARS: usize = 80;
+pub(crate) const SCENE_DESCRIPTION_MAX_CHARS: usize = 240;
 pub(crate) const SCENE_PROMPT_MAX_CHARS: usize = 4000;
 pub const DEFAULT_HISTORY_MAX_ENTRIES: u32 = 5000;
 pub const MAX_BACKUP_DICTIONARY_ENTRIES: usize = 10_000;
@@ -65,6 +73,16 @@ pub struct SystemSceneOverride {
     pub prompt_template: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct LlmFallbackEntry {
+    pub provider: String,
+    pub model: String,
+    pub base_url: String,
+    /// Per-attempt timeout; 0 uses the default.
+    pub timeout_ms: u32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(default)]
 pub struct ShortcutBinding {
@@ -337,12 +355,23 @@ pub struct AppConfig {
     pub stt_custom_base_url: String,
     pub stt_custom_model: String,
     pub stt_volcengine_resource_id: String,
+    /// GGML model file used by the offline Whisper provider.
+    pub stt_local_model_path: String,
+    /// Buffered STT providers tried in order when the primary fails.
+    pub stt_fallback_providers: Vec<String>,
     pub llm_provider: String,
     pub llm_api_key: String,
     pub llm_model: String,
     pub llm_base_url: String,
+    /// Per-attempt timeout for the primary LLM provider; 0 uses the default.
+    pub llm_timeout_ms: u32,
+    /// LLM providers tried in order when the primary keeps failing.
+    pub llm_fallback_chain: Vec<LlmFallbackEntry>,
     pub polish_enabled: bool,
     pub context_adaptation_enabled: bool,
+    /// Turn spoken punctuation ("comma", "new line") into symbols when
+    /// polish is off.
+    pub spoken_formatting_enabled: bool,
     pub voice_routing_flags: crate::voice_intent::VoiceRoutingFlags,
     pub polish_style: String,
     pub polish_custom_prompt: String,
@@ -351,6 +380,8 @@ pub struct AppConfig {
     pub system_scene_overrides: Vec<SystemSceneOverride>,
     pub active_scene: Option<ActiveScene>,
     pub family_scene_assignments: Vec<FamilySceneAssignment>,
+    /// Conditional automatic scenes, tried before mapping and family scenes.
+    pub scene_rules: Vec<SceneRule>,
     pub translate_enabled: bool,
     pub target_lang: String,
     pub translation: TranslationConfig,
@@ -365,6 +396,14 @@ pub struct AppConfig {
     pub windows_sendinput_newline_mode: String,
     pub streaming_insert_enabled: bool,
     pub selected_text_enabled: bool,
+    /// Output token budget for Ask answers.
+    pub ask_answer_token_limit: u32,
+    /// Ground Ask answers in passages from `knowledge_base_folder`.
+    pub knowledge_base_enabled: bool,
+    pub knowledge_base_folder: String,
+    /// OpenAI-compatible embeddings endpoint; empty keeps retrieval lexical.
+    pub knowledge_embedding_base_url: String,
+    pub knowledge_embedding_model: String,
     pub theme: String,
     pub auto_start: bool,
     pub close_to_tray: bool,
@@ -372,6 +411,8 @@ pub struct AppConfig {
     pub recording_limit_mode: crate::stt::capabilities::RecordingLimitMode,
     pub custom_recording_limit_seconds: u32,
     pub max_recording_seconds: u32,
+    /// Toggle-mode recordings stop after this much silence following speech; 0 disables.
+    pub auto_stop_silence_ms: u32,
     pub managed_stt_capability_state: Option<crate::stt::capabilities::ManagedSttCapabilityState>,
     pub history_enabled: bool,
     pub history_retention_days: u32,
@@ -392,12 +433,17 @@ impl Default for AppConfig {
             stt_custom_model: crate::stt::config::DEFAULT_CUSTOM_WHISPER_MODEL.to_string(),
             stt_volcengine_resource_id: crate::stt::volcengine::VOLCENGINE_SEEDASR_RESOURCE_ID
                 .to_string(),
+            stt_local_model_path: String::new(),
+            stt_fallback_providers: Vec::new(),
             llm_provider: "openrouter".to_string(),
             llm_api_key: String::new(),
             llm_model: "google/gemini-2.5-flash".to_string(),
             llm_base_url: "https://openrouter.ai/api/v1".to_string(),
+            llm_timeout_ms: crate::llm::failover::DEFAULT_LLM_TIMEOUT_MS,
+            llm_fallback_chain: Vec::new(),
             polish_enabled: true,
             context_adaptation_enabled: true,
+            spoken_formatting_enabled: false,
             voice_routing_flags: crate::voice_intent::VoiceRoutingFlags::default(),
             polish_style: "clean".to_string(),
             polish_custom_prompt: String::new(),
@@ -406,6 +452,7 @@ impl Default for AppConfig {
             system_scene_overrides: Vec::new(),
             active_scene: None,
             family_scene_assignments: Vec::new(),
+            scene_rules: Vec::new(),
             translate_enabled: false,
             target_lang: "en".to_string(),
             translation: TranslationConfig::default(),
@@ -420,6 +467,11 @@ impl Default for AppConfig {
             windows_sendinput_newline_mode: "enter".to_string(),
             streaming_insert_enabled: false,
             selected_text_enabled: false,
+            ask_answer_token_limit: crate::commands::ask::ASK_OUTPUT_TOKEN_LIMIT,
+            knowledge_base_enabled: false,
+            knowledge_base_folder: String::new(),
+            knowledge_embedding_base_url: String::new(),
+            knowledge_embedding_model: String::new(),
             theme: "system".to_string(),
             auto_start: true,
             close_to_tray: true,
@@ -427,6 +479,7 @@ impl Default for AppConfig {
             recording_limit_mode: crate::stt::capabilities::RecordingLimitMode::Auto,
             custom_recording_limit_seconds: 600,
             max_recording_seconds: 30,
+            auto_stop_silence_ms: 0,
             managed_stt_capability_state: None,
             history_enabled: true,
             history_retention_days: 0,
@@ -565,6 +618,9 @@ impl AppConfig {
             &self.custom_scenes,
             &self.system_scene_overrides,
         );
+        scene_rules::sanitize_scene_rules(&mut self.scene_rules, |scene_id| {
+            scene_id_exists(scene_id, &self.custom_scenes, &self.system_scene_overrides)
+        });
         self.translation.normalize(&self.target_lang);
         self.target_lang = self.translation.active_target.clone();
         self.normalize_insertion_strategy();
@@ -572,14 +628,32 @@ impl AppConfig {
         self.normalize_windows_sendinput_newline_mode();
         self.normalize_hotkey_settings();
         self.normalize_history_settings();
+        self.normalize_ask_settings();
         self.recompute_recording_limit_mirror();
     }
 
+    /// Overrides the output settings with the focused app's policy.
+    pub fn apply_output_policy(&mut self, policy: &AppOutputPolicy) {
+        if !policy.is_valid() {
+            return;
+        }
+        if let Some(strategy) = &policy.insertion_strategy {
+            self.insertion_strategy = strategy.clone();
+            self.normalize_insertion_strategy();
+        }
+        if let Some(shortcut) = &policy.paste_shortcut {
+            self.paste_shortcut = shortcut.clone();
+        }
+        if let Some(mode) = &policy.newline_mode {
+            self.windows_sendinput_newline_mode = mode.clone();
+        }
+        if let Some(enabled) = policy.streaming_insert_enabled {
+            self.streaming_insert_enabled = enabled;
+        }
+    }
+
     fn normalize_insertion_strategy(&mut self) {
-        if !matches!(
-            self.insertion_strategy.as_str(),
-            "auto" | "keyboard" | "clipboardPaste" | "clipboardCopyOnly" | "windowsSendInput"
-        ) {
+        if !is_insertion_strategy_value(&self.insertion_strategy) {
             self.insertion_strategy = "auto".to_string();
         }
 
@@ -588,19 +662,13 @@ impl AppConfig {
     }
 
     fn normalize_paste_shortcut(&mut self) {
-        if !matches!(
-            self.paste_shortcut.as_str(),
-            "ctrlV" | "ctrlShiftV" | "shiftInsert"
-        ) {
+        if !is_paste_shortcut_value(&self.paste_shortcut) {
             self.paste_shortcut = "ctrlV".to_string();
         }
     }
 
     fn normalize_windows_sendinput_newline_mode(&mut self) {
-        if !matches!(
-            self.windows_sendinput_newline_mode.as_str(),
-            "enter" | "shiftEnter" | "crlf"
-        ) {
+        if !is_windows_sendinput_newline_mode_value(&self.windows_sendinput_newline_mode) {
             self.windows_sendinput_newline_mode = "enter".to_string();
         }
     }
@@ -612,6 +680,19 @@ impl AppConfig {
             .clamp(1, DEFAULT_HISTORY_MAX_ENTRIES);
     }
 
+    fn normalize_ask_settings(&mut self) {
+        use crate::commands::ask::{
+            ASK_MAX_OUTPUT_TOKEN_LIMIT, ASK_MIN_OUTPUT_TOKEN_LIMIT, ASK_OUTPUT_TOKEN_LIMIT,
+        };
+        self.ask_answer_token_limit = match self.ask_answer_token_limit {
+            0 => ASK_OUTPUT_TOKEN_LIMIT,
+            limit => limit.clamp(ASK_MIN_OUTPUT_TOKEN_LIMIT, ASK_MAX_OUTPUT_TOKEN_LIMIT),
+        };
+        self.knowledge_base_folder = self.knowledge_base_folder.trim().to_string();
+        self.knowledge_embedding_base_url = self.knowledge_embedding_base_url.trim().to_string();
+        self.knowledge_embedding_model = self.knowledge_embedding_model.trim().to_string();
+    }
+
     pub fn history_retention_policy(&self) -> HistoryRetentionPolicy {
         HistoryRetentionPolicy {
             enabled: self.history_enabled,
@@ -892,6 +973,26 @@ fn normalize_hotkey_primary(value: &str) -> Option<String> {
     Some(normalized)
 }
 
+pub(crate) fn is_insertion_strategy_value(value: &str) -> bool {
+    matches!(
+        value,
+        "auto"
+            | "keyboard"
+            | "clipboardPaste"
+            | "clipboardCopyOnly"
+            | "windowsSendInput"
+            | "waylandVirtualKeyboard"
+    )
+}
+
+pub(crate) fn is_paste_shortcut_value(value: &str) -> bool {
+    matches!(value, "ctrlV" | "ctrlShiftV" | "shiftInsert")
+}
+
+pub(crate) fn is_windows_sendinput_newline_mode_value(value: &str) -> bool {
+    matches!(value, "enter" | "shiftEnter" | "crlf")
+}
+
 fn insertion_strategy_from_legacy_output_mode(output_mode: &str) -> &'static str {
     if output_mode == "clipboard" {
         "clipboardPaste"
@@ -953,7 +1054,7 @@ fn sanitize_custom_scenes(scenes: &mut Vec<CustomScene>) {
     scenes.truncate(CUSTOM_SCENES_MAX_COUNT);
 }
 
-fn system_scene_prompt(scene_id: &str) -> Option<&'static str> {
+pub(crate) fn system_scene_prompt(scene_id: &str) -> Option<&'static str> {
     match scene_id {
         "system_email" => Some(
             "Email system mode: produce an email body when there is enough content. Use a greeting when the recipient is spoken, concise body paragraphs, and a light closing when appropriate. Do not generate a subject unless explicitly requested.",
@@ -1062,6 +1163,44 @@ pub(crate) fn scene_prompt_for_id(config: &AppConfig, scene_id: &str) -> Option<
         })
 }
 
+/// English display names of the system and built-in scenes, matching the
+/// names the scenes pane shows for them.
+fn bundled_scene_name(scene_id: &str) -> Option<&'static str> {
+    match scene_id {
+        "system_email" => Some("Email Format"),
+        "system_work_chat" => Some("Work Chat"),
+        "system_personal_chat" => Some("Personal Chat"),
+        "system_document" => Some("Document Notes"),
+        "system_project_management" => Some("Project Update"),
+        "system_developer_collaboration" => Some("Engineering Note"),
+        "system_prompt_or_code" => Some("Prompt / Code Request"),
+        "system_support" => Some("Support Reply"),
+        "system_social" => Some("Social Post"),
+        "builtin_clean_dictation" => Some("Clean Dictation"),
+        "builtin_meeting_notes" => Some("Meeting Notes"),
+        "builtin_professional_email" => Some("Professional Email"),
+        "builtin_support_reply" => Some("Support Reply"),
+        "builtin_technical_explanation" => Some("Technical Explanation"),
+        "builtin_code_comment" => Some("Code Comment"),
+        "builtin_product_spec_notes" => Some("Product Spec Notes"),
+        _ => None,
+    }
+}
+
+fn scene_name_for_id(config: &AppConfig, scene_id: &str) -> String {
+    let scene_id = scene_id.trim();
+    bundled_scene_name(scene_id)
+        .map(str::to_string)
+        .or_else(|| {
+            config
+                .custom_scenes
+                .iter()
+                .find(|scene| scene.id == scene_id)
+                .map(|scene| scene.name.clone())
+        })
+        .unwrap_or_else(|| scene_id.to_string())
+}
+
 fn default_system_scene_id_for_family(family: ContextFamily) -> Option<&'static str> {
     match family {
         ContextFamily::Email => Some("system_email"),
@@ -1087,6 +1226,34 @@ pub(crate) fn family_scene_prompt(config: &AppConfig, family: ContextFamily) ->
     scene_prompt_for_id(config, scene_id)
 }
 
+/// A scene picked by a scene rule, with the id of the rule that picked it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SceneRuleMatch {
+    pub(crate) rule_id: String,
+    pub(crate) scene: ActiveScene,
+}
+
+/// Scene chosen by the first matching scene rule, shaped like a manual scene
+/// so history records it the same way. Rules never override a manual scene.
+pub(crate) fn scene_rule_scene(
+    config: &AppConfig,
+    input: &SceneRuleInput<'_>,
+) -> Option<SceneRuleMatch> {
+    if config.active_scene.is_some() {
+        return None;
+    }
+    let rule = scene_rules::matching_scene_rule(&config.scene_rules, input)?;
+    Some(SceneRuleMatch {
+        rule_id: rule.id.clone(),
+        scene: ActiveScene {
+            id: rule.scene_id.clone(),
+            source: "rule".to_string(),
+            name: scene_name_for_id(config, &rule.scene_id),
+            prompt_template: scene_prompt_for_id(config, &rule.scene_id)?,
+        },
+    })
+}
+
 pub(crate) fn automatic_scene_prompt(
     config: &AppConfig,
     family: ContextFamily,
@@ -1101,28 +1268,32 @@ pub(crate) fn automatic_scene_prompt(
         .or_else(|| family_scene_prompt(config, family))
 }
 
+fn scene_id_exists(
+    scene_id: &str,
+    custom_scenes: &[CustomScene],
+    system_scene_overrides: &[SystemSceneOverride],
+) -> bool {
+    !scene_id.is_empty()
+        && (builtin_scene_prompt(scene_id).is_some()
+            || system_scene_prompt(scene_id).is_some()
+            || system_scene_overrides
+                .iter()
+                .any(|scene| scene.id == scene_id)
+            || custom_scenes.iter().any(|scene| scene.id == scene_id))
+}
+
 fn sanitize_family_scene_assignments(
     assignments: &mut Vec<FamilySceneAssignment>,
     custom_scenes: &[CustomScene],
     system_scene_overrides: &[SystemSceneOverride],
 ) {
-    let valid_custom_ids: HashSet<&str> = custom_scenes
-        .iter()
-        .map(|scene| scene.id.as_str())
-        .collect();
     let mut seen_families = HashSet::new();
 
     for assignment in assignments.iter_mut() {
         assignment.scene_id = sanitize_scene_string(&assignment.scene_id, SCENE_ID_MAX_CHARS);
     }
     assignments.retain(|assignment| {
-        !assignment.scene_id.is_empty()
-            && (builtin_scene_prompt(&assignment.scene_id).is_some()
-                || system_scene_prompt(&assignment.scene_id).is_some()
-                || system_scene_overrides
-                    .iter()
-                    .any(|scene| scene.id == assignment.scene_id)
-                || valid_custom_ids.contains(assignment.scene_id.as_str()))
+        scene_id_exists(&assignment.scene_id, custom_scenes, system_scene_overrides)
             && seen_families.insert(assignment.family)
     });
 }
@@ -1235,6 +1406,23 @@ pub struct HistoryEntry {
     pub active_scene_prompt_truncated: bool,
     pub output_status: Option<String>,
     pub output_error: Option<String>,
+    #[serde(default)]
+    pub applied_corrections: Vec<AppliedCorrection>,
+    /// Pinned entries are never removed by retention pruning.
+    #[serde(default)]
+    pub pinned: bool,
+    /// Set on regenerations; points at the original dictation.
+    #[serde(default)]
+    pub parent_id: Option<i64>,
+    /// STT provider that produced the transcript, after any failover.
+    #[serde(default)]
+    pub stt_provider: Option<String>,
+    /// LLM provider that served the polish, after any failover.
+    #[serde(default)]
+    pub llm_provider: Option<String>,
+    /// Scene rule that picked the active scene, if one did.
+    #[serde(default)]
+    pub scene_rule_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -1263,6 +1451,37 @@ impl HistoryProviderKind {
     }
 }
 
+pub const DEFAULT_HISTORY_SEARCH_PAGE_SIZE: u32 = 50;
+pub const MAX_HISTORY_SEARCH_PAGE_SIZE: u32 = 200;
+const MAX_HISTORY_SEARCH_TEXT_CHARS: usize = 200;
+pub const MAX_HISTORY_TEXT_CHARS: usize = 1_000_000;
+/// The trigram tokenizer cannot match terms shorter than three characters.
+const MIN_HISTORY_FTS_TERM_CHARS: usize = 3;
+
+/// Filters for `HistoryStore::search`. Dates use the stored
+/// `%Y-%m-%dT%H:%M:%S` format or a plain `%Y-%m-%d`; both bounds are inclusive.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HistorySearchQuery {
+    pub text: String,
+    pub context_family: Option<ContextFamily>,
+    pub provider_kind: Option<HistoryProviderKind>,
+    pub active_scene_id: Option<String>,
+    pub output_status: Option<String>,
+    pub created_from: Option<String>,
+    pub created_to: Option<String>,
+    pub pinned: Option<bool>,
+    /// Id of the last entry on the previous page.
+    pub cursor: Option<i64>,
+    pub limit: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistorySearchPage {
+    pub entries: Vec<HistoryEntry>,
+    pub next_cursor: Option<i64>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct HistoryRetentionPolicy {
     pub enabled: bool,
@@ -1311,11 +1530,18 @@ impl HistoryStore {
                 active_scene_prompt_chars INTEGER,
                 active_scene_prompt_truncated INTEGER NOT NULL DEFAULT 0,
                 output_status TEXT,
-                output_error TEXT
+                output_error TEXT,
+                applied_corrections TEXT,
+                pinned INTEGER NOT NULL DEFAULT 0,
+                parent_id INTEGER,
+                stt_provider TEXT,
+                llm_provider TEXT,
+                scene_rule_id TEXT
             );",
         )?;
         ensure_history_optional_columns(&conn)?;
         migrate_legacy_history_context(&conn)?;
+        ensure_history_search_index(&conn)?;
         Ok(Self {
             conn: Mutex::new(conn),
         })
@@ -1331,62 +1557,55 @@ impl HistoryStore {
         entry: HistoryEntry,
         policy: &HistoryRetentionPolicy,
     ) -> Result<()> {
+        self.insert_with_policy(entry, policy).await.map(|_| ())
+    }
+
+    /// Like `add_with_policy`, but returns the stored entry when history is
+    /// enabled and the entry survived pruning.
+    pub async fn insert_with_policy(
+        &self,
+        entry: HistoryEntry,
+        policy: &HistoryRetentionPolicy,
+    ) -> Result<Option<HistoryEntry>> {
         if !policy.enabled {
-            return Ok(());
+            return Ok(None);
         }
 
         let now_iso = entry.created_at.clone();
-        {
+        let id = {
             let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
-            conn.execute(
-                "INSERT INTO history (
-                    created_at,
-                    app_name,
-                    app_type,
-                    context_profile_id,
-                    context_label,
-                    context_icon_key,
-                    context_family,
-                    browser_access_status,
-                    provider_kind,
-                    raw_text,
-                    polished_text,
-                    language,
-                    duration_ms,
-                    active_scene_id,
-                    active_scene_source,
-                    active_scene_name,
-                    active_scene_prompt_chars,
-                    active_scene_prompt_truncated,
-                    output_status,
-                    output_error
-                )
-             VALUES (?1, '', '', ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)",
-                rusqlite::params![
-                    entry.created_at,
-                    entry.context_profile_id,
-                    entry.context_label,
-                    entry.context_icon_key,
-                    context_family_db_value(entry.context_family),
-                    entry.browser_access_status.as_history_value(),
-                    entry.provider_kind.as_db_value(),
-                    entry.raw_text,
-                    entry.polished_text,
-                    entry.language,
-                    entry.duration_ms,
-                    entry.active_scene_id,
-                    entry.active_scene_source,
-                    entry.active_scene_name,
-                    entry.active_scene_prompt_chars,
-                    entry.active_scene_prompt_truncated,
-                    entry.output_status,
-                    entry.output_error,
-                ],
-            )?;
-        }
+            insert_history_entry(&conn, &entry)?;
+            conn.last_insert_rowid()
+        };
 
         self.prune_with_policy(policy, &now_iso).await?;
-        Ok(())
+        self.get(id).await
+    }
+
+    pub async fn get(&self, id: i64) -> Result<Option<HistoryEntry>> {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = conn
+            .query_row(
+                &format!("SELECT {HISTORY_SELECT_COLUMNS} FROM history WHERE id = ?1"),
+                rusqlite::params![id],
+                history_entry_from_row,
+            )
+            .optional()?;
+        Ok(entry)
+    }
+
+    /// Regenerations of `parent_id`, newest first.
+    pub async fn regenerations(&self, parent_id: i64) -> Result<Vec<HistoryEntry>> {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {HISTORY_SELECT_COLUMNS} FROM history WHERE parent_id = ?1 ORDER BY id DESC"
+        ))?;
+        let rows = stmt.query_map(rusqlite::params![parent_id], history_entry_from_row)?;
+        let mut entries = Vec::new();
+        for row in rows {
+            entries.push(row?);
+        }
+        Ok(entries)
     }
 
     pub async fn prune_with_policy(
@@ -1396,13 +1615,17 @@ impl HistoryStore {
     ) -> Result<()> {
         let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
         if !policy.enabled {
-            conn.execute("DELETE FROM history", [])?;
+            conn.execute("DELETE FROM history WHERE pinned = 0", [])?;
+            delete_orphaned_regenerations(&conn)?;
             return Ok(());
         }
 
+        // Pinned entries neither count toward nor fall out of the entry cap.
         let max_entries = policy.max_entries.clamp(1, DEFAULT_HISTORY_MAX_ENTRIES);
         conn.execute(
-            "DELETE FROM history WHERE id NOT IN (SELECT id FROM history ORDER BY id DESC LIMIT ?1)",
+            "DELETE FROM history
+             WHERE pinned = 0
+               AND id NOT IN (SELECT id FROM history WHERE pinned = 0 ORDER BY id DESC LIMIT ?1)",
             rusqlite::params![max_entries],
         )?;
 
@@ -1411,65 +1634,22 @@ impl HistoryStore {
                 let cutoff = now - chrono::Duration::days(policy.retention_days as i64);
                 let cutoff_iso = cutoff.format("%Y-%m-%dT%H:%M:%S").to_string();
                 conn.execute(
-                    "DELETE FROM history WHERE created_at < ?1",
+                    "DELETE FROM history WHERE pinned = 0 AND created_at < ?1",
                     rusqlite::params![cutoff_iso],
                 )?;
             }
         }
 
+        delete_orphaned_regenerations(&conn)?;
         Ok(())
     }
 
     pub async fn list(&self, limit: u32, offset: u32) -> Result<Vec<HistoryEntry>> {
         let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
-        let mut stmt = conn.prepare(
-            "SELECT
-                id,
-                created_at,
-                context_profile_id,
-                context_label,
-                context_icon_key,
-                context_family,
-                browser_access_status,
-                provider_kind,
-                raw_text,
-                polished_text,
-                language,
-                duration_ms,
-                active_scene_id,
-                active_scene_source,
-                active_scene_name,
-                active_scene_prompt_chars,
-                active_scene_prompt_truncated,
-                output_status,
-                output_error
-             FROM history ORDER BY id DESC LIMIT ?1 OFFSET ?2",
-        )?;
-        let rows = stmt.query_map(rusqlite::params![limit, offset], |row| {
-            Ok(HistoryEntry {
-                id: row.get(0)?,
-                created_at: row.get(1)?,
-                context_profile_id: row.get(2)?,
-                context_label: row.get(3)?,
-                context_icon_key: row.get(4)?,
-                context_family: context_family_from_db(&row.get::<_, String>(5)?),
-                browser_access_status: BrowserAccessStatus::from_history_value(
-                    row.get::<_, Option<String>>(6)?.as_deref(),
-                ),
-                provider_kind: HistoryProviderKind::from_db_value(&row.get::<_, String>(7)?),
-                raw_text: row.get(8)?,
-                polished_text: row.get(9)?,
-                language: row.get(10)?,
-                duration_ms: row.get(11)?,
-                active_scene_id: row.get(12)?,
-                active_scene_source: row.get(13)?,
-                active_scene_name: row.get(14)?,
-                active_scene_prompt_chars: row.get(15)?,
-                active_scene_prompt_truncated: row.get(16)?,
-                output_status: row.get(17)?,
-                output_error: row.get(18)?,
-            })
-        })?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {HISTORY_SELECT_COLUMNS} FROM history ORDER BY id DESC LIMIT ?1 OFFSET ?2"
+        ))?;
+        let rows = stmt.query_map(rusqlite::params![limit, offset], history_entry_from_row)?;
         let mut entries = Vec::new();
         for row in rows {
             entries.push(row?);
@@ -1477,6 +1657,105 @@ impl HistoryStore {
         Ok(entries)
     }
 
+    /// Full-text search over raw and polished text with keyset pagination,
+    /// newest first.
+    pub async fn search(&self, query: &HistorySearchQuery) -> Result<HistorySearchPage> {
+        let limit = match query.limit {
+            0 => DEFAULT_HISTORY_SEARCH_PAGE_SIZE,
+            limit => limit.min(MAX_HISTORY_SEARCH_PAGE_SIZE),
+        };
+
+        let (filter, mut params) = history_search_filter(query)?;
+        let mut sql = format!("SELECT {HISTORY_SELECT_COLUMNS} FROM history WHERE 1 = 1{filter}");
+        if let Some(cursor) = query.cursor {
+            sql.push_str(" AND id < ?");
+            params.push(cursor.into());
+        }
+        sql.push_str(" ORDER BY id DESC LIMIT ?");
+        params.push(i64::from(limit + 1).into());
+
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map(rusqlite::params_from_iter(params), history_entry_from_row)?;
+        let mut entries = Vec::new();
+        for row in rows {
+            entries.push(row?);
+        }
+        let next_cursor = if entries.len() > limit as usize {
+            entries.truncate(limit as usize);
+            entries.last().map(|entry| entry.id)
+        } else {
+            None
+        };
+        Ok(HistorySearchPage {
+            entries,
+            next_cursor,
+        })
+    }
+
+    /// Deletes an entry together with its regenerations.
+    pub async fn delete(&self, id: i64) -> Result<()> {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let deleted = conn.execute(
+            "DELETE FROM history WHERE id = ?1 OR parent_id = ?1",
+            rusqlite::params![id],
+        )?;
+        if deleted == 0 {
+            anyhow::bail!("history_entry_not_found");
+        }
+        Ok(())
+    }
+
+    /// Deletes every unpinned entry matching the search filters, ignoring the
+    /// cursor and page size, together with the regenerations of deleted
+    /// entries. Returns the number of deleted entries.
+    pub async fn delete_matching(&self, query: &HistorySearchQuery) -> Result<usize> {
+        let (filter, params) = history_search_filter(query)?;
+        if filter.is_empty() {
+            anyhow::bail!("history_delete_filter_empty");
+        }
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let deleted = conn.execute(
+            &format!("DELETE FROM history WHERE pinned = 0{filter}"),
+            rusqlite::params_from_iter(params),
+        )?;
+        Ok(deleted + delete_orphaned_regenerations(&conn)?)
+    }
+
+    pub async fn set_pinned(&self, id: i64, pinned: bool) -> Result<()> {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let updated = conn.execute(
+            "UPDATE history SET pinned = ?2 WHERE id = ?1",
+            rusqlite::params![id, pinned],
+        )?;
+        if updated == 0 {
+            anyhow::bail!("history_entry_not_found");
+        }
+        Ok(())
+    }
+
+    pub async fn update_polished_text(&self, id: i64, polished_text: &str) -> Result<HistoryEntry> {
+        let polished_text = polished_text.trim();
+        if polished_text.is_empty() {
+            anyhow::bail!("history_polished_text_empty");
+        }
+        if polished_text.chars().count() > MAX_HISTORY_TEXT_CHARS {
+            anyhow::bail!("history_polished_text_too_long");
+        }
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let updated = conn.execute(
+            "UPDATE history SET polished_text = ?2 WHERE id = ?1",
+            rusqlite::params![id, polished_text],
+        )?;
+        if updated == 0 {
+            anyhow::bail!("history_entry_not_found");
+        }
+        drop(conn);
+        self.get(id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("history_entry_not_found"))
+    }
+
     pub async fn clear(&self) -> Result<()> {
         let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
         conn.execute("DELETE FROM history", [])?;
@@ -1519,65 +1798,28 @@ impl HistoryStore {
                 } else {
                     None
                 };
+                let mut unpinned = 0;
                 let mut entries = entries
                     .into_iter()
                     .filter(|entry| {
-                        cutoff.is_none_or(|cutoff| {
-                            chrono::NaiveDateTime::parse_from_str(
-                                &entry.created_at,
-                                "%Y-%m-%dT%H:%M:%S",
-                            )
-                            .is_ok_and(|created_at| created_at >= cutoff)
-                        })
+                        if entry.pinned {
+                            return true;
+                        }
+                        let keep = unpinned < max_entries
+                            && cutoff.is_none_or(|cutoff| {
+                                chrono::NaiveDateTime::parse_from_str(
+                                    &entry.created_at,
+                                    "%Y-%m-%dT%H:%M:%S",
+                                )
+                                .is_ok_and(|created_at| created_at >= cutoff)
+                            });
+                        unpinned += usize::from(keep);
+                        keep
                     })
-                    .take(max_entries)
                     .collect::<Vec<_>>();
                 entries.reverse();
                 for entry in entries {
-                    transaction.execute(
-                        "INSERT INTO history (
-                            created_at,
-                            app_name,
-                            app_type,
-                            context_profile_id,
-                            context_label,
-                            context_icon_key,
-                            context_family,
-                            browser_access_status,
-                            provider_kind,
-                            raw_text,
-                            polished_text,
-                            language,
-                            duration_ms,
-                            active_scene_id,
-                            active_scene_source,
-                            active_scene_name,
-                            active_scene_prompt_chars,
-                            active_scene_prompt_truncated,
-                            output_status,
-                            output_error
-                        ) VALUES (?1, '', '', ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)",
-                        rusqlite::params![
-                            entry.created_at,
-                            entry.context_profile_id,
-                            entry.context_label,
-                            entry.context_icon_key,
-                            context_family_db_value(entry.context_family),
-                            entry.browser_access_status.as_history_value(),
-                            entry.provider_kind.as_db_value(),
-                            entry.raw_text,
-                            entry.polished_text,
-                            entry.language,
-                            entry.duration_ms,
-                            entry.active_scene_id,
-                            entry.active_scene_source,
-                            entry.active_scene_name,
-                            entry.active_scene_prompt_chars,
-                            entry.active_scene_prompt_truncated,
-                            entry.output_status,
-                            entry.output_error,
-                        ],
-                    )?;
+                    insert_history_entry(&transaction, &entry)?;
                 }
             }
         }
@@ -1607,6 +1849,149 @@ impl HistoryStore {
     }
 }
 
+const HISTORY_SELECT_COLUMNS: &str = "id,
+    created_at,
+    context_profile_id,
+    context_label,
+    context_icon_key,
+    context_family,
+    browser_access_status,
+    provider_kind,
+    raw_text,
+    polished_text,
+    language,
+    duration_ms,
+    active_scene_id,
+    active_scene_source,
+    active_scene_name,
+    active_scene_prompt_chars,
+    active_scene_prompt_truncated,
+    output_status,
+    output_error,
+    applied_corrections,
+    pinned,
+    parent_id,
+    stt_provider,
+    llm_provider,
+    scene_rule_id";
+
+fn insert_history_entry(conn: &Connection, entry: &HistoryEntry) -> Result<()> {
+    conn.execute(
+        "INSERT INTO history (
+            created_at,
+            app_name,
+            app_type,
+            context_profile_id,
+            context_label,
+            context_icon_key,
+            context_family,
+            browser_access_status,
+            provider_kind,
+            raw_text,
+            polished_text,
+            language,
+            duration_ms,
+            active_scene_id,
+            active_scene_source,
+            active_scene_name,
+            active_scene_prompt_chars,
+            active_scene_prompt_truncated,
+            output_status,
+            output_error,
+            applied_corrections,
+            pinned,
+            parent_id,
+            stt_provider,
+            llm_provider,
+            scene_rule_id
+        ) VALUES (?1, '', '', ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24)",
+        rusqlite::params![
+            entry.created_at,
+            entry.context_profile_id,
+            entry.context_label,
+            entry.context_icon_key,
+            context_family_db_value(entry.context_family),
+            entry.browser_access_status.as_history_value(),
+            entry.provider_kind.as_db_value(),
+            entry.raw_text,
+            entry.polished_text,
+            entry.language,
+            entry.duration_ms,
+            entry.active_scene_id,
+            entry.active_scene_source,
+            entry.active_scene_name,
+            entry.active_scene_prompt_chars,
+            entry.active_scene_prompt_truncated,
+            entry.output_status,
+            entry.output_error,
+            applied_corrections_db_value(&entry.applied_corrections),
+            entry.pinned,
+            entry.parent_id,
+            entry.stt_provider,
+            entry.llm_provider,
+            entry.scene_rule_id,
+        ],
+    )?;
+    Ok(())
+}
+
+/// Regenerations go with their parent, like `HistoryStore::delete`, so bulk
+/// deletes and pruning never leave a regeneration pointing at nothing.
+fn delete_orphaned_regenerations(conn: &rusqlite::Connection) -> rusqlite::Result<usize> {
+    conn.execute(
+        "DELETE FROM history
+         WHERE parent_id IS NOT NULL AND parent_id NOT IN (SELECT id FROM history)",
+        [],
+    )
+}
+
+fn history_entry_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<HistoryEntry> {
+    Ok(HistoryEntry {
+        id: row.get(0)?,
+        created_at: row.get(1)?,
+        context_profile_id: row.get(2)?,
+        context_label: row.get(3)?,
+        context_icon_key: row.get(4)?,
+        context_family: context_family_from_db(&row.get::<_, String>(5)?),
+        browser_access_status: BrowserAccessStatus::from_history_value(
+            row.get::<_, Option<String>>(6)?.as_deref(),
+        ),
+        provider_kind: HistoryProviderKind::from_db_value(&row.get::<_, String>(7)?),
+        raw_text: row.get(8)?,
+        polished_text: row.get(9)?,
+        language: row.get(10)?,
+        duration_ms: row.get(11)?,
+        active_scene_id: row.get(12)?,
+        active_scene_source: row.get(13)?,
+        active_scene_name: row.get(14)?,
+        active_scene_prompt_chars: row.get(15)?,
+        active_scene_prompt_truncated: row.get(16)?,
+        output_status: row.get(17)?,
+        output_error: row.get(18)?,
+        applied_corrections: applied_corrections_from_db(
+            row.get::<_, Option<String>>(19)?.as_deref(),
+        ),
+        pinned: row.get(20)?,
+        parent_id: row.get(21)?,
+        stt_provider: row.get(22)?,
+        llm_provider: row.get(23)?,
+        scene_rule_id: row.get(24)?,
+    })
+}
+
+fn applied_corrections_db_value(corrections: &[AppliedCorrection]) -> Option<String> {
+    if corrections.is_empty() {
+        return None;
+    }
+    serde_json::to_string(corrections).ok()
+}
+
+fn applied_corrections_from_db(value: Option<&str>) -> Vec<AppliedCorrection> {
+    value
+        .and_then(|value| serde_json::from_str(value).ok())
+        .unwrap_or_default()
+}
+
 fn prepare_backup_dictionary(
     entries: Vec<DictionaryEntry>,
 ) -> Result<Vec<(String, Option<String>)>> {
@@ -1705,6 +2090,18 @@ fn ensure_history_optional_columns(conn: &Connection) -> Result<()> {
             "provider_kind",
             "ALTER TABLE history ADD COLUMN provider_kind TEXT NOT NULL DEFAULT 'local'",
         ),
+        (
+            "applied_corrections",
+            "ALTER TABLE history ADD COLUMN applied_corrections TEXT",
+        ),
+        (
+            "pinned",
+            "ALTER TABLE history ADD COLUMN pinned INTEGER NOT NULL DEFAULT 0",
+        ),
+        ("parent_id", "ALTER TABLE history ADD COLUMN parent_id INTEGER"),
+        ("stt_provider", "ALTER TABLE history ADD COLUMN stt_provider TEXT"),
+        ("llm_provider", "ALTER TABLE history ADD COLUMN llm_provider TEXT"),
+        ("scene_rule_id", "ALTER TABLE history ADD COLUMN scene_rule_id TEXT"),
     ] {
         if !columns.contains(name) {
             conn.execute(ddl, [])?;
@@ -1714,6 +2111,139 @@ fn ensure_history_optional_columns(conn: &Connection) -> Result<()> {
     Ok(())
 }
 
+/// Keeps an external-content FTS5 index in sync with `history` through
+/// triggers. The trigram tokenizer gives substring matches, which also works
+/// for CJK text that has no spaces between words.
+fn ensure_history_search_index(conn: &Connection) -> Result<()> {
+    let exists: bool = conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'history_fts')",
+        [],
+        |row| row.get(0),
+    )?;
+    conn.execute_batch(
+        "CREATE VIRTUAL TABLE IF NOT EXISTS history_fts USING fts5(
+            raw_text,
+            polished_text,
+            content = 'history',
+            content_rowid = 'id',
+            tokenize = 'trigram'
+        );
+        CREATE TRIGGER IF NOT EXISTS history_fts_after_insert AFTER INSERT ON history BEGIN
+            INSERT INTO history_fts (rowid, raw_text, polished_text)
+            VALUES (new.id, new.raw_text, new.polished_text);
+        END;
+        CREATE TRIGGER IF NOT EXISTS history_fts_after_delete AFTER DELETE ON history BEGIN
+            INSERT INTO history_fts (history_fts, rowid, raw_text, polished_text)
+            VALUES ('delete', old.id, old.raw_text, old.polished_text);
+        END;
+        CREATE TRIGGER IF NOT EXISTS history_fts_after_update
+        AFTER UPDATE OF raw_text, polished_text ON history BEGIN
+            INSERT INTO history_fts (history_fts, rowid, raw_text, polished_text)
+            VALUES ('delete', old.id, old.raw_text, old.polished_text);
+            INSERT INTO history_fts (rowid, raw_text, polished_text)
+            VALUES (new.id, new.raw_text, new.polished_text);
+        END;",
+    )?;
+    if !exists {
+        conn.execute(
+            "INSERT INTO history_fts (history_fts) VALUES ('rebuild')",
+            [],
+        )?;
+    }
+    Ok(())
+}
+
+/// Builds the ` AND ...` clauses shared by history search and bulk delete.
+fn history_search_filter(
+    query: &HistorySearchQuery,
+) -> Result<(String, Vec<rusqlite::types::Value>)> {
+    let text = query.text.trim();
+    if text.chars().count() > MAX_HISTORY_SEARCH_TEXT_CHARS {
+        anyhow::bail!("history_search_text_too_long");
+    }
+    let mut sql = String::new();
+    let mut params: Vec<rusqlite::types::Value> = Vec::new();
+
+    let (fts_terms, short_terms): (Vec<_>, Vec<_>) = text
+        .split_whitespace()
+        .partition(|term| term.chars().count() >= MIN_HISTORY_FTS_TERM_CHARS);
+    if !fts_terms.is_empty() {
+        sql.push_str(" AND id IN (SELECT rowid FROM history_fts WHERE history_fts MATCH ?)");
+        params.push(history_fts_match_expression(&fts_terms).into());
+    }
+    for term in short_terms {
+        sql.push_str(" AND (raw_text LIKE ? ESCAPE '\\' OR polished_text LIKE ? ESCAPE '\\')");
+        let pattern = format!("%{}%", escape_like_pattern(term));
+        params.push(pattern.clone().into());
+        params.push(pattern.into());
+    }
+    if let Some(family) = query.context_family {
+        sql.push_str(" AND context_family = ?");
+        params.push(context_family_db_value(family).to_string().into());
+    }
+    if let Some(provider_kind) = query.provider_kind {
+        sql.push_str(" AND provider_kind = ?");
+        params.push(provider_kind.as_db_value().to_string().into());
+    }
+    if let Some(scene_id) = non_empty_filter(query.active_scene_id.as_deref()) {
+        sql.push_str(" AND active_scene_id = ?");
+        params.push(scene_id.into());
+    }
+    if let Some(status) = non_empty_filter(query.output_status.as_deref()) {
+        sql.push_str(" AND output_status = ?");
+        params.push(status.into());
+    }
+    if let Some(from) = non_empty_filter(query.created_from.as_deref()) {
+        sql.push_str(" AND created_at >= ?");
+        params.push(history_search_date_bound(&from, false)?.into());
+    }
+    if let Some(to) = non_empty_filter(query.created_to.as_deref()) {
+        sql.push_str(" AND created_at <= ?");
+        params.push(history_search_date_bound(&to, true)?.into());
+    }
+    if let Some(pinned) = query.pinned {
+        sql.push_str(" AND pinned = ?");
+        params.push(i64::from(pinned).into());
+    }
+    Ok((sql, params))
+}
+
+fn history_fts_match_expression(terms: &[&str]) -> String {
+    terms
+        .iter()
+        .map(|term| format!("\"{}\"", term.replace('"', "\"\"")))
+        .collect::<Vec<_>>()
+        .join(" AND ")
+}
+
+fn escape_like_pattern(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for character in value.chars() {
+        if matches!(character, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(character);
+    }
+    escaped
+}
+
+fn non_empty_filter(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+fn history_search_date_bound(value: &str, end_of_day: bool) -> Result<String> {
+    if chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S").is_ok() {
+        return Ok(value.to_string());
+    }
+    let date = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| anyhow::anyhow!("history_search_invalid_date"))?;
+    let time = if end_of_day { "23:59:59" } else { "00:00:00" };
+    Ok(format!("{}T{time}", date.format("%Y-%m-%d")))
+}
+
 fn migrate_legacy_history_context(conn: &Connection) -> Result<()> {
     let registry = AppRegistry::builtin().map_err(anyhow::Error::msg)?;
     let rows = {
@@ -1809,80 +2339,207 @@ fn context_family_from_db(value: &str) -> ContextFamily {
     }
 }
 
-// ─── DictionaryStore (SQLite backed) ───
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DictionaryEntry {
-    pub id: i64,
-    pub word: String,
-    pub pronunciation: Option<String>,
-}
+// ─── AskThreadStore (SQLite backed) ───
 
+/// One answered question of a multi-turn Ask session.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-pub struct CorrectionRule {
+pub struct AskTurn {
     pub id: i64,
-    pub pattern: String,
-    pub replacement: String,
-    pub enabled: bool,
+    pub session_id: String,
+    /// RFC 3339 UTC timestamp of the answer.
+    pub created_at: String,
+    pub question: String,
+    pub answer: String,
 }
 
-pub struct DictionaryStore {
+pub struct AskThreadStore {
     conn: Mutex<Connection>,
 }
 
-impl DictionaryStore {
+impl AskThreadStore {
     pub fn new(db_path: PathBuf) -> Result<Self> {
         let conn = Connection::open(&db_path)?;
         conn.execute_batch("PRAGMA journal_mode=WAL;")?;
         conn.execute_batch(
-            "CREATE TABLE IF NOT EXISTS dictionary (
+            "CREATE TABLE IF NOT EXISTS ask_turns (
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
-                word TEXT NOT NULL,
-                pronunciation TEXT
+                session_id TEXT NOT NULL,
+                created_at TEXT NOT NULL,
+                question TEXT NOT NULL,
+                answer TEXT NOT NULL
             );
-            CREATE TABLE IF NOT EXISTS correction_rules (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                pattern TEXT NOT NULL,
-                replacement TEXT NOT NULL,
-                enabled INTEGER NOT NULL DEFAULT 1,
-                created_at TEXT NOT NULL DEFAULT (datetime('now'))
-            );",
+            CREATE INDEX IF NOT EXISTS idx_ask_turns_session ON ask_turns(session_id, id);",
         )?;
         Ok(Self {
             conn: Mutex::new(conn),
         })
     }
 
-    pub async fn add(&self, word: &str, pronunciation: Option<&str>) -> Result<()> {
-        let word = validate_dictionary_text(word, 100, "dictionary_word")?;
-        let pronunciation = pronunciation
-            .map(|value| validate_dictionary_text(value, 100, "dictionary_pronunciation"))
-            .transpose()?
-            .filter(|value| !value.is_empty());
-        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
-        if dictionary_identity_exists(&conn, &normalized_dictionary_identity(&word), None)? {
-            anyhow::bail!("dictionary_duplicate");
+    /// Stores the turn and prunes old turns with the history retention
+    /// policy. Nothing is stored while history is disabled.
+    pub async fn append_with_policy(
+        &self,
+        session_id: &str,
+        created_at: &str,
+        question: &str,
+        answer: &str,
+        policy: &HistoryRetentionPolicy,
+    ) -> Result<Option<AskTurn>> {
+        if !policy.enabled {
+            return Ok(None);
         }
+
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
         conn.execute(
-            "INSERT INTO dictionary (word, pronunciation) VALUES (?1, ?2)",
-            rusqlite::params![word, pronunciation],
+            "INSERT INTO ask_turns (session_id, created_at, question, answer)
+             VALUES (?1, ?2, ?3, ?4)",
+            rusqlite::params![session_id, created_at, question, answer],
         )?;
-        Ok(())
-    }
+        let turn = AskTurn {
+            id: conn.last_insert_rowid(),
+            session_id: session_id.to_string(),
+            created_at: created_at.to_string(),
+            question: question.to_string(),
+            answer: answer.to_string(),
+        };
 
-    pub async fn update(&self, id: i64, word: &str, pronunciation: Option<&str>) -> Result<()> {
-        let word = validate_dictionary_text(word, 100, "dictionary_word")?;
-        let pronunciation = pronunciation
-            .map(|value| validate_dictionary_text(value, 100, "dictionary_pronunciation"))
-            .transpose()?
-            .filter(|value| !value.is_empty());
-        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
-        if dictionary_identity_exists(&conn, &normalized_dictionary_identity(&word), Some(id))? {
-            anyhow::bail!("dictionary_duplicate");
-        }
-        let updated = conn.execute(
-            "UPDATE dictionary SET word = ?2, pronunciation = ?3 WHERE id = ?1",
-            rusqlite::params![id, word, pronunciation],
+        let max_entries = policy.max_entries.clamp(1, DEFAULT_HISTORY_MAX_ENTRIES);
+        conn.execute(
+            "DELETE FROM ask_turns
+             WHERE id NOT IN (SELECT id FROM ask_turns ORDER BY id DESC LIMIT ?1)",
+            rusqlite::params![max_entries],
+        )?;
+
+        if policy.retention_days > 0 {
+            if let Ok(now) = chrono::DateTime::parse_from_rfc3339(created_at) {
+                let cutoff = now - chrono::Duration::days(policy.retention_days as i64);
+                conn.execute(
+                    "DELETE FROM ask_turns WHERE created_at < ?1",
+                    rusqlite::params![cutoff.to_rfc3339()],
+                )?;
+            }
+        }
+
+        Ok(Some(turn))
+    }
+
+    /// Turns of `session_id`, oldest first.
+    pub async fn turns(&self, session_id: &str) -> Result<Vec<AskTurn>> {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let mut stmt = conn.prepare(
+            "SELECT id, session_id, created_at, question, answer
+             FROM ask_turns WHERE session_id = ?1 ORDER BY id ASC",
+        )?;
+        let rows = stmt.query_map(rusqlite::params![session_id], |row| {
+            Ok(AskTurn {
+                id: row.get(0)?,
+                session_id: row.get(1)?,
+                created_at: row.get(2)?,
+                question: row.get(3)?,
+                answer: row.get(4)?,
+            })
+        })?;
+        let mut turns = Vec::new();
+        for row in rows {
+            turns.push(row?);
+        }
+        Ok(turns)
+    }
+
+    /// Session of the most recently stored turn.
+    pub async fn latest_session_id(&self) -> Result<Option<String>> {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let session_id = conn
+            .query_row(
+                "SELECT session_id FROM ask_turns ORDER BY id DESC LIMIT 1",
+                [],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(session_id)
+    }
+
+    pub async fn clear(&self) -> Result<()> {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        conn.execute("DELETE FROM ask_turns", [])?;
+        Ok(())
+    }
+}
+
+// ─── DictionaryStore (SQLite backed) ───
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DictionaryEntry {
+    pub id: i64,
+    pub word: String,
+    pub pronunciation: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CorrectionRule {
+    pub id: i64,
+    pub pattern: String,
+    pub replacement: String,
+    pub enabled: bool,
+}
+
+pub struct DictionaryStore {
+    conn: Mutex<Connection>,
+}
+
+impl DictionaryStore {
+    pub fn new(db_path: PathBuf) -> Result<Self> {
+        let conn = Connection::open(&db_path)?;
+        conn.execute_batch("PRAGMA journal_mode=WAL;")?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS dictionary (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                word TEXT NOT NULL,
+                pronunciation TEXT
+            );
+            CREATE TABLE IF NOT EXISTS correction_rules (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                pattern TEXT NOT NULL,
+                replacement TEXT NOT NULL,
+                enabled INTEGER NOT NULL DEFAULT 1,
+                created_at TEXT NOT NULL DEFAULT (datetime('now'))
+            );",
+        )?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    pub async fn add(&self, word: &str, pronunciation: Option<&str>) -> Result<()> {
+        let word = validate_dictionary_text(word, 100, "dictionary_word")?;
+        let pronunciation = pronunciation
+            .map(|value| validate_dictionary_text(value, 100, "dictionary_pronunciation"))
+            .transpose()?
+            .filter(|value| !value.is_empty());
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        if dictionary_identity_exists(&conn, &normalized_dictionary_identity(&word), None)? {
+            anyhow::bail!("dictionary_duplicate");
+        }
+        conn.execute(
+            "INSERT INTO dictionary (word, pronunciation) VALUES (?1, ?2)",
+            rusqlite::params![word, pronunciation],
+        )?;
+        Ok(())
+    }
+
+    pub async fn update(&self, id: i64, word: &str, pronunciation: Option<&str>) -> Result<()> {
+        let word = validate_dictionary_text(word, 100, "dictionary_word")?;
+        let pronunciation = pronunciation
+            .map(|value| validate_dictionary_text(value, 100, "dictionary_pronunciation"))
+            .transpose()?
+            .filter(|value| !value.is_empty());
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        if dictionary_identity_exists(&conn, &normalized_dictionary_identity(&word), Some(id))? {
+            anyhow::bail!("dictionary_duplicate");
+        }
+        let updated = conn.execute(
+            "UPDATE dictionary SET word = ?2, pronunciation = ?3 WHERE id = ?1",
+            rusqlite::params![id, word, pronunciation],
         )?;
         if updated == 0 {
             anyhow::bail!("dictionary_entry_not_found");
@@ -2356,6 +3013,22 @@ mod tests {
         assert_eq!(invalid_config.history_max_entries, 5000);
     }
 
+    #[test]
+    fn app_config_defaults_and_clamps_ask_answer_budget() {
+        let default_config =
+            AppConfig::from_stored_value(serde_json::json!({ "llm_provider": "openrouter" }))
+                .unwrap();
+        assert_eq!(default_config.ask_answer_token_limit, 80);
+
+        for (stored, expected) in [(0, 80), (5, 40), (400, 400), (100_000, 1024)] {
+            let config = AppConfig::from_stored_value(
+                serde_json::json!({ "ask_answer_token_limit": stored }),
+            )
+            .unwrap();
+            assert_eq!(config.ask_answer_token_limit, expected);
+        }
+    }
+
     #[test]
     fn shortcut_binding_accepts_native_single_key_triggers() {
         let fn_binding = ShortcutBinding::from_hotkey("Fn").expect("Fn parses");
@@ -2749,6 +3422,41 @@ mod tests {
         assert_eq!(invalid_config.windows_sendinput_newline_mode, "enter");
     }
 
+    #[test]
+    fn app_config_applies_only_valid_app_output_policies() {
+        let mut config = AppConfig {
+            paste_shortcut: "shiftInsert".to_string(),
+            streaming_insert_enabled: true,
+            ..Default::default()
+        };
+        config.apply_output_policy(&AppOutputPolicy {
+            insertion_strategy: Some("clipboardCopyOnly".to_string()),
+            streaming_insert_enabled: Some(false),
+            ..AppOutputPolicy::default()
+        });
+        assert_eq!(config.insertion_strategy, "clipboardCopyOnly");
+        assert_eq!(config.output_mode, "clipboard");
+        assert_eq!(config.paste_shortcut, "shiftInsert");
+        assert!(!config.streaming_insert_enabled);
+
+        config.apply_output_policy(&AppOutputPolicy {
+            paste_shortcut: Some("ctrlShiftV".to_string()),
+            newline_mode: Some("altEnter".to_string()),
+            ..AppOutputPolicy::default()
+        });
+        assert_eq!(config.paste_shortcut, "shiftInsert");
+        assert_eq!(config.windows_sendinput_newline_mode, "enter");
+
+        let wayland = AppOutputPolicy {
+            insertion_strategy: Some("waylandVirtualKeyboard".to_string()),
+            ..AppOutputPolicy::default()
+        };
+        assert!(wayland.is_valid());
+        config.apply_output_policy(&wayland);
+        assert_eq!(config.insertion_strategy, "waylandVirtualKeyboard");
+        assert_eq!(config.output_mode, "keyboard");
+    }
+
     #[test]
     fn app_config_defaults_missing_custom_scenes() {
         let value = serde_json::json!({
@@ -2875,6 +3583,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn scene_rules_resolve_known_scenes_unless_a_manual_scene_is_active() {
+        let mut config = AppConfig {
+            scene_rules: vec![
+                SceneRule {
+                    id: "rule_late_slack".to_string(),
+                    name: "Late Slack".to_string(),
+                    scene_id: "builtin_meeting_notes".to_string(),
+                    conditions: SceneRuleConditions {
+                        profile_ids: vec!["chat.slack".to_string()],
+                        ..SceneRuleConditions::default()
+                    },
+                    ..SceneRule::default()
+                },
+                SceneRule {
+                    id: "rule_deleted_scene".to_string(),
+                    name: "Deleted".to_string(),
+                    scene_id: "custom_deleted".to_string(),
+                    ..SceneRule::default()
+                },
+            ],
+            ..Default::default()
+        };
+        config.normalize_values();
+        assert_eq!(config.scene_rules.len(), 1);
+
+        let input = SceneRuleInput {
+            family: ContextFamily::WorkChat,
+            profile_id: "chat.slack",
+            language: Some("en"),
+            weekday: 1,
+            minute_of_day: 22 * 60,
+            has_selected_text: false,
+        };
+        let matched = scene_rule_scene(&config, &input).unwrap();
+        assert_eq!(matched.rule_id, "rule_late_slack");
+        let scene = matched.scene;
+        assert_eq!(scene.id, "builtin_meeting_notes");
+        assert_eq!(scene.source, "rule");
+        assert_eq!(scene.name, "Meeting Notes");
+        assert_eq!(
+            Some(scene.prompt_template),
+            scene_prompt_for_id(&config, "builtin_meeting_notes")
+        );
+
+        config.active_scene = Some(ActiveScene {
+            id: "builtin_clean_dictation".to_string(),
+            source: "builtin".to_string(),
+            name: "Clean Dictation".to_string(),
+            prompt_template: "Manual scene wins.".to_string(),
+        });
+        assert_eq!(scene_rule_scene(&config, &input), None);
+    }
+
     #[test]
     fn system_scene_overrides_replace_default_system_prompts() {
         let mut config = AppConfig {
@@ -3187,6 +3949,12 @@ mod tests {
             active_scene_prompt_truncated: false,
             output_status: None,
             output_error: None,
+            applied_corrections: Vec::new(),
+            pinned: false,
+            parent_id: None,
+            stt_provider: None,
+            llm_provider: None,
+            scene_rule_id: None,
         }
     }
 
@@ -3228,6 +3996,121 @@ mod tests {
         (history, dictionary)
     }
 
+    #[tokio::test]
+    async fn ask_thread_store_keeps_sessions_apart_in_order() {
+        let path = std::env::temp_dir().join(format!(
+            "opentypeless-ask-thread-test-{}.sqlite",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let store = AskThreadStore::new(path.clone()).unwrap();
+        assert_eq!(store.latest_session_id().await.unwrap(), None);
+
+        store
+            .append_with_policy(
+                "a",
+                "2026-07-01T00:00:00+00:00",
+                "What is Rust?",
+                "A language.",
+                &HistoryRetentionPolicy::default(),
+            )
+            .await
+            .unwrap();
+        store
+            .append_with_policy(
+                "b",
+                "2026-07-01T00:01:00+00:00",
+                "Other thread",
+                "Sure.",
+                &HistoryRetentionPolicy::default(),
+            )
+            .await
+            .unwrap();
+        let follow_up = store
+            .append_with_policy(
+                "a",
+                "2026-07-01T00:02:00+00:00",
+                "And Go?",
+                "Also a language.",
+                &HistoryRetentionPolicy::default(),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+
+        let reopened = AskThreadStore::new(path).unwrap();
+        let turns = reopened.turns("a").await.unwrap();
+        assert_eq!(
+            turns
+                .iter()
+                .map(|turn| turn.question.as_str())
+                .collect::<Vec<_>>(),
+            vec!["What is Rust?", "And Go?"]
+        );
+        assert_eq!(turns[1], follow_up);
+        assert_eq!(
+            reopened.latest_session_id().await.unwrap().as_deref(),
+            Some("a")
+        );
+
+        reopened.clear().await.unwrap();
+        assert!(reopened.turns("a").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn ask_thread_store_follows_the_history_retention_policy() {
+        let path = std::env::temp_dir().join(format!(
+            "opentypeless-ask-thread-retention-test-{}.sqlite",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let store = AskThreadStore::new(path).unwrap();
+        let policy = HistoryRetentionPolicy {
+            enabled: true,
+            max_entries: 2,
+            retention_days: 7,
+        };
+
+        for (created_at, question) in [
+            ("2026-06-01T00:00:00+00:00", "Too old"),
+            ("2026-07-01T00:00:00+00:00", "Over the cap"),
+            ("2026-07-02T00:00:00+00:00", "Kept"),
+            ("2026-07-03T00:00:00+00:00", "Newest"),
+        ] {
+            store
+                .append_with_policy("a", created_at, question, "Answer.", &policy)
+                .await
+                .unwrap();
+        }
+        assert_eq!(
+            store
+                .turns("a")
+                .await
+                .unwrap()
+                .iter()
+                .map(|turn| turn.question.as_str())
+                .collect::<Vec<_>>(),
+            vec!["Kept", "Newest"]
+        );
+
+        let disabled = HistoryRetentionPolicy {
+            enabled: false,
+            ..policy
+        };
+        assert_eq!(
+            store
+                .append_with_policy("a", "2026-07-04T00:00:00+00:00", "Q", "A", &disabled)
+                .await
+                .unwrap(),
+            None
+        );
+        assert_eq!(store.turns("a").await.unwrap().len(), 2);
+    }
+
     #[tokio::test]
     async fn history_store_respects_disabled_policy() {
         let store = temp_history_store("disabled");
@@ -3297,6 +4180,238 @@ mod tests {
         assert_eq!(entries[0].polished_text, "polished 2");
     }
 
+    #[tokio::test]
+    async fn pinned_history_entries_are_exempt_from_pruning() {
+        let store = temp_history_store("pinned-prune");
+        let policy = HistoryRetentionPolicy {
+            enabled: true,
+            max_entries: 1,
+            retention_days: 7,
+        };
+        let mut pinned = test_history_entry(1, "2026-06-01T00:00:00");
+        pinned.pinned = true;
+        store.add_with_policy(pinned, &policy).await.unwrap();
+        for id in 2..=3 {
+            store
+                .add_with_policy(
+                    test_history_entry(id, &format!("2026-07-0{id}T00:00:00")),
+                    &policy,
+                )
+                .await
+                .unwrap();
+        }
+
+        let entries = store.list(10, 0).await.unwrap();
+        assert_eq!(
+            entries
+                .iter()
+                .map(|entry| (entry.polished_text.as_str(), entry.pinned))
+                .collect::<Vec<_>>(),
+            vec![("polished 3", false), ("polished 1", true)]
+        );
+
+        store
+            .prune_with_policy(
+                &HistoryRetentionPolicy {
+                    enabled: false,
+                    ..policy
+                },
+                "2026-07-05T00:00:00",
+            )
+            .await
+            .unwrap();
+        let entries = store.list(10, 0).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert!(entries[0].pinned);
+    }
+
+    #[tokio::test]
+    async fn history_entries_can_be_deleted_pinned_and_edited_individually() {
+        let store = temp_history_store("entry-actions");
+        for id in 1..=3 {
+            store
+                .add(test_history_entry(id, &format!("2026-07-0{id}T00:00:00")))
+                .await
+                .unwrap();
+        }
+        let ids = store
+            .list(10, 0)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|entry| entry.id)
+            .collect::<Vec<_>>();
+
+        store.delete(ids[0]).await.unwrap();
+        assert_eq!(
+            store.delete(ids[0]).await.unwrap_err().to_string(),
+            "history_entry_not_found"
+        );
+
+        store.set_pinned(ids[1], true).await.unwrap();
+        let edited = store
+            .update_polished_text(ids[1], "  Edited sensitive draft  ")
+            .await
+            .unwrap();
+        assert_eq!(edited.polished_text, "Edited sensitive draft");
+        assert!(edited.pinned);
+        assert_eq!(
+            store
+                .update_polished_text(ids[1], "   ")
+                .await
+                .unwrap_err()
+                .to_string(),
+            "history_polished_text_empty"
+        );
+
+        let search = HistorySearchQuery {
+            text: "sensitive".to_string(),
+            ..HistorySearchQuery::default()
+        };
+        assert_eq!(store.search(&search).await.unwrap().entries[0].id, ids[1]);
+        let stale = HistorySearchQuery {
+            text: "polished 2".to_string(),
+            ..HistorySearchQuery::default()
+        };
+        assert!(store.search(&stale).await.unwrap().entries.is_empty());
+    }
+
+    #[tokio::test]
+    async fn regenerations_link_to_their_parent_and_are_deleted_with_it() {
+        let store = temp_history_store("regenerations");
+        let policy = HistoryRetentionPolicy {
+            enabled: true,
+            max_entries: 100,
+            retention_days: 0,
+        };
+        let parent = store
+            .insert_with_policy(test_history_entry(0, "2026-07-01T00:00:00"), &policy)
+            .await
+            .unwrap()
+            .unwrap();
+        for created_at in ["2026-07-01T00:01:00", "2026-07-01T00:02:00"] {
+            let child = HistoryEntry {
+                parent_id: Some(parent.id),
+                ..test_history_entry(0, created_at)
+            };
+            store.insert_with_policy(child, &policy).await.unwrap();
+        }
+        let unrelated = store
+            .insert_with_policy(test_history_entry(0, "2026-07-02T00:00:00"), &policy)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let regenerations = store.regenerations(parent.id).await.unwrap();
+        assert_eq!(regenerations.len(), 2);
+        assert_eq!(regenerations[0].created_at, "2026-07-01T00:02:00");
+        assert!(regenerations
+            .iter()
+            .all(|entry| entry.parent_id == Some(parent.id)));
+        assert!(store
+            .insert_with_policy(
+                test_history_entry(0, "2026-07-03T00:00:00"),
+                &HistoryRetentionPolicy {
+                    enabled: false,
+                    ..policy
+                },
+            )
+            .await
+            .unwrap()
+            .is_none());
+
+        store.delete(parent.id).await.unwrap();
+        assert!(store.regenerations(parent.id).await.unwrap().is_empty());
+        let remaining = store.list(10, 0).await.unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].id, unrelated.id);
+        assert!(store.get(parent.id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn bulk_history_delete_requires_a_filter_and_keeps_pinned_entries() {
+        let store = temp_history_store("bulk-delete");
+        for id in 1..=4 {
+            let mut entry = test_history_entry(id, &format!("2026-07-0{id}T00:00:00"));
+            entry.pinned = id == 1;
+            store.add(entry).await.unwrap();
+        }
+
+        assert_eq!(
+            store
+                .delete_matching(&HistorySearchQuery::default())
+                .await
+                .unwrap_err()
+                .to_string(),
+            "history_delete_filter_empty"
+        );
+
+        let deleted = store
+            .delete_matching(&HistorySearchQuery {
+                created_to: Some("2026-07-03".to_string()),
+                ..HistorySearchQuery::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(deleted, 2);
+        let remaining = store
+            .list(10, 0)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|entry| entry.polished_text)
+            .collect::<Vec<_>>();
+        assert_eq!(remaining, vec!["polished 4", "polished 1"]);
+    }
+
+    #[tokio::test]
+    async fn bulk_delete_and_pruning_take_regenerations_with_their_parent() {
+        let store = temp_history_store("bulk-regenerations");
+        let policy = HistoryRetentionPolicy {
+            enabled: true,
+            max_entries: 100,
+            retention_days: 0,
+        };
+        let mut parents = Vec::new();
+        for (id, created_at) in [(1, "2026-07-01T00:00:00"), (2, "2026-07-05T00:00:00")] {
+            let parent = store
+                .insert_with_policy(test_history_entry(id, created_at), &policy)
+                .await
+                .unwrap()
+                .unwrap();
+            let child = HistoryEntry {
+                parent_id: Some(parent.id),
+                ..test_history_entry(id * 10, "2026-07-09T00:00:00")
+            };
+            store.insert_with_policy(child, &policy).await.unwrap();
+            parents.push(parent);
+        }
+
+        let deleted = store
+            .delete_matching(&HistorySearchQuery {
+                created_to: Some("2026-07-02".to_string()),
+                ..HistorySearchQuery::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(deleted, 2);
+        assert!(store.regenerations(parents[0].id).await.unwrap().is_empty());
+        assert_eq!(store.regenerations(parents[1].id).await.unwrap().len(), 1);
+
+        store
+            .prune_with_policy(
+                &HistoryRetentionPolicy {
+                    retention_days: 1,
+                    ..policy
+                },
+                "2026-07-09T12:00:00",
+            )
+            .await
+            .unwrap();
+        assert!(store.list(10, 0).await.unwrap().is_empty());
+    }
+
     #[tokio::test]
     async fn backup_restore_replaces_all_requested_data_in_one_transaction() {
         let (history, dictionary) = temp_backup_stores("replace");
@@ -3412,12 +4527,32 @@ mod tests {
         assert!(!entries[0].active_scene_prompt_truncated);
     }
 
+    #[tokio::test]
+    async fn history_store_persists_the_matched_scene_rule_id() {
+        let store = temp_history_store("scene-rule-id");
+        let mut entry = test_history_entry(1, "2026-07-01T00:00:00");
+        entry.active_scene_id = Some("builtin_meeting_notes".to_string());
+        entry.active_scene_source = Some("rule".to_string());
+        entry.scene_rule_id = Some("rule_late_slack".to_string());
+
+        store.add(entry).await.unwrap();
+        store
+            .add(test_history_entry(2, "2026-07-01T00:00:01"))
+            .await
+            .unwrap();
+
+        let entries = store.list(10, 0).await.unwrap();
+        assert_eq!(entries[0].scene_rule_id, None);
+        assert_eq!(entries[1].scene_rule_id.as_deref(), Some("rule_late_slack"));
+    }
+
     #[tokio::test]
     async fn history_store_persists_output_status_diagnostics() {
         let store = temp_history_store("output-diagnostics");
         let mut entry = test_history_entry(1, "2026-07-01T00:00:00");
         entry.output_status = Some("partial".to_string());
         entry.output_error = Some("LLM failed after partial streaming insert".to_string());
+        entry.llm_provider = Some("groq".to_string());
 
         store.add(entry).await.unwrap();
 
@@ -3427,6 +4562,165 @@ mod tests {
             entries[0].output_error.as_deref(),
             Some("LLM failed after partial streaming insert")
         );
+        assert_eq!(entries[0].llm_provider.as_deref(), Some("groq"));
+    }
+
+    #[tokio::test]
+    async fn history_store_persists_applied_correction_audit() {
+        let store = temp_history_store("applied-corrections");
+        let mut entry = test_history_entry(1, "2026-07-01T00:00:00");
+        entry.applied_corrections = vec![AppliedCorrection {
+            rule_id: 7,
+            stage: crate::corrections::CorrectionStage::Transcript,
+            pattern: "拓肯".to_string(),
+            replacement: "Token".to_string(),
+            count: 2,
+        }];
+
+        store.add(entry.clone()).await.unwrap();
+        store
+            .add(test_history_entry(2, "2026-07-01T00:00:01"))
+            .await
+            .unwrap();
+
+        let entries = store.list(10, 0).await.unwrap();
+        assert!(entries[0].applied_corrections.is_empty());
+        assert_eq!(entries[1].applied_corrections, entry.applied_corrections);
+    }
+
+    #[tokio::test]
+    async fn history_search_matches_substrings_in_raw_and_polished_text() {
+        let store = temp_history_store("search-text");
+        let mut first = test_history_entry(1, "2026-07-01T00:00:00");
+        first.raw_text = "please ship the quarterly report".to_string();
+        first.polished_text = "Please ship the quarterly report.".to_string();
+        let mut second = test_history_entry(2, "2026-07-01T00:00:01");
+        second.raw_text = "这个拓肯来自接口".to_string();
+        second.polished_text = "这个 Token 来自接口".to_string();
+        let mut third = test_history_entry(3, "2026-07-01T00:00:02");
+        third.polished_text = "100% done with the QA pass".to_string();
+        for entry in [first, second, third] {
+            store.add(entry).await.unwrap();
+        }
+
+        let search = |text: &str| HistorySearchQuery {
+            text: text.to_string(),
+            ..HistorySearchQuery::default()
+        };
+        let ids = |page: HistorySearchPage| {
+            page.entries
+                .into_iter()
+                .map(|entry| entry.id)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            ids(store.search(&search("QUARTER")).await.unwrap()),
+            vec![1]
+        );
+        assert_eq!(ids(store.search(&search("token")).await.unwrap()), vec![2]);
+        assert_eq!(ids(store.search(&search("拓肯")).await.unwrap()), vec![2]);
+        assert_eq!(ids(store.search(&search("100%")).await.unwrap()), vec![3]);
+        assert_eq!(
+            ids(store.search(&search("QA done")).await.unwrap()),
+            vec![3]
+        );
+        assert!(ids(store.search(&search("0%x")).await.unwrap()).is_empty());
+        assert!(ids(store.search(&search("\"report")).await.unwrap()).is_empty());
+        assert_eq!(ids(store.search(&search("")).await.unwrap()), vec![3, 2, 1]);
+    }
+
+    #[tokio::test]
+    async fn history_search_filters_and_paginates_with_a_cursor() {
+        let store = temp_history_store("search-filters");
+        for id in 1..=5 {
+            let mut entry = test_history_entry(id, &format!("2026-07-0{id}T08:00:00"));
+            entry.raw_text = format!("standup note {id}");
+            if id % 2 == 1 {
+                entry.context_family = ContextFamily::WorkChat;
+                entry.provider_kind = HistoryProviderKind::Byok;
+                entry.active_scene_id = Some("scene-standup".to_string());
+                entry.output_status = Some("inserted".to_string());
+            }
+            store.add(entry).await.unwrap();
+        }
+
+        let mut query = HistorySearchQuery {
+            text: "standup".to_string(),
+            context_family: Some(ContextFamily::WorkChat),
+            provider_kind: Some(HistoryProviderKind::Byok),
+            active_scene_id: Some("scene-standup".to_string()),
+            output_status: Some("inserted".to_string()),
+            created_from: Some("2026-07-01".to_string()),
+            created_to: Some("2026-07-05".to_string()),
+            limit: 2,
+            ..HistorySearchQuery::default()
+        };
+        let first_page = store.search(&query).await.unwrap();
+        assert_eq!(
+            first_page
+                .entries
+                .iter()
+                .map(|entry| entry.id)
+                .collect::<Vec<_>>(),
+            vec![5, 3]
+        );
+        assert_eq!(first_page.next_cursor, Some(3));
+
+        query.cursor = first_page.next_cursor;
+        let second_page = store.search(&query).await.unwrap();
+        assert_eq!(second_page.entries.len(), 1);
+        assert_eq!(second_page.entries[0].id, 1);
+        assert_eq!(second_page.next_cursor, None);
+
+        query.cursor = None;
+        query.created_from = Some("2026-07-02T00:00:00".to_string());
+        query.created_to = Some("2026-07-03".to_string());
+        let ranged = store.search(&query).await.unwrap();
+        assert_eq!(ranged.entries.len(), 1);
+        assert_eq!(ranged.entries[0].id, 3);
+
+        query.created_to = Some("July 3".to_string());
+        assert_eq!(
+            store.search(&query).await.unwrap_err().to_string(),
+            "history_search_invalid_date"
+        );
+    }
+
+    #[tokio::test]
+    async fn history_search_index_follows_deletes_and_backfills_existing_rows() {
+        let path = std::env::temp_dir().join(format!(
+            "opentypeless-history-test-search-index-{}.sqlite",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let store = HistoryStore::new(path.clone()).unwrap();
+        let mut entry = test_history_entry(1, "2026-07-01T00:00:00");
+        entry.raw_text = "legacy dictation".to_string();
+        store.add(entry).await.unwrap();
+        {
+            let conn = store.conn.lock().unwrap();
+            conn.execute_batch(
+                "DROP TRIGGER history_fts_after_insert;
+                 DROP TRIGGER history_fts_after_delete;
+                 DROP TRIGGER history_fts_after_update;
+                 DROP TABLE history_fts;",
+            )
+            .unwrap();
+        }
+        drop(store);
+
+        let store = HistoryStore::new(path).unwrap();
+        let query = HistorySearchQuery {
+            text: "legacy".to_string(),
+            ..HistorySearchQuery::default()
+        };
+        assert_eq!(store.search(&query).await.unwrap().entries.len(), 1);
+
+        store.clear().await.unwrap();
+        assert!(store.search(&query).await.unwrap().entries.is_empty());
     }
 
     #[tokio::test]
@@ -0,0 +1,406 @@
+//! Conditional scene rules.
+//!
+//! A rule picks an automatic scene when all of its conditions hold for the
+//! current recording: context family or profile, custom app mapping, spoken
+//! language, weekday and local time window, and whether text is selected. An
+//! empty condition matches anything. Rules run in ascending `priority`, ties
+//! in list order, and the first match wins over mapping and family scenes.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use super::sanitize_scene_string;
+use crate::app_detector::types::ContextFamily;
+
+pub const SCENE_RULES_MAX_COUNT: usize = 50;
+const SCENE_RULE_NAME_MAX_CHARS: usize = 80;
+const SCENE_RULE_VALUES_MAX_COUNT: usize = 20;
+const SCENE_RULE_VALUE_MAX_CHARS: usize = 120;
+const LANGUAGE_MAX_CHARS: usize = 8;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct SceneRule {
+    pub id: String,
+    pub name: String,
+    pub enabled: bool,
+    /// Lower values are evaluated first.
+    pub priority: u32,
+    pub scene_id: String,
+    pub conditions: SceneRuleConditions,
+}
+
+impl Default for SceneRule {
+    fn default() -> Self {
+        Self {
+            id: String::new(),
+            name: String::new(),
+            enabled: true,
+            priority: 0,
+            scene_id: String::new(),
+            conditions: SceneRuleConditions::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct SceneRuleConditions {
+    pub families: Vec<ContextFamily>,
+    pub profile_ids: Vec<String>,
+    /// Custom app mapping ids.
+    pub mapping_ids: Vec<String>,
+    /// Primary language subtags such as `en` or `zh`.
+    pub languages: Vec<String>,
+    /// ISO weekday numbers, 1 for Monday through 7 for Sunday.
+    pub weekdays: Vec<u8>,
+    pub time_window: Option<SceneTimeWindow>,
+    pub selected_text: Option<bool>,
+}
+
+/// Local `HH:MM` window, end exclusive. A start after the end wraps past
+/// midnight.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct SceneTimeWindow {
+    pub start: String,
+    pub end: String,
+}
+
+impl SceneTimeWindow {
+    fn contains(&self, minute_of_day: u16) -> bool {
+        let (Some(start), Some(end)) = (parse_minutes(&self.start), parse_minutes(&self.end))
+        else {
+            return false;
+        };
+        if start <= end {
+            (start..end).contains(&minute_of_day)
+        } else {
+            minute_of_day >= start || minute_of_day < end
+        }
+    }
+}
+
+/// What a rule can see about the recording being polished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SceneRuleInput<'a> {
+    pub family: ContextFamily,
+    pub profile_id: &'a str,
+    pub language: Option<&'a str>,
+    pub weekday: u8,
+    pub minute_of_day: u16,
+    pub has_selected_text: bool,
+}
+
+impl SceneRuleConditions {
+    fn matches(&self, input: &SceneRuleInput<'_>) -> bool {
+        (self.families.is_empty() || self.families.contains(&input.family))
+            && (self.profile_ids.is_empty()
+                || self.profile_ids.iter().any(|id| id == input.profile_id))
+            && (self.mapping_ids.is_empty()
+                || self.mapping_ids.iter().any(|id| {
+                    crate::app_detector::user_mappings::mapping_profile_id(id) == input.profile_id
+                }))
+            && (self.languages.is_empty()
+                || input
+                    .language
+                    .is_some_and(|language| self.languages.iter().any(|value| value == language)))
+            && (self.weekdays.is_empty() || self.weekdays.contains(&input.weekday))
+            && self
+                .time_window
+                .as_ref()
+                .is_none_or(|window| window.contains(input.minute_of_day))
+            && self
+                .selected_text
+                .is_none_or(|selected| selected == input.has_selected_text)
+    }
+}
+
+/// The first enabled rule, by priority, whose conditions all hold.
+pub(crate) fn matching_scene_rule<'a>(
+    rules: &'a [SceneRule],
+    input: &SceneRuleInput<'_>,
+) -> Option<&'a SceneRule> {
+    rules
+        .iter()
+        .enumerate()
+        .filter(|(_, rule)| rule.enabled && rule.conditions.matches(input))
+        .min_by_key(|(index, rule)| (rule.priority, *index))
+        .map(|(_, rule)| rule)
+}
+
+/// Language of a transcript: the configured STT language when one is set,
+/// otherwise a guess from the script. Latin script is shared by too many
+/// languages to guess from, so it yields `None` and only rules without a
+/// language condition apply.
+pub(crate) fn speech_language(stt_language: &str, text: &str) -> Option<String> {
+    let configured = normalize_language(stt_language);
+    if !matches!(configured.as_str(), "" | "multi" | "auto" | "automatic") {
+        return Some(configured);
+    }
+    use crate::spoken_formatting::{is_han, is_hangul, is_kana};
+    if text.chars().any(is_kana) {
+        Some("ja".to_string())
+    } else if text.chars().any(is_hangul) {
+        Some("ko".to_string())
+    } else if text.chars().any(is_han) {
+        Some("zh".to_string())
+    } else if text
+        .chars()
+        .any(|character| ('\u{0400}'..='\u{04FF}').contains(&character))
+    {
+        Some("ru".to_string())
+    } else {
+        None
+    }
+}
+
+fn normalize_language(value: &str) -> String {
+    value
+        .trim()
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+fn parse_minutes(value: &str) -> Option<u16> {
+    let (hours, minutes) = value.trim().split_once(':')?;
+    if hours.len() != 2 || minutes.len() != 2 {
+        return None;
+    }
+    let hours: u16 = hours.parse().ok()?;
+    let minutes: u16 = minutes.parse().ok()?;
+    (hours < 24 && minutes < 60).then_some(hours * 60 + minutes)
+}
+
+fn sanitize_values(values: &mut Vec<String>, normalize: impl Fn(&str) -> String) {
+    let mut seen = HashSet::new();
+    *values = values
+        .iter()
+        .map(|value| normalize(value))
+        .filter(|value| !value.is_empty() && seen.insert(value.clone()))
+        .take(SCENE_RULE_VALUES_MAX_COUNT)
+        .collect();
+}
+
+/// Drops rules without a name, with an unknown scene or with conditions that
+/// could never be met, and trims everything else to the stored limits.
+pub(crate) fn sanitize_scene_rules(
+    rules: &mut Vec<SceneRule>,
+    scene_exists: impl Fn(&str) -> bool,
+) {
+    let mut seen_ids = HashSet::new();
+    for rule in rules.iter_mut() {
+        rule.id = sanitize_scene_string(&rule.id, super::SCENE_ID_MAX_CHARS);
+        rule.name = sanitize_scene_string(&rule.name, SCENE_RULE_NAME_MAX_CHARS);
+        rule.scene_id = sanitize_scene_string(&rule.scene_id, super::SCENE_ID_MAX_CHARS);
+
+        let conditions = &mut rule.conditions;
+        let mut seen_families = HashSet::new();
+        conditions
+            .families
+            .retain(|family| seen_families.insert(*family));
+        sanitize_values(&mut conditions.profile_ids, |value| {
+            sanitize_scene_string(value, SCENE_RULE_VALUE_MAX_CHARS)
+        });
+        sanitize_values(&mut conditions.mapping_ids, |value| {
+            sanitize_scene_string(value, SCENE_RULE_VALUE_MAX_CHARS)
+        });
+        sanitize_values(&mut conditions.languages, |value| {
+            let language = normalize_language(value);
+            if language.len() <= LANGUAGE_MAX_CHARS
+                && language.bytes().all(|byte| byte.is_ascii_lowercase())
+            {
+                language
+            } else {
+                String::new()
+            }
+        });
+        conditions.weekdays.sort_unstable();
+        conditions.weekdays.dedup();
+        conditions.weekdays.retain(|day| (1..=7).contains(day));
+        if let Some(window) = conditions.time_window.as_mut() {
+            window.start = window.start.trim().to_string();
+            window.end = window.end.trim().to_string();
+        }
+    }
+    rules.retain(|rule| {
+        !rule.id.is_empty()
+            && !rule.name.is_empty()
+            && scene_exists(&rule.scene_id)
+            && rule.conditions.time_window.as_ref().is_none_or(|window| {
+                parse_minutes(&window.start).is_some()
+                    && parse_minutes(&window.end).is_some()
+                    && window.start != window.end
+            })
+            && seen_ids.insert(rule.id.clone())
+    });
+    rules.truncate(SCENE_RULES_MAX_COUNT);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(id: &str, priority: u32, conditions: SceneRuleConditions) -> SceneRule {
+        SceneRule {
+            id: id.to_string(),
+            name: id.to_string(),
+            priority,
+            scene_id: format!("scene_{id}"),
+            conditions,
+            ..SceneRule::default()
+        }
+    }
+
+    fn input(minute_of_day: u16) -> SceneRuleInput<'static> {
+        SceneRuleInput {
+            family: ContextFamily::WorkChat,
+            profile_id: "chat.slack",
+            language: Some("en"),
+            weekday: 2,
+            minute_of_day,
+            has_selected_text: false,
+        }
+    }
+
+    #[test]
+    fn scene_rules_match_in_priority_order_then_list_order() {
+        let rules = vec![
+            rule("catch_all", 10, SceneRuleConditions::default()),
+            rule(
+                "slack_workday",
+                1,
+                SceneRuleConditions {
+                    profile_ids: vec!["chat.slack".to_string()],
+                    weekdays: vec![1, 2, 3, 4, 5],
+                    ..SceneRuleConditions::default()
+                },
+            ),
+            rule("same_priority_later", 1, SceneRuleConditions::default()),
+        ];
+
+        assert_eq!(
+            matching_scene_rule(&rules, &input(600)).map(|rule| rule.id.as_str()),
+            Some("slack_workday")
+        );
+        let weekend = SceneRuleInput {
+            weekday: 6,
+            ..input(600)
+        };
+        assert_eq!(
+            matching_scene_rule(&rules, &weekend).map(|rule| rule.id.as_str()),
+            Some("same_priority_later")
+        );
+    }
+
+    #[test]
+    fn scene_rule_conditions_cover_language_selection_mapping_and_time() {
+        let night = SceneRuleConditions {
+            families: vec![ContextFamily::WorkChat],
+            languages: vec!["zh".to_string()],
+            time_window: Some(SceneTimeWindow {
+                start: "22:00".to_string(),
+                end: "06:30".to_string(),
+            }),
+            selected_text: Some(true),
+            ..SceneRuleConditions::default()
+        };
+        let chinese_selection = SceneRuleInput {
+            language: Some("zh"),
+            has_selected_text: true,
+            ..input(23 * 60)
+        };
+        assert!(night.matches(&chinese_selection));
+        assert!(night.matches(&SceneRuleInput {
+            minute_of_day: 6 * 60 + 29,
+            ..chinese_selection
+        }));
+        assert!(!night.matches(&SceneRuleInput {
+            minute_of_day: 6 * 60 + 30,
+            ..chinese_selection
+        }));
+        assert!(!night.matches(&SceneRuleInput {
+            has_selected_text: false,
+            ..chinese_selection
+        }));
+        assert!(!night.matches(&SceneRuleInput {
+            language: None,
+            ..chinese_selection
+        }));
+
+        let mapping = SceneRuleConditions {
+            mapping_ids: vec!["4f2c-91".to_string()],
+            ..SceneRuleConditions::default()
+        };
+        assert!(mapping.matches(&SceneRuleInput {
+            profile_id: "user.4f2c91",
+            ..input(600)
+        }));
+        assert!(!mapping.matches(&input(600)));
+    }
+
+    #[test]
+    fn scene_rules_sanitize_conditions_and_drop_unusable_rules() {
+        let mut rules = vec![
+            SceneRule {
+                conditions: SceneRuleConditions {
+                    languages: vec![" en-US ".to_string(), "EN".to_string(), "x1".to_string()],
+                    weekdays: vec![5, 0, 1, 5, 9],
+                    ..SceneRuleConditions::default()
+                },
+                ..rule(" kept ", 0, SceneRuleConditions::default())
+            },
+            rule("missing_scene", 0, SceneRuleConditions::default()),
+            rule(
+                "empty_window",
+                0,
+                SceneRuleConditions {
+                    time_window: Some(SceneTimeWindow {
+                        start: "09:00".to_string(),
+                        end: "09:00".to_string(),
+                    }),
+                    ..SceneRuleConditions::default()
+                },
+            ),
+            rule(
+                "bad_time",
+                0,
+                SceneRuleConditions {
+                    time_window: Some(SceneTimeWindow {
+                        start: "9:00".to_string(),
+                        end: "24:00".to_string(),
+                    }),
+                    ..SceneRuleConditions::default()
+                },
+            ),
+        ];
+
+        sanitize_scene_rules(&mut rules, |scene_id| scene_id != "scene_missing_scene");
+
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].id, "kept");
+        assert_eq!(rules[0].conditions.languages, vec!["en".to_string()]);
+        assert_eq!(rules[0].conditions.weekdays, vec![1, 5]);
+    }
+
+    #[test]
+    fn speech_language_prefers_the_configured_language_then_script() {
+        assert_eq!(speech_language("zh-TW", "hello").as_deref(), Some("zh"));
+        assert_eq!(
+            speech_language("multi", "こんにちは").as_deref(),
+            Some("ja")
+        );
+        assert_eq!(speech_language("auto", "안녕하세요").as_deref(), Some("ko"));
+        assert_eq!(speech_language("multi", "你好").as_deref(), Some("zh"));
+        assert_eq!(speech_language("multi", "привет").as_deref(), Some("ru"));
+        assert_eq!(speech_language("multi", "hello"), None);
+        assert_eq!(speech_language("auto", "Guten Morgen"), None);
+        assert_eq!(
+            speech_language("en-US", "Guten Morgen").as_deref(),
+            Some("en")
+        );
+        assert_eq!(speech_language("multi", "123"), None);
+    }
+}
@@ -4,7 +4,7 @@ use tokio_tungstenite::{connect_async, tungstenite::Message};
 
 use crate::error::AppError;
 
-use super::{SttConfig, SttProvider, TranscriptEvent};
+use super::{vocabulary, SttConfig, SttProvider, TranscriptEvent};
 
 type WsStream =
     tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
@@ -25,12 +25,20 @@ impl AssemblyAiProvider {
     }
 
     fn build_url(config: &SttConfig) -> String {
-        format!(
+        let mut endpoint = format!(
             "wss://streaming.assemblyai.com/v3/ws?\
              sample_rate={}&\
              format_turns=true",
             config.sample_rate
-        )
+        );
+        // Universal streaming takes word boost as a JSON array in `keyterms_prompt`.
+        let terms = vocabulary::hint_terms(&config.vocabulary).collect::<Vec<_>>();
+        if !terms.is_empty() {
+            let keyterms = serde_json::to_string(&terms).unwrap_or_default();
+            endpoint.push_str("&keyterms_prompt=");
+            endpoint.extend(url::form_urlencoded::byte_serialize(keyterms.as_bytes()));
+        }
+        endpoint
     }
 }
 
@@ -162,3 +170,24 @@ impl SttProvider for AssemblyAiProvider {
         "AssemblyAI"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_url_sends_vocabulary_as_keyterms_prompt() {
+        let config = SttConfig {
+            vocabulary: vec![vocabulary::VocabularyHint {
+                word: "OpenTypeless".to_string(),
+                pronunciation: None,
+            }],
+            ..SttConfig::default()
+        };
+
+        let url = AssemblyAiProvider::build_url(&config);
+
+        assert!(url.ends_with("&keyterms_prompt=%5B%22OpenTypeless%22%5D"));
+        assert!(!AssemblyAiProvider::build_url(&SttConfig::default()).contains("keyterms_prompt"));
+    }
+}
@@ -48,6 +48,7 @@ pub struct SttRecordingCapability {
     pub max_upload_bytes: Option<u64>,
     pub source: RecordingLimitSource,
     pub explanation_key: String,
+    pub supports_vocabulary_hints: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -156,6 +157,9 @@ fn capability(
         max_upload_bytes,
         source,
         explanation_key: explanation_key.to_string(),
+        supports_vocabulary_hints: super::vocabulary::provider_supports_vocabulary_hints(
+            provider_id,
+        ),
     }
 }
 
@@ -179,6 +183,16 @@ fn static_provider_capability(provider_id: &str) -> SttRecordingCapability {
             RecordingLimitSource::Provider,
             "recordingLimits.reasons.appleSpeech",
         ),
+        // Offline Whisper holds the whole recording as PCM until it stops.
+        super::config::LOCAL_WHISPER_PROVIDER => capability(
+            provider_id,
+            SttTransport::LocalBuffered,
+            600,
+            720,
+            Some(CLIENT_FILE_BUFFER_BYTES),
+            RecordingLimitSource::ClientBuffer,
+            "recordingLimits.reasons.clientBuffer",
+        ),
         "groq-whisper" | "openai-whisper" | "siliconflow" => capability(
             provider_id,
             SttTransport::FileUpload,
@@ -321,6 +335,10 @@ fn managed_capability(
     }
 }
 
+pub fn provider_transport(provider_id: &str) -> SttTransport {
+    static_provider_capability(provider_id).transport
+}
+
 pub fn resolve_recording_limit(
     config: &AppConfig,
     managed_state: Option<&ManagedSttCapabilityState>,
@@ -415,6 +433,13 @@ mod tests {
                 60,
                 RecordingLimitSource::Provider,
             ),
+            (
+                "local-whisper",
+                SttTransport::LocalBuffered,
+                600,
+                720,
+                RecordingLimitSource::ClientBuffer,
+            ),
             (
                 "groq-whisper",
                 SttTransport::FileUpload,
@@ -480,6 +505,11 @@ mod tests {
             assert_eq!(resolved.capability.hard_max_seconds, hard, "{provider}");
             assert_eq!(resolved.capability.source, source, "{provider}");
             assert_eq!(resolved.effective_max_seconds, recommended, "{provider}");
+            assert_eq!(
+                resolved.capability.supports_vocabulary_hints,
+                !matches!(provider, "glm-asr" | "apple-speech" | "siliconflow"),
+                "{provider}"
+            );
         }
     }
 
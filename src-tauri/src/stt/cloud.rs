@@ -562,6 +562,8 @@ mod tests {
             resource_id: None,
             operation_id: Some("operation-1".to_string()),
             managed_audio: Some(managed_config()),
+            vocabulary: Vec::new(),
+            local_model_path: None,
         }
     }
 
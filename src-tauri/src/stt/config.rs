@@ -9,6 +9,7 @@ use super::whisper_compat::WhisperCompatConfig;
 
 pub const APPLE_SPEECH_PROVIDER: &str = "apple-speech";
 pub const CUSTOM_WHISPER_PROVIDER: &str = "custom-whisper";
+pub const LOCAL_WHISPER_PROVIDER: &str = "local-whisper";
 pub const CUSTOM_WHISPER_PRESET_SPEACHES: &str = "speaches";
 pub const CUSTOM_WHISPER_PRESET_CUSTOM: &str = "custom";
 pub const DEFAULT_CUSTOM_WHISPER_BASE_URL: &str = "http://localhost:8000/v1";
@@ -105,7 +106,7 @@ pub fn build_known_whisper_config(provider: &str) -> Option<WhisperCompatConfig>
 pub fn stt_provider_requires_api_key(provider: &str) -> bool {
     !matches!(
         provider,
-        "cloud" | CUSTOM_WHISPER_PROVIDER | APPLE_SPEECH_PROVIDER
+        "cloud" | CUSTOM_WHISPER_PROVIDER | APPLE_SPEECH_PROVIDER | LOCAL_WHISPER_PROVIDER
     )
 }
 
@@ -171,6 +172,12 @@ mod tests {
         assert!(get_whisper_config(APPLE_SPEECH_PROVIDER).is_none());
     }
 
+    #[test]
+    fn offline_whisper_does_not_require_api_key_or_endpoint() {
+        assert!(!stt_provider_requires_api_key(LOCAL_WHISPER_PROVIDER));
+        assert!(get_whisper_config(LOCAL_WHISPER_PROVIDER).is_none());
+    }
+
     #[test]
     fn test_normalize_custom_whisper_base_url() {
         let endpoint = normalize_custom_whisper_endpoint("http://localhost:8000/v1").unwrap();
@@ -4,7 +4,7 @@ use tokio_tungstenite::{connect_async, tungstenite::Message};
 
 use crate::error::AppError;
 
-use super::{SttConfig, SttProvider, TranscriptEvent};
+use super::{vocabulary, SttConfig, SttProvider, TranscriptEvent};
 
 type WsStream =
     tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
@@ -26,7 +26,7 @@ impl DeepgramProvider {
 
     fn build_url(config: &SttConfig) -> String {
         let lang = config.language.as_deref().unwrap_or("multi");
-        format!(
+        let mut endpoint = format!(
             "wss://api.deepgram.com/v1/listen?\
              model=nova-3&\
              smart_format={}&\
@@ -39,7 +39,13 @@ impl DeepgramProvider {
              sample_rate={}&\
              channels=1",
             config.smart_format, lang, config.sample_rate
-        )
+        );
+        // Nova-3 biases recognition through repeated `keyterm` parameters.
+        for term in vocabulary::hint_terms(&config.vocabulary) {
+            endpoint.push_str("&keyterm=");
+            endpoint.extend(url::form_urlencoded::byte_serialize(term.as_bytes()));
+        }
+        endpoint
     }
 }
 
@@ -168,6 +174,29 @@ impl SttProvider for DeepgramProvider {
 mod tests {
     use super::*;
 
+    #[test]
+    fn build_url_adds_encoded_keyterms_for_vocabulary_hints() {
+        let config = SttConfig {
+            vocabulary: vec![
+                vocabulary::VocabularyHint {
+                    word: "OpenTypeless".to_string(),
+                    pronunciation: Some("open typeless".to_string()),
+                },
+                vocabulary::VocabularyHint {
+                    word: "Tauri & Rust".to_string(),
+                    pronunciation: None,
+                },
+            ],
+            ..SttConfig::default()
+        };
+
+        let url = DeepgramProvider::build_url(&config);
+
+        assert!(url.contains("model=nova-3&"));
+        assert!(url.ends_with("&keyterm=OpenTypeless&keyterm=Tauri+%26+Rust"));
+        assert!(!DeepgramProvider::build_url(&SttConfig::default()).contains("keyterm"));
+    }
+
     #[test]
     fn parses_speech_final_message_as_final_transcript() {
         let message = serde_json::json!({
@@ -0,0 +1,431 @@
+//! Ordered STT fallback chain.
+//!
+//! The primary provider runs live. Fallbacks must be buffered providers: they
+//! receive a replay of everything captured so far when the current provider
+//! fails, then transcribe on `disconnect` as usual.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+
+use crate::error::AppError;
+use crate::storage::AppConfig;
+
+use super::capabilities::{provider_transport, SttTransport, CLIENT_FILE_BUFFER_BYTES};
+use super::{SttConfig, SttProvider, TranscriptEvent};
+
+/// Replay chunks match the capture cadence (20 ms at 16 kHz).
+const REPLAY_CHUNK_BYTES: usize = 640;
+
+/// Id of the provider currently serving the session, readable after the
+/// wrapper has been moved into the STT task.
+pub type ActiveSttProvider = Arc<Mutex<Option<String>>>;
+
+pub struct FailoverCandidate {
+    pub provider_id: String,
+    pub provider: Box<dyn SttProvider>,
+    pub config: SttConfig,
+}
+
+/// Configured fallbacks in order, without the primary, duplicates or
+/// streaming providers (those cannot transcribe a replay on disconnect).
+pub fn fallback_provider_ids(config: &AppConfig) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::new();
+    for id in &config.stt_fallback_providers {
+        let id = id.trim();
+        if id.is_empty()
+            || id == config.stt_provider
+            || ids.iter().any(|existing| existing == id)
+            || provider_transport(id) == SttTransport::Streaming
+        {
+            continue;
+        }
+        ids.push(id.to_string());
+    }
+    ids
+}
+
+/// Config view used to resolve a fallback's credentials and provider-specific
+/// fields. The legacy inline key belongs to the primary, so it is dropped.
+pub fn fallback_source_config(config: &AppConfig, provider_id: &str) -> AppConfig {
+    let mut source = config.clone();
+    source.stt_provider = provider_id.to_string();
+    source.stt_api_key.clear();
+    source
+}
+
+pub struct FailoverSttProvider {
+    active: FailoverCandidate,
+    fallbacks: VecDeque<FailoverCandidate>,
+    replay: Vec<u8>,
+    replay_overflowed: bool,
+    emitted_final: bool,
+    active_slot: ActiveSttProvider,
+}
+
+impl FailoverSttProvider {
+    pub fn new(
+        primary_id: String,
+        primary: Box<dyn SttProvider>,
+        fallbacks: Vec<FailoverCandidate>,
+        active_slot: ActiveSttProvider,
+    ) -> Self {
+        *active_slot.lock().unwrap_or_else(|e| e.into_inner()) = None;
+        Self {
+            active: FailoverCandidate {
+                provider_id: primary_id,
+                provider: primary,
+                config: SttConfig::default(),
+            },
+            fallbacks: fallbacks.into(),
+            replay: Vec::new(),
+            replay_overflowed: false,
+            emitted_final: false,
+            active_slot,
+        }
+    }
+
+    fn record_active(&self) {
+        *self.active_slot.lock().unwrap_or_else(|e| e.into_inner()) =
+            Some(self.active.provider_id.clone());
+    }
+
+    /// Text already delivered by the failed provider would be duplicated by a
+    /// replay, so failover is only possible before the first final transcript.
+    fn can_fail_over(&self, error: &AppError) -> bool {
+        error.is_retryable()
+            && !self.fallbacks.is_empty()
+            && !self.replay_overflowed
+            && !self.emitted_final
+    }
+
+    async fn fail_over(&mut self, error: AppError) -> Result<(), AppError> {
+        tracing::warn!(
+            "STT provider {} failed, trying fallback: {}",
+            self.active.provider_id,
+            error
+        );
+        // Candidates are only removed once their attempt finishes, so a caller
+        // dropping this future mid-replay leaves the chain intact.
+        while let Some(next) = self.fallbacks.front_mut() {
+            let result = Self::connect_and_replay(next, &self.replay).await;
+            let Some(next) = self.fallbacks.pop_front() else {
+                break;
+            };
+            match result {
+                Ok(()) => {
+                    tracing::info!("STT failed over to {}", next.provider_id);
+                    self.active = next;
+                    self.record_active();
+                    return Ok(());
+                }
+                Err(fallback_error) => {
+                    tracing::warn!(
+                        "STT fallback {} unavailable: {}",
+                        next.provider_id,
+                        fallback_error
+                    );
+                }
+            }
+        }
+        Err(error)
+    }
+
+    async fn connect_and_replay(
+        candidate: &mut FailoverCandidate,
+        replay: &[u8],
+    ) -> Result<(), AppError> {
+        candidate.provider.connect(&candidate.config).await?;
+        for chunk in replay.chunks(REPLAY_CHUNK_BYTES) {
+            candidate.provider.send_audio(chunk).await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SttProvider for FailoverSttProvider {
+    async fn connect(&mut self, config: &SttConfig) -> Result<(), AppError> {
+        self.active.config = config.clone();
+        match self.active.provider.connect(config).await {
+            Ok(()) => {
+                self.record_active();
+                Ok(())
+            }
+            Err(error) if self.can_fail_over(&error) => self.fail_over(error).await,
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn send_audio(&mut self, chunk: &[u8]) -> Result<(), AppError> {
+        if !self.fallbacks.is_empty() && !self.replay_overflowed {
+            if (self.replay.len() + chunk.len()) as u64 > CLIENT_FILE_BUFFER_BYTES {
+                tracing::warn!("STT replay buffer full; fallback disabled for this recording");
+                self.replay_overflowed = true;
+                self.replay = Vec::new();
+            } else {
+                self.replay.extend_from_slice(chunk);
+            }
+        }
+        match self.active.provider.send_audio(chunk).await {
+            Err(error) if self.can_fail_over(&error) => self.fail_over(error).await,
+            result => result,
+        }
+    }
+
+    async fn recv_transcript(&mut self) -> Result<Option<TranscriptEvent>, AppError> {
+        loop {
+            match self.active.provider.recv_transcript().await {
+                Err(error) if self.can_fail_over(&error) => self.fail_over(error).await?,
+                result => {
+                    if matches!(result, Ok(Some(TranscriptEvent::Final { .. }))) {
+                        self.emitted_final = true;
+                    }
+                    return result;
+                }
+            }
+        }
+    }
+
+    async fn disconnect(&mut self) -> Result<Option<String>, AppError> {
+        loop {
+            match self.active.provider.disconnect().await {
+                Err(error) if self.can_fail_over(&error) => self.fail_over(error).await?,
+                result => return result,
+            }
+        }
+    }
+
+    fn recording_limit_override_seconds(&self) -> Option<u32> {
+        self.active.provider.recording_limit_override_seconds()
+    }
+
+    fn recording_limit_override_explanation_key(&self) -> Option<&'static str> {
+        self.active
+            .provider
+            .recording_limit_override_explanation_key()
+    }
+
+    fn name(&self) -> &str {
+        self.active.provider.name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Script {
+        connect_error: Option<AppError>,
+        disconnect_error: Option<AppError>,
+        transcript: Option<String>,
+    }
+
+    struct ScriptedProvider {
+        name: &'static str,
+        script: Script,
+        received: Arc<Mutex<Vec<u8>>>,
+    }
+
+    fn scripted(name: &'static str, script: Script) -> (Box<dyn SttProvider>, Arc<Mutex<Vec<u8>>>) {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        (
+            Box::new(ScriptedProvider {
+                name,
+                script,
+                received: received.clone(),
+            }),
+            received,
+        )
+    }
+
+    fn candidate(id: &str, provider: Box<dyn SttProvider>) -> FailoverCandidate {
+        FailoverCandidate {
+            provider_id: id.to_string(),
+            provider,
+            config: SttConfig::default(),
+        }
+    }
+
+    #[async_trait]
+    impl SttProvider for ScriptedProvider {
+        async fn connect(&mut self, _config: &SttConfig) -> Result<(), AppError> {
+            self.script.connect_error.take().map_or(Ok(()), Err)
+        }
+
+        async fn send_audio(&mut self, chunk: &[u8]) -> Result<(), AppError> {
+            self.received.lock().unwrap().extend_from_slice(chunk);
+            Ok(())
+        }
+
+        async fn recv_transcript(&mut self) -> Result<Option<TranscriptEvent>, AppError> {
+            std::future::pending().await
+        }
+
+        async fn disconnect(&mut self) -> Result<Option<String>, AppError> {
+            match self.script.disconnect_error.take() {
+                Some(error) => Err(error),
+                None => Ok(self.script.transcript.clone()),
+            }
+        }
+
+        fn name(&self) -> &str {
+            self.name
+        }
+    }
+
+    #[tokio::test]
+    async fn disconnect_failure_replays_captured_audio_into_the_next_provider() {
+        let (primary, _) = scripted(
+            "primary",
+            Script {
+                disconnect_error: Some(AppError::Network("reset".to_string())),
+                ..Script::default()
+            },
+        );
+        let (broken, _) = scripted(
+            "broken",
+            Script {
+                connect_error: Some(AppError::Auth("no key".to_string())),
+                ..Script::default()
+            },
+        );
+        let (backup, backup_audio) = scripted(
+            "backup",
+            Script {
+                transcript: Some("hello".to_string()),
+                ..Script::default()
+            },
+        );
+        let active = ActiveSttProvider::default();
+        let mut provider = FailoverSttProvider::new(
+            "groq-whisper".to_string(),
+            primary,
+            vec![
+                candidate("custom-whisper", broken),
+                candidate("openai-whisper", backup),
+            ],
+            active.clone(),
+        );
+
+        provider.connect(&SttConfig::default()).await.unwrap();
+        assert_eq!(active.lock().unwrap().as_deref(), Some("groq-whisper"));
+        let audio: Vec<u8> = (0..2_000u32).map(|i| i as u8).collect();
+        provider.send_audio(&audio[..1_000]).await.unwrap();
+        provider.send_audio(&audio[1_000..]).await.unwrap();
+
+        assert_eq!(
+            provider.disconnect().await.unwrap().as_deref(),
+            Some("hello")
+        );
+        assert_eq!(*backup_audio.lock().unwrap(), audio);
+        assert_eq!(active.lock().unwrap().as_deref(), Some("openai-whisper"));
+        assert_eq!(provider.name(), "backup");
+    }
+
+    #[tokio::test]
+    async fn connect_failure_moves_on_but_non_retryable_errors_do_not() {
+        let (primary, _) = scripted(
+            "primary",
+            Script {
+                connect_error: Some(AppError::Timeout(std::time::Duration::from_secs(5))),
+                ..Script::default()
+            },
+        );
+        let (backup, _) = scripted("backup", Script::default());
+        let active = ActiveSttProvider::default();
+        let mut provider = FailoverSttProvider::new(
+            "deepgram".to_string(),
+            primary,
+            vec![candidate("groq-whisper", backup)],
+            active.clone(),
+        );
+        provider.connect(&SttConfig::default()).await.unwrap();
+        assert_eq!(active.lock().unwrap().as_deref(), Some("groq-whisper"));
+
+        let (primary, _) = scripted(
+            "primary",
+            Script {
+                connect_error: Some(AppError::Auth("bad key".to_string())),
+                ..Script::default()
+            },
+        );
+        let (backup, _) = scripted("backup", Script::default());
+        let mut provider = FailoverSttProvider::new(
+            "deepgram".to_string(),
+            primary,
+            vec![candidate("groq-whisper", backup)],
+            ActiveSttProvider::default(),
+        );
+        assert!(matches!(
+            provider.connect(&SttConfig::default()).await,
+            Err(AppError::Auth(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn exhausted_chain_returns_the_original_error() {
+        let (primary, _) = scripted(
+            "primary",
+            Script {
+                disconnect_error: Some(AppError::Api {
+                    status: 503,
+                    body: "busy".to_string(),
+                }),
+                ..Script::default()
+            },
+        );
+        let (backup, _) = scripted(
+            "backup",
+            Script {
+                connect_error: Some(AppError::Network("offline".to_string())),
+                ..Script::default()
+            },
+        );
+        let mut provider = FailoverSttProvider::new(
+            "glm-asr".to_string(),
+            primary,
+            vec![candidate("groq-whisper", backup)],
+            ActiveSttProvider::default(),
+        );
+        provider.connect(&SttConfig::default()).await.unwrap();
+        provider.send_audio(&[0; 640]).await.unwrap();
+
+        assert!(matches!(
+            provider.disconnect().await,
+            Err(AppError::Api { status: 503, .. })
+        ));
+    }
+
+    #[test]
+    fn fallback_ids_skip_primary_duplicates_and_streaming_providers() {
+        let config = AppConfig {
+            stt_provider: "groq-whisper".to_string(),
+            stt_fallback_providers: vec![
+                " openai-whisper ".to_string(),
+                "groq-whisper".to_string(),
+                "deepgram".to_string(),
+                "openai-whisper".to_string(),
+                String::new(),
+                "local-whisper".to_string(),
+            ],
+            ..AppConfig::default()
+        };
+
+        assert_eq!(
+            fallback_provider_ids(&config),
+            vec!["openai-whisper".to_string(), "local-whisper".to_string()]
+        );
+        assert!(fallback_source_config(
+            &AppConfig {
+                stt_api_key: "primary-key".to_string(),
+                ..config
+            },
+            "openai-whisper"
+        )
+        .stt_api_key
+        .is_empty());
+    }
+}
@@ -0,0 +1,315 @@
+//! In-process Whisper transcription backed by whisper.cpp.
+//!
+//! The engine is only compiled with the `local-whisper` cargo feature; without
+//! it the provider still exists so configs and diagnostics round-trip, but
+//! `connect` reports that the build does not include it.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use crate::error::AppError;
+
+use super::config::LOCAL_WHISPER_PROVIDER;
+use super::{vocabulary, SttConfig, SttProvider, TranscriptEvent};
+
+/// whisper.cpp only accepts 16 kHz mono input.
+pub const LOCAL_WHISPER_SAMPLE_RATE: u32 = 16_000;
+/// `GGML_FILE_MAGIC` (0x67676d6c) as stored little-endian on disk.
+const GGML_FILE_MAGIC: [u8; 4] = *b"lmgg";
+
+pub fn is_available_in_build() -> bool {
+    cfg!(feature = "local-whisper")
+}
+
+/// Checks that `path` points at a readable GGML Whisper model.
+pub fn validate_model_path(path: &str) -> Result<PathBuf, String> {
+    let trimmed = path.trim();
+    if trimmed.is_empty() {
+        return Err("Model file is required for Offline Whisper".to_string());
+    }
+    let path = PathBuf::from(trimmed);
+    if !path.is_absolute() {
+        return Err("Model file must be an absolute path".to_string());
+    }
+    if !path.is_file() {
+        return Err(format!("Model file not found: {}", path.display()));
+    }
+    if !has_ggml_magic(&path)? {
+        return Err("Model file is not a GGML Whisper model".to_string());
+    }
+    Ok(path)
+}
+
+fn has_ggml_magic(path: &Path) -> Result<bool, String> {
+    let mut magic = [0u8; 4];
+    let mut file =
+        std::fs::File::open(path).map_err(|e| format!("Model file could not be opened: {e}"))?;
+    match file.read_exact(&mut magic) {
+        Ok(()) => Ok(magic == GGML_FILE_MAGIC),
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => Ok(false),
+        Err(e) => Err(format!("Model file could not be read: {e}")),
+    }
+}
+
+/// Whisper language code for the configured STT language, or `None` to let
+/// the model detect it.
+fn whisper_language(language: Option<&str>) -> Option<String> {
+    let language = language?.trim();
+    let code = language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    (!code.is_empty() && code != "multi").then_some(code)
+}
+
+fn pcm16_to_f32(pcm: &[u8]) -> Vec<f32> {
+    pcm.chunks_exact(2)
+        .map(|sample| i16::from_le_bytes([sample[0], sample[1]]) as f32 / 32_768.0)
+        .collect()
+}
+
+struct TranscriptionJob {
+    model_path: PathBuf,
+    samples: Vec<f32>,
+    language: Option<String>,
+    initial_prompt: Option<String>,
+}
+
+pub struct LocalWhisperProvider {
+    job: Option<TranscriptionJob>,
+    audio_buffer: Vec<u8>,
+}
+
+impl Default for LocalWhisperProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocalWhisperProvider {
+    pub fn new() -> Self {
+        Self {
+            job: None,
+            audio_buffer: Vec::new(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SttProvider for LocalWhisperProvider {
+    async fn connect(&mut self, config: &SttConfig) -> Result<(), AppError> {
+        if !is_available_in_build() {
+            return Err(AppError::Config(
+                "Offline Whisper is not included in this build".to_string(),
+            ));
+        }
+        if config.sample_rate != LOCAL_WHISPER_SAMPLE_RATE {
+            return Err(AppError::Config(format!(
+                "Offline Whisper requires {LOCAL_WHISPER_SAMPLE_RATE} Hz audio"
+            )));
+        }
+        let model_path = validate_model_path(config.local_model_path.as_deref().unwrap_or(""))
+            .map_err(AppError::Config)?;
+
+        self.job = Some(TranscriptionJob {
+            model_path,
+            samples: Vec::new(),
+            language: whisper_language(config.language.as_deref()),
+            initial_prompt: vocabulary::whisper_prompt(&config.vocabulary),
+        });
+        self.audio_buffer.clear();
+        Ok(())
+    }
+
+    async fn send_audio(&mut self, chunk: &[u8]) -> Result<(), AppError> {
+        self.audio_buffer.extend_from_slice(chunk);
+        Ok(())
+    }
+
+    async fn recv_transcript(&mut self) -> Result<Option<TranscriptEvent>, AppError> {
+        std::future::pending().await
+    }
+
+    async fn disconnect(&mut self) -> Result<Option<String>, AppError> {
+        let Some(mut job) = self.job.take() else {
+            return Ok(None);
+        };
+        if self.audio_buffer.is_empty() {
+            return Ok(None);
+        }
+
+        let audio = std::mem::take(&mut self.audio_buffer);
+        job.samples = pcm16_to_f32(crate::audio::vad::trim_silence(
+            &audio,
+            LOCAL_WHISPER_SAMPLE_RATE,
+        ));
+        let text = tauri::async_runtime::spawn_blocking(move || engine::transcribe(&job))
+            .await
+            .map_err(|e| AppError::Config(format!("Offline Whisper task failed: {e}")))?
+            .map_err(AppError::Config)?;
+
+        let text = text.trim().to_string();
+        Ok((!text.is_empty()).then_some(text))
+    }
+
+    fn name(&self) -> &str {
+        LOCAL_WHISPER_PROVIDER
+    }
+}
+
+#[cfg(feature = "local-whisper")]
+mod engine {
+    use std::path::{Path, PathBuf};
+    use std::sync::{Arc, Mutex};
+
+    use whisper_rs::{FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters};
+
+    use super::TranscriptionJob;
+
+    const MAX_THREADS: usize = 8;
+
+    /// Loading a model takes seconds, so the last one stays resident.
+    static LOADED_MODEL: Mutex<Option<(PathBuf, Arc<WhisperContext>)>> = Mutex::new(None);
+
+    fn context_for(path: &Path) -> Result<Arc<WhisperContext>, String> {
+        let mut loaded = LOADED_MODEL.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((loaded_path, context)) = loaded.as_ref() {
+            if loaded_path == path {
+                return Ok(context.clone());
+            }
+        }
+
+        let path_str = path
+            .to_str()
+            .ok_or_else(|| "Model path is not valid UTF-8".to_string())?;
+        let context =
+            WhisperContext::new_with_params(path_str, WhisperContextParameters::default())
+                .map_err(|e| format!("Offline Whisper model failed to load: {e}"))?;
+        let context = Arc::new(context);
+        *loaded = Some((path.to_path_buf(), context.clone()));
+        Ok(context)
+    }
+
+    pub(super) fn transcribe(job: &TranscriptionJob) -> Result<String, String> {
+        let context = context_for(&job.model_path)?;
+        let mut state = context
+            .create_state()
+            .map_err(|e| format!("Offline Whisper state failed: {e}"))?;
+
+        let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
+        params.set_language(Some(job.language.as_deref().unwrap_or("auto")));
+        if let Some(prompt) = job.initial_prompt.as_deref() {
+            params.set_initial_prompt(prompt);
+        }
+        let threads = std::thread::available_parallelism()
+            .map(|count| count.get().min(MAX_THREADS))
+            .unwrap_or(4);
+        params.set_n_threads(threads as i32);
+        params.set_no_context(true);
+        params.set_print_progress(false);
+        params.set_print_realtime(false);
+        params.set_print_special(false);
+        params.set_print_timestamps(false);
+
+        state
+            .full(params, &job.samples)
+            .map_err(|e| format!("Offline Whisper transcription failed: {e}"))?;
+
+        let segments = state
+            .full_n_segments()
+            .map_err(|e| format!("Offline Whisper transcription failed: {e}"))?;
+        let mut text = String::new();
+        for index in 0..segments {
+            let segment = state
+                .full_get_segment_text(index)
+                .map_err(|e| format!("Offline Whisper transcription failed: {e}"))?;
+            text.push_str(&segment);
+        }
+        Ok(text)
+    }
+}
+
+#[cfg(not(feature = "local-whisper"))]
+mod engine {
+    use super::TranscriptionJob;
+
+    pub(super) fn transcribe(_job: &TranscriptionJob) -> Result<String, String> {
+        Err("Offline Whisper is not included in this build".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_model(name: &str, contents: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "opentypeless-local-whisper-{}-{}.bin",
+            name,
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn model_path_must_be_an_existing_ggml_file() {
+        let model = temp_model("valid", b"lmgg\x01\x00\x00\x00");
+        let not_ggml = temp_model("onnx", b"\x08\x07\x12\x00");
+
+        assert_eq!(
+            validate_model_path(&format!("  {}  ", model.display())).unwrap(),
+            model
+        );
+        assert!(validate_model_path("   ").is_err());
+        assert!(validate_model_path("models/ggml-base.bin")
+            .unwrap_err()
+            .contains("absolute"));
+        assert!(validate_model_path(&not_ggml.display().to_string())
+            .unwrap_err()
+            .contains("GGML"));
+        assert!(validate_model_path(&std::env::temp_dir().display().to_string()).is_err());
+
+        let _ = std::fs::remove_file(model);
+        let _ = std::fs::remove_file(not_ggml);
+    }
+
+    #[test]
+    fn language_and_samples_are_converted_for_whisper() {
+        assert_eq!(whisper_language(Some("zh-TW")).as_deref(), Some("zh"));
+        assert_eq!(whisper_language(Some("EN")).as_deref(), Some("en"));
+        assert_eq!(whisper_language(Some("multi")), None);
+        assert_eq!(whisper_language(None), None);
+
+        let samples = pcm16_to_f32(&[0x00, 0x80, 0x00, 0x00, 0xff, 0x7f, 0x01]);
+        assert_eq!(samples.len(), 3);
+        assert_eq!(samples[0], -1.0);
+        assert_eq!(samples[1], 0.0);
+        assert!(samples[2] > 0.999);
+    }
+
+    #[tokio::test]
+    async fn connect_reports_missing_model_or_engine() {
+        let mut provider = LocalWhisperProvider::new();
+        let result = provider
+            .connect(&SttConfig {
+                local_model_path: Some("/nonexistent/ggml-base.bin".to_string()),
+                ..SttConfig::default()
+            })
+            .await;
+
+        let AppError::Config(message) = result.unwrap_err() else {
+            panic!("expected a config error");
+        };
+        if is_available_in_build() {
+            assert!(message.contains("not found"));
+        } else {
+            assert!(message.contains("not included"));
+        }
+        assert_eq!(provider.disconnect().await.unwrap(), None);
+    }
+}
@@ -4,7 +4,10 @@ pub mod capabilities;
 pub mod cloud;
 pub mod config;
 pub mod deepgram;
+pub mod failover;
+pub mod local_whisper;
 pub mod managed_audio;
+pub mod vocabulary;
 pub mod volcengine;
 pub mod whisper_compat;
 
@@ -13,6 +16,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::error::AppError;
 
+use vocabulary::VocabularyHint;
 use whisper_compat::{WhisperCompatConfig, WhisperCompatProvider};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,6 +28,12 @@ pub struct SttConfig {
     pub resource_id: Option<String>,
     pub operation_id: Option<String>,
     pub managed_audio: Option<managed_audio::ManagedAudioEncodingConfig>,
+    /// Dictionary terms forwarded to providers that support vocabulary biasing.
+    #[serde(default)]
+    pub vocabulary: Vec<VocabularyHint>,
+    /// GGML model file for the offline Whisper provider.
+    #[serde(default)]
+    pub local_model_path: Option<String>,
 }
 
 impl Default for SttConfig {
@@ -36,6 +46,8 @@ impl Default for SttConfig {
             resource_id: None,
             operation_id: None,
             managed_audio: None,
+            vocabulary: Vec::new(),
+            local_model_path: None,
         }
     }
 }
@@ -89,6 +101,7 @@ pub fn create_provider(
         volcengine::VOLCENGINE_DOUBAO_PROVIDER => {
             Ok(Box::new(volcengine::VolcengineDoubaoProvider::new()))
         }
+        config::LOCAL_WHISPER_PROVIDER => Ok(Box::new(local_whisper::LocalWhisperProvider::new())),
         config::CUSTOM_WHISPER_PROVIDER => {
             let wc = custom_whisper_config.ok_or_else(|| {
                 AppError::Config("Local / Custom Whisper is missing base URL or model".to_string())
@@ -145,6 +158,12 @@ mod tests {
         assert_eq!(provider.name(), "Apple Speech");
     }
 
+    #[test]
+    fn creates_offline_whisper_provider_without_network_config() {
+        let provider = create_provider("local-whisper", None, None).unwrap();
+        assert_eq!(provider.name(), config::LOCAL_WHISPER_PROVIDER);
+    }
+
     #[test]
     fn unknown_stt_provider_returns_error() {
         let result = create_provider("not-a-provider", None, None);
@@ -0,0 +1,172 @@
+use serde::{Deserialize, Serialize};
+
+use crate::storage::DictionaryEntry;
+
+use super::config::{CUSTOM_WHISPER_PROVIDER, LOCAL_WHISPER_PROVIDER};
+use super::volcengine::VOLCENGINE_DOUBAO_PROVIDER;
+
+/// Upper bound on hints sent per session. Deepgram and AssemblyAI both cap
+/// key terms at 100 and longer lists mostly add latency.
+pub const MAX_VOCABULARY_HINTS: usize = 100;
+/// AssemblyAI rejects key terms longer than 50 characters.
+pub const MAX_VOCABULARY_HINT_CHARS: usize = 50;
+/// Whisper only reads the last ~224 tokens of the prompt.
+const MAX_WHISPER_PROMPT_CHARS: usize = 600;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VocabularyHint {
+    pub word: String,
+    pub pronunciation: Option<String>,
+}
+
+/// Providers whose native request format carries vocabulary biasing. GLM-ASR
+/// and SenseVoice ignore the Whisper prompt field, and the managed cloud and
+/// Apple Speech providers do not accept client vocabulary yet.
+pub fn provider_supports_vocabulary_hints(provider_id: &str) -> bool {
+    matches!(
+        provider_id,
+        "deepgram"
+            | "assemblyai"
+            | VOLCENGINE_DOUBAO_PROVIDER
+            | "openai-whisper"
+            | "groq-whisper"
+            | CUSTOM_WHISPER_PROVIDER
+            | LOCAL_WHISPER_PROVIDER
+    )
+}
+
+/// Converts dictionary entries into bounded, de-duplicated STT hints.
+pub fn hints_from_dictionary(entries: &[DictionaryEntry]) -> Vec<VocabularyHint> {
+    let mut seen = std::collections::HashSet::new();
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let word = entry.word.split_whitespace().collect::<Vec<_>>().join(" ");
+            if word.is_empty() || word.chars().count() > MAX_VOCABULARY_HINT_CHARS {
+                return None;
+            }
+            if !seen.insert(word.to_lowercase()) {
+                return None;
+            }
+            let pronunciation = entry
+                .pronunciation
+                .as_deref()
+                .map(|value| value.split_whitespace().collect::<Vec<_>>().join(" "))
+                .filter(|value| !value.is_empty() && !value.eq_ignore_ascii_case(&word));
+            Some(VocabularyHint {
+                word,
+                pronunciation,
+            })
+        })
+        .take(MAX_VOCABULARY_HINTS)
+        .collect()
+}
+
+/// Written forms to boost, in dictionary order.
+pub fn hint_terms(hints: &[VocabularyHint]) -> impl Iterator<Item = &str> {
+    hints
+        .iter()
+        .take(MAX_VOCABULARY_HINTS)
+        .map(|hint| hint.word.as_str())
+        .filter(|word| !word.trim().is_empty())
+}
+
+/// Builds a Whisper `prompt` that lists the spelling of each term, followed by
+/// its spoken form when the user recorded one.
+pub fn whisper_prompt(hints: &[VocabularyHint]) -> Option<String> {
+    let mut prompt = String::new();
+    for hint in hints.iter().take(MAX_VOCABULARY_HINTS) {
+        let word = hint.word.trim();
+        if word.is_empty() {
+            continue;
+        }
+        let item = match hint.pronunciation.as_deref().map(str::trim) {
+            Some(pronunciation) if !pronunciation.is_empty() => {
+                format!("{word} ({pronunciation})")
+            }
+            _ => word.to_string(),
+        };
+        let separator = if prompt.is_empty() { "" } else { ", " };
+        if prompt.chars().count() + separator.len() + item.chars().count()
+            > MAX_WHISPER_PROMPT_CHARS
+        {
+            break;
+        }
+        prompt.push_str(separator);
+        prompt.push_str(&item);
+    }
+    (!prompt.is_empty()).then(|| format!("Vocabulary: {prompt}."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: i64, word: &str, pronunciation: Option<&str>) -> DictionaryEntry {
+        DictionaryEntry {
+            id,
+            word: word.to_string(),
+            pronunciation: pronunciation.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn dictionary_hints_are_normalized_deduplicated_and_bounded() {
+        let mut entries = vec![
+            entry(1, "  Open  Typeless ", Some(" open   typeless ")),
+            entry(2, "open typeless", None),
+            entry(3, "Kubernetes", Some("koo ber net ees")),
+            entry(4, "   ", None),
+            entry(5, &"x".repeat(MAX_VOCABULARY_HINT_CHARS + 1), None),
+        ];
+        for id in 0..200 {
+            entries.push(entry(10 + id, &format!("term{id}"), None));
+        }
+
+        let hints = hints_from_dictionary(&entries);
+
+        assert_eq!(hints.len(), MAX_VOCABULARY_HINTS);
+        assert_eq!(
+            hints[0],
+            VocabularyHint {
+                word: "Open Typeless".to_string(),
+                pronunciation: None,
+            }
+        );
+        assert_eq!(hints[1].word, "Kubernetes");
+        assert_eq!(hints[1].pronunciation.as_deref(), Some("koo ber net ees"));
+        assert_eq!(hints[2].word, "term0");
+    }
+
+    #[test]
+    fn whisper_prompt_lists_spelling_with_spoken_form() {
+        let hints = hints_from_dictionary(&[
+            entry(1, "OpenTypeless", Some("open typeless")),
+            entry(2, "Tauri", None),
+        ]);
+
+        assert_eq!(
+            whisper_prompt(&hints).as_deref(),
+            Some("Vocabulary: OpenTypeless (open typeless), Tauri.")
+        );
+        assert_eq!(whisper_prompt(&[]), None);
+    }
+
+    #[test]
+    fn capability_flag_matches_providers_with_native_biasing() {
+        for provider in [
+            "deepgram",
+            "assemblyai",
+            "volcengine-doubao",
+            "openai-whisper",
+            "groq-whisper",
+            "custom-whisper",
+            "local-whisper",
+        ] {
+            assert!(provider_supports_vocabulary_hints(provider), "{provider}");
+        }
+        for provider in ["cloud", "glm-asr", "siliconflow", "apple-speech", "unknown"] {
+            assert!(!provider_supports_vocabulary_hints(provider), "{provider}");
+        }
+    }
+}
@@ -9,7 +9,7 @@ use tokio_tungstenite::{
 
 use crate::error::AppError;
 
-use super::{SttConfig, SttProvider, TranscriptEvent};
+use super::{vocabulary, SttConfig, SttProvider, TranscriptEvent};
 
 type WsStream =
     tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
@@ -132,7 +132,7 @@ fn build_frame(
 }
 
 fn build_full_client_request_frame(config: &SttConfig, sequence: i32) -> Result<Vec<u8>, AppError> {
-    let payload = serde_json::json!({
+    let mut payload = serde_json::json!({
         "user": {
             "uid": "opentypeless"
         },
@@ -152,6 +152,9 @@ fn build_full_client_request_frame(config: &SttConfig, sequence: i32) -> Result<
             "show_utterances": true
         }
     });
+    if let Some(corpus) = hotword_corpus(config) {
+        payload["request"]["corpus"] = corpus;
+    }
     build_frame(
         FULL_CLIENT_REQUEST,
         POS_SEQUENCE,
@@ -161,6 +164,18 @@ fn build_full_client_request_frame(config: &SttConfig, sequence: i32) -> Result<
     )
 }
 
+/// Doubao takes hotwords as a JSON string nested in `corpus.context`.
+fn hotword_corpus(config: &SttConfig) -> Option<serde_json::Value> {
+    let hotwords = vocabulary::hint_terms(&config.vocabulary)
+        .map(|word| serde_json::json!({ "word": word }))
+        .collect::<Vec<_>>();
+    if hotwords.is_empty() {
+        return None;
+    }
+    let context = serde_json::json!({ "hotwords": hotwords }).to_string();
+    Some(serde_json::json!({ "context": context }))
+}
+
 fn build_audio_request_frame(chunk: &[u8], sequence: i32) -> Result<Vec<u8>, AppError> {
     let flags = if sequence < 0 {
         NEG_WITH_SEQUENCE
@@ -579,6 +594,8 @@ mod tests {
             resource_id: None,
             operation_id: None,
             managed_audio: None,
+            vocabulary: Vec::new(),
+            local_model_path: None,
         }
     }
 
@@ -616,6 +633,24 @@ mod tests {
         assert_eq!(value["request"]["show_utterances"], true);
     }
 
+    #[test]
+    fn full_client_request_carries_vocabulary_as_hotwords() {
+        let mut config = test_config(None);
+        config.vocabulary = vec![crate::stt::vocabulary::VocabularyHint {
+            word: "豆包".to_string(),
+            pronunciation: Some("dou bao".to_string()),
+        }];
+        let frame = build_full_client_request_frame(&config, 1).unwrap();
+        let payload_size = u32::from_be_bytes(frame[8..12].try_into().unwrap()) as usize;
+        let payload = ungzip_payload(&frame[12..12 + payload_size]).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&payload).unwrap();
+
+        let context: serde_json::Value =
+            serde_json::from_str(value["request"]["corpus"]["context"].as_str().unwrap()).unwrap();
+        assert_eq!(context["hotwords"][0]["word"], "豆包");
+        assert!(hotword_corpus(&test_config(None)).is_none());
+    }
+
     #[test]
     fn builds_final_audio_frame_with_negative_sequence() {
         let frame = build_audio_request_frame(b"\x01\x02", -2).unwrap();
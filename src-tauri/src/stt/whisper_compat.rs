@@ -2,7 +2,7 @@ use async_trait::async_trait;
 
 use crate::error::AppError;
 
-use super::{SttConfig, SttProvider, TranscriptEvent};
+use super::{vocabulary, SttConfig, SttProvider, TranscriptEvent};
 
 /// Configuration for a Whisper-compatible HTTP file-upload STT provider.
 #[derive(Debug)]
@@ -48,6 +48,15 @@ impl WhisperCompatProvider {
         }
     }
 
+    /// Whisper has no keyword list, so dictionary terms go into the `prompt`
+    /// field for providers known to honor it.
+    fn vocabulary_prompt(&self, config: &SttConfig) -> Option<String> {
+        if !vocabulary::provider_supports_vocabulary_hints(&self.provider_config.provider_name) {
+            return None;
+        }
+        vocabulary::whisper_prompt(&config.vocabulary)
+    }
+
     /// Build a WAV file from raw PCM 16-bit mono audio. Public so test helpers can reuse it.
     pub fn build_wav(pcm: &[u8], sample_rate: u32) -> Vec<u8> {
         let data_len = pcm.len() as u32;
@@ -125,8 +134,10 @@ impl SttProvider for WhisperCompatProvider {
             return Ok(None);
         }
 
-        let audio_len_secs = self.audio_buffer.len() as f64 / (config.sample_rate as f64 * 2.0);
-        let wav_data = Self::build_wav(&self.audio_buffer, config.sample_rate);
+        // Leading/trailing silence only costs upload time and invites hallucinated text.
+        let speech = crate::audio::vad::trim_silence(&self.audio_buffer, config.sample_rate);
+        let audio_len_secs = speech.len() as f64 / (config.sample_rate as f64 * 2.0);
+        let wav_data = Self::build_wav(speech, config.sample_rate);
         self.audio_buffer.clear();
         tracing::info!(
             "{}: sending {:.1}s of audio for transcription",
@@ -152,6 +163,10 @@ impl SttProvider for WhisperCompatProvider {
                 }
             }
 
+            if let Some(prompt) = self.vocabulary_prompt(&config) {
+                form = form.text("prompt", prompt);
+            }
+
             // Provider-specific extra fields
             for (key, value) in &self.provider_config.extra_fields {
                 form = form.text(key.clone(), value.clone());
@@ -273,12 +288,38 @@ mod tests {
                 resource_id: None,
                 operation_id: None,
                 managed_audio: None,
+                vocabulary: Vec::new(),
+                local_model_path: None,
             })
             .await;
 
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn vocabulary_prompt_is_only_sent_to_providers_that_honor_it() {
+        let config = SttConfig {
+            vocabulary: vec![vocabulary::VocabularyHint {
+                word: "OpenTypeless".to_string(),
+                pronunciation: Some("open typeless".to_string()),
+            }],
+            ..SttConfig::default()
+        };
+        let groq = WhisperCompatProvider::new(
+            crate::stt::config::build_known_whisper_config("groq-whisper").unwrap(),
+        );
+        let glm = WhisperCompatProvider::new(
+            crate::stt::config::build_known_whisper_config("glm-asr").unwrap(),
+        );
+
+        assert_eq!(
+            groq.vocabulary_prompt(&config).as_deref(),
+            Some("Vocabulary: OpenTypeless (open typeless).")
+        );
+        assert_eq!(groq.vocabulary_prompt(&SttConfig::default()), None);
+        assert_eq!(glm.vocabulary_prompt(&config), None);
+    }
+
     #[tokio::test]
     async fn recv_transcript_waits_for_file_based_provider() {
         let mut provider = WhisperCompatProvider::new(WhisperCompatConfig {
@@ -1,7 +1,9 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::app_detector::types::TargetAppGuard;
+use crate::output::{InsertResult, InsertStatus};
 
 use super::search::SearchUrl;
 use super::{VoiceIntent, VoiceIntentKind, VoiceOutputPlacement, VoiceRoutingFlags};
@@ -25,6 +27,7 @@ pub enum VoiceExecutionFallbackReason {
     SelectionLost,
     FocusRestoreFailed,
     OutputFailed,
+    NothingToEdit,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -36,6 +39,115 @@ pub struct VoiceExecutionResult {
     pub fallback_reason: Option<VoiceExecutionFallbackReason>,
 }
 
+/// Text the app last typed into a target, kept so editing commands can
+/// remove or select exactly that text and nothing the user wrote. Spans are
+/// counted in grapheme clusters, the unit one Backspace or Shift+Left moves
+/// over, so emoji sequences and combining marks are not split.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VoiceInsertionRecord {
+    text: String,
+    target_guard: TargetAppGuard,
+}
+
+impl VoiceInsertionRecord {
+    /// Records the part of `text` that `result` reports as typed; copies and
+    /// failed insertions leave nothing to edit.
+    pub fn from_insert_result(
+        text: &str,
+        result: &InsertResult,
+        target_guard: TargetAppGuard,
+    ) -> Option<Self> {
+        if !matches!(
+            result.status,
+            InsertStatus::Inserted | InsertStatus::PartiallyInserted
+        ) || result.chars_inserted == 0
+        {
+            return None;
+        }
+        Some(Self {
+            text: text.chars().take(result.chars_inserted).collect(),
+            target_guard,
+        })
+    }
+
+    pub fn graphemes_inserted(&self) -> usize {
+        grapheme_count(&self.text)
+    }
+
+    /// Grapheme clusters before the cursor that `kind` removes or selects.
+    pub fn edit_span(&self, kind: VoiceIntentKind) -> Option<usize> {
+        let span = match kind {
+            VoiceIntentKind::UndoInsertion | VoiceIntentKind::SelectInsertion => {
+                self.graphemes_inserted()
+            }
+            VoiceIntentKind::DeleteLastSentence => last_sentence_graphemes(&self.text),
+            VoiceIntentKind::DeleteLastWord => last_word_graphemes(&self.text),
+            _ => return None,
+        };
+        (span > 0).then_some(span)
+    }
+
+    /// The record after `kind` ran. Selecting hands the text back to the
+    /// user, so it is no longer safe to count backspaces from it.
+    pub fn after_edit(mut self, kind: VoiceIntentKind) -> Option<Self> {
+        match kind {
+            VoiceIntentKind::DeleteLastSentence | VoiceIntentKind::DeleteLastWord => {
+                let keep = self.graphemes_inserted() - self.edit_span(kind)?;
+                self.text = self.text.graphemes(true).take(keep).collect();
+            }
+            VoiceIntentKind::NewLine => self.text.push('\n'),
+            VoiceIntentKind::NewParagraph => self.text.push_str("\n\n"),
+            _ => return None,
+        }
+        (!self.text.is_empty()).then_some(self)
+    }
+}
+
+fn grapheme_count(text: &str) -> usize {
+    text.graphemes(true).count()
+}
+
+fn is_sentence_end(character: char) -> bool {
+    matches!(character, '.' | '!' | '?' | '。' | '！' | '？' | '\n')
+}
+
+/// The last sentence with its closing punctuation and the space before it.
+fn last_sentence_graphemes(text: &str) -> usize {
+    let body = text.trim_end_matches(|character: char| {
+        character.is_whitespace() || is_sentence_end(character)
+    });
+    let start = body
+        .char_indices()
+        .rev()
+        .find(|(_, character)| is_sentence_end(*character))
+        .map_or(0, |(index, character)| index + character.len_utf8());
+    grapheme_count(&text[start..])
+}
+
+/// The last space-separated word with its trailing punctuation. Chinese and
+/// Japanese have no spaces, so there only the last character goes.
+fn last_word_graphemes(text: &str) -> usize {
+    let body = text.trim_end_matches(|character: char| {
+        character.is_whitespace() || character.is_ascii_punctuation() || is_sentence_end(character)
+    });
+    let Some(last) = body.graphemes(true).next_back() else {
+        return grapheme_count(text);
+    };
+    let start = if last.chars().next().is_some_and(is_unspaced_script) {
+        body.len() - last.len()
+    } else {
+        body.rfind(char::is_whitespace).unwrap_or(0)
+    };
+    grapheme_count(&text[start..])
+}
+
+fn is_unspaced_script(character: char) -> bool {
+    matches!(
+        character as u32,
+        0x3040..=0x30FF | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF
+    )
+}
+
 pub struct VoiceExecutionRequest<'a> {
     pub intent: &'a VoiceIntent,
     pub generated_output: &'a str,
@@ -43,6 +155,8 @@ pub struct VoiceExecutionRequest<'a> {
     pub selected_text_available: bool,
     pub restore_target_before_insert: bool,
     pub flags: VoiceRoutingFlags,
+    /// What editing commands act on; `None` when nothing was typed yet.
+    pub last_insertion: Option<&'a VoiceInsertionRecord>,
 }
 
 #[async_trait]
@@ -54,6 +168,12 @@ pub trait VoiceExecutionBackend: Send {
     async fn popup_answer(&mut self, text: &str) -> Result<(), String>;
     async fn copy_to_clipboard(&mut self, text: &str) -> Result<(), String>;
     async fn open_search(&mut self, url: &SearchUrl) -> Result<(), String>;
+    /// Presses Backspace `chars` times in the target app.
+    async fn delete_before_cursor(&mut self, chars: usize) -> Result<(), String>;
+    /// Extends the selection `chars` characters to the left of the cursor.
+    async fn select_before_cursor(&mut self, chars: usize) -> Result<(), String>;
+    /// Types `count` line breaks the way dictated newlines are typed.
+    async fn insert_line_breaks(&mut self, count: usize) -> Result<(), String>;
 }
 
 pub async fn execute_voice_intent(
@@ -69,7 +189,9 @@ pub async fn execute_voice_intent(
         );
     }
 
-    if request.intent.kind != VoiceIntentKind::Search && request.generated_output.trim().is_empty()
+    if request.intent.kind != VoiceIntentKind::Search
+        && !request.intent.kind.is_edit()
+        && request.generated_output.trim().is_empty()
     {
         return result(
             request.intent,
@@ -86,6 +208,7 @@ pub async fn execute_voice_intent(
             popup_or_copy(request.intent, request.generated_output, None, backend).await
         }
         VoiceOutputPlacement::OpenUrl => execute_search(request, backend).await,
+        VoiceOutputPlacement::EditInPlace => execute_edit(request, backend).await,
     }
 }
 
@@ -195,7 +318,7 @@ async fn execute_search(
     request: VoiceExecutionRequest<'_>,
     backend: &mut dyn VoiceExecutionBackend,
 ) -> VoiceExecutionResult {
-    let Some(provider) = request.intent.search_provider else {
+    let Some(provider) = request.intent.search_provider.clone() else {
         return result(
             request.intent,
             None,
@@ -238,6 +361,67 @@ async fn execute_search(
     }
 }
 
+async fn execute_edit(
+    request: VoiceExecutionRequest<'_>,
+    backend: &mut dyn VoiceExecutionBackend,
+) -> VoiceExecutionResult {
+    if !backend.target_matches(request.target_guard) {
+        return result(
+            request.intent,
+            None,
+            VoiceExecutionStatus::Prevented,
+            Some(VoiceExecutionFallbackReason::TargetChanged),
+        );
+    }
+
+    let kind = request.intent.kind;
+    let outcome = match kind {
+        VoiceIntentKind::NewLine => backend.insert_line_breaks(1).await,
+        VoiceIntentKind::NewParagraph => backend.insert_line_breaks(2).await,
+        _ => {
+            let Some((insertion, span)) = request
+                .last_insertion
+                .and_then(|insertion| insertion.edit_span(kind).map(|span| (insertion, span)))
+            else {
+                return result(
+                    request.intent,
+                    None,
+                    VoiceExecutionStatus::Prevented,
+                    Some(VoiceExecutionFallbackReason::NothingToEdit),
+                );
+            };
+            // The text must still be in the app it was typed into.
+            if !backend.target_matches(&insertion.target_guard) {
+                return result(
+                    request.intent,
+                    None,
+                    VoiceExecutionStatus::Prevented,
+                    Some(VoiceExecutionFallbackReason::TargetChanged),
+                );
+            }
+            if kind == VoiceIntentKind::SelectInsertion {
+                backend.select_before_cursor(span).await
+            } else {
+                backend.delete_before_cursor(span).await
+            }
+        }
+    };
+    match outcome {
+        Ok(()) => result(
+            request.intent,
+            Some(VoiceOutputPlacement::EditInPlace),
+            VoiceExecutionStatus::Completed,
+            None,
+        ),
+        Err(_) => result(
+            request.intent,
+            None,
+            VoiceExecutionStatus::Failed,
+            Some(VoiceExecutionFallbackReason::OutputFailed),
+        ),
+    }
+}
+
 async fn popup_or_copy(
     intent: &VoiceIntent,
     output: &str,
@@ -275,6 +459,12 @@ fn feature_enabled(kind: VoiceIntentKind, flags: VoiceRoutingFlags) -> bool {
         VoiceIntentKind::RewriteSelection => flags.rewrite_selection,
         VoiceIntentKind::TranslateSelection => flags.translate_selection,
         VoiceIntentKind::Search => flags.search,
+        VoiceIntentKind::UndoInsertion
+        | VoiceIntentKind::DeleteLastSentence
+        | VoiceIntentKind::DeleteLastWord
+        | VoiceIntentKind::NewLine
+        | VoiceIntentKind::NewParagraph
+        | VoiceIntentKind::SelectInsertion => flags.edit_commands,
         VoiceIntentKind::DictateInsert
         | VoiceIntentKind::TranslateInsert
         | VoiceIntentKind::AskSelection
@@ -321,6 +511,7 @@ mod tests {
         insert_fails: bool,
         copy_fails: bool,
         opened_url: Option<String>,
+        edited_chars: Option<usize>,
     }
 
     #[async_trait]
@@ -372,6 +563,24 @@ mod tests {
             self.opened_url = Some(url.as_str().to_string());
             Ok(())
         }
+
+        async fn delete_before_cursor(&mut self, chars: usize) -> Result<(), String> {
+            self.actions.push("delete_before_cursor");
+            self.edited_chars = Some(chars);
+            Ok(())
+        }
+
+        async fn select_before_cursor(&mut self, chars: usize) -> Result<(), String> {
+            self.actions.push("select_before_cursor");
+            self.edited_chars = Some(chars);
+            Ok(())
+        }
+
+        async fn insert_line_breaks(&mut self, count: usize) -> Result<(), String> {
+            self.actions.push("insert_line_breaks");
+            self.edited_chars = Some(count);
+            Ok(())
+        }
     }
 
     fn intent(kind: VoiceIntentKind) -> VoiceIntent {
@@ -394,6 +603,7 @@ mod tests {
                 Some(SearchProvider::Google),
                 Some("rust".to_string()),
             ),
+            _ => (VoiceOutputPlacement::EditInPlace, None, None),
         };
         VoiceIntent::from_parts(
             kind,
@@ -421,6 +631,7 @@ mod tests {
             selected_text_available,
             restore_target_before_insert,
             flags,
+            last_insertion: None,
         }
     }
 
@@ -690,4 +901,187 @@ mod tests {
         assert_eq!(result.status, VoiceExecutionStatus::Prevented);
         assert!(disabled.actions.is_empty());
     }
+
+    fn insertion(text: &str) -> VoiceInsertionRecord {
+        VoiceInsertionRecord::from_insert_result(
+            text,
+            &InsertResult::inserted(
+                crate::output::InsertionStrategy::Keyboard,
+                text.chars().count(),
+            ),
+            TargetAppGuard::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn voice_insertion_record_measures_only_typed_text() {
+        let partial = VoiceInsertionRecord::from_insert_result(
+            "Hello world",
+            &InsertResult::partially_inserted(crate::output::InsertionStrategy::Keyboard, 5),
+            TargetAppGuard::default(),
+        )
+        .unwrap();
+        assert_eq!(partial.graphemes_inserted(), 5);
+        assert!(VoiceInsertionRecord::from_insert_result(
+            "Hello",
+            &InsertResult::copied_fallback(crate::output::InsertionStrategy::ClipboardCopyOnly, 5),
+            TargetAppGuard::default(),
+        )
+        .is_none());
+
+        let english = insertion("Ship it today. Then tell the team!");
+        assert_eq!(
+            english.edit_span(VoiceIntentKind::DeleteLastSentence),
+            Some(" Then tell the team!".chars().count())
+        );
+        assert_eq!(
+            english.edit_span(VoiceIntentKind::DeleteLastWord),
+            Some(" team!".chars().count())
+        );
+        assert_eq!(english.edit_span(VoiceIntentKind::UndoInsertion), Some(34));
+        assert_eq!(english.edit_span(VoiceIntentKind::NewLine), None);
+        let shorter = english
+            .clone()
+            .after_edit(VoiceIntentKind::DeleteLastSentence)
+            .unwrap();
+        assert_eq!(shorter, insertion("Ship it today."));
+        assert_eq!(
+            shorter.edit_span(VoiceIntentKind::DeleteLastSentence),
+            Some(14)
+        );
+        assert!(english
+            .clone()
+            .after_edit(VoiceIntentKind::UndoInsertion)
+            .is_none());
+        assert!(english
+            .after_edit(VoiceIntentKind::SelectInsertion)
+            .is_none());
+
+        let chinese = insertion("今天发布。明天通知团队。");
+        assert_eq!(
+            chinese.edit_span(VoiceIntentKind::DeleteLastSentence),
+            Some("明天通知团队。".chars().count())
+        );
+        assert_eq!(chinese.edit_span(VoiceIntentKind::DeleteLastWord), Some(2));
+        assert_eq!(
+            chinese
+                .after_edit(VoiceIntentKind::NewParagraph)
+                .unwrap()
+                .graphemes_inserted(),
+            14
+        );
+
+        // One Backspace removes a whole emoji sequence or accented letter.
+        let emoji = insertion("Ship it 👍🏽. Thanks e\u{301}quipe 👨‍👩‍👧");
+        assert_eq!(emoji.edit_span(VoiceIntentKind::DeleteLastWord), Some(2));
+        assert_eq!(
+            emoji.edit_span(VoiceIntentKind::DeleteLastSentence),
+            Some(16)
+        );
+        assert_eq!(emoji.edit_span(VoiceIntentKind::UndoInsertion), Some(26));
+        assert_eq!(
+            emoji
+                .after_edit(VoiceIntentKind::DeleteLastWord)
+                .unwrap()
+                .edit_span(VoiceIntentKind::DeleteLastWord),
+            Some(7)
+        );
+    }
+
+    #[tokio::test]
+    async fn voice_intent_executor_edits_only_the_recorded_insertion() {
+        let last = insertion("First point. Second point.");
+        for (kind, action, chars) in [
+            (VoiceIntentKind::UndoInsertion, "delete_before_cursor", 26),
+            (
+                VoiceIntentKind::DeleteLastSentence,
+                "delete_before_cursor",
+                14,
+            ),
+            (VoiceIntentKind::DeleteLastWord, "delete_before_cursor", 7),
+            (VoiceIntentKind::SelectInsertion, "select_before_cursor", 26),
+            (VoiceIntentKind::NewParagraph, "insert_line_breaks", 2),
+        ] {
+            let intent = intent(kind);
+            let mut backend = FakeBackend {
+                target_matches: true,
+                ..Default::default()
+            };
+            let result = execute_voice_intent(
+                VoiceExecutionRequest {
+                    last_insertion: Some(&last),
+                    ..request(&intent, "", false, false, VoiceRoutingFlags::default())
+                },
+                &mut backend,
+            )
+            .await;
+            assert_eq!(result.status, VoiceExecutionStatus::Completed, "{kind:?}");
+            assert_eq!(
+                result.actual_placement,
+                Some(VoiceOutputPlacement::EditInPlace)
+            );
+            assert_eq!(backend.actions.last(), Some(&action), "{kind:?}");
+            assert_eq!(backend.edited_chars, Some(chars), "{kind:?}");
+        }
+
+        let undo = intent(VoiceIntentKind::UndoInsertion);
+        let mut nothing = FakeBackend {
+            target_matches: true,
+            ..Default::default()
+        };
+        let result = execute_voice_intent(
+            request(&undo, "", false, false, VoiceRoutingFlags::default()),
+            &mut nothing,
+        )
+        .await;
+        assert_eq!(
+            result.fallback_reason,
+            Some(VoiceExecutionFallbackReason::NothingToEdit)
+        );
+        assert_eq!(nothing.actions, ["target_matches"]);
+
+        let mut moved = FakeBackend::default();
+        let result = execute_voice_intent(
+            VoiceExecutionRequest {
+                last_insertion: Some(&last),
+                ..request(&undo, "", false, false, VoiceRoutingFlags::default())
+            },
+            &mut moved,
+        )
+        .await;
+        assert_eq!(result.status, VoiceExecutionStatus::Prevented);
+        assert_eq!(
+            result.fallback_reason,
+            Some(VoiceExecutionFallbackReason::TargetChanged)
+        );
+        assert!(!moved.actions.contains(&"delete_before_cursor"));
+
+        let mut disabled = FakeBackend {
+            target_matches: true,
+            ..Default::default()
+        };
+        let result = execute_voice_intent(
+            VoiceExecutionRequest {
+                last_insertion: Some(&last),
+                ..request(
+                    &undo,
+                    "",
+                    false,
+                    false,
+                    VoiceRoutingFlags {
+                        edit_commands: false,
+                        ..VoiceRoutingFlags::default()
+                    },
+                )
+            },
+            &mut disabled,
+        )
+        .await;
+        assert_eq!(
+            result.fallback_reason,
+            Some(VoiceExecutionFallbackReason::FeatureDisabled)
+        );
+        assert!(disabled.actions.is_empty());
+    }
 }
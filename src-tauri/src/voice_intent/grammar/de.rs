@@ -0,0 +1,139 @@
+use super::{CommandMatch, SearchMatch};
+use crate::voice_intent::normalize::{trim_command_payload, NormalizedUtterance};
+use crate::voice_intent::{SearchProvider, VoiceIntentKind};
+
+pub(super) fn match_draft(view: &NormalizedUtterance<'_>) -> CommandMatch<String> {
+    // "schreib das um" is a rewrite, not a draft whose payload is "das um".
+    if matches_rewrite(view) {
+        return CommandMatch::NoMatch;
+    }
+    for prefix in ["antworte mit", "verfasse", "entwirf", "schreibe", "schreib"] {
+        if !view.starts_with_prefix(prefix, true) {
+            continue;
+        }
+        return view
+            .payload_after_prefix(prefix)
+            .map(CommandMatch::Matched)
+            .unwrap_or(CommandMatch::MissingPayload);
+    }
+    CommandMatch::NoMatch
+}
+
+pub(super) fn matches_rewrite(view: &NormalizedUtterance<'_>) -> bool {
+    [
+        "schreib das um",
+        "schreibe das um",
+        "formuliere das um",
+        "mach das kürzer",
+        "mach das länger",
+        "mach das freundlicher",
+        "mach das herzlicher",
+        "mach das formeller",
+        "mach das prägnanter",
+        "korrigiere die grammatik",
+        "korrigiere die rechtschreibung",
+        "formatiere das als",
+        "mach daraus",
+    ]
+    .iter()
+    .any(|prefix| view.starts_with_prefix(prefix, true))
+}
+
+pub(super) fn matches_translation(view: &NormalizedUtterance<'_>) -> bool {
+    [
+        "übersetze das ins",
+        "übersetze das auf",
+        "übersetze das in",
+        "übersetze die auswahl ins",
+        "übersetze die auswahl auf",
+    ]
+    .iter()
+    .any(|prefix| {
+        view.starts_with_prefix(prefix, true) && view.payload_after_prefix(prefix).is_some()
+    })
+}
+
+pub(super) fn matches_informational(view: &NormalizedUtterance<'_>) -> bool {
+    [
+        "fasse das zusammen",
+        "erkläre das",
+        "erklär das",
+        "vergleiche das",
+        "was ",
+        "warum ",
+        "wieso ",
+        "wie ",
+        "wer ",
+        "wann ",
+        "wo ",
+    ]
+    .iter()
+    .any(|prefix| view.match_text().starts_with(prefix))
+}
+
+pub(super) fn match_search(
+    view: &NormalizedUtterance<'_>,
+    providers: &[(&str, SearchProvider)],
+) -> CommandMatch<SearchMatch> {
+    for command in ["suche", "such", "finde"] {
+        let Some(rest) = view.payload_after_prefix(command) else {
+            if view.starts_with_prefix(command, true) {
+                return CommandMatch::MissingPayload;
+            }
+            continue;
+        };
+
+        let normalized = rest.to_ascii_lowercase();
+        for (name, provider) in providers {
+            // "suche rust auf google"
+            let suffix = format!(" auf {name}");
+            if normalized.ends_with(&suffix) {
+                let query_end = rest.len() - suffix.len();
+                return search_match(provider.clone(), &rest[..query_end]);
+            }
+
+            // "suche auf youtube nach rust"
+            let prefix = format!("auf {name} nach ");
+            if normalized.starts_with(&prefix) {
+                return search_match(provider.clone(), &rest[prefix.len()..]);
+            }
+        }
+    }
+    CommandMatch::NoMatch
+}
+
+fn search_match(provider: SearchProvider, query: &str) -> CommandMatch<SearchMatch> {
+    trim_command_payload(query)
+        .map(|query| {
+            CommandMatch::Matched(SearchMatch {
+                provider: provider.clone(),
+                query: query.to_string(),
+            })
+        })
+        .unwrap_or(CommandMatch::MissingPayload)
+}
+
+pub(super) const EDIT_COMMANDS: &[(&str, VoiceIntentKind)] = &[
+    ("rückgängig", VoiceIntentKind::UndoInsertion),
+    ("mach das rückgängig", VoiceIntentKind::UndoInsertion),
+    ("letzten satz löschen", VoiceIntentKind::DeleteLastSentence),
+    (
+        "lösche den letzten satz",
+        VoiceIntentKind::DeleteLastSentence,
+    ),
+    ("letztes wort löschen", VoiceIntentKind::DeleteLastWord),
+    ("lösche das letzte wort", VoiceIntentKind::DeleteLastWord),
+    ("neue zeile", VoiceIntentKind::NewLine),
+    ("neuer absatz", VoiceIntentKind::NewParagraph),
+    ("markiere das", VoiceIntentKind::SelectInsertion),
+    ("letzte eingabe markieren", VoiceIntentKind::SelectInsertion),
+];
+
+pub(super) fn provider_names() -> [(&'static str, SearchProvider); 4] {
+    [
+        ("google", SearchProvider::Google),
+        ("youtube", SearchProvider::YouTube),
+        ("amazon", SearchProvider::Amazon),
+        ("github", SearchProvider::GitHub),
+    ]
+}
@@ -1,6 +1,6 @@
 use super::{CommandMatch, SearchMatch};
 use crate::voice_intent::normalize::{trim_command_payload, NormalizedUtterance};
-use crate::voice_intent::SearchProvider;
+use crate::voice_intent::{SearchProvider, VoiceIntentKind};
 
 pub(super) fn match_draft(view: &NormalizedUtterance<'_>) -> CommandMatch<String> {
     for prefix in ["reply with", "compose", "draft", "write"] {
@@ -63,7 +63,10 @@ pub(super) fn matches_informational(view: &NormalizedUtterance<'_>) -> bool {
     .any(|prefix| view.match_text().starts_with(prefix))
 }
 
-pub(super) fn match_search(view: &NormalizedUtterance<'_>) -> CommandMatch<SearchMatch> {
+pub(super) fn match_search(
+    view: &NormalizedUtterance<'_>,
+    providers: &[(&str, SearchProvider)],
+) -> CommandMatch<SearchMatch> {
     for command in ["search", "find"] {
         let Some(rest) = view.payload_after_prefix(command) else {
             if view.starts_with_prefix(command, true) {
@@ -72,7 +75,7 @@ pub(super) fn match_search(view: &NormalizedUtterance<'_>) -> CommandMatch<Searc
             continue;
         };
 
-        for (name, provider) in provider_names() {
+        for (name, provider) in providers {
             let normalized = rest.to_ascii_lowercase();
             let suffix = format!(" on {name}");
             if normalized.ends_with(&suffix) {
@@ -80,7 +83,7 @@ pub(super) fn match_search(view: &NormalizedUtterance<'_>) -> CommandMatch<Searc
                 return trim_command_payload(&rest[..query_end])
                     .map(|query| {
                         CommandMatch::Matched(SearchMatch {
-                            provider,
+                            provider: provider.clone(),
                             query: query.to_string(),
                         })
                     })
@@ -93,7 +96,7 @@ pub(super) fn match_search(view: &NormalizedUtterance<'_>) -> CommandMatch<Searc
                     return trim_command_payload(&rest[prefix.len()..])
                         .map(|query| {
                             CommandMatch::Matched(SearchMatch {
-                                provider,
+                                provider: provider.clone(),
                                 query: query.to_string(),
                             })
                         })
@@ -105,7 +108,24 @@ pub(super) fn match_search(view: &NormalizedUtterance<'_>) -> CommandMatch<Searc
     CommandMatch::NoMatch
 }
 
-fn provider_names() -> [(&'static str, SearchProvider); 4] {
+pub(super) const EDIT_COMMANDS: &[(&str, VoiceIntentKind)] = &[
+    ("undo that", VoiceIntentKind::UndoInsertion),
+    ("undo last insertion", VoiceIntentKind::UndoInsertion),
+    ("scratch that", VoiceIntentKind::UndoInsertion),
+    ("delete last sentence", VoiceIntentKind::DeleteLastSentence),
+    (
+        "delete the last sentence",
+        VoiceIntentKind::DeleteLastSentence,
+    ),
+    ("delete last word", VoiceIntentKind::DeleteLastWord),
+    ("delete the last word", VoiceIntentKind::DeleteLastWord),
+    ("new line", VoiceIntentKind::NewLine),
+    ("new paragraph", VoiceIntentKind::NewParagraph),
+    ("select that", VoiceIntentKind::SelectInsertion),
+    ("select last insertion", VoiceIntentKind::SelectInsertion),
+];
+
+pub(super) fn provider_names() -> [(&'static str, SearchProvider); 4] {
     [
         ("google", SearchProvider::Google),
         ("youtube", SearchProvider::YouTube),
@@ -0,0 +1,136 @@
+use super::{CommandMatch, SearchMatch};
+use crate::voice_intent::normalize::{trim_command_payload, NormalizedUtterance};
+use crate::voice_intent::{SearchProvider, VoiceIntentKind};
+
+pub(super) fn match_draft(view: &NormalizedUtterance<'_>) -> CommandMatch<String> {
+    for prefix in ["responde con", "contesta con", "redacta", "escribe"] {
+        if !view.starts_with_prefix(prefix, true) {
+            continue;
+        }
+        return view
+            .payload_after_prefix(prefix)
+            .map(CommandMatch::Matched)
+            .unwrap_or(CommandMatch::MissingPayload);
+    }
+    CommandMatch::NoMatch
+}
+
+pub(super) fn matches_rewrite(view: &NormalizedUtterance<'_>) -> bool {
+    [
+        "reescribe esto",
+        "reformula esto",
+        "hazlo más corto",
+        "hazlo más largo",
+        "hazlo más cálido",
+        "hazlo más amable",
+        "hazlo más formal",
+        "hazlo más conciso",
+        "corrige la gramática",
+        "corrige la ortografía",
+        "dale formato de",
+        "convierte esto en",
+    ]
+    .iter()
+    .any(|prefix| view.starts_with_prefix(prefix, true))
+}
+
+pub(super) fn matches_translation(view: &NormalizedUtterance<'_>) -> bool {
+    [
+        "traduce esto al",
+        "traduce esto a",
+        "traduce la selección al",
+        "traduce la selección a",
+    ]
+    .iter()
+    .any(|prefix| {
+        view.starts_with_prefix(prefix, true) && view.payload_after_prefix(prefix).is_some()
+    })
+}
+
+pub(super) fn matches_informational(view: &NormalizedUtterance<'_>) -> bool {
+    // Transcripts often open questions with an inverted question mark.
+    let text = view.match_text().trim_start_matches(['¿', '¡']);
+    [
+        "resume esto",
+        "explica esto",
+        "explícame esto",
+        "compara esto",
+        "qué ",
+        "por qué ",
+        "cómo ",
+        "quién ",
+        "cuándo ",
+        "dónde ",
+    ]
+    .iter()
+    .any(|prefix| text.starts_with(prefix))
+}
+
+pub(super) fn match_search(
+    view: &NormalizedUtterance<'_>,
+    providers: &[(&str, SearchProvider)],
+) -> CommandMatch<SearchMatch> {
+    for command in ["busca", "encuentra"] {
+        let Some(rest) = view.payload_after_prefix(command) else {
+            if view.starts_with_prefix(command, true) {
+                return CommandMatch::MissingPayload;
+            }
+            continue;
+        };
+
+        let normalized = rest.to_ascii_lowercase();
+        for (name, provider) in providers {
+            // "busca rust en google"
+            let suffix = format!(" en {name}");
+            if normalized.ends_with(&suffix) {
+                let query_end = rest.len() - suffix.len();
+                return search_match(provider.clone(), &rest[..query_end]);
+            }
+
+            // "busca en youtube recetas de pasta"
+            let prefix = format!("en {name} ");
+            if normalized.starts_with(&prefix) {
+                return search_match(provider.clone(), &rest[prefix.len()..]);
+            }
+        }
+    }
+    CommandMatch::NoMatch
+}
+
+fn search_match(provider: SearchProvider, query: &str) -> CommandMatch<SearchMatch> {
+    trim_command_payload(query)
+        .map(|query| {
+            CommandMatch::Matched(SearchMatch {
+                provider: provider.clone(),
+                query: query.to_string(),
+            })
+        })
+        .unwrap_or(CommandMatch::MissingPayload)
+}
+
+pub(super) const EDIT_COMMANDS: &[(&str, VoiceIntentKind)] = &[
+    ("deshacer", VoiceIntentKind::UndoInsertion),
+    ("deshaz eso", VoiceIntentKind::UndoInsertion),
+    ("borra la última frase", VoiceIntentKind::DeleteLastSentence),
+    (
+        "borrar la última frase",
+        VoiceIntentKind::DeleteLastSentence,
+    ),
+    ("borra la última palabra", VoiceIntentKind::DeleteLastWord),
+    ("borrar la última palabra", VoiceIntentKind::DeleteLastWord),
+    ("nueva línea", VoiceIntentKind::NewLine),
+    ("nueva linea", VoiceIntentKind::NewLine),
+    ("nuevo párrafo", VoiceIntentKind::NewParagraph),
+    ("nuevo parrafo", VoiceIntentKind::NewParagraph),
+    ("selecciona eso", VoiceIntentKind::SelectInsertion),
+    ("selecciona lo último", VoiceIntentKind::SelectInsertion),
+];
+
+pub(super) fn provider_names() -> [(&'static str, SearchProvider); 4] {
+    [
+        ("google", SearchProvider::Google),
+        ("youtube", SearchProvider::YouTube),
+        ("amazon", SearchProvider::Amazon),
+        ("github", SearchProvider::GitHub),
+    ]
+}
@@ -0,0 +1,143 @@
+use super::{CommandMatch, SearchMatch};
+use crate::voice_intent::normalize::{trim_command_payload, NormalizedUtterance};
+use crate::voice_intent::{SearchProvider, VoiceIntentKind};
+
+pub(super) fn match_draft(view: &NormalizedUtterance<'_>) -> CommandMatch<String> {
+    for prefix in ["réponds avec", "réponds par", "rédige", "écris", "compose"] {
+        if !view.starts_with_prefix(prefix, true) {
+            continue;
+        }
+        return view
+            .payload_after_prefix(prefix)
+            .map(CommandMatch::Matched)
+            .unwrap_or(CommandMatch::MissingPayload);
+    }
+    CommandMatch::NoMatch
+}
+
+pub(super) fn matches_rewrite(view: &NormalizedUtterance<'_>) -> bool {
+    [
+        "réécris ça",
+        "réécris ceci",
+        "reformule ça",
+        "reformule ceci",
+        "rends ça plus court",
+        "rends ça plus long",
+        "rends ça plus chaleureux",
+        "rends ça plus amical",
+        "rends ça plus formel",
+        "rends ça plus concis",
+        "corrige la grammaire",
+        "corrige l'orthographe",
+        "corrige l’orthographe",
+        "mets ça en forme de",
+        "transforme ça en",
+    ]
+    .iter()
+    .any(|prefix| view.starts_with_prefix(prefix, true))
+}
+
+pub(super) fn matches_translation(view: &NormalizedUtterance<'_>) -> bool {
+    [
+        "traduis ça en",
+        "traduis ceci en",
+        "traduis la sélection en",
+    ]
+    .iter()
+    .any(|prefix| {
+        view.starts_with_prefix(prefix, true) && view.payload_after_prefix(prefix).is_some()
+    })
+}
+
+pub(super) fn matches_informational(view: &NormalizedUtterance<'_>) -> bool {
+    [
+        "résume ça",
+        "résume ceci",
+        "explique ça",
+        "explique ceci",
+        "compare ça",
+        "qu'est-ce que",
+        "qu’est-ce que",
+        "pourquoi ",
+        "comment ",
+        "qui ",
+        "quand ",
+        "où ",
+    ]
+    .iter()
+    .any(|prefix| view.match_text().starts_with(prefix))
+}
+
+pub(super) fn match_search(
+    view: &NormalizedUtterance<'_>,
+    providers: &[(&str, SearchProvider)],
+) -> CommandMatch<SearchMatch> {
+    for command in ["recherche", "cherche", "trouve"] {
+        let Some(rest) = view.payload_after_prefix(command) else {
+            if view.starts_with_prefix(command, true) {
+                return CommandMatch::MissingPayload;
+            }
+            continue;
+        };
+
+        let normalized = rest.to_ascii_lowercase();
+        for (name, provider) in providers {
+            // "cherche rust sur google"
+            let suffix = format!(" sur {name}");
+            if normalized.ends_with(&suffix) {
+                let query_end = rest.len() - suffix.len();
+                return search_match(provider.clone(), &rest[..query_end]);
+            }
+
+            // "cherche sur youtube des recettes de crêpes"
+            let prefix = format!("sur {name} ");
+            if normalized.starts_with(&prefix) {
+                return search_match(provider.clone(), &rest[prefix.len()..]);
+            }
+        }
+    }
+    CommandMatch::NoMatch
+}
+
+fn search_match(provider: SearchProvider, query: &str) -> CommandMatch<SearchMatch> {
+    trim_command_payload(query)
+        .map(|query| {
+            CommandMatch::Matched(SearchMatch {
+                provider: provider.clone(),
+                query: query.to_string(),
+            })
+        })
+        .unwrap_or(CommandMatch::MissingPayload)
+}
+
+pub(super) const EDIT_COMMANDS: &[(&str, VoiceIntentKind)] = &[
+    ("annule ça", VoiceIntentKind::UndoInsertion),
+    ("annuler", VoiceIntentKind::UndoInsertion),
+    (
+        "supprime la dernière phrase",
+        VoiceIntentKind::DeleteLastSentence,
+    ),
+    (
+        "efface la dernière phrase",
+        VoiceIntentKind::DeleteLastSentence,
+    ),
+    ("supprime le dernier mot", VoiceIntentKind::DeleteLastWord),
+    ("efface le dernier mot", VoiceIntentKind::DeleteLastWord),
+    ("nouvelle ligne", VoiceIntentKind::NewLine),
+    ("à la ligne", VoiceIntentKind::NewLine),
+    ("nouveau paragraphe", VoiceIntentKind::NewParagraph),
+    ("sélectionne ça", VoiceIntentKind::SelectInsertion),
+    (
+        "sélectionne le dernier texte",
+        VoiceIntentKind::SelectInsertion,
+    ),
+];
+
+pub(super) fn provider_names() -> [(&'static str, SearchProvider); 4] {
+    [
+        ("google", SearchProvider::Google),
+        ("youtube", SearchProvider::YouTube),
+        ("amazon", SearchProvider::Amazon),
+        ("github", SearchProvider::GitHub),
+    ]
+}
@@ -0,0 +1,153 @@
+use super::{CommandMatch, SearchMatch};
+use crate::voice_intent::normalize::{trim_command_payload, NormalizedUtterance};
+use crate::voice_intent::{SearchProvider, VoiceIntentKind};
+
+// Japanese rarely puts spaces between words, so every prefix must be followed
+// by a delimiter ("下書き、…") or end the utterance; otherwise "返信してくれて
+// ありがとう" would become a draft of "くれてありがとう".
+
+pub(super) fn match_draft(view: &NormalizedUtterance<'_>) -> CommandMatch<String> {
+    for prefix in [
+        "返信文を書いて",
+        "メールを書いて",
+        "下書きして",
+        "返信して",
+        "下書き",
+        "返信",
+    ] {
+        if !view.starts_with_prefix(prefix, true) {
+            continue;
+        }
+        return view
+            .payload_after_prefix(prefix)
+            .map(CommandMatch::Matched)
+            .unwrap_or(CommandMatch::MissingPayload);
+    }
+    CommandMatch::NoMatch
+}
+
+pub(super) fn matches_rewrite(view: &NormalizedUtterance<'_>) -> bool {
+    [
+        "これを書き直して",
+        "これを言い換えて",
+        "これを短くして",
+        "これを長くして",
+        "これを丁寧にして",
+        "これをやわらかくして",
+        "これを簡潔にして",
+        "これを整えて",
+        "これを箇条書きにして",
+        "文法を直して",
+        "誤字を直して",
+    ]
+    .iter()
+    .any(|prefix| view.starts_with_prefix(prefix, true))
+}
+
+/// "これを英語に翻訳して": the target language sits between the object and
+/// the verb, so both ends are anchored.
+pub(super) fn matches_translation(view: &NormalizedUtterance<'_>) -> bool {
+    let text = trim_sentence_end(view.match_text());
+    ["これを", "この文章を", "選択部分を"].iter().any(|object| {
+        text.strip_prefix(object).is_some_and(|rest| {
+            ["に翻訳して", "に訳して", "に翻訳"].iter().any(|verb| {
+                rest.strip_suffix(verb)
+                    .is_some_and(|target| !target.trim().is_empty())
+            })
+        })
+    })
+}
+
+pub(super) fn matches_informational(view: &NormalizedUtterance<'_>) -> bool {
+    [
+        "これを要約して",
+        "これを説明して",
+        "これを比較して",
+        "これはどういう意味",
+        "なぜ",
+        "どうして",
+        "どうやって",
+    ]
+    .iter()
+    .any(|prefix| view.starts_with_prefix(prefix, false))
+        || ["何", "誰", "いつ", "どこ"].iter().any(|word| {
+            view.match_text()
+                .strip_prefix(word)
+                .is_some_and(|rest| !starts_indefinite(rest))
+        })
+}
+
+/// Whether the text after a question word turns it into an indefinite
+/// word: いつも, 誰か, どこでも, 何とか, いつの間に. か only counts when it
+/// does not start から, so "いつから" stays a question.
+fn starts_indefinite(rest: &str) -> bool {
+    ["も", "でも", "とか", "とも", "の間に"]
+        .iter()
+        .any(|particle| rest.starts_with(particle))
+        || (rest.starts_with('か') && !rest.starts_with("から"))
+}
+
+/// "googleでrustを検索して" / "ユーチューブで 猫の動画 を検索"
+pub(super) fn match_search(
+    view: &NormalizedUtterance<'_>,
+    providers: &[(&str, SearchProvider)],
+) -> CommandMatch<SearchMatch> {
+    let text = trim_sentence_end(view.match_text());
+    for (name, provider) in providers {
+        for leading in [format!("{name}で"), format!("{name} で")] {
+            let Some(rest) = text.strip_prefix(&leading) else {
+                continue;
+            };
+            for verb in ["を検索して", "を検索", "を調べて"] {
+                if let Some(query) = rest.strip_suffix(verb) {
+                    return view
+                        .original_for_match_range(leading.len(), leading.len() + query.len())
+                        .as_deref()
+                        .and_then(trim_command_payload)
+                        .map(|query| {
+                            CommandMatch::Matched(SearchMatch {
+                                provider: provider.clone(),
+                                query: query.to_string(),
+                            })
+                        })
+                        .unwrap_or(CommandMatch::MissingPayload);
+                }
+            }
+            if matches!(rest.trim(), "検索して" | "検索" | "調べて") {
+                return CommandMatch::MissingPayload;
+            }
+        }
+    }
+    CommandMatch::NoMatch
+}
+
+fn trim_sentence_end(text: &str) -> &str {
+    text.trim_end_matches(['。', '.', '！', '!'])
+}
+
+pub(super) const EDIT_COMMANDS: &[(&str, VoiceIntentKind)] = &[
+    ("元に戻して", VoiceIntentKind::UndoInsertion),
+    ("取り消して", VoiceIntentKind::UndoInsertion),
+    ("最後の文を削除", VoiceIntentKind::DeleteLastSentence),
+    ("最後の文を消して", VoiceIntentKind::DeleteLastSentence),
+    ("最後の単語を削除", VoiceIntentKind::DeleteLastWord),
+    ("最後の単語を消して", VoiceIntentKind::DeleteLastWord),
+    ("改行", VoiceIntentKind::NewLine),
+    ("新しい段落", VoiceIntentKind::NewParagraph),
+    ("段落を変えて", VoiceIntentKind::NewParagraph),
+    ("今の入力を選択", VoiceIntentKind::SelectInsertion),
+    ("今のを選択して", VoiceIntentKind::SelectInsertion),
+];
+
+pub(super) fn provider_names() -> [(&'static str, SearchProvider); 8] {
+    [
+        ("google", SearchProvider::Google),
+        ("グーグル", SearchProvider::Google),
+        ("youtube", SearchProvider::YouTube),
+        ("ユーチューブ", SearchProvider::YouTube),
+        ("amazon", SearchProvider::Amazon),
+        ("アマゾン", SearchProvider::Amazon),
+        ("github", SearchProvider::GitHub),
+        ("ギットハブ", SearchProvider::GitHub),
+    ]
+}
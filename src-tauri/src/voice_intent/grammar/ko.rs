@@ -0,0 +1,166 @@
+use super::{CommandMatch, SearchMatch};
+use crate::voice_intent::normalize::{trim_command_payload, NormalizedUtterance};
+use crate::voice_intent::{SearchProvider, VoiceIntentKind};
+
+pub(super) fn match_draft(view: &NormalizedUtterance<'_>) -> CommandMatch<String> {
+    for prefix in [
+        "초안 작성해 줘",
+        "답장 작성해 줘",
+        "이메일 작성해 줘",
+        "메시지 작성해 줘",
+        "초안 작성",
+        "답장 작성",
+        "이메일 작성",
+        "메시지 작성",
+        "답장해 줘",
+    ] {
+        if !view.starts_with_prefix(prefix, true) {
+            continue;
+        }
+        return view
+            .payload_after_prefix(prefix)
+            .map(CommandMatch::Matched)
+            .unwrap_or(CommandMatch::MissingPayload);
+    }
+    CommandMatch::NoMatch
+}
+
+pub(super) fn matches_rewrite(view: &NormalizedUtterance<'_>) -> bool {
+    [
+        "이거 다시 써 줘",
+        "이거 다시 써줘",
+        "이 문장 다시 써 줘",
+        "이거 고쳐 써 줘",
+        "이거 짧게 해 줘",
+        "이거 길게 해 줘",
+        "이거 더 정중하게",
+        "이거 더 부드럽게",
+        "이거 더 간결하게",
+        "이거 더 친근하게",
+        "이거 다듬어 줘",
+        "이거 목록으로 바꿔 줘",
+        "문법 고쳐 줘",
+        "맞춤법 고쳐 줘",
+    ]
+    .iter()
+    .any(|prefix| view.starts_with_prefix(prefix, true))
+}
+
+/// "이거 영어로 번역해 줘": the target language sits between the object and
+/// the verb, so both ends are anchored.
+pub(super) fn matches_translation(view: &NormalizedUtterance<'_>) -> bool {
+    let text = trim_sentence_end(view.match_text());
+    [
+        "이거 ",
+        "이것을 ",
+        "이 부분을 ",
+        "이 문장을 ",
+        "선택한 부분을 ",
+    ]
+    .iter()
+    .any(|object| {
+        text.strip_prefix(object).is_some_and(|rest| {
+            [
+                "로 번역해 주세요",
+                "로 번역해 줘",
+                "로 번역해줘",
+                "로 번역해",
+            ]
+            .iter()
+            .any(|verb| {
+                rest.strip_suffix(verb)
+                    .is_some_and(|target| !target.trim().is_empty())
+            })
+        })
+    })
+}
+
+pub(super) fn matches_informational(view: &NormalizedUtterance<'_>) -> bool {
+    [
+        "이거 요약해",
+        "이거 설명해",
+        "이거 비교해",
+        "이게 무슨 뜻",
+        "왜 ",
+        "어떻게 ",
+        "무엇",
+        "뭐",
+        "누가 ",
+        "언제 ",
+        "어디",
+    ]
+    .iter()
+    .any(|prefix| view.match_text().starts_with(prefix))
+}
+
+/// "구글에서 러스트 검색해 줘"
+pub(super) fn match_search(
+    view: &NormalizedUtterance<'_>,
+    providers: &[(&str, SearchProvider)],
+) -> CommandMatch<SearchMatch> {
+    let text = trim_sentence_end(view.match_text());
+    for (name, provider) in providers {
+        let leading = format!("{name}에서 ");
+        let Some(rest) = text.strip_prefix(&leading) else {
+            continue;
+        };
+        for verb in [
+            " 검색해 주세요",
+            " 검색해 줘",
+            " 검색해줘",
+            " 찾아 줘",
+            " 찾아줘",
+            " 검색",
+        ] {
+            if let Some(query) = rest.strip_suffix(verb) {
+                return view
+                    .original_for_match_range(leading.len(), leading.len() + query.len())
+                    .as_deref()
+                    .and_then(trim_command_payload)
+                    .map(|query| {
+                        CommandMatch::Matched(SearchMatch {
+                            provider: provider.clone(),
+                            query: query.to_string(),
+                        })
+                    })
+                    .unwrap_or(CommandMatch::MissingPayload);
+            }
+        }
+        if rest.starts_with("검색") || rest.starts_with("찾아") {
+            return CommandMatch::MissingPayload;
+        }
+    }
+    CommandMatch::NoMatch
+}
+
+fn trim_sentence_end(text: &str) -> &str {
+    text.trim_end_matches(['.', '!', '。'])
+}
+
+pub(super) const EDIT_COMMANDS: &[(&str, VoiceIntentKind)] = &[
+    ("실행 취소", VoiceIntentKind::UndoInsertion),
+    ("방금 입력 취소", VoiceIntentKind::UndoInsertion),
+    ("마지막 문장 삭제", VoiceIntentKind::DeleteLastSentence),
+    ("마지막 문장 지워 줘", VoiceIntentKind::DeleteLastSentence),
+    ("마지막 단어 삭제", VoiceIntentKind::DeleteLastWord),
+    ("마지막 단어 지워 줘", VoiceIntentKind::DeleteLastWord),
+    ("줄 바꿈", VoiceIntentKind::NewLine),
+    ("줄바꿈", VoiceIntentKind::NewLine),
+    ("새 문단", VoiceIntentKind::NewParagraph),
+    ("새 단락", VoiceIntentKind::NewParagraph),
+    ("방금 입력 선택", VoiceIntentKind::SelectInsertion),
+    ("방금 입력한 거 선택해 줘", VoiceIntentKind::SelectInsertion),
+];
+
+pub(super) fn provider_names() -> [(&'static str, SearchProvider); 8] {
+    [
+        ("google", SearchProvider::Google),
+        ("구글", SearchProvider::Google),
+        ("youtube", SearchProvider::YouTube),
+        ("유튜브", SearchProvider::YouTube),
+        ("amazon", SearchProvider::Amazon),
+        ("아마존", SearchProvider::Amazon),
+        ("github", SearchProvider::GitHub),
+        ("깃허브", SearchProvider::GitHub),
+    ]
+}
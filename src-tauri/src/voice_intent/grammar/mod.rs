@@ -1,9 +1,21 @@
+mod de;
 mod en;
+mod es;
+mod fr;
+mod ja;
+mod ko;
+mod user;
 mod zh_hans;
 mod zh_hant;
 
 use super::normalize::NormalizedUtterance;
-use super::{CommandLocale, SearchProvider};
+use super::{CommandLocale, SearchProvider, VoiceIntentKind};
+
+pub(crate) use user::current_user_grammar;
+pub use user::{
+    install_user_grammar, load_user_grammar, PayloadRule, UserGrammar, UserGrammarLoad,
+    USER_GRAMMAR_FILE,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub(crate) enum CommandMatch<T> {
@@ -26,6 +38,11 @@ pub(crate) fn match_draft(
         CommandLocale::En => en::match_draft(view),
         CommandLocale::ZhHans => zh_hans::match_draft(view),
         CommandLocale::ZhHant => zh_hant::match_draft(view),
+        CommandLocale::Ja => ja::match_draft(view),
+        CommandLocale::Ko => ko::match_draft(view),
+        CommandLocale::De => de::match_draft(view),
+        CommandLocale::Es => es::match_draft(view),
+        CommandLocale::Fr => fr::match_draft(view),
     }
 }
 
@@ -34,6 +51,11 @@ pub(crate) fn matches_rewrite(locale: CommandLocale, view: &NormalizedUtterance<
         CommandLocale::En => en::matches_rewrite(view),
         CommandLocale::ZhHans => zh_hans::matches_rewrite(view),
         CommandLocale::ZhHant => zh_hant::matches_rewrite(view),
+        CommandLocale::Ja => ja::matches_rewrite(view),
+        CommandLocale::Ko => ko::matches_rewrite(view),
+        CommandLocale::De => de::matches_rewrite(view),
+        CommandLocale::Es => es::matches_rewrite(view),
+        CommandLocale::Fr => fr::matches_rewrite(view),
     }
 }
 
@@ -42,6 +64,11 @@ pub(crate) fn matches_translation(locale: CommandLocale, view: &NormalizedUttera
         CommandLocale::En => en::matches_translation(view),
         CommandLocale::ZhHans => zh_hans::matches_translation(view),
         CommandLocale::ZhHant => zh_hant::matches_translation(view),
+        CommandLocale::Ja => ja::matches_translation(view),
+        CommandLocale::Ko => ko::matches_translation(view),
+        CommandLocale::De => de::matches_translation(view),
+        CommandLocale::Es => es::matches_translation(view),
+        CommandLocale::Fr => fr::matches_translation(view),
     }
 }
 
@@ -50,20 +77,75 @@ pub(crate) fn matches_informational(locale: CommandLocale, view: &NormalizedUtte
         CommandLocale::En => en::matches_informational(view),
         CommandLocale::ZhHans => zh_hans::matches_informational(view),
         CommandLocale::ZhHant => zh_hant::matches_informational(view),
+        CommandLocale::Ja => ja::matches_informational(view),
+        CommandLocale::Ko => ko::matches_informational(view),
+        CommandLocale::De => de::matches_informational(view),
+        CommandLocale::Es => es::matches_informational(view),
+        CommandLocale::Fr => fr::matches_informational(view),
     }
 }
 
 pub(crate) fn match_search(
     locale: CommandLocale,
     view: &NormalizedUtterance<'_>,
+    user: &UserGrammar,
 ) -> CommandMatch<SearchMatch> {
+    let providers = search_provider_names(locale, user);
     match locale {
-        CommandLocale::En => en::match_search(view),
-        CommandLocale::ZhHans => zh_hans::match_search(view),
-        CommandLocale::ZhHant => zh_hant::match_search(view),
+        CommandLocale::En => en::match_search(view, &providers),
+        CommandLocale::ZhHans => zh_hans::match_search(view, &providers),
+        CommandLocale::ZhHant => zh_hant::match_search(view, &providers),
+        CommandLocale::Ja => ja::match_search(view, &providers),
+        CommandLocale::Ko => ko::match_search(view, &providers),
+        CommandLocale::De => de::match_search(view, &providers),
+        CommandLocale::Es => es::match_search(view, &providers),
+        CommandLocale::Fr => fr::match_search(view, &providers),
     }
 }
 
+/// Spoken provider names for `locale`. Built-in names come first so a user
+/// alias can add a search target but never redirect "google" elsewhere.
+fn search_provider_names(locale: CommandLocale, user: &UserGrammar) -> Vec<(&str, SearchProvider)> {
+    let mut names: Vec<(&str, SearchProvider)> = match locale {
+        CommandLocale::En => en::provider_names().to_vec(),
+        CommandLocale::ZhHans => zh_hans::provider_names().to_vec(),
+        CommandLocale::ZhHant => zh_hant::provider_names().to_vec(),
+        CommandLocale::Ja => ja::provider_names().to_vec(),
+        CommandLocale::Ko => ko::provider_names().to_vec(),
+        CommandLocale::De => de::provider_names().to_vec(),
+        CommandLocale::Es => es::provider_names().to_vec(),
+        CommandLocale::Fr => fr::provider_names().to_vec(),
+    };
+    names.extend(user.search_aliases(locale));
+    names
+}
+
+/// Editing commands only count as the whole utterance, so dictated prose
+/// that mentions "new paragraph" is still typed as text.
+pub(crate) fn match_edit(
+    locale: CommandLocale,
+    view: &NormalizedUtterance<'_>,
+) -> Option<VoiceIntentKind> {
+    let commands = match locale {
+        CommandLocale::En => en::EDIT_COMMANDS,
+        CommandLocale::ZhHans => zh_hans::EDIT_COMMANDS,
+        CommandLocale::ZhHant => zh_hant::EDIT_COMMANDS,
+        CommandLocale::Ja => ja::EDIT_COMMANDS,
+        CommandLocale::Ko => ko::EDIT_COMMANDS,
+        CommandLocale::De => de::EDIT_COMMANDS,
+        CommandLocale::Es => es::EDIT_COMMANDS,
+        CommandLocale::Fr => fr::EDIT_COMMANDS,
+    };
+    let text = view
+        .match_text()
+        .trim_end_matches(['.', '。', '!', '！', '?', '？'])
+        .trim_end();
+    commands
+        .iter()
+        .find(|(phrase, _)| *phrase == text)
+        .map(|(_, kind)| *kind)
+}
+
 pub(crate) fn exact_confidence(view: &NormalizedUtterance<'_>) -> f32 {
     if view
         .match_text()
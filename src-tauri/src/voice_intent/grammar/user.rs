@@ -0,0 +1,574 @@
+//! User-defined voice command grammar, loaded from `voice_grammar.json` in
+//! the app data folder and consulted after the built-in locale grammars.
+//!
+//! ```json
+//! {
+//!   "search_providers": [
+//!     { "id": "docs_rs", "name": "docs.rs", "aliases": { "en": ["docs.rs", "docs"] },
+//!       "url_template": "https://docs.rs/releases/search?query={query}" }
+//!   ],
+//!   "rules": [
+//!     { "locale": "en", "prefixes": ["polish this up"],
+//!       "intent": "rewrite_selection", "placement": "replace_selection" },
+//!     { "locale": "en", "prefixes": ["jot down"], "intent": "draft_insert",
+//!       "placement": "insert_at_cursor", "payload": "required" },
+//!     { "locale": "en", "prefixes": ["check the docs for"], "intent": "search",
+//!       "placement": "open_url", "payload": "required", "provider": "docs_rs" }
+//!   ]
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, OnceLock, RwLock};
+
+use serde::Deserialize;
+
+use super::CommandMatch;
+use crate::voice_intent::normalize::NormalizedUtterance;
+use crate::voice_intent::search::{CustomSearchProvider, SearchShape};
+use crate::voice_intent::types::is_custom_search_provider_id;
+use crate::voice_intent::{
+    CommandLocale, SearchProvider, VoiceIntent, VoiceIntentKind, VoiceOutputPlacement,
+};
+
+pub const USER_GRAMMAR_FILE: &str = "voice_grammar.json";
+const MAX_RULES: usize = 200;
+const MAX_PREFIX_CHARS: usize = 60;
+const MAX_SEARCH_PROVIDERS: usize = 50;
+const MAX_PROVIDER_NAME_CHARS: usize = 60;
+
+/// What the text after a matched prefix means for the command.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PayloadRule {
+    /// Trailing text is allowed but dropped: "polish this up for Slack" runs
+    /// the command with no payload, exactly as "polish this up" would.
+    #[default]
+    Ignored,
+    /// Trailing text is the payload; the command is incomplete without it.
+    Required,
+    /// The utterance must be the prefix alone.
+    Forbidden,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GrammarFile {
+    #[serde(default)]
+    search_providers: Vec<SearchProviderSpec>,
+    #[serde(default)]
+    rules: Vec<GrammarRuleSpec>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SearchProviderSpec {
+    id: String,
+    name: String,
+    aliases: HashMap<CommandLocale, Vec<String>>,
+    url_template: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GrammarRuleSpec {
+    locale: CommandLocale,
+    prefixes: Vec<String>,
+    intent: VoiceIntentKind,
+    placement: VoiceOutputPlacement,
+    #[serde(default)]
+    payload: PayloadRule,
+    #[serde(default)]
+    provider: Option<SearchProvider>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct UserRule {
+    locale: CommandLocale,
+    /// Lowercased, whitespace-collapsed prefixes as matched against utterances.
+    prefixes: Vec<String>,
+    kind: VoiceIntentKind,
+    placement: VoiceOutputPlacement,
+    payload: PayloadRule,
+    provider: Option<SearchProvider>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct UserCommand {
+    pub kind: VoiceIntentKind,
+    pub placement: VoiceOutputPlacement,
+    pub provider: Option<SearchProvider>,
+    pub payload: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UserGrammar {
+    rules: Vec<UserRule>,
+    search_providers: Vec<CustomSearchProvider>,
+}
+
+#[derive(Debug, Default)]
+pub struct UserGrammarLoad {
+    pub grammar: UserGrammar,
+    /// One message per rule or search provider that failed validation and was
+    /// skipped.
+    pub rejected: Vec<String>,
+}
+
+impl UserGrammar {
+    /// Parses a grammar file. A malformed file is an error; individual rules
+    /// and search providers that fail validation are skipped and reported.
+    pub fn parse(source: &str) -> Result<UserGrammarLoad, String> {
+        let file: GrammarFile =
+            serde_json::from_str(source).map_err(|e| format!("invalid voice grammar: {e}"))?;
+        let mut load = UserGrammarLoad::default();
+        // Providers first: rules may only name providers that survived validation.
+        for spec in file.search_providers {
+            let label = format!("search provider {}", spec.id);
+            if load.grammar.search_providers.len() >= MAX_SEARCH_PROVIDERS {
+                load.rejected.push(format!(
+                    "{label}: more than {MAX_SEARCH_PROVIDERS} search providers"
+                ));
+                continue;
+            }
+            if load.grammar.search_provider(&spec.id).is_some() {
+                load.rejected.push(format!("{label}: duplicate id"));
+                continue;
+            }
+            match validate_search_provider(spec) {
+                Ok(provider) => load.grammar.search_providers.push(provider),
+                Err(message) => load.rejected.push(format!("{label}: {message}")),
+            }
+        }
+        for (index, spec) in file.rules.into_iter().enumerate() {
+            if load.grammar.rules.len() >= MAX_RULES {
+                load.rejected
+                    .push(format!("rule {}: more than {MAX_RULES} rules", index + 1));
+                continue;
+            }
+            if let Some(SearchProvider::Custom(id)) = &spec.provider {
+                if load.grammar.search_provider(id).is_none() {
+                    load.rejected
+                        .push(format!("rule {}: unknown search provider {id}", index + 1));
+                    continue;
+                }
+            }
+            match UserRule::validate(spec) {
+                Ok(rule) => load.grammar.rules.push(rule),
+                Err(message) => load.rejected.push(format!("rule {}: {message}", index + 1)),
+            }
+        }
+        Ok(load)
+    }
+
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty() && self.search_providers.is_empty()
+    }
+
+    pub fn search_provider_count(&self) -> usize {
+        self.search_providers.len()
+    }
+
+    pub(crate) fn search_provider(&self, id: &str) -> Option<&CustomSearchProvider> {
+        self.search_providers
+            .iter()
+            .find(|provider| provider.id() == id)
+    }
+
+    /// Spoken names of the custom search providers for `locale`, longest
+    /// first so "rust docs" is tried before "docs".
+    pub(crate) fn search_aliases(&self, locale: CommandLocale) -> Vec<(&str, SearchProvider)> {
+        let mut aliases: Vec<(&str, SearchProvider)> = self
+            .search_providers
+            .iter()
+            .flat_map(|provider| {
+                provider
+                    .aliases(locale)
+                    .map(|alias| (alias, provider.provider()))
+            })
+            .collect();
+        aliases.sort_by_key(|(alias, _)| std::cmp::Reverse(alias.chars().count()));
+        aliases
+    }
+
+    /// Words that make an utterance look like a user command, so the negation,
+    /// quoting and identifier guards cover user phrasing too. For spaced
+    /// scripts this is each prefix's first word, mirroring the built-in verbs.
+    pub(crate) fn signal_words(&self, locale: CommandLocale) -> Vec<&str> {
+        let mut words: Vec<&str> = self
+            .rules
+            .iter()
+            .filter(|rule| rule.locale == locale)
+            .flat_map(|rule| rule.prefixes.iter())
+            .map(|prefix| {
+                if prefix.is_ascii() {
+                    prefix.split(' ').next().unwrap_or(prefix)
+                } else {
+                    prefix.as_str()
+                }
+            })
+            .collect();
+        words.sort_unstable();
+        words.dedup();
+        words
+    }
+
+    /// Whether a signal word for `locale` appears in `raw`. Locales written
+    /// with spaces need a whole-word match, so "tidy" does not fire on
+    /// "untidy"; Chinese, Japanese and Korean attach particles directly to
+    /// the verb and keep substring matching.
+    pub(crate) fn has_signal(&self, locale: CommandLocale, raw: &str) -> bool {
+        let normalized = raw.to_lowercase();
+        let whole_words = !matches!(
+            locale,
+            CommandLocale::ZhHans | CommandLocale::ZhHant | CommandLocale::Ja | CommandLocale::Ko
+        );
+        self.signal_words(locale).iter().any(|word| {
+            if whole_words {
+                contains_word(&normalized, word)
+            } else {
+                normalized.contains(word)
+            }
+        })
+    }
+
+    /// Locales whose user commands appear in `raw`.
+    pub(crate) fn signal_locales(&self, raw: &str) -> Vec<CommandLocale> {
+        let mut locales: Vec<CommandLocale> = Vec::new();
+        for rule in &self.rules {
+            if !locales.contains(&rule.locale) && self.has_signal(rule.locale, raw) {
+                locales.push(rule.locale);
+            }
+        }
+        locales
+    }
+
+    /// First rule, in file order, whose prefix starts the utterance and whose
+    /// intent is one of `kinds`.
+    pub(crate) fn match_command(
+        &self,
+        locale: CommandLocale,
+        view: &NormalizedUtterance<'_>,
+        kinds: &[VoiceIntentKind],
+    ) -> CommandMatch<UserCommand> {
+        for rule in self
+            .rules
+            .iter()
+            .filter(|rule| rule.locale == locale && kinds.contains(&rule.kind))
+        {
+            for prefix in &rule.prefixes {
+                if !view.starts_with_prefix(prefix, prefix.is_ascii()) {
+                    continue;
+                }
+                let trailing = view.payload_after_prefix(prefix);
+                let payload = match (rule.payload, trailing) {
+                    (PayloadRule::Forbidden, Some(_)) => continue,
+                    (PayloadRule::Required, None) => return CommandMatch::MissingPayload,
+                    (PayloadRule::Required, payload) => payload,
+                    (PayloadRule::Ignored | PayloadRule::Forbidden, _) => None,
+                };
+                return CommandMatch::Matched(UserCommand {
+                    kind: rule.kind,
+                    placement: rule.placement,
+                    provider: rule.provider.clone(),
+                    payload,
+                });
+            }
+        }
+        CommandMatch::NoMatch
+    }
+}
+
+impl UserRule {
+    fn validate(spec: GrammarRuleSpec) -> Result<Self, String> {
+        if !matches!(
+            spec.intent,
+            VoiceIntentKind::DraftInsert
+                | VoiceIntentKind::RewriteSelection
+                | VoiceIntentKind::TranslateSelection
+                | VoiceIntentKind::AskSelection
+                | VoiceIntentKind::Search
+        ) {
+            return Err(format!(
+                "intent {} cannot be bound to a voice command",
+                spec.intent.as_str()
+            ));
+        }
+
+        // The rule must describe an intent the router could actually build.
+        let sample_payload = (spec.payload == PayloadRule::Required).then(|| "payload".to_string());
+        VoiceIntent::from_parts(
+            spec.intent,
+            spec.placement,
+            1.0,
+            spec.provider.clone(),
+            sample_payload,
+            Some(spec.locale),
+            None,
+        )
+        .map_err(|e| e.to_string())?;
+
+        let mut prefixes = Vec::new();
+        for prefix in &spec.prefixes {
+            let normalized = NormalizedUtterance::new(prefix).match_text().to_string();
+            if normalized.is_empty() {
+                continue;
+            }
+            if normalized.chars().count() > MAX_PREFIX_CHARS {
+                return Err(format!("prefix longer than {MAX_PREFIX_CHARS} characters"));
+            }
+            if !prefixes.contains(&normalized) {
+                prefixes.push(normalized);
+            }
+        }
+        if prefixes.is_empty() {
+            return Err("at least one prefix is required".to_string());
+        }
+
+        Ok(Self {
+            locale: spec.locale,
+            prefixes,
+            kind: spec.intent,
+            placement: spec.placement,
+            payload: spec.payload,
+            provider: spec.provider,
+        })
+    }
+}
+
+/// Whether `word` occurs in `text` with no letter or digit directly before
+/// or after it.
+fn contains_word(text: &str, word: &str) -> bool {
+    !word.is_empty()
+        && text.match_indices(word).any(|(start, _)| {
+            let before = text[..start].chars().next_back();
+            let after = text[start + word.len()..].chars().next();
+            !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+        })
+}
+
+fn validate_search_provider(spec: SearchProviderSpec) -> Result<CustomSearchProvider, String> {
+    if !is_custom_search_provider_id(&spec.id)
+        || SearchProvider::BUILT_IN
+            .iter()
+            .any(|provider| provider.id() == spec.id)
+    {
+        return Err(
+            "id must be 1-32 lowercase letters, digits, '-' or '_' and not a built-in provider"
+                .to_string(),
+        );
+    }
+    let name = spec.name.trim();
+    if name.is_empty() || name.chars().count() > MAX_PROVIDER_NAME_CHARS {
+        return Err(format!(
+            "name must be 1-{MAX_PROVIDER_NAME_CHARS} characters"
+        ));
+    }
+    let shape = SearchShape::from_template(&spec.url_template).map_err(|e| e.to_string())?;
+
+    let mut aliases = Vec::new();
+    for (locale, names) in spec.aliases {
+        for alias in &names {
+            let normalized = NormalizedUtterance::new(alias).match_text().to_string();
+            if normalized.is_empty() {
+                continue;
+            }
+            if normalized.chars().count() > MAX_PREFIX_CHARS {
+                return Err(format!("alias longer than {MAX_PREFIX_CHARS} characters"));
+            }
+            if !aliases.contains(&(locale, normalized.clone())) {
+                aliases.push((locale, normalized));
+            }
+        }
+    }
+    if aliases.is_empty() {
+        return Err("at least one alias is required".to_string());
+    }
+
+    Ok(CustomSearchProvider::new(
+        spec.id,
+        name.to_string(),
+        aliases,
+        shape,
+    ))
+}
+
+fn slot() -> &'static RwLock<Arc<UserGrammar>> {
+    static USER_GRAMMAR: OnceLock<RwLock<Arc<UserGrammar>>> = OnceLock::new();
+    USER_GRAMMAR.get_or_init(|| RwLock::new(Arc::new(UserGrammar::default())))
+}
+
+/// Replaces the grammar used by `VoiceIntentRouter::route`.
+pub fn install_user_grammar(grammar: UserGrammar) {
+    *slot().write().unwrap_or_else(|e| e.into_inner()) = Arc::new(grammar);
+}
+
+pub(crate) fn current_user_grammar() -> Arc<UserGrammar> {
+    slot().read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Reads the grammar file at `path`; a missing file is an empty grammar.
+pub fn load_user_grammar(path: &Path) -> Result<UserGrammarLoad, String> {
+    match std::fs::read_to_string(path) {
+        Ok(source) => UserGrammar::parse(&source),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            Ok(UserGrammarLoad::default())
+        }
+        Err(error) => Err(format!("failed to read {}: {error}", path.display())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_are_validated_through_voice_intent_parts() {
+        let load = UserGrammar::parse(
+            r#"{ "rules": [
+                { "locale": "en", "prefixes": ["Polish  this UP", "polish this up"],
+                  "intent": "rewrite_selection", "placement": "replace_selection" },
+                { "locale": "en", "prefixes": ["jot down"],
+                  "intent": "draft_insert", "placement": "insert_at_cursor" },
+                { "locale": "en", "prefixes": ["look up"],
+                  "intent": "search", "placement": "open_url", "payload": "required" },
+                { "locale": "en", "prefixes": ["type"],
+                  "intent": "dictate_insert", "placement": "insert_at_cursor" },
+                { "locale": "zh_hans", "prefixes": [" "],
+                  "intent": "rewrite_selection", "placement": "replace_selection" }
+            ] }"#,
+        )
+        .unwrap();
+
+        assert_eq!(load.grammar.len(), 1);
+        assert_eq!(load.grammar.rules[0].prefixes, ["polish this up"]);
+        assert_eq!(load.rejected.len(), 4);
+        assert!(load.rejected[0].starts_with("rule 2: voice intent requires a non-empty payload"));
+        assert!(load.rejected[1].contains("requires a provider"));
+        assert!(load.rejected[2].contains("dictate_insert cannot be bound"));
+        assert!(load.rejected[3].contains("at least one prefix"));
+
+        assert!(UserGrammar::parse(r#"{ "rules": [], "extra": 1 }"#).is_err());
+        assert!(
+            load_user_grammar(Path::new("/nonexistent/voice_grammar.json"))
+                .unwrap()
+                .grammar
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn payload_rules_control_trailing_text() {
+        let grammar = UserGrammar::parse(
+            r#"{ "rules": [
+                { "locale": "en", "prefixes": ["tidy up"], "payload": "forbidden",
+                  "intent": "rewrite_selection", "placement": "replace_selection" },
+                { "locale": "en", "prefixes": ["jot down"], "payload": "required",
+                  "intent": "draft_insert", "placement": "insert_at_cursor" }
+            ] }"#,
+        )
+        .unwrap()
+        .grammar;
+        let rewrite = [VoiceIntentKind::RewriteSelection];
+        let draft = [VoiceIntentKind::DraftInsert];
+
+        let matched = |text: &str, kinds: &[VoiceIntentKind]| {
+            grammar.match_command(CommandLocale::En, &NormalizedUtterance::new(text), kinds)
+        };
+        assert!(matches!(
+            matched("Tidy up.", &rewrite),
+            CommandMatch::Matched(_)
+        ));
+        assert_eq!(
+            matched("tidy up the garage", &rewrite),
+            CommandMatch::NoMatch
+        );
+        assert_eq!(matched("jot down", &draft), CommandMatch::MissingPayload);
+        assert_eq!(matched("jot downtown", &draft), CommandMatch::NoMatch);
+        let CommandMatch::Matched(command) = matched("jot down: Milk, Eggs", &draft) else {
+            panic!("expected a draft command");
+        };
+        assert_eq!(command.payload.as_deref(), Some("Milk, Eggs"));
+        assert_eq!(matched("jot down milk", &rewrite), CommandMatch::NoMatch);
+
+        assert_eq!(grammar.signal_words(CommandLocale::En), ["jot", "tidy"]);
+        assert!(grammar.has_signal(CommandLocale::En, "Please don't tidy up"));
+        assert!(grammar.has_signal(CommandLocale::En, "Tidy, then send"));
+        assert!(!grammar.has_signal(CommandLocale::En, "The room is untidy"));
+        assert!(!grammar.has_signal(CommandLocale::En, "adjoting numbers"));
+        assert!(!grammar.has_signal(CommandLocale::ZhHans, "tidy up"));
+    }
+
+    #[test]
+    fn signal_words_match_whole_words_only_in_spaced_scripts() {
+        let grammar = UserGrammar::parse(
+            r#"{ "rules": [
+                { "locale": "fr", "prefixes": ["épure"],
+                  "intent": "rewrite_selection", "placement": "replace_selection" },
+                { "locale": "ja", "prefixes": ["まとめて"],
+                  "intent": "rewrite_selection", "placement": "replace_selection" }
+            ] }"#,
+        )
+        .unwrap()
+        .grammar;
+        assert!(grammar.has_signal(CommandLocale::Fr, "Épure ce paragraphe"));
+        assert!(!grammar.has_signal(CommandLocale::Fr, "un texte épuré"));
+        assert!(grammar.has_signal(CommandLocale::Ja, "これをまとめてください"));
+        assert_eq!(grammar.signal_locales("un texte épuré"), []);
+    }
+
+    #[test]
+    fn search_providers_are_validated_before_rules_that_use_them() {
+        let load = UserGrammar::parse(
+            r#"{
+              "search_providers": [
+                { "id": "docs_rs", "name": " docs.rs ",
+                  "aliases": { "en": ["Docs.rs", "rust  docs"], "de": ["Rust-Doku"] },
+                  "url_template": "https://docs.rs/releases/search?query={query}" },
+                { "id": "docs_rs", "name": "again", "aliases": { "en": ["again"] },
+                  "url_template": "https://docs.rs/releases/search?query={query}" },
+                { "id": "google", "name": "Mine", "aliases": { "en": ["mine"] },
+                  "url_template": "https://example.com/search?q={query}" },
+                { "id": "jira", "name": "Jira", "aliases": { "en": ["jira"] },
+                  "url_template": "http://jira.example.com/search?q={query}" },
+                { "id": "wiki", "name": "Wiki", "aliases": { "en": [" "] },
+                  "url_template": "https://wiki.example.com/search?q={query}" }
+              ],
+              "rules": [
+                { "locale": "en", "prefixes": ["check the docs for"], "payload": "required",
+                  "intent": "search", "placement": "open_url", "provider": "docs_rs" },
+                { "locale": "en", "prefixes": ["check jira for"], "payload": "required",
+                  "intent": "search", "placement": "open_url", "provider": "jira" }
+              ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(load.grammar.search_provider_count(), 1);
+        assert_eq!(load.grammar.len(), 1);
+        assert_eq!(load.rejected.len(), 5);
+        assert_eq!(load.rejected[0], "search provider docs_rs: duplicate id");
+        assert!(load.rejected[1].contains("not a built-in provider"));
+        assert!(load.rejected[2].starts_with("search provider jira: search URL template"));
+        assert!(load.rejected[3].contains("at least one alias"));
+        assert_eq!(load.rejected[4], "rule 2: unknown search provider jira");
+
+        let provider = load.grammar.search_provider("docs_rs").unwrap();
+        assert_eq!(provider.name(), "docs.rs");
+        let docs = SearchProvider::Custom("docs_rs".to_string());
+        assert_eq!(
+            load.grammar.search_aliases(CommandLocale::En),
+            [("rust docs", docs.clone()), ("docs.rs", docs.clone())]
+        );
+        assert_eq!(
+            load.grammar.search_aliases(CommandLocale::De),
+            [("rust-doku", docs)]
+        );
+        assert!(load.grammar.search_aliases(CommandLocale::Fr).is_empty());
+    }
+}
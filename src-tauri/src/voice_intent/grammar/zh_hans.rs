@@ -1,6 +1,6 @@
 use super::{CommandMatch, SearchMatch};
 use crate::voice_intent::normalize::{trim_command_payload, NormalizedUtterance};
-use crate::voice_intent::SearchProvider;
+use crate::voice_intent::{SearchProvider, VoiceIntentKind};
 
 pub(super) fn match_draft(view: &NormalizedUtterance<'_>) -> CommandMatch<String> {
     for prefix in ["写一封", "帮我写", "回复说", "写个", "起草"] {
@@ -77,22 +77,31 @@ pub(super) fn matches_informational(view: &NormalizedUtterance<'_>) -> bool {
     .any(|prefix| view.starts_with_prefix(prefix, false))
 }
 
-pub(super) fn match_search(view: &NormalizedUtterance<'_>) -> CommandMatch<SearchMatch> {
-    match_search_with_verbs(view, &["搜索", "搜"])
+pub(super) fn match_search(
+    view: &NormalizedUtterance<'_>,
+    providers: &[(&str, SearchProvider)],
+) -> CommandMatch<SearchMatch> {
+    match_search_with_verbs(view, providers, &["搜索", "搜"])
 }
 
 fn match_search_with_verbs(
     view: &NormalizedUtterance<'_>,
+    providers: &[(&str, SearchProvider)],
     verbs: &[&str],
 ) -> CommandMatch<SearchMatch> {
     let text = view.match_text();
-    for (name, provider) in provider_names() {
+    for (name, provider) in providers {
         for verb in verbs {
             let leading = format!("在 {name} {verb}");
             if view.starts_with_prefix(&leading, false) {
                 return view
                     .payload_after_prefix(&leading)
-                    .map(|query| CommandMatch::Matched(SearchMatch { provider, query }))
+                    .map(|query| {
+                        CommandMatch::Matched(SearchMatch {
+                            provider: provider.clone(),
+                            query,
+                        })
+                    })
                     .unwrap_or(CommandMatch::MissingPayload);
             }
 
@@ -107,7 +116,7 @@ fn match_search_with_verbs(
                     .and_then(trim_command_payload)
                     .map(|query| {
                         CommandMatch::Matched(SearchMatch {
-                            provider,
+                            provider: provider.clone(),
                             query: query.to_string(),
                         })
                     })
@@ -118,7 +127,21 @@ fn match_search_with_verbs(
     CommandMatch::NoMatch
 }
 
-fn provider_names() -> [(&'static str, SearchProvider); 4] {
+pub(super) const EDIT_COMMANDS: &[(&str, VoiceIntentKind)] = &[
+    ("撤销", VoiceIntentKind::UndoInsertion),
+    ("撤销上一次输入", VoiceIntentKind::UndoInsertion),
+    ("删除上一句", VoiceIntentKind::DeleteLastSentence),
+    ("删掉上一句", VoiceIntentKind::DeleteLastSentence),
+    ("删除上一个词", VoiceIntentKind::DeleteLastWord),
+    ("删掉上一个词", VoiceIntentKind::DeleteLastWord),
+    ("换行", VoiceIntentKind::NewLine),
+    ("新段落", VoiceIntentKind::NewParagraph),
+    ("另起一段", VoiceIntentKind::NewParagraph),
+    ("选中刚才的内容", VoiceIntentKind::SelectInsertion),
+    ("选中上一次输入", VoiceIntentKind::SelectInsertion),
+];
+
+pub(super) fn provider_names() -> [(&'static str, SearchProvider); 4] {
     [
         ("google", SearchProvider::Google),
         ("youtube", SearchProvider::YouTube),
@@ -1,6 +1,6 @@
 use super::{CommandMatch, SearchMatch};
 use crate::voice_intent::normalize::{trim_command_payload, NormalizedUtterance};
-use crate::voice_intent::SearchProvider;
+use crate::voice_intent::{SearchProvider, VoiceIntentKind};
 
 pub(super) fn match_draft(view: &NormalizedUtterance<'_>) -> CommandMatch<String> {
     for prefix in ["寫一封", "幫我寫", "回覆說", "寫個", "起草"] {
@@ -54,15 +54,23 @@ pub(super) fn matches_informational(view: &NormalizedUtterance<'_>) -> bool {
     .any(|prefix| view.starts_with_prefix(prefix, false))
 }
 
-pub(super) fn match_search(view: &NormalizedUtterance<'_>) -> CommandMatch<SearchMatch> {
+pub(super) fn match_search(
+    view: &NormalizedUtterance<'_>,
+    providers: &[(&str, SearchProvider)],
+) -> CommandMatch<SearchMatch> {
     let text = view.match_text();
-    for (name, provider) in provider_names() {
+    for (name, provider) in providers {
         for verb in ["搜尋", "搜"] {
             let leading = format!("在 {name} {verb}");
             if view.starts_with_prefix(&leading, false) {
                 return view
                     .payload_after_prefix(&leading)
-                    .map(|query| CommandMatch::Matched(SearchMatch { provider, query }))
+                    .map(|query| {
+                        CommandMatch::Matched(SearchMatch {
+                            provider: provider.clone(),
+                            query,
+                        })
+                    })
                     .unwrap_or(CommandMatch::MissingPayload);
             }
 
@@ -77,7 +85,7 @@ pub(super) fn match_search(view: &NormalizedUtterance<'_>) -> CommandMatch<Searc
                     .and_then(trim_command_payload)
                     .map(|query| {
                         CommandMatch::Matched(SearchMatch {
-                            provider,
+                            provider: provider.clone(),
                             query: query.to_string(),
                         })
                     })
@@ -88,7 +96,22 @@ pub(super) fn match_search(view: &NormalizedUtterance<'_>) -> CommandMatch<Searc
     CommandMatch::NoMatch
 }
 
-fn provider_names() -> [(&'static str, SearchProvider); 4] {
+pub(super) const EDIT_COMMANDS: &[(&str, VoiceIntentKind)] = &[
+    ("撤銷", VoiceIntentKind::UndoInsertion),
+    ("復原", VoiceIntentKind::UndoInsertion),
+    ("撤銷上一次輸入", VoiceIntentKind::UndoInsertion),
+    ("刪除上一句", VoiceIntentKind::DeleteLastSentence),
+    ("刪掉上一句", VoiceIntentKind::DeleteLastSentence),
+    ("刪除上一個詞", VoiceIntentKind::DeleteLastWord),
+    ("刪掉上一個詞", VoiceIntentKind::DeleteLastWord),
+    ("換行", VoiceIntentKind::NewLine),
+    ("新段落", VoiceIntentKind::NewParagraph),
+    ("另起一段", VoiceIntentKind::NewParagraph),
+    ("選取剛才的內容", VoiceIntentKind::SelectInsertion),
+    ("選取上一次輸入", VoiceIntentKind::SelectInsertion),
+];
+
+pub(super) fn provider_names() -> [(&'static str, SearchProvider); 4] {
     [
         ("google", SearchProvider::Google),
         ("youtube", SearchProvider::YouTube),
@@ -1,3 +1,4 @@
+use super::grammar::UserGrammar;
 use super::{CommandLocale, RouteFallbackReason};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -17,14 +18,18 @@ impl From<GuardReason> for RouteFallbackReason {
     }
 }
 
-pub(crate) fn guard_reason(locale: CommandLocale, raw: &str) -> Option<GuardReason> {
-    if has_code_or_identifier_command(locale, raw) {
+pub(crate) fn guard_reason(
+    locale: CommandLocale,
+    raw: &str,
+    user: &UserGrammar,
+) -> Option<GuardReason> {
+    if has_code_or_identifier_command(locale, raw, user) {
         return Some(GuardReason::CodeOrIdentifier);
     }
-    if has_quoted_or_reported_command(locale, raw) {
+    if has_quoted_or_reported_command(locale, raw, user) {
         return Some(GuardReason::QuotedOrReported);
     }
-    if has_negated_command(locale, raw) {
+    if has_negated_command(locale, raw, user) {
         return Some(GuardReason::Negated);
     }
     None
@@ -89,14 +94,125 @@ pub(crate) fn has_command_signal(locale: CommandLocale, raw: &str) -> bool {
         ]
         .iter()
         .any(|marker| normalized.contains(marker)),
+        CommandLocale::Ja => [
+            "下書き",
+            "返信",
+            "書いて",
+            "書か",
+            "書き直",
+            "言い換え",
+            "短く",
+            "長く",
+            "丁寧に",
+            "簡潔に",
+            "整えて",
+            "箇条書き",
+            "直し",
+            "直さ",
+            "翻訳",
+            "訳し",
+            "訳さ",
+            "要約",
+            "説明",
+            "比較",
+            "検索",
+            "調べ",
+        ]
+        .iter()
+        .any(|marker| normalized.contains(marker)),
+        CommandLocale::Ko => [
+            "초안",
+            "답장",
+            "작성",
+            "다시 써",
+            "다시 쓰",
+            "고쳐",
+            "짧게",
+            "길게",
+            "정중하게",
+            "부드럽게",
+            "간결하게",
+            "친근하게",
+            "다듬어",
+            "목록으로",
+            "번역",
+            "요약",
+            "설명",
+            "비교",
+            "검색",
+            "찾아",
+        ]
+        .iter()
+        .any(|marker| normalized.contains(marker)),
+        CommandLocale::De => [
+            "entwirf",
+            "entwerf",
+            "verfass",
+            "schreib",
+            "antwort",
+            "formulier",
+            "mach das",
+            "mach daraus",
+            "korrigier",
+            "formatier",
+            "übersetz",
+            "fasse",
+            "erklär",
+            "vergleich",
+            "suche",
+            "such ",
+            "finde",
+            "find ",
+        ]
+        .iter()
+        .any(|marker| normalized.contains(marker)),
+        CommandLocale::Es => [
+            "redact", "escrib", "respond", "contest", "reformul", "hazlo", "corrig", "corrij",
+            "correg", "formato", "conviert", "traduc", "traduz", "traduj", "resum", "explic",
+            "compar", "busc", "busqu", "encuentr",
+        ]
+        .iter()
+        .any(|marker| normalized.contains(marker)),
+        // Identifiers usually drop the accents: `ecris_helper.py`.
+        CommandLocale::Fr => [
+            "rédig",
+            "redig",
+            "écri",
+            "ecri",
+            "répond",
+            "repond",
+            "reformul",
+            "rends ça",
+            "corrig",
+            "mets ça en forme",
+            "transform",
+            "tradui",
+            "résum",
+            "expliqu",
+            "compar",
+            "cherch",
+            "trouv",
+        ]
+        .iter()
+        .any(|marker| normalized.contains(marker)),
     }
 }
 
+/// Built-in command signal, or a word from one of the user's grammar rules.
+pub(crate) fn has_signal(locale: CommandLocale, raw: &str, user: &UserGrammar) -> bool {
+    has_command_signal(locale, raw) || user.has_signal(locale, raw)
+}
+
+/// Signal check for automatic language detection. German, Spanish and French
+/// share the Latin script with English and are only routed when selected
+/// explicitly, so their phrase tables do not take part.
 pub(crate) fn has_any_supported_command_signal(raw: &str) -> bool {
     [
         CommandLocale::En,
         CommandLocale::ZhHans,
         CommandLocale::ZhHant,
+        CommandLocale::Ja,
+        CommandLocale::Ko,
     ]
     .iter()
     .any(|locale| has_command_signal(*locale, raw))
@@ -132,14 +248,20 @@ fn contains_ascii_command_signal(normalized: &str) -> bool {
     .any(|marker| normalized.contains(marker))
 }
 
-fn has_code_or_identifier_command(locale: CommandLocale, raw: &str) -> bool {
-    if !has_command_signal(locale, raw) {
+fn has_code_or_identifier_command(locale: CommandLocale, raw: &str, user: &UserGrammar) -> bool {
+    if !has_signal(locale, raw, user) {
         return false;
     }
     if raw.contains('_') || raw.contains('(') || raw.contains(')') {
         return true;
     }
 
+    let extra_markers: Vec<&str> = user
+        .signal_words(locale)
+        .into_iter()
+        .filter(|word| word.is_ascii())
+        .chain(identifier_verbs(locale).iter().copied())
+        .collect();
     let normalized = raw.to_ascii_lowercase();
     for marker in [
         "draft",
@@ -155,7 +277,10 @@ fn has_code_or_identifier_command(locale: CommandLocale, raw: &str) -> bool {
         "turn",
         "fix",
         "make",
-    ] {
+    ]
+    .into_iter()
+    .chain(extra_markers.iter().copied())
+    {
         for separator in ['.', '-', '?'] {
             let pattern = format!("{marker}{separator}");
             if normalized.find(&pattern).is_some_and(|index| {
@@ -181,7 +306,10 @@ fn has_code_or_identifier_command(locale: CommandLocale, raw: &str) -> bool {
         "turn",
         "fix",
         "make",
-    ] {
+    ]
+    .into_iter()
+    .chain(extra_markers.iter().copied())
+    {
         if raw.match_indices(marker).any(|(index, value)| {
             raw[index + value.len()..]
                 .chars()
@@ -195,8 +323,46 @@ fn has_code_or_identifier_command(locale: CommandLocale, raw: &str) -> bool {
     false
 }
 
-pub(crate) fn has_quoted_or_reported_command(locale: CommandLocale, raw: &str) -> bool {
-    if !has_command_signal(locale, raw) {
+/// Command verbs of Latin-script locales that also show up in identifiers
+/// such as `redacta.js` or `suche-Index`.
+fn identifier_verbs(locale: CommandLocale) -> &'static [&'static str] {
+    match locale {
+        CommandLocale::De => &[
+            "entwirf",
+            "verfasse",
+            "schreib",
+            "formatiere",
+            "suche",
+            "finde",
+        ],
+        CommandLocale::Es => &[
+            "redacta",
+            "escribe",
+            "responde",
+            "traduce",
+            "corrige",
+            "busca",
+            "reformula",
+        ],
+        CommandLocale::Fr => &[
+            "rédige",
+            "redige",
+            "écris",
+            "ecris",
+            "traduis",
+            "cherche",
+            "reformule",
+        ],
+        _ => &[],
+    }
+}
+
+pub(crate) fn has_quoted_or_reported_command(
+    locale: CommandLocale,
+    raw: &str,
+    user: &UserGrammar,
+) -> bool {
+    if !has_signal(locale, raw, user) {
         return false;
     }
     let normalized = raw.to_lowercase();
@@ -236,6 +402,57 @@ pub(crate) fn has_quoted_or_reported_command(locale: CommandLocale, raw: &str) -
         ]
         .iter()
         .any(|marker| normalized.contains(marker)),
+        CommandLocale::Ja => [
+            "と言",
+            "って言",
+            "という言葉",
+            "と書いてあ",
+            "と書かれて",
+            "引用",
+        ]
+        .iter()
+        .any(|marker| normalized.contains(marker)),
+        CommandLocale::Ko => [
+            "라고 했",
+            "라고 말했",
+            "라고 하더",
+            "라는 말",
+            "라고 적혀",
+            "라고 써 있",
+            "인용",
+        ]
+        .iter()
+        .any(|marker| normalized.contains(marker)),
+        CommandLocale::De => [
+            " sagt",
+            "hat gesagt",
+            "bat mich",
+            "der satz",
+            "das wort",
+            "zitat:",
+        ]
+        .iter()
+        .any(|marker| normalized.contains(marker)),
+        CommandLocale::Es => [
+            " dijo",
+            " dice",
+            "me pidió que",
+            "la frase",
+            "la palabra",
+            "cita:",
+        ]
+        .iter()
+        .any(|marker| normalized.contains(marker)),
+        CommandLocale::Fr => [
+            " dit",
+            "m'a demandé de",
+            "m’a demandé de",
+            "la phrase",
+            "le mot",
+            "citation",
+        ]
+        .iter()
+        .any(|marker| normalized.contains(marker)),
     };
     reported || has_balanced_command_quotes(raw)
 }
@@ -246,10 +463,12 @@ fn has_balanced_command_quotes(raw: &str) -> bool {
         || (raw.contains('“') && raw.contains('”'))
         || (raw.contains('「') && raw.contains('」'))
         || (raw.contains('『') && raw.contains('』'))
+        || (raw.contains('„') && raw.contains('“'))
+        || (raw.contains('«') && raw.contains('»'))
 }
 
-fn has_negated_command(locale: CommandLocale, raw: &str) -> bool {
-    if !has_command_signal(locale, raw) {
+fn has_negated_command(locale: CommandLocale, raw: &str, user: &UserGrammar) -> bool {
+    if !has_signal(locale, raw, user) {
         return false;
     }
     let normalized = raw.trim().to_lowercase();
@@ -286,6 +505,72 @@ fn has_negated_command(locale: CommandLocale, raw: &str) -> bool {
         ]
         .iter()
         .any(|prefix| normalized.starts_with(prefix)),
+        // Japanese and Korean negate at the end of the clause.
+        CommandLocale::Ja => [
+            "しないで",
+            "しなくていい",
+            "しなくて大丈夫",
+            "書かないで",
+            "直さないで",
+            "訳さないで",
+            "調べないで",
+            "やめて",
+            "するな",
+            "不要",
+        ]
+        .iter()
+        .any(|marker| normalized.contains(marker)),
+        CommandLocale::Ko => {
+            ["안 ", "절대 ", "아직 "]
+                .iter()
+                .any(|prefix| normalized.starts_with(prefix))
+                || ["지 마", "지 말", "필요 없", "안 해도", "지 않아도"]
+                    .iter()
+                    .any(|marker| normalized.contains(marker))
+        }
+        // German negation usually follows the verb, and separable verbs push
+        // it one word further: "schreib keine Antwort", "schreib das nicht um".
+        CommandLocale::De => {
+            [
+                "nicht ",
+                "bitte nicht ",
+                "noch nicht ",
+                "kein ",
+                "keine ",
+                "niemals ",
+                "lieber nicht ",
+            ]
+            .iter()
+            .any(|prefix| normalized.starts_with(prefix))
+                || normalized.split_whitespace().skip(1).take(2).any(|word| {
+                    matches!(
+                        word,
+                        "nicht" | "kein" | "keine" | "keinen" | "keinem" | "keiner" | "nichts"
+                    )
+                })
+        }
+        CommandLocale::Es => [
+            "no ",
+            "nunca ",
+            "todavía no ",
+            "aún no ",
+            "por favor no ",
+            "mejor no ",
+        ]
+        .iter()
+        .any(|prefix| normalized.starts_with(prefix)),
+        CommandLocale::Fr => [
+            "ne ",
+            "n'",
+            "n’",
+            "ne pas ",
+            "pas ",
+            "jamais ",
+            "surtout pas",
+            "s'il te plaît ne ",
+        ]
+        .iter()
+        .any(|prefix| normalized.starts_with(prefix)),
     }
 }
 
@@ -296,16 +581,17 @@ mod tests {
 
     #[test]
     fn voice_intent_grammar_guard_order_prefers_identifier_then_report_then_negation() {
+        let none = UserGrammar::default();
         assert_eq!(
-            guard_reason(CommandLocale::En, "compose.yaml says do not draft"),
+            guard_reason(CommandLocale::En, "compose.yaml says do not draft", &none),
             Some(GuardReason::CodeOrIdentifier)
         );
         assert_eq!(
-            guard_reason(CommandLocale::En, "she said do not draft a reply"),
+            guard_reason(CommandLocale::En, "she said do not draft a reply", &none),
             Some(GuardReason::QuotedOrReported)
         );
         assert_eq!(
-            guard_reason(CommandLocale::En, "do not draft a reply"),
+            guard_reason(CommandLocale::En, "do not draft a reply", &none),
             Some(GuardReason::Negated)
         );
     }
@@ -314,11 +600,13 @@ mod tests {
     fn voice_intent_grammar_detects_balanced_command_quotes_but_not_apostrophes() {
         assert!(has_quoted_or_reported_command(
             CommandLocale::En,
-            "she said \"draft a reply\""
+            "she said \"draft a reply\"",
+            &UserGrammar::default()
         ));
         assert!(!has_quoted_or_reported_command(
             CommandLocale::En,
-            "draft tomorrow's reply"
+            "draft tomorrow's reply",
+            &UserGrammar::default()
         ));
     }
 }
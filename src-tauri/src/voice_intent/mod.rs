@@ -7,8 +7,8 @@ pub mod types;
 
 pub use types::*;
 
-use grammar::{CommandMatch, SearchMatch};
-use guards::{guard_reason, has_any_supported_command_signal, has_command_signal};
+use grammar::{CommandMatch, SearchMatch, UserGrammar};
+use guards::{guard_reason, has_any_supported_command_signal, has_signal};
 use normalize::NormalizedUtterance;
 
 pub struct VoiceIntentRouter;
@@ -28,6 +28,7 @@ pub fn plan_voice_provider_work(
 ) -> VoiceProviderWorkPlan {
     let provider_input = match intent.kind {
         VoiceIntentKind::Search => None,
+        kind if kind.is_edit() => None,
         VoiceIntentKind::DraftInsert => intent.payload.clone(),
         _ => Some(utterance.to_string()),
     };
@@ -51,6 +52,12 @@ enum LocaleResolution {
 
 impl VoiceIntentRouter {
     pub fn route(request: VoiceRouteRequest<'_>) -> VoiceIntent {
+        Self::route_with_grammar(request, &grammar::current_user_grammar())
+    }
+
+    /// Routes with the built-in grammars plus `user`, whose rules are tried
+    /// only where the built-ins find no command.
+    pub fn route_with_grammar(request: VoiceRouteRequest<'_>, user: &UserGrammar) -> VoiceIntent {
         if request.mode == VoiceMode::Translate {
             return route_translate_mode(&request);
         }
@@ -63,7 +70,13 @@ impl VoiceIntentRouter {
             );
         }
 
-        let locale = match resolve_locale(request.speech_language, request.utterance) {
+        if request.mode == VoiceMode::Dictate && !request.has_selected_text {
+            if let Some(intent) = route_edit(&request, user) {
+                return intent;
+            }
+        }
+
+        let locale = match resolve_locale(request.speech_language, request.utterance, user) {
             LocaleResolution::Supported(locale) => locale,
             LocaleResolution::Unsupported => {
                 return fallback_intent(
@@ -87,8 +100,8 @@ impl VoiceIntentRouter {
         };
         let view = NormalizedUtterance::new(request.utterance);
 
-        if has_command_signal(locale, request.utterance) {
-            if let Some(reason) = guard_reason(locale, request.utterance) {
+        if has_signal(locale, request.utterance, user) {
+            if let Some(reason) = guard_reason(locale, request.utterance, user) {
                 return fallback_intent(
                     request.mode,
                     request.has_selected_text,
@@ -99,17 +112,56 @@ impl VoiceIntentRouter {
         }
 
         match request.mode {
-            VoiceMode::Dictate => route_dictate(request, locale, &view),
-            VoiceMode::Ask => route_ask(request, locale, &view),
+            VoiceMode::Dictate => route_dictate(request, locale, &view, user),
+            VoiceMode::Ask => route_ask(request, locale, &view, user),
             VoiceMode::Translate => unreachable!("translate mode returned before grammar routing"),
         }
     }
 }
 
+/// Editing commands act on the last insertion, so they are only taken when
+/// nothing is selected. Automatic language detection tries the same locales
+/// as the command signal check.
+fn route_edit(request: &VoiceRouteRequest<'_>, user: &UserGrammar) -> Option<VoiceIntent> {
+    let locales = match resolve_locale(request.speech_language, request.utterance, user) {
+        LocaleResolution::Supported(locale) => vec![locale],
+        LocaleResolution::Unsupported => return None,
+        LocaleResolution::Ambiguous | LocaleResolution::NoCommand => vec![
+            CommandLocale::En,
+            CommandLocale::ZhHans,
+            CommandLocale::ZhHant,
+            CommandLocale::Ja,
+            CommandLocale::Ko,
+        ],
+    };
+    let view = NormalizedUtterance::new(request.utterance);
+    let (locale, kind) = locales
+        .into_iter()
+        .find_map(|locale| grammar::match_edit(locale, &view).map(|kind| (locale, kind)))?;
+    if !request.flags.edit_commands {
+        return Some(fallback_intent(
+            request.mode,
+            false,
+            Some(locale),
+            Some(RouteFallbackReason::FeatureDisabled),
+        ));
+    }
+    Some(intent(
+        kind,
+        VoiceOutputPlacement::EditInPlace,
+        grammar::exact_confidence(&view),
+        None,
+        None,
+        Some(locale),
+        None,
+    ))
+}
+
 fn route_dictate(
     request: VoiceRouteRequest<'_>,
     locale: CommandLocale,
     view: &NormalizedUtterance<'_>,
+    user: &UserGrammar,
 ) -> VoiceIntent {
     if !request.has_selected_text {
         return match grammar::match_draft(locale, view) {
@@ -134,12 +186,21 @@ fn route_dictate(
                 Some(locale),
                 Some(RouteFallbackReason::MissingPayload),
             ),
-            CommandMatch::NoMatch => fallback_intent(
-                request.mode,
-                false,
-                Some(locale),
-                discussed_command_reason(locale, request.utterance),
-            ),
+            CommandMatch::NoMatch => route_user_command(
+                &request,
+                locale,
+                view,
+                user,
+                &[VoiceIntentKind::DraftInsert],
+            )
+            .unwrap_or_else(|| {
+                fallback_intent(
+                    request.mode,
+                    false,
+                    Some(locale),
+                    discussed_command_reason(locale, request.utterance, user),
+                )
+            }),
         };
     }
 
@@ -184,12 +245,25 @@ fn route_dictate(
     if grammar::matches_informational(locale, view) {
         return fallback_intent(request.mode, true, Some(locale), None);
     }
+    if let Some(intent) = route_user_command(
+        &request,
+        locale,
+        view,
+        user,
+        &[
+            VoiceIntentKind::TranslateSelection,
+            VoiceIntentKind::RewriteSelection,
+            VoiceIntentKind::AskSelection,
+        ],
+    ) {
+        return intent;
+    }
 
     fallback_intent(
         request.mode,
         true,
         Some(locale),
-        discussed_command_reason(locale, request.utterance),
+        discussed_command_reason(locale, request.utterance, user),
     )
 }
 
@@ -197,17 +271,18 @@ fn route_ask(
     request: VoiceRouteRequest<'_>,
     locale: CommandLocale,
     view: &NormalizedUtterance<'_>,
+    user: &UserGrammar,
 ) -> VoiceIntent {
     if request.has_selected_text {
         return fallback_intent(
             VoiceMode::Ask,
             true,
             Some(locale),
-            discussed_command_reason(locale, request.utterance),
+            discussed_command_reason(locale, request.utterance, user),
         );
     }
 
-    match grammar::match_search(locale, view) {
+    match grammar::match_search(locale, view, user) {
         CommandMatch::Matched(SearchMatch { provider, query }) if request.flags.search => {
             return intent(
                 VoiceIntentKind::Search,
@@ -237,6 +312,11 @@ fn route_ask(
         }
         CommandMatch::NoMatch => {}
     }
+    if let Some(intent) =
+        route_user_command(&request, locale, view, user, &[VoiceIntentKind::Search])
+    {
+        return intent;
+    }
 
     match grammar::match_draft(locale, view) {
         CommandMatch::Matched(payload) if request.flags.draft_insert => intent(
@@ -260,13 +340,73 @@ fn route_ask(
             Some(locale),
             Some(RouteFallbackReason::MissingPayload),
         ),
-        CommandMatch::NoMatch => fallback_intent(
-            VoiceMode::Ask,
-            false,
+        CommandMatch::NoMatch => route_user_command(
+            &request,
+            locale,
+            view,
+            user,
+            &[VoiceIntentKind::DraftInsert],
+        )
+        .unwrap_or_else(|| {
+            fallback_intent(
+                VoiceMode::Ask,
+                false,
+                Some(locale),
+                discussed_command_reason(locale, request.utterance, user),
+            )
+        }),
+    }
+}
+
+/// Tries the user grammar for `kinds`; `None` when no user rule matches.
+fn route_user_command(
+    request: &VoiceRouteRequest<'_>,
+    locale: CommandLocale,
+    view: &NormalizedUtterance<'_>,
+    user: &UserGrammar,
+    kinds: &[VoiceIntentKind],
+) -> Option<VoiceIntent> {
+    let command = match user.match_command(locale, view, kinds) {
+        CommandMatch::NoMatch => return None,
+        CommandMatch::MissingPayload => {
+            return Some(fallback_intent(
+                request.mode,
+                request.has_selected_text,
+                Some(locale),
+                Some(RouteFallbackReason::MissingPayload),
+            ))
+        }
+        CommandMatch::Matched(command) => command,
+    };
+    let enabled = match command.kind {
+        VoiceIntentKind::DraftInsert => request.flags.draft_insert,
+        VoiceIntentKind::RewriteSelection => request.flags.rewrite_selection,
+        VoiceIntentKind::TranslateSelection => request.flags.translate_selection,
+        VoiceIntentKind::Search => request.flags.search,
+        _ => true,
+    };
+    if !enabled {
+        return Some(fallback_intent(
+            request.mode,
+            request.has_selected_text,
             Some(locale),
-            discussed_command_reason(locale, request.utterance),
-        ),
+            Some(RouteFallbackReason::FeatureDisabled),
+        ));
+    }
+    // Questions about the selection stay on the Ask path, like the built-in
+    // informational phrases.
+    if command.kind == VoiceIntentKind::AskSelection {
+        return Some(fallback_intent(request.mode, true, Some(locale), None));
     }
+    Some(intent(
+        command.kind,
+        command.placement,
+        grammar::exact_confidence(view),
+        command.provider,
+        command.payload,
+        Some(locale),
+        None,
+    ))
 }
 
 fn route_translate_mode(request: &VoiceRouteRequest<'_>) -> VoiceIntent {
@@ -351,7 +491,11 @@ fn intent(
     .expect("router must construct only valid voice intents")
 }
 
-fn resolve_locale(mode: SpeechLanguageMode<'_>, utterance: &str) -> LocaleResolution {
+fn resolve_locale(
+    mode: SpeechLanguageMode<'_>,
+    utterance: &str,
+    user: &UserGrammar,
+) -> LocaleResolution {
     match mode {
         SpeechLanguageMode::Explicit(value) => match value.trim().to_ascii_lowercase().as_str() {
             "en" | "en-us" | "en-gb" | "english" => LocaleResolution::Supported(CommandLocale::En),
@@ -361,26 +505,48 @@ fn resolve_locale(mode: SpeechLanguageMode<'_>, utterance: &str) -> LocaleResolu
             "zh-tw" | "zh-hk" | "zh-hant" | "zh_hant" => {
                 LocaleResolution::Supported(CommandLocale::ZhHant)
             }
-            "multi" | "auto" | "automatic" => resolve_automatic_locale(utterance),
+            "ja" | "ja-jp" | "japanese" => LocaleResolution::Supported(CommandLocale::Ja),
+            "ko" | "ko-kr" | "korean" => LocaleResolution::Supported(CommandLocale::Ko),
+            "de" | "de-de" | "de-at" | "de-ch" | "german" => {
+                LocaleResolution::Supported(CommandLocale::De)
+            }
+            "es" | "es-es" | "es-mx" | "es-419" | "spanish" => {
+                LocaleResolution::Supported(CommandLocale::Es)
+            }
+            "fr" | "fr-fr" | "fr-ca" | "fr-be" | "fr-ch" | "french" => {
+                LocaleResolution::Supported(CommandLocale::Fr)
+            }
+            "multi" | "auto" | "automatic" => resolve_automatic_locale(utterance, user),
             _ => LocaleResolution::Unsupported,
         },
-        SpeechLanguageMode::Automatic => resolve_automatic_locale(utterance),
+        SpeechLanguageMode::Automatic => resolve_automatic_locale(utterance, user),
     }
 }
 
-fn resolve_automatic_locale(utterance: &str) -> LocaleResolution {
-    if !has_any_supported_command_signal(utterance) {
+fn resolve_automatic_locale(utterance: &str, user: &UserGrammar) -> LocaleResolution {
+    let user_locales = user.signal_locales(utterance);
+    if !has_any_supported_command_signal(utterance) && user_locales.is_empty() {
         return LocaleResolution::NoCommand;
     }
 
-    let has_ascii_command = has_command_signal(CommandLocale::En, utterance);
+    let has_ascii_command = has_signal(CommandLocale::En, utterance, user);
     let has_cjk = utterance.chars().any(is_cjk);
-    if has_ascii_command && has_cjk {
+    let has_kana = utterance.chars().any(is_kana);
+    let has_hangul = utterance.chars().any(is_hangul);
+    if has_ascii_command && (has_cjk || has_kana || has_hangul) {
         return LocaleResolution::Ambiguous;
     }
     if has_ascii_command {
         return LocaleResolution::Supported(CommandLocale::En);
     }
+    // Kana and Hangul identify their language outright; Han characters alone
+    // are shared by Chinese and Japanese and fall through to the count below.
+    match (has_kana, has_hangul) {
+        (true, true) => return LocaleResolution::Ambiguous,
+        (true, false) => return LocaleResolution::Supported(CommandLocale::Ja),
+        (false, true) => return LocaleResolution::Supported(CommandLocale::Ko),
+        (false, false) => {}
+    }
 
     let simplified = utterance
         .chars()
@@ -391,7 +557,10 @@ fn resolve_automatic_locale(utterance: &str) -> LocaleResolution {
         .filter(|character| "寫幫覆說發這譯選條簡個潤擴彙總釋麼為哪則郵".contains(*character))
         .count();
     match (simplified, traditional) {
-        (0, 0) => LocaleResolution::Ambiguous,
+        (0, 0) => match user_locales.as_slice() {
+            [locale] if *locale != CommandLocale::En => LocaleResolution::Supported(*locale),
+            _ => LocaleResolution::Ambiguous,
+        },
         (0, _) => LocaleResolution::Supported(CommandLocale::ZhHant),
         (_, 0) => LocaleResolution::Supported(CommandLocale::ZhHans),
         _ => LocaleResolution::Ambiguous,
@@ -402,7 +571,19 @@ fn is_cjk(character: char) -> bool {
     matches!(character as u32, 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF)
 }
 
-fn discussed_command_reason(locale: CommandLocale, utterance: &str) -> Option<RouteFallbackReason> {
+fn is_kana(character: char) -> bool {
+    matches!(character as u32, 0x3040..=0x30FF | 0x31F0..=0x31FF)
+}
+
+fn is_hangul(character: char) -> bool {
+    matches!(character as u32, 0x1100..=0x11FF | 0x3130..=0x318F | 0xAC00..=0xD7AF)
+}
+
+fn discussed_command_reason(
+    locale: CommandLocale,
+    utterance: &str,
+    user: &UserGrammar,
+) -> Option<RouteFallbackReason> {
     let normalized = utterance.trim().to_lowercase();
     let discussed = match locale {
         CommandLocale::En => [
@@ -448,8 +629,82 @@ fn discussed_command_reason(locale: CommandLocale, utterance: &str) -> Option<Ro
         ]
         .iter()
         .any(|prefix| normalized.starts_with(prefix)),
+        CommandLocale::Ja => [
+            "明日",
+            "あとで",
+            "後で",
+            "来週",
+            "たぶん",
+            "多分",
+            "チームで",
+            "今度",
+            "そのうち",
+            "予定では",
+        ]
+        .iter()
+        .any(|prefix| normalized.starts_with(prefix)),
+        CommandLocale::Ko => [
+            "내일 ",
+            "나중에 ",
+            "다음 주",
+            "아마 ",
+            "팀에서 ",
+            "우리는 ",
+            "언젠가 ",
+            "계획은 ",
+        ]
+        .iter()
+        .any(|prefix| normalized.starts_with(prefix)),
+        CommandLocale::De => [
+            "ich muss ",
+            "wir sollten ",
+            "können wir ",
+            "ich könnte ",
+            "der nächste schritt ist ",
+            "wir haben besprochen",
+            "ich plane",
+            "vielleicht ",
+            "das team wird ",
+            "ich will ",
+            "ich möchte ",
+            "ich sollte ",
+        ]
+        .iter()
+        .any(|prefix| normalized.starts_with(prefix)),
+        CommandLocale::Es => [
+            "tengo que ",
+            "deberíamos ",
+            "podemos ",
+            "quizás ",
+            "quizá ",
+            "el siguiente paso es ",
+            "hablamos de cómo ",
+            "planeo ",
+            "el equipo va a ",
+            "quiero ",
+            "debería ",
+            "a lo mejor ",
+        ]
+        .iter()
+        .any(|prefix| normalized.starts_with(prefix)),
+        CommandLocale::Fr => [
+            "je dois ",
+            "on devrait ",
+            "nous devrions ",
+            "on peut ",
+            "peut-être ",
+            "la prochaine étape est ",
+            "on a discuté de ",
+            "je prévois de ",
+            "l'équipe va ",
+            "je veux ",
+            "je voudrais ",
+            "je devrais ",
+        ]
+        .iter()
+        .any(|prefix| normalized.starts_with(prefix)),
     };
-    (discussed && has_command_signal(locale, utterance)).then_some(RouteFallbackReason::Ambiguous)
+    (discussed && has_signal(locale, utterance, user)).then_some(RouteFallbackReason::Ambiguous)
 }
 
 #[cfg(test)]
@@ -523,6 +778,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn japanese_question_words_do_not_shadow_indefinite_words() {
+        let user = UserGrammar::parse(
+            r#"{ "rules": [
+                { "locale": "ja", "prefixes": ["いつもの感じに整えて"],
+                  "intent": "rewrite_selection", "placement": "replace_selection" },
+                { "locale": "ja", "prefixes": ["誰かに送る文面にして"],
+                  "intent": "rewrite_selection", "placement": "replace_selection" },
+                { "locale": "ja", "prefixes": ["いつからの話か書き足して"],
+                  "intent": "rewrite_selection", "placement": "replace_selection" }
+            ] }"#,
+        )
+        .unwrap()
+        .grammar;
+        let route = |utterance| {
+            VoiceIntentRouter::route_with_grammar(
+                request(
+                    VoiceMode::Dictate,
+                    utterance,
+                    true,
+                    SpeechLanguageMode::Explicit("ja"),
+                ),
+                &user,
+            )
+        };
+
+        for utterance in ["いつもの感じに整えて", "誰かに送る文面にして"] {
+            assert_eq!(
+                route(utterance).kind,
+                VoiceIntentKind::RewriteSelection,
+                "{utterance}"
+            );
+        }
+        // Real questions still go to Ask before user rules are tried.
+        for utterance in ["いつからの話か書き足して", "いつの情報？", "何ですか"]
+        {
+            assert_eq!(
+                route(utterance).kind,
+                VoiceIntentKind::AskSelection,
+                "{utterance}"
+            );
+        }
+    }
+
     #[test]
     fn voice_intent_grammar_keeps_ask_with_selection_nondestructive() {
         for utterance in [
@@ -570,6 +869,241 @@ mod tests {
         assert_eq!(mixed.fallback_reason, Some(RouteFallbackReason::Ambiguous));
     }
 
+    #[test]
+    fn voice_intent_user_grammar_extends_builtins_behind_the_same_guards() {
+        let user = UserGrammar::parse(
+            r#"{ "rules": [
+                { "locale": "en", "prefixes": ["polish this up"],
+                  "intent": "rewrite_selection", "placement": "replace_selection" },
+                { "locale": "en", "prefixes": ["jot down"], "payload": "required",
+                  "intent": "draft_insert", "placement": "insert_at_cursor" },
+                { "locale": "en", "prefixes": ["look up code"], "payload": "required",
+                  "intent": "search", "placement": "open_url", "provider": "github" },
+                { "locale": "zh_hans", "prefixes": ["润一下"],
+                  "intent": "rewrite_selection", "placement": "replace_selection" }
+            ] }"#,
+        )
+        .unwrap()
+        .grammar;
+        let route = |mode, utterance, selected, language| {
+            VoiceIntentRouter::route_with_grammar(
+                request(mode, utterance, selected, language),
+                &user,
+            )
+        };
+        let en = || SpeechLanguageMode::Explicit("en");
+
+        let rewrite = route(VoiceMode::Dictate, "Polish this up for Slack", true, en());
+        assert_eq!(rewrite.kind, VoiceIntentKind::RewriteSelection);
+        assert_eq!(rewrite.grammar_locale, Some(CommandLocale::En));
+
+        let draft = route(VoiceMode::Dictate, "jot down: milk, eggs", false, en());
+        assert_eq!(draft.kind, VoiceIntentKind::DraftInsert);
+        assert_eq!(draft.payload.as_deref(), Some("milk, eggs"));
+        let missing = route(VoiceMode::Dictate, "jot down", false, en());
+        assert_eq!(
+            missing.fallback_reason,
+            Some(RouteFallbackReason::MissingPayload)
+        );
+
+        let search = route(VoiceMode::Ask, "look up code tokio select", false, en());
+        assert_eq!(search.kind, VoiceIntentKind::Search);
+        assert_eq!(search.search_provider, Some(SearchProvider::GitHub));
+        assert_eq!(search.payload.as_deref(), Some("tokio select"));
+
+        let builtin = route(VoiceMode::Dictate, "rewrite this", true, en());
+        assert_eq!(builtin.kind, VoiceIntentKind::RewriteSelection);
+
+        for (utterance, reason) in [
+            ("don't polish this up", RouteFallbackReason::Negated),
+            (
+                "she said \"polish this up\"",
+                RouteFallbackReason::QuotedOrReported,
+            ),
+            (
+                "polish.rs needs polish this up",
+                RouteFallbackReason::CodeOrIdentifier,
+            ),
+        ] {
+            let routed = route(VoiceMode::Dictate, utterance, true, en());
+            assert_eq!(routed.kind, VoiceIntentKind::AskSelection, "{utterance}");
+            assert_eq!(routed.fallback_reason, Some(reason), "{utterance}");
+        }
+
+        let automatic = route(
+            VoiceMode::Dictate,
+            "润一下",
+            true,
+            SpeechLanguageMode::Automatic,
+        );
+        assert_eq!(automatic.kind, VoiceIntentKind::RewriteSelection);
+        assert_eq!(automatic.grammar_locale, Some(CommandLocale::ZhHans));
+    }
+
+    #[test]
+    fn voice_intent_edit_commands_need_the_whole_utterance_and_no_selection() {
+        let route = |utterance, selected, language| {
+            VoiceIntentRouter::route(request(VoiceMode::Dictate, utterance, selected, language))
+        };
+
+        for (utterance, language, kind, locale) in [
+            (
+                "Scratch that.",
+                "en",
+                VoiceIntentKind::UndoInsertion,
+                CommandLocale::En,
+            ),
+            (
+                "new paragraph",
+                "multi",
+                VoiceIntentKind::NewParagraph,
+                CommandLocale::En,
+            ),
+            (
+                "删掉上一句",
+                "zh-Hans",
+                VoiceIntentKind::DeleteLastSentence,
+                CommandLocale::ZhHans,
+            ),
+            (
+                "換行",
+                "multi",
+                VoiceIntentKind::NewLine,
+                CommandLocale::ZhHant,
+            ),
+            (
+                "最後の単語を削除",
+                "ja",
+                VoiceIntentKind::DeleteLastWord,
+                CommandLocale::Ja,
+            ),
+            (
+                "방금 입력 선택",
+                "multi",
+                VoiceIntentKind::SelectInsertion,
+                CommandLocale::Ko,
+            ),
+            (
+                "Neuer Absatz",
+                "de",
+                VoiceIntentKind::NewParagraph,
+                CommandLocale::De,
+            ),
+        ] {
+            let routed = route(utterance, false, SpeechLanguageMode::Explicit(language));
+            assert_eq!(routed.kind, kind, "{utterance}");
+            assert_eq!(
+                routed.placement,
+                VoiceOutputPlacement::EditInPlace,
+                "{utterance}"
+            );
+            assert_eq!(routed.grammar_locale, Some(locale), "{utterance}");
+            assert_eq!(routed.payload, None, "{utterance}");
+        }
+
+        for utterance in [
+            "delete the last sentence of the contract",
+            "start a new paragraph about pricing",
+            "\"undo that\"",
+        ] {
+            let routed = route(utterance, false, SpeechLanguageMode::Explicit("en"));
+            assert!(!routed.kind.is_edit(), "{utterance}");
+        }
+
+        let selected = route("undo that", true, SpeechLanguageMode::Explicit("en"));
+        assert_eq!(selected.kind, VoiceIntentKind::AskSelection);
+
+        let disabled = VoiceIntentRouter::route(VoiceRouteRequest {
+            mode: VoiceMode::Dictate,
+            utterance: "undo that",
+            has_selected_text: false,
+            speech_language: SpeechLanguageMode::Explicit("en"),
+            flags: VoiceRoutingFlags {
+                edit_commands: false,
+                ..VoiceRoutingFlags::default()
+            },
+        });
+        assert_eq!(disabled.kind, VoiceIntentKind::DictateInsert);
+        assert_eq!(
+            disabled.fallback_reason,
+            Some(RouteFallbackReason::FeatureDisabled)
+        );
+
+        let ask = VoiceIntentRouter::route(request(
+            VoiceMode::Ask,
+            "undo that",
+            false,
+            SpeechLanguageMode::Explicit("en"),
+        ));
+        assert_eq!(ask.kind, VoiceIntentKind::OpenQuestion);
+    }
+
+    #[test]
+    fn voice_intent_user_search_providers_are_spoken_like_builtins() {
+        let user = UserGrammar::parse(
+            r#"{ "search_providers": [
+                { "id": "docs_rs", "name": "docs.rs",
+                  "aliases": { "en": ["docs.rs", "rust docs"], "de": ["rust-doku"] },
+                  "url_template": "https://docs.rs/releases/search?query={query}" },
+                { "id": "team_wiki", "name": "Team wiki", "aliases": { "ja": ["ウィキ"] },
+                  "url_template": "https://wiki.example.com/search?q={query}" }
+            ] }"#,
+        )
+        .unwrap()
+        .grammar;
+        let route = |utterance, language| {
+            VoiceIntentRouter::route_with_grammar(
+                request(VoiceMode::Ask, utterance, false, language),
+                &user,
+            )
+        };
+        let docs = Some(SearchProvider::Custom("docs_rs".to_string()));
+
+        for (utterance, language, provider, query) in [
+            (
+                "search serde derive on docs.rs",
+                SpeechLanguageMode::Explicit("en"),
+                docs.clone(),
+                "serde derive",
+            ),
+            (
+                "search rust docs for async traits",
+                SpeechLanguageMode::Explicit("en"),
+                docs.clone(),
+                "async traits",
+            ),
+            (
+                "suche tokio auf Rust-Doku",
+                SpeechLanguageMode::Explicit("de"),
+                docs.clone(),
+                "tokio",
+            ),
+            (
+                "ウィキで休暇申請を検索して",
+                SpeechLanguageMode::Explicit("ja"),
+                Some(SearchProvider::Custom("team_wiki".to_string())),
+                "休暇申請",
+            ),
+            (
+                "search serde on google",
+                SpeechLanguageMode::Explicit("en"),
+                Some(SearchProvider::Google),
+                "serde",
+            ),
+        ] {
+            let routed = route(utterance, language);
+            assert_eq!(routed.kind, VoiceIntentKind::Search, "{utterance}");
+            assert_eq!(routed.search_provider, provider, "{utterance}");
+            assert_eq!(routed.payload.as_deref(), Some(query), "{utterance}");
+        }
+
+        let other_locale = route(
+            "search serde on rust-doku",
+            SpeechLanguageMode::Explicit("en"),
+        );
+        assert_ne!(other_locale.search_provider, docs);
+    }
+
     #[test]
     fn voice_route_e2e_plans_one_provider_call_max_and_exact_output_behavior() {
         struct Case<'a> {
@@ -644,6 +1178,16 @@ mod tests {
                 allow_streaming: false,
                 restore_target: false,
             },
+            Case {
+                mode: VoiceMode::Dictate,
+                utterance: "Delete the last sentence.",
+                has_selection: false,
+                kind: VoiceIntentKind::DeleteLastSentence,
+                provider_calls: 0,
+                provider_input: None,
+                allow_streaming: false,
+                restore_target: false,
+            },
             Case {
                 mode: VoiceMode::Translate,
                 utterance: "See you tomorrow",
@@ -758,13 +1302,15 @@ mod tests {
             *counts.entry(format!("{:?}", result.kind)).or_default() += 1;
             if case.destructive_blocker {
                 blockers += 1;
-                if matches!(
-                    result.kind,
-                    VoiceIntentKind::DraftInsert
-                        | VoiceIntentKind::RewriteSelection
-                        | VoiceIntentKind::TranslateSelection
-                        | VoiceIntentKind::Search
-                ) {
+                if result.kind.is_edit()
+                    || matches!(
+                        result.kind,
+                        VoiceIntentKind::DraftInsert
+                            | VoiceIntentKind::RewriteSelection
+                            | VoiceIntentKind::TranslateSelection
+                            | VoiceIntentKind::Search
+                    )
+                {
                     destructive_false_positives += 1;
                 }
             }
@@ -802,4 +1348,44 @@ mod tests {
             include_str!("../../tests/fixtures/voice_intent_zh_hant.json"),
         );
     }
+
+    #[test]
+    fn voice_intent_corpus_ja() {
+        run_corpus(
+            "ja",
+            include_str!("../../tests/fixtures/voice_intent_ja.json"),
+        );
+    }
+
+    #[test]
+    fn voice_intent_corpus_ko() {
+        run_corpus(
+            "ko",
+            include_str!("../../tests/fixtures/voice_intent_ko.json"),
+        );
+    }
+
+    #[test]
+    fn voice_intent_corpus_de() {
+        run_corpus(
+            "de",
+            include_str!("../../tests/fixtures/voice_intent_de.json"),
+        );
+    }
+
+    #[test]
+    fn voice_intent_corpus_es() {
+        run_corpus(
+            "es",
+            include_str!("../../tests/fixtures/voice_intent_es.json"),
+        );
+    }
+
+    #[test]
+    fn voice_intent_corpus_fr() {
+        run_corpus(
+            "fr",
+            include_str!("../../tests/fixtures/voice_intent_fr.json"),
+        );
+    }
 }
@@ -86,7 +86,7 @@ fn is_command_boundary(character: char) -> bool {
     character.is_whitespace()
         || matches!(
             character,
-            ':' | '：' | ',' | '，' | '.' | '。' | '!' | '！' | '?' | '？' | '-' | '—'
+            ':' | '：' | ',' | '，' | '、' | '.' | '。' | '!' | '！' | '?' | '？' | '-' | '—'
         )
 }
 
@@ -97,7 +97,18 @@ pub(crate) fn trim_command_payload(value: &str) -> Option<&str> {
             character.is_whitespace()
                 || matches!(
                     character,
-                    ':' | '：' | ',' | '，' | '.' | '。' | '!' | '！' | '?' | '？' | '-' | '—'
+                    ':' | '：'
+                        | ','
+                        | '，'
+                        | '、'
+                        | '.'
+                        | '。'
+                        | '!'
+                        | '！'
+                        | '?'
+                        | '？'
+                        | '-'
+                        | '—'
                 )
         })
         .trim();
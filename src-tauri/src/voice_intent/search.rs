@@ -1,8 +1,13 @@
-use super::SearchProvider;
+use super::{CommandLocale, SearchProvider};
+
+/// Stands in for `{query}` while a URL template is parsed.
+const TEMPLATE_QUERY_MARKER: &str = "voice-search-query-marker";
+const MAX_TEMPLATE_CHARS: usize = 2048;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SearchUrl {
     provider: SearchProvider,
+    shape: SearchShape,
     url: url::Url,
 }
 
@@ -10,6 +15,8 @@ pub struct SearchUrl {
 pub enum SearchUrlError {
     EmptyQuery,
     InvalidShape,
+    InvalidTemplate,
+    UnknownProvider,
 }
 
 impl std::fmt::Display for SearchUrlError {
@@ -17,23 +24,160 @@ impl std::fmt::Display for SearchUrlError {
         formatter.write_str(match self {
             Self::EmptyQuery => "search query is empty",
             Self::InvalidShape => "search URL does not match an allow-listed provider shape",
+            Self::InvalidTemplate => {
+                "search URL template must look like https://host/path?key={query}"
+            }
+            Self::UnknownProvider => "search provider is not configured",
         })
     }
 }
 
 impl std::error::Error for SearchUrlError {}
 
+/// The only address a provider may open: an HTTPS host and path with the
+/// query in one named parameter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchShape {
+    host: String,
+    path: String,
+    query_key: String,
+}
+
+impl SearchShape {
+    fn built_in(provider: &SearchProvider) -> Option<Self> {
+        let (host, path, query_key) = match provider {
+            SearchProvider::Google => ("www.google.com", "/search", "q"),
+            SearchProvider::YouTube => ("www.youtube.com", "/results", "search_query"),
+            SearchProvider::Amazon => ("www.amazon.com", "/s", "k"),
+            SearchProvider::GitHub => ("github.com", "/search", "q"),
+            SearchProvider::Custom(_) => return None,
+        };
+        Some(Self {
+            host: host.to_string(),
+            path: path.to_string(),
+            query_key: query_key.to_string(),
+        })
+    }
+
+    /// Parses a user template such as `https://docs.rs/releases/search?query={query}`.
+    /// The template must already have the shape `SearchUrl::validate`
+    /// enforces, with `{query}` as the whole value of its only parameter.
+    pub fn from_template(template: &str) -> Result<Self, SearchUrlError> {
+        let template = template.trim();
+        if template.chars().count() > MAX_TEMPLATE_CHARS || template.matches("{query}").count() != 1
+        {
+            return Err(SearchUrlError::InvalidTemplate);
+        }
+        let url = url::Url::parse(&template.replace("{query}", TEMPLATE_QUERY_MARKER))
+            .map_err(|_| SearchUrlError::InvalidTemplate)?;
+        let pairs = url.query_pairs().collect::<Vec<_>>();
+        let [(query_key, query_value)] = pairs.as_slice() else {
+            return Err(SearchUrlError::InvalidTemplate);
+        };
+        if url.scheme() != "https"
+            || url.port().is_some()
+            || url.fragment().is_some()
+            || !url.username().is_empty()
+            || url.password().is_some()
+            || query_key.trim().is_empty()
+            || query_value != TEMPLATE_QUERY_MARKER
+        {
+            return Err(SearchUrlError::InvalidTemplate);
+        }
+        let Some(host) = url.host_str() else {
+            return Err(SearchUrlError::InvalidTemplate);
+        };
+        Ok(Self {
+            host: host.to_string(),
+            path: url.path().to_string(),
+            query_key: query_key.to_string(),
+        })
+    }
+}
+
+/// A user-defined search target from `voice_grammar.json`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CustomSearchProvider {
+    id: String,
+    name: String,
+    /// Lowercased spoken names per locale, matched like the built-in names.
+    aliases: Vec<(CommandLocale, String)>,
+    shape: SearchShape,
+}
+
+impl CustomSearchProvider {
+    pub(crate) fn new(
+        id: String,
+        name: String,
+        aliases: Vec<(CommandLocale, String)>,
+        shape: SearchShape,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            aliases,
+            shape,
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn provider(&self) -> SearchProvider {
+        SearchProvider::Custom(self.id.clone())
+    }
+
+    pub(crate) fn aliases(&self, locale: CommandLocale) -> impl Iterator<Item = &str> {
+        self.aliases
+            .iter()
+            .filter(move |(alias_locale, _)| *alias_locale == locale)
+            .map(|(_, alias)| alias.as_str())
+    }
+}
+
 impl SearchUrl {
+    /// Builds the URL for a built-in provider, or for a custom provider from
+    /// the installed voice grammar.
     pub fn new(provider: SearchProvider, query: &str) -> Result<Self, SearchUrlError> {
+        let shape = match &provider {
+            SearchProvider::Custom(id) => super::grammar::current_user_grammar()
+                .search_provider(id)
+                .map(|custom| custom.shape.clone())
+                .ok_or(SearchUrlError::UnknownProvider)?,
+            built_in => SearchShape::built_in(built_in).ok_or(SearchUrlError::InvalidShape)?,
+        };
+        Self::with_shape(provider, shape, query)
+    }
+
+    pub fn custom(provider: &CustomSearchProvider, query: &str) -> Result<Self, SearchUrlError> {
+        Self::with_shape(provider.provider(), provider.shape.clone(), query)
+    }
+
+    fn with_shape(
+        provider: SearchProvider,
+        shape: SearchShape,
+        query: &str,
+    ) -> Result<Self, SearchUrlError> {
         let query = query.trim();
         if query.is_empty() {
             return Err(SearchUrlError::EmptyQuery);
         }
-        let (origin, path, query_key) = provider_shape(provider);
-        let mut url = url::Url::parse(origin).map_err(|_| SearchUrlError::InvalidShape)?;
-        url.set_path(path);
-        url.query_pairs_mut().clear().append_pair(query_key, query);
-        let search_url = Self { provider, url };
+        let mut url = url::Url::parse(&format!("https://{}", shape.host))
+            .map_err(|_| SearchUrlError::InvalidShape)?;
+        url.set_path(&shape.path);
+        url.query_pairs_mut()
+            .clear()
+            .append_pair(&shape.query_key, query);
+        let search_url = Self {
+            provider,
+            shape,
+            url,
+        };
         search_url.validate()?;
         Ok(search_url)
     }
@@ -43,12 +187,16 @@ impl SearchUrl {
     }
 
     pub fn validate(&self) -> Result<(), SearchUrlError> {
-        let (origin, expected_path, expected_key) = provider_shape(self.provider);
-        let expected_origin = url::Url::parse(origin).map_err(|_| SearchUrlError::InvalidShape)?;
+        // Built-in providers never take their shape from anywhere but the table.
+        if let Some(expected) = SearchShape::built_in(&self.provider) {
+            if expected != self.shape {
+                return Err(SearchUrlError::InvalidShape);
+            }
+        }
         if self.url.scheme() != "https"
-            || self.url.host_str() != expected_origin.host_str()
+            || self.url.host_str() != Some(self.shape.host.as_str())
             || self.url.port().is_some()
-            || self.url.path() != expected_path
+            || self.url.path() != self.shape.path
             || self.url.fragment().is_some()
             || !self.url.username().is_empty()
             || self.url.password().is_some()
@@ -56,7 +204,7 @@ impl SearchUrl {
             return Err(SearchUrlError::InvalidShape);
         }
         let pairs = self.url.query_pairs().collect::<Vec<_>>();
-        if pairs.len() != 1 || pairs[0].0 != expected_key || pairs[0].1.trim().is_empty() {
+        if pairs.len() != 1 || pairs[0].0 != self.shape.query_key || pairs[0].1.trim().is_empty() {
             return Err(SearchUrlError::InvalidShape);
         }
         Ok(())
@@ -65,35 +213,23 @@ impl SearchUrl {
     #[cfg(test)]
     fn try_from_url(value: &str) -> Result<Self, SearchUrlError> {
         let url = url::Url::parse(value).map_err(|_| SearchUrlError::InvalidShape)?;
-        let provider = [
-            SearchProvider::Google,
-            SearchProvider::YouTube,
-            SearchProvider::Amazon,
-            SearchProvider::GitHub,
-        ]
-        .into_iter()
-        .find(|provider| {
-            let (origin, path, _) = provider_shape(*provider);
-            url::Url::parse(origin)
-                .ok()
-                .is_some_and(|expected| expected.host_str() == url.host_str() && path == url.path())
-        })
-        .ok_or(SearchUrlError::InvalidShape)?;
-        let search_url = Self { provider, url };
+        let (provider, shape) = SearchProvider::BUILT_IN
+            .into_iter()
+            .filter_map(|provider| SearchShape::built_in(&provider).map(|shape| (provider, shape)))
+            .find(|(_, shape)| {
+                url.host_str() == Some(shape.host.as_str()) && url.path() == shape.path
+            })
+            .ok_or(SearchUrlError::InvalidShape)?;
+        let search_url = Self {
+            provider,
+            shape,
+            url,
+        };
         search_url.validate()?;
         Ok(search_url)
     }
 }
 
-fn provider_shape(provider: SearchProvider) -> (&'static str, &'static str, &'static str) {
-    match provider {
-        SearchProvider::Google => ("https://www.google.com", "/search", "q"),
-        SearchProvider::YouTube => ("https://www.youtube.com", "/results", "search_query"),
-        SearchProvider::Amazon => ("https://www.amazon.com", "/s", "k"),
-        SearchProvider::GitHub => ("https://github.com", "/search", "q"),
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -162,4 +298,55 @@ mod tests {
         assert!(SearchUrl::try_from_url("https://www.google.com/other?q=rust").is_err());
         assert!(SearchUrl::try_from_url("https://www.google.com/search?other=rust").is_err());
     }
+
+    #[test]
+    fn search_templates_must_put_the_query_in_one_https_parameter() {
+        let shape =
+            SearchShape::from_template("https://docs.rs/releases/search?query={query}").unwrap();
+        let provider = CustomSearchProvider::new(
+            "docs_rs".to_string(),
+            "docs.rs".to_string(),
+            vec![(CommandLocale::En, "docs.rs".to_string())],
+            shape,
+        );
+        let url = SearchUrl::custom(&provider, "serde json & co").unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://docs.rs/releases/search?query=serde+json+%26+co"
+        );
+        assert!(url.validate().is_ok());
+        assert_eq!(
+            SearchUrl::custom(&provider, "  "),
+            Err(SearchUrlError::EmptyQuery)
+        );
+
+        for template in [
+            "http://docs.rs/search?q={query}",
+            "https://docs.rs:8443/search?q={query}",
+            "https://user@docs.rs/search?q={query}",
+            "https://docs.rs/search?q={query}#top",
+            "https://docs.rs/search?q={query}&lang=en",
+            "https://docs.rs/search?q=rust+{query}",
+            "https://docs.rs/{query}?q={query}",
+            "https://docs.rs/search/{query}",
+            "https://docs.rs/search?={query}",
+            "https://docs.rs/search",
+            "javascript:alert('{query}')",
+            "file:///search?q={query}",
+        ] {
+            assert_eq!(
+                SearchShape::from_template(template),
+                Err(SearchUrlError::InvalidTemplate),
+                "{template}"
+            );
+        }
+    }
+
+    #[test]
+    fn custom_search_provider_needs_an_installed_definition() {
+        assert_eq!(
+            SearchUrl::new(SearchProvider::Custom("not_installed".to_string()), "rust"),
+            Err(SearchUrlError::UnknownProvider)
+        );
+    }
 }
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -19,6 +21,12 @@ pub enum VoiceIntentKind {
     AskSelection,
     OpenQuestion,
     Search,
+    UndoInsertion,
+    DeleteLastSentence,
+    DeleteLastWord,
+    NewLine,
+    NewParagraph,
+    SelectInsertion,
 }
 
 impl VoiceIntentKind {
@@ -32,8 +40,28 @@ impl VoiceIntentKind {
             Self::AskSelection => "ask_selection",
             Self::OpenQuestion => "open_question",
             Self::Search => "search",
+            Self::UndoInsertion => "undo_insertion",
+            Self::DeleteLastSentence => "delete_last_sentence",
+            Self::DeleteLastWord => "delete_last_word",
+            Self::NewLine => "new_line",
+            Self::NewParagraph => "new_paragraph",
+            Self::SelectInsertion => "select_insertion",
         }
     }
+
+    /// Editing commands act on text already in the target app and never
+    /// call a language model.
+    pub fn is_edit(self) -> bool {
+        matches!(
+            self,
+            Self::UndoInsertion
+                | Self::DeleteLastSentence
+                | Self::DeleteLastWord
+                | Self::NewLine
+                | Self::NewParagraph
+                | Self::SelectInsertion
+        )
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -43,6 +71,7 @@ pub enum VoiceOutputPlacement {
     ReplaceSelection,
     PopupAnswer,
     OpenUrl,
+    EditInPlace,
 }
 
 impl VoiceOutputPlacement {
@@ -52,38 +81,104 @@ impl VoiceOutputPlacement {
             Self::ReplaceSelection => "replace_selection",
             Self::PopupAnswer => "popup_answer",
             Self::OpenUrl => "open_url",
+            Self::EditInPlace => "edit_in_place",
         }
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+/// Where a voice search opens. Built-in providers serialize as `google`,
+/// `youtube`, `amazon` and `github`; user-defined ones as their own id.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub enum SearchProvider {
     Google,
-    #[serde(rename = "youtube")]
     YouTube,
     Amazon,
-    #[serde(rename = "github")]
     GitHub,
+    /// A provider from the `search_providers` section of `voice_grammar.json`.
+    Custom(String),
 }
 
 impl SearchProvider {
-    pub fn display_name(self) -> &'static str {
+    pub const BUILT_IN: [SearchProvider; 4] =
+        [Self::Google, Self::YouTube, Self::Amazon, Self::GitHub];
+
+    pub fn id(&self) -> &str {
+        match self {
+            Self::Google => "google",
+            Self::YouTube => "youtube",
+            Self::Amazon => "amazon",
+            Self::GitHub => "github",
+            Self::Custom(id) => id,
+        }
+    }
+
+    pub fn display_name(&self) -> Cow<'static, str> {
         match self {
-            Self::Google => "Google",
-            Self::YouTube => "YouTube",
-            Self::Amazon => "Amazon",
-            Self::GitHub => "GitHub",
+            Self::Google => Cow::Borrowed("Google"),
+            Self::YouTube => Cow::Borrowed("YouTube"),
+            Self::Amazon => Cow::Borrowed("Amazon"),
+            Self::GitHub => Cow::Borrowed("GitHub"),
+            Self::Custom(id) => Cow::Owned(
+                super::grammar::current_user_grammar()
+                    .search_provider(id)
+                    .map(|provider| provider.name().to_string())
+                    .unwrap_or_else(|| id.clone()),
+            ),
         }
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// Custom provider ids: 1-32 lowercase ASCII letters, digits, `-` or `_`,
+/// starting with a letter.
+pub(crate) fn is_custom_search_provider_id(id: &str) -> bool {
+    (1..=32).contains(&id.len())
+        && id.starts_with(|character: char| character.is_ascii_lowercase())
+        && id.chars().all(|character| {
+            character.is_ascii_lowercase()
+                || character.is_ascii_digit()
+                || matches!(character, '-' | '_')
+        })
+}
+
+impl TryFrom<String> for SearchProvider {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if let Some(provider) = Self::BUILT_IN
+            .into_iter()
+            .find(|provider| provider.id() == value)
+        {
+            return Ok(provider);
+        }
+        if is_custom_search_provider_id(&value) {
+            Ok(Self::Custom(value))
+        } else {
+            Err(format!("invalid search provider id: {value}"))
+        }
+    }
+}
+
+impl From<SearchProvider> for String {
+    fn from(provider: SearchProvider) -> Self {
+        match provider {
+            SearchProvider::Custom(id) => id,
+            built_in => built_in.id().to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum CommandLocale {
     En,
     ZhHans,
     ZhHant,
+    Ja,
+    Ko,
+    De,
+    Es,
+    Fr,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -194,12 +289,18 @@ impl VoiceIntent {
                 VoiceOutputPlacement::PopupAnswer
             }
             VoiceIntentKind::Search => VoiceOutputPlacement::OpenUrl,
+            VoiceIntentKind::UndoInsertion
+            | VoiceIntentKind::DeleteLastSentence
+            | VoiceIntentKind::DeleteLastWord
+            | VoiceIntentKind::NewLine
+            | VoiceIntentKind::NewParagraph
+            | VoiceIntentKind::SelectInsertion => VoiceOutputPlacement::EditInPlace,
         };
         if placement != required_placement {
             return Err(VoiceIntentError::InvalidPlacement);
         }
 
-        match (kind, search_provider) {
+        match (kind, &search_provider) {
             (VoiceIntentKind::Search, None) => return Err(VoiceIntentError::MissingSearchProvider),
             (VoiceIntentKind::Search, Some(_)) | (_, None) => {}
             (_, Some(_)) => return Err(VoiceIntentError::UnexpectedSearchProvider),
@@ -244,6 +345,8 @@ pub struct VoiceRoutingFlags {
     pub translate_selection: bool,
     #[serde(default = "default_true")]
     pub search: bool,
+    #[serde(default = "default_true")]
+    pub edit_commands: bool,
 }
 
 impl Default for VoiceRoutingFlags {
@@ -253,6 +356,7 @@ impl Default for VoiceRoutingFlags {
             rewrite_selection: true,
             translate_selection: true,
             search: true,
+            edit_commands: true,
         }
     }
 }
@@ -312,7 +416,7 @@ mod tests {
                 case.flags,
                 case.expected_kind,
                 case.expected_placement,
-                case.expected_provider,
+                case.expected_provider.as_ref(),
                 case.expected_payload.as_deref(),
             );
         }
@@ -327,6 +431,11 @@ mod tests {
         assert_fixture_contract(include_str!(
             "../../tests/fixtures/voice_intent_zh_hant.json"
         ));
+        assert_fixture_contract(include_str!("../../tests/fixtures/voice_intent_ja.json"));
+        assert_fixture_contract(include_str!("../../tests/fixtures/voice_intent_ko.json"));
+        assert_fixture_contract(include_str!("../../tests/fixtures/voice_intent_de.json"));
+        assert_fixture_contract(include_str!("../../tests/fixtures/voice_intent_es.json"));
+        assert_fixture_contract(include_str!("../../tests/fixtures/voice_intent_fr.json"));
     }
 
     #[test]
@@ -352,6 +461,7 @@ mod tests {
             serde_json::to_value(CommandLocale::ZhHant).unwrap(),
             "zh_hant"
         );
+        assert_eq!(serde_json::to_value(CommandLocale::Ja).unwrap(), "ja");
         assert_eq!(
             serde_json::to_value(RouteFallbackReason::FeatureDisabled).unwrap(),
             "feature_disabled"
@@ -381,6 +491,27 @@ mod tests {
             None,
         )
         .is_err());
+
+        assert!(VoiceIntent::from_parts(
+            VoiceIntentKind::DeleteLastWord,
+            VoiceOutputPlacement::ReplaceSelection,
+            1.0,
+            None,
+            None,
+            Some(CommandLocale::En),
+            None,
+        )
+        .is_err());
+        assert!(VoiceIntent::from_parts(
+            VoiceIntentKind::NewParagraph,
+            VoiceOutputPlacement::EditInPlace,
+            1.0,
+            None,
+            None,
+            Some(CommandLocale::En),
+            None,
+        )
+        .is_ok());
     }
 
     #[test]
@@ -466,6 +597,7 @@ mod tests {
                 rewrite_selection: true,
                 translate_selection: true,
                 search: true,
+                edit_commands: true,
             }
         );
     }
@@ -430,7 +430,7 @@ function buildEnglish() {
     corpus.blocked({
       id: 'unsupported-locale',
       mode: utterance.includes('search') ? 'ask' : 'dictate',
-      locale: 'fr',
+      locale: 'pt',
       utterance,
       hasSelection: /rewrite|translate|warmer|grammar|format|turn/.test(utterance),
       reason: 'unsupported_locale',
@@ -457,6 +457,7 @@ function buildEnglish() {
     })
   }
 
+  addEditCases(corpus, locale, editCommands.en)
   return corpus.cases
 }
 
@@ -713,7 +714,7 @@ function buildChinese({ traditional }) {
     corpus.blocked({
       id: 'unsupported-locale',
       mode: utterance.includes(words.searchVerb) ? 'ask' : 'dictate',
-      locale: 'fr',
+      locale: 'pt',
       utterance,
       hasSelection: words.rewrite.includes(utterance) || words.translate.includes(utterance),
       reason: 'unsupported_locale',
@@ -740,6 +741,1847 @@ function buildChinese({ traditional }) {
     })
   }
 
+  if (!traditional) {
+    corpus.add({
+      id: 'draft-email-natural-object',
+      locale,
+      utterance: '写一份给 Sara 的邮件麻烦她帮我订一下会议室',
+      expectedKind: 'draft_insert',
+      expectedPlacement: 'insert_at_cursor',
+      expectedPayload: '给 Sara 的邮件麻烦她帮我订一下会议室',
+    })
+    corpus.add({
+      id: 'draft-email-natural-recipient-first-person',
+      locale,
+      utterance: '我想写一份给 Sara 的邮件我们这周的会议在下午五点',
+      expectedKind: 'draft_insert',
+      expectedPlacement: 'insert_at_cursor',
+      expectedPayload: '给 Sara 的邮件我们这周的会议在下午五点',
+    })
+    corpus.blocked({
+      id: 'information-security-dictation',
+      locale,
+      utterance: '我想写一份信息安全方案',
+      reason: null,
+    })
+  }
+
+  addEditCases(corpus, locale, editCommands[traditional ? 'zh_hant' : 'zh_hans'])
+  return corpus.cases
+}
+
+// Editing commands act on the last insertion, so every corpus also checks
+// that they stay dictation inside a sentence, with a selection, or while the
+// feature is off.
+const editCommands = {
+  en: {
+    commands: [
+      ['undo_insertion', 'Undo that'],
+      ['delete_last_sentence', 'Delete the last sentence.'],
+      ['delete_last_word', 'delete last word'],
+      ['new_line', 'New line'],
+      ['new_paragraph', 'new paragraph'],
+      ['select_insertion', 'Select that'],
+    ],
+    insideSentence: [
+      'undo that change in the settings',
+      'please delete the last sentence',
+      'a new line of products',
+      'I will select that option',
+    ],
+  },
+  zh_hans: {
+    commands: [
+      ['undo_insertion', '撤销'],
+      ['delete_last_sentence', '删除上一句'],
+      ['delete_last_word', '删除上一个词'],
+      ['new_line', '换行'],
+      ['new_paragraph', '新段落'],
+      ['select_insertion', '选中刚才的内容'],
+    ],
+    insideSentence: [
+      '撤销这个决定需要时间',
+      '我们换行业了',
+      '新段落的内容很重要',
+    ],
+  },
+  zh_hant: {
+    commands: [
+      ['undo_insertion', '撤銷'],
+      ['delete_last_sentence', '刪除上一句'],
+      ['delete_last_word', '刪除上一個詞'],
+      ['new_line', '換行'],
+      ['new_paragraph', '新段落'],
+      ['select_insertion', '選取剛才的內容'],
+    ],
+    insideSentence: [
+      '撤銷這個決定需要時間',
+      '我們換行業了',
+      '新段落的內容很重要',
+    ],
+  },
+  ja: {
+    commands: [
+      ['undo_insertion', '元に戻して'],
+      ['delete_last_sentence', '最後の文を削除'],
+      ['delete_last_word', '最後の単語を削除'],
+      ['new_line', '改行'],
+      ['new_paragraph', '新しい段落'],
+      ['select_insertion', '今の入力を選択'],
+    ],
+    insideSentence: [
+      '元に戻してから確認します',
+      '改行コードを確認して',
+    ],
+  },
+  ko: {
+    commands: [
+      ['undo_insertion', '실행 취소'],
+      ['delete_last_sentence', '마지막 문장 삭제'],
+      ['delete_last_word', '마지막 단어 삭제'],
+      ['new_line', '줄 바꿈'],
+      ['new_paragraph', '새 문단'],
+      ['select_insertion', '방금 입력 선택'],
+    ],
+    insideSentence: [
+      '실행 취소 버튼을 눌렀어요',
+      '새 문단을 쓰는 중이에요',
+    ],
+  },
+  de: {
+    commands: [
+      ['undo_insertion', 'Rückgängig'],
+      ['delete_last_sentence', 'Letzten Satz löschen'],
+      ['delete_last_word', 'Letztes Wort löschen'],
+      ['new_line', 'Neue Zeile'],
+      ['new_paragraph', 'Neuer Absatz'],
+      ['select_insertion', 'Markiere das'],
+    ],
+    insideSentence: [
+      'Die neue Zeile im Vertrag fehlt',
+      'Rückgängig machen ist schwer',
+    ],
+  },
+  es: {
+    commands: [
+      ['undo_insertion', 'Deshacer'],
+      ['delete_last_sentence', 'Borra la última frase'],
+      ['delete_last_word', 'Borra la última palabra'],
+      ['new_line', 'Nueva línea'],
+      ['new_paragraph', 'Nuevo párrafo'],
+      ['select_insertion', 'Selecciona eso'],
+    ],
+    insideSentence: [
+      'La nueva línea de productos llega mañana',
+      'Deshacer el cambio fue difícil',
+    ],
+  },
+  fr: {
+    commands: [
+      ['undo_insertion', 'Annule ça'],
+      ['delete_last_sentence', 'Supprime la dernière phrase'],
+      ['delete_last_word', 'Supprime le dernier mot'],
+      ['new_line', 'Nouvelle ligne'],
+      ['new_paragraph', 'Nouveau paragraphe'],
+      ['select_insertion', 'Sélectionne ça'],
+    ],
+    insideSentence: [
+      'La nouvelle ligne de bus ouvre demain',
+      'Le nouveau paragraphe est plus clair',
+    ],
+  },
+}
+
+function addEditCases(corpus, locale, { commands, insideSentence }) {
+  const editFlags = flags({ edit_commands: true })
+  for (const [expectedKind, utterance] of commands) {
+    corpus.add({
+      id: 'edit-command',
+      locale,
+      utterance,
+      routeFlags: editFlags,
+      expectedKind,
+      expectedPlacement: 'edit_in_place',
+    })
+  }
+  for (const utterance of insideSentence) {
+    corpus.add({
+      id: 'edit-inside-sentence',
+      locale,
+      utterance,
+      routeFlags: editFlags,
+      ...fallback('dictate', false),
+      destructiveBlocker: true,
+    })
+  }
+  const [, deleteSentence] = commands.find(([kind]) => kind === 'delete_last_sentence')
+  corpus.add({
+    id: 'edit-with-selection',
+    locale,
+    utterance: deleteSentence,
+    hasSelection: true,
+    routeFlags: editFlags,
+    ...fallback('dictate', true),
+    destructiveBlocker: true,
+  })
+  corpus.add({
+    id: 'edit-disabled',
+    locale,
+    utterance: deleteSentence,
+    routeFlags: flags({ edit_commands: false }),
+    ...fallback('dictate', false),
+    expectedFallbackReason: 'feature_disabled',
+    destructiveBlocker: true,
+  })
+}
+
+// Japanese, Korean, German, Spanish and French share one corpus layout, so
+// each is a table of utterances per case family. Search templates take
+// {query} and {provider}.
+const localeCorpora = {
+  ja: {
+    prefix: 'ja',
+    locale: 'ja',
+    draftSeparator: '',
+    drafts: [
+      ['下書き、', '明日の会議の案内'],
+      ['下書き：', '来週の出張の予定をチームに共有する'],
+      ['返信、', 'ご連絡ありがとうございます。確認します'],
+      ['返信：', '承知しました。金曜日までに対応します'],
+      ['下書きして、', '新製品の発表についてのお知らせ'],
+      ['返信して、', '日程は水曜日の午後でお願いします'],
+      ['メールを書いて、', '山田さんへの見積もりのお礼'],
+      ['返信文を書いて、', '納期が一週間遅れることへのお詫び'],
+      ['下書き、', '歓迎会のお知らせ'],
+      ['下書き ', 'プロジェクトの進捗報告'],
+      ['返信、', '資料を拝見しました。とても分かりやすいです'],
+      ['メールを書いて：', '取引先への訪問日程の調整'],
+      ['下書きして：', '夏季休暇のお知らせ'],
+      ['返信して ', 'はい、参加します'],
+      ['返信文を書いて ', 'お問い合わせいただいた件の回答'],
+      ['下書き、', '新しいメンバーの紹介文'],
+      ['返信、', '了解です。あとで電話します'],
+      ['メールを書いて、', '経理部への請求書の確認依頼'],
+      ['下書き、', '社内報の六月号の記事'],
+      ['返信：', 'ありがとうございます、助かりました'],
+      ['下書きして、', 'オフィス移転の案内'],
+      ['返信して、', '明日の十時で大丈夫です'],
+      ['メールを書いて、', '田中部長への出張報告'],
+      ['下書き、', 'ワークショップの議題'],
+      ['返信文を書いて、', '見積もりの再提出について'],
+      ['下書き：', '年末の挨拶状'],
+      ['返信、', 'その件は佐藤さんに確認します。'],
+      ['下書きして ', '新サービスのプレスリリース'],
+      ['メールを書いて ', '面接の日程候補の連絡'],
+      ['下書き、', '顧客アンケートの依頼文'],
+      ['返信して：', '来週の月曜日に伺います'],
+      ['下書き、', 'Q3の売上報告のまとめ'],
+      ['返信、', 'Slackで共有しました'],
+      ['メールを書いて、', 'Wi-Fiメンテナンスのお知らせ'],
+      ['下書き、', '引っ越しの挨拶'],
+    ],
+    askDrafts: [
+      ['下書き、', '日程変更のお願い'],
+      ['返信、', 'ご確認ありがとうございます'],
+      ['メールを書いて、', '総務部への備品の申請'],
+      ['下書きして、', '研修の案内'],
+      ['返信して、', '承知いたしました'],
+      ['返信文を書いて、', 'ご質問への回答'],
+      ['下書き：', 'プロジェクト概要'],
+      ['返信：', '了解しました、よろしくお願いします'],
+      ['メールを書いて：', '鈴木さんへのお礼'],
+      ['下書き ', '朝会の議事メモ'],
+    ],
+    rewrites: [
+      'これを書き直して',
+      'これを書き直して、もっとカジュアルに',
+      'これを書き直して。',
+      'これを言い換えて',
+      'これを言い換えて、お客様向けに',
+      'これを短くして',
+      'これを短くして、二文以内で',
+      'これを短くして！',
+      'これを長くして',
+      'これを丁寧にして',
+      'これを丁寧にして、上司向けに',
+      'これをやわらかくして',
+      'これを簡潔にして',
+      'これを簡潔にして。',
+      'これを整えて',
+      'これを整えて、読みやすく',
+      'これを箇条書きにして',
+      'これを箇条書きにして。',
+      '文法を直して',
+      '文法を直して、ください',
+      '誤字を直して',
+      '誤字を直して。',
+      'これを書き直して ビジネス向けに',
+      'これを言い換えて。',
+      'これを丁寧にして。',
+    ],
+    translations: [
+      'これを英語に翻訳して',
+      'これを英語に翻訳して。',
+      'これを中国語に訳して',
+      'これをフランス語に翻訳',
+      'この文章を英語に翻訳して',
+      'この文章を韓国語に訳して',
+      'この文章をドイツ語に翻訳して！',
+      '選択部分を英語に翻訳して',
+      '選択部分をスペイン語に訳して',
+      '選択部分をイタリア語に翻訳',
+      'これをやさしい英語に翻訳して',
+      'これをビジネス英語に訳して',
+    ],
+    selectionQuestions: [
+      'これを要約して',
+      'これを要約して、三行で',
+      'これを説明して',
+      'これを説明して、簡単に',
+      'これを比較して',
+      'これはどういう意味？',
+      'なぜこういう書き方なの？',
+      'どうしてこの表現なの？',
+      'どうやって改善できる？',
+      '何が言いたいの？',
+      '誰が書いたの？',
+      'いつの情報？',
+      'どこが分かりにくい？',
+      'この文は自然ですか？',
+      '長すぎますか？',
+    ],
+    searches: [
+      ['{provider}で{query}を検索して', 'google', '東京の天気'],
+      ['グーグルで{query}を検索して', 'google', '近くのカフェ'],
+      ['{provider} で {query} を検索', 'google', 'rust ownership'],
+      ['{provider}で{query}を調べて', 'google', '大阪までの新幹線'],
+      ['グーグルで{query}を調べて', 'google', '確定申告の期限'],
+      ['{provider}で{query}を検索', 'google', '英会話教室 渋谷'],
+      ['{provider}で{query}を検索して', 'youtube', '猫の動画'],
+      ['ユーチューブで{query}を検索して', 'youtube', 'ギターの弾き方'],
+      ['{provider} で {query} を検索して', 'youtube', '朝のヨガ'],
+      ['{provider}で{query}を調べて', 'youtube', 'パスタのレシピ'],
+      ['ユーチューブで{query}を検索', 'youtube', '今日のニュース'],
+      ['{provider}で{query}を検索して', 'youtube', 'Tauri の解説'],
+      ['{provider}で{query}を検索して', 'amazon', 'USB-Cケーブル'],
+      ['アマゾンで{query}を検索して', 'amazon', '登山靴'],
+      ['{provider} で {query} を検索', 'amazon', 'コーヒーミル'],
+      ['{provider}で{query}を調べて', 'amazon', 'エルゴノミクスマウス'],
+      ['アマゾンで{query}を検索', 'amazon', 'A5のノート'],
+      ['{provider}で{query}を検索して', 'amazon', '機内持ち込みのスーツケース'],
+      ['{provider}で{query}を検索して', 'github', 'tauri global shortcut'],
+      ['ギットハブで{query}を検索して', 'github', 'serde json examples'],
+      ['{provider} で {query} を検索', 'github', 'whisper rust bindings'],
+      ['{provider}で{query}を調べて', 'github', 'tokio select macro'],
+      ['ギットハブで{query}を検索', 'github', 'opentypeless'],
+      ['{provider}で{query}を検索して。', 'google', '京都の紅葉'],
+      ['{provider}で{query}を検索して！', 'youtube', 'ドキュメンタリー 富士山'],
+    ],
+    safeDictation: [
+      '明日の会議は十時からです',
+      '資料は共有フォルダに入れました',
+      '電車が遅れているので少し遅れます',
+      '見積もりは今月末まで有効です',
+      '早速のご連絡ありがとうございます',
+      '第三四半期の予算が承認されました',
+      '二階のプリンターが直りました',
+      '大阪からよろしくお伝えください',
+      '会議は木曜日に変更になりました',
+      '新しい机は金曜日に届きます',
+      '請求書の支払いは完了しています',
+      '今日は社員食堂がお休みです',
+      'お昼のあとで会いましょう',
+      'よろしくお願いいたします',
+      '本日の作業は以上です',
+    ],
+    safeQuestions: [
+      '東京は今何時？',
+      'カナダの首都はどこ？',
+      '富士山の高さは？',
+      '空はなぜ青いの？',
+      '明日の天気はどう？',
+    ],
+    negated: [
+      ['dictate', false, '返信しないで'],
+      ['dictate', false, 'まだ返信しないでください'],
+      ['dictate', false, '下書きしなくていいです'],
+      ['dictate', false, 'この件は返信しなくて大丈夫'],
+      ['dictate', false, 'メールは書かないで'],
+      ['dictate', false, '部長には返信しないでおいて'],
+      ['dictate', false, '返信文は書かないでください'],
+      ['dictate', false, '下書きはやめて'],
+      ['dictate', false, '返信は不要です'],
+      ['dictate', false, '下書きは不要、自分で書く'],
+      ['dictate', false, 'お客様には返信するな'],
+      ['dictate', false, '今は下書きしないで'],
+      ['dictate', true, 'これは翻訳しないで'],
+      ['dictate', true, '訳さないでください'],
+      ['dictate', true, '誤字は直さないで'],
+      ['dictate', true, 'これを短くしないで'],
+      ['dictate', true, '書き直しはやめて'],
+      ['dictate', true, '言い換えは不要です'],
+      ['dictate', true, '箇条書きにしないで'],
+      ['dictate', true, '翻訳しなくていい'],
+      ['dictate', true, '文法は直さないでそのままで'],
+      ['dictate', true, '丁寧にしなくて大丈夫'],
+      ['ask', false, '検索しないで'],
+      ['ask', false, 'googleで検索しないでください'],
+      ['ask', false, '調べないで、知ってるから'],
+      ['ask', false, 'ユーチューブで検索はやめて'],
+      ['ask', false, '検索は不要です'],
+      ['ask', false, '返信しないで'],
+      ['ask', false, 'アマゾンで調べないで'],
+      ['ask', false, 'まだ下書きしなくていい'],
+    ],
+    reported: [
+      ['dictate', false, '部長が返信してと言っていました'],
+      ['dictate', false, '田中さんが下書きしてって言ってた'],
+      ['dictate', false, '「返信、了解です」と書いてありました'],
+      ['dictate', false, 'マニュアルに下書きと書かれている'],
+      ['dictate', false, '引用：メールを書いて、お礼を伝える'],
+      ['dictate', false, '「下書き、案内文」はテンプレートの例です'],
+      ['dictate', false, 'お客様が返信してほしいと言っていた'],
+      ['dictate', false, '返信という言葉が多すぎる'],
+      ['dictate', false, '『メールを書いて』というタスクがある'],
+      ['dictate', false, '課長がメールを書いてって言った'],
+      ['dictate', false, 'チャットに「返信して」と書かれていた'],
+      ['dictate', false, '"下書き、報告書"がメモに残っていた'],
+      ['dictate', true, '佐藤さんがこれを短くしてと言った'],
+      ['dictate', true, '「これを英語に翻訳して」と書いてありました'],
+      ['dictate', true, '上司が書き直してって言ってた'],
+      ['dictate', true, '引用：これを丁寧にして'],
+      ['dictate', true, '『文法を直して』は例文です'],
+      ['dictate', true, '翻訳という言葉の使い方'],
+      ['dictate', true, '先生が誤字を直してと言いました'],
+      ['dictate', true, '「これを箇条書きにして」とメモにある'],
+      ['dictate', true, 'お客様が言い換えてほしいと言っていた'],
+      ['dictate', true, '"これを整えて"と書かれていた'],
+      ['ask', false, '部長がgoogleで調べてと言っていた'],
+      ['ask', false, '「ユーチューブで猫を検索して」と書いてある'],
+      ['ask', false, '引用：アマゾンで本を検索'],
+      ['ask', false, '友達が検索してって言ってた'],
+      ['ask', false, '『グーグルで天気を調べて』と書かれていた'],
+      ['ask', false, '検索という言葉の意味'],
+      ['ask', false, '課長が返信してと言った'],
+      ['ask', false, '「ギットハブで検索して」はメモです'],
+    ],
+    identifiers: [
+      ['dictate', false, 'draft_note.pyを直して'],
+      ['dictate', false, 'reply_helper を返信に使う'],
+      ['dictate', false, 'write()の戻り値を確認'],
+      ['dictate', false, 'compose.yamlに返信用の設定がある'],
+      ['dictate', false, 'format.rsを整えて'],
+      ['dictate', false, 'draft-v2ブランチはマージ済み'],
+      ['dictate', false, '返信_テンプレート を更新した'],
+      ['dictate', false, '下書き(仮)のファイル'],
+      ['dictate', false, 'make_draft関数で下書きを作る'],
+      ['dictate', false, 'search-index.jsonを確認'],
+      ['dictate', true, 'translate_text.pyを翻訳して'],
+      ['dictate', true, 'rewrite()を書き直して'],
+      ['dictate', true, 'format.tsを整えて'],
+      ['dictate', true, 'fix-typo.shで誤字を直して'],
+      ['dictate', true, '翻訳(英語)の列'],
+      ['dictate', true, '短く_する のフラグ'],
+      ['ask', false, 'search_api.rsを調べて'],
+      ['ask', false, 'find()の結果を検索'],
+      ['ask', false, '検索_履歴 が重い'],
+      ['ask', false, 'search-utilsを検索して'],
+    ],
+    midSentence: [
+      ['dictate', false, '明日返信します'],
+      ['dictate', false, 'あとで下書きを送ります'],
+      ['dictate', false, '後でメールを書いておきます'],
+      ['dictate', false, '来週返信する予定です'],
+      ['dictate', false, 'たぶん下書きは今日中に終わります'],
+      ['dictate', false, '多分返信は遅れます'],
+      ['dictate', false, 'チームで下書きを確認します'],
+      ['dictate', false, '今度返信文を書いておきます'],
+      ['dictate', false, 'そのうち返信します'],
+      ['dictate', false, '予定では明日下書きを出します'],
+      ['dictate', false, '明日の朝メールを書いて送ります'],
+      ['dictate', false, 'あとで返信してもいいですか'],
+      ['dictate', true, 'あとでこれを短くします'],
+      ['dictate', true, '明日英語に翻訳します'],
+      ['dictate', true, 'チームで文法を直します'],
+      ['dictate', true, 'たぶん書き直しが必要です'],
+      ['ask', false, 'あとでgoogleで検索します'],
+      ['ask', false, '明日ホテルを調べます'],
+      ['ask', false, 'チームで検索方法を決めます'],
+      ['ask', false, '今度ユーチューブで調べてみます'],
+    ],
+    missingPayload: [
+      ['dictate', '下書き'],
+      ['dictate', '返信。'],
+      ['dictate', '下書きして'],
+      ['dictate', 'メールを書いて！'],
+      ['dictate', '返信文を書いて、'],
+      ['ask', 'googleで検索して'],
+      ['ask', 'グーグルで調べて'],
+      ['ask', 'ユーチューブで検索'],
+    ],
+    disabled: [
+      ['dictate', false, 'draft_insert', '下書き、明日のメモ'],
+      ['dictate', true, 'rewrite_selection', 'これを短くして'],
+      ['dictate', true, 'translate_selection', 'これを英語に翻訳して'],
+      ['ask', false, 'search', 'googleでrustを検索して'],
+    ],
+    askWithSelection: [
+      'これを書き直して',
+      'これを短くして',
+      'これを英語に翻訳して',
+      '文法を直して',
+      'これを箇条書きにして',
+      'これを丁寧にして',
+    ],
+    automatic: [
+      ['draft', '下書き、来週の会議の案内', '来週の会議の案内'],
+      ['draft', '返信：承知しました', '承知しました'],
+      ['translate', 'これを英語に翻訳して'],
+      ['rewrite', 'これを短くして'],
+      ['search', 'グーグルで東京の天気を検索して', 'google', '東京の天気'],
+      ['mixed', 'dictate', false, 'draft 明日のメモを書いて'],
+      ['mixed', 'dictate', true, 'translate これを英語に'],
+      ['mixed', 'ask', false, 'search 東京の天気を調べて'],
+    ],
+  },
+  ko: {
+    prefix: 'ko',
+    locale: 'ko',
+    draftSeparator: '',
+    drafts: [
+      ['초안 작성해 줘, ', '내일 회의 안내문'],
+      ['답장 작성해 줘: ', '확인했습니다. 금요일까지 처리하겠습니다'],
+      ['이메일 작성해 줘, ', '김 부장님께 견적서 감사 인사'],
+      ['메시지 작성해 줘, ', '팀에 출장 일정 공유'],
+      ['초안 작성: ', '신제품 출시 공지'],
+      ['답장 작성: ', '네, 수요일 오후 괜찮습니다'],
+      ['이메일 작성: ', '납기 지연에 대한 사과'],
+      ['메시지 작성: ', '오늘 점심 같이 드실래요?'],
+      ['답장해 줘, ', '감사합니다. 자료 잘 받았습니다'],
+      ['초안 작성해 줘: ', '환영회 안내'],
+      ['답장 작성해 줘, ', '좋습니다, 그때 뵙겠습니다'],
+      ['이메일 작성해 줘: ', '거래처 방문 일정 조율'],
+      ['메시지 작성해 줘: ', '회의실 예약 완료 알림'],
+      ['초안 작성, ', '여름 휴가 공지'],
+      ['답장 작성, ', '문의하신 내용에 대한 답변'],
+      ['이메일 작성, ', '경리팀에 청구서 확인 요청'],
+      ['메시지 작성, ', '새 팀원 소개'],
+      ['답장해 줘: ', '알겠습니다. 이따 전화드릴게요'],
+      ['초안 작성해 줘, ', '사내 소식지 6월호 기사'],
+      ['답장 작성해 줘: ', '덕분에 잘 해결됐습니다'],
+      ['이메일 작성해 줘, ', '사무실 이전 안내'],
+      ['메시지 작성해 줘, ', '내일 10시에 뵙겠습니다'],
+      ['초안 작성: ', '워크숍 안건'],
+      ['답장 작성: ', '견적서 재제출 관련 회신'],
+      ['이메일 작성: ', '연말 인사'],
+      ['답장해 줘, ', '네, 참석하겠습니다.'],
+      ['초안 작성해 줘 ', '신규 서비스 보도자료'],
+      ['이메일 작성해 줘 ', '면접 일정 후보 안내'],
+      ['메시지 작성: ', '고객 설문 참여 부탁'],
+      ['답장 작성해 줘 ', '다음 주 월요일에 방문하겠습니다'],
+      ['초안 작성, ', '3분기 매출 보고 요약'],
+      ['답장해 줘 ', 'Slack에 공유했습니다'],
+      ['이메일 작성해 줘, ', 'Wi-Fi 점검 안내'],
+      ['메시지 작성해 줘, ', '이사 인사'],
+      ['초안 작성해 줘, ', '프로젝트 진행 상황 보고'],
+    ],
+    askDrafts: [
+      ['초안 작성해 줘, ', '일정 변경 요청'],
+      ['답장 작성해 줘, ', '확인 감사합니다'],
+      ['이메일 작성해 줘, ', '총무팀에 비품 신청'],
+      ['메시지 작성해 줘, ', '교육 안내'],
+      ['답장해 줘, ', '알겠습니다'],
+      ['초안 작성: ', '프로젝트 개요'],
+      ['답장 작성: ', '잘 부탁드립니다'],
+      ['이메일 작성: ', '박 대리님께 감사 인사'],
+      ['메시지 작성: ', '아침 회의 메모'],
+      ['초안 작성, ', '고객 미팅 요약'],
+    ],
+    rewrites: [
+      '이거 다시 써 줘',
+      '이거 다시 써 줘, 좀 더 편하게',
+      '이거 다시 써줘',
+      '이 문장 다시 써 줘',
+      '이 문장 다시 써 줘. 고객용으로',
+      '이거 고쳐 써 줘',
+      '이거 짧게 해 줘',
+      '이거 짧게 해 줘, 두 문장 이내로',
+      '이거 길게 해 줘',
+      '이거 더 정중하게',
+      '이거 더 정중하게 바꿔 줘',
+      '이거 더 부드럽게',
+      '이거 더 부드럽게 해 줘',
+      '이거 더 간결하게',
+      '이거 더 간결하게 정리해 줘',
+      '이거 더 친근하게',
+      '이거 다듬어 줘',
+      '이거 다듬어 줘. 읽기 쉽게',
+      '이거 목록으로 바꿔 줘',
+      '문법 고쳐 줘',
+      '문법 고쳐 줘.',
+      '맞춤법 고쳐 줘',
+      '맞춤법 고쳐 줘, 띄어쓰기도',
+      '이거 다시 써 줘!',
+      '이거 더 친근하게 바꿔 줘',
+    ],
+    translations: [
+      '이거 영어로 번역해 줘',
+      '이거 영어로 번역해 주세요',
+      '이거 일본어로 번역해줘',
+      '이거 중국어로 번역해',
+      '이것을 영어로 번역해 줘',
+      '이것을 프랑스어로 번역해 주세요',
+      '이 부분을 독일어로 번역해 줘',
+      '이 문장을 영어로 번역해 줘.',
+      '이 문장을 스페인어로 번역해줘',
+      '선택한 부분을 영어로 번역해 줘',
+      '선택한 부분을 이탈리아어로 번역해 주세요',
+      '이거 쉬운 영어로 번역해 줘',
+    ],
+    selectionQuestions: [
+      '이거 요약해 줘',
+      '이거 요약해 줘, 세 줄로',
+      '이거 설명해 줘',
+      '이거 설명해 줘, 쉽게',
+      '이거 비교해 줘',
+      '이게 무슨 뜻이야?',
+      '왜 이렇게 썼어?',
+      '어떻게 개선할 수 있을까?',
+      '무엇을 말하려는 거야?',
+      '뭐가 문제야?',
+      '누가 쓴 거야?',
+      '언제 작성된 정보야?',
+      '어디가 어색해?',
+      '이 문장 자연스러워?',
+      '너무 길어?',
+    ],
+    searches: [
+      ['{provider}에서 {query} 검색해 줘', 'google', '서울 날씨'],
+      ['구글에서 {query} 검색해 줘', 'google', '근처 카페'],
+      ['{provider}에서 {query} 검색', 'google', 'rust ownership'],
+      ['구글에서 {query} 찾아 줘', 'google', '부산 가는 KTX'],
+      ['구글에서 {query} 검색해 주세요', 'google', '연말정산 기한'],
+      ['{provider}에서 {query} 검색해줘', 'google', '강남 영어 학원'],
+      ['{provider}에서 {query} 검색해 줘', 'youtube', '고양이 영상'],
+      ['유튜브에서 {query} 검색해 줘', 'youtube', '기타 치는 법'],
+      ['유튜브에서 {query} 찾아줘', 'youtube', '아침 요가'],
+      ['{provider}에서 {query} 검색', 'youtube', '파스타 레시피'],
+      ['유튜브에서 {query} 검색', 'youtube', '오늘 뉴스'],
+      ['{provider}에서 {query} 검색해 줘', 'youtube', 'Tauri 강의'],
+      ['{provider}에서 {query} 검색해 줘', 'amazon', 'USB-C 케이블'],
+      ['아마존에서 {query} 검색해 줘', 'amazon', '등산화'],
+      ['{provider}에서 {query} 찾아 줘', 'amazon', '커피 그라인더'],
+      ['아마존에서 {query} 검색해줘', 'amazon', '인체공학 마우스'],
+      ['아마존에서 {query} 검색', 'amazon', 'A5 노트'],
+      ['{provider}에서 {query} 검색해 주세요', 'amazon', '기내용 캐리어'],
+      ['{provider}에서 {query} 검색해 줘', 'github', 'tauri global shortcut'],
+      ['깃허브에서 {query} 검색해 줘', 'github', 'serde json examples'],
+      ['{provider}에서 {query} 검색', 'github', 'whisper rust bindings'],
+      ['깃허브에서 {query} 찾아 줘', 'github', 'tokio select macro'],
+      ['깃허브에서 {query} 검색', 'github', 'opentypeless'],
+      ['{provider}에서 {query} 검색해 줘.', 'google', '경주 단풍'],
+      ['{provider}에서 {query} 검색해 줘!', 'youtube', '제주도 다큐멘터리'],
+    ],
+    safeDictation: [
+      '내일 회의는 10시에 시작합니다',
+      '자료는 공유 폴더에 올렸습니다',
+      '지하철이 늦어서 조금 늦을 것 같아요',
+      '견적은 이번 달 말까지 유효합니다',
+      '빠른 연락 감사합니다',
+      '3분기 예산이 승인되었습니다',
+      '2층 프린터가 고쳐졌어요',
+      '부산에서 안부 전합니다',
+      '회의가 목요일로 변경되었습니다',
+      '새 책상은 금요일에 도착합니다',
+      '청구서 결제는 완료되었습니다',
+      '오늘은 구내식당이 쉽니다',
+      '점심 먹고 봬요',
+      '잘 부탁드립니다',
+      '오늘 업무는 여기까지입니다',
+    ],
+    safeQuestions: [
+      '도쿄는 지금 몇 시야?',
+      '캐나다의 수도는 어디야?',
+      '한라산 높이는 얼마나 돼?',
+      '하늘은 왜 파래?',
+      '내일 날씨 어때?',
+    ],
+    negated: [
+      ['dictate', false, '답장하지 마'],
+      ['dictate', false, '아직 답장 작성하지 마세요'],
+      ['dictate', false, '초안 작성할 필요 없어'],
+      ['dictate', false, '이 건은 답장 안 해도 돼'],
+      ['dictate', false, '이메일 작성하지 말고 기다려'],
+      ['dictate', false, '부장님께는 답장하지 말아 줘'],
+      ['dictate', false, '안 써도 되는 답장이야, 작성하지 마'],
+      ['dictate', false, '절대 답장 보내지 마'],
+      ['dictate', false, '초안은 필요 없어요'],
+      ['dictate', false, '메시지 작성하지 않아도 돼'],
+      ['dictate', false, '고객에게 답장하지 마세요'],
+      ['dictate', false, '지금은 초안 작성하지 마'],
+      ['dictate', true, '이건 번역하지 마'],
+      ['dictate', true, '번역하지 마세요'],
+      ['dictate', true, '맞춤법은 고쳐 쓰지 마'],
+      ['dictate', true, '이거 짧게 하지 마'],
+      ['dictate', true, '다시 쓰지 말아 줘'],
+      ['dictate', true, '정중하게 바꿀 필요 없어'],
+      ['dictate', true, '목록으로 바꾸지 마'],
+      ['dictate', true, '번역 안 해도 돼'],
+      ['dictate', true, '문법은 고쳐 주지 말고 그대로 둬'],
+      ['dictate', true, '다듬어 주지 않아도 돼'],
+      ['ask', false, '검색하지 마'],
+      ['ask', false, '구글에서 검색하지 마세요'],
+      ['ask', false, '찾아보지 마, 알고 있어'],
+      ['ask', false, '유튜브에서 검색하지 말아 줘'],
+      ['ask', false, '검색할 필요 없어요'],
+      ['ask', false, '답장하지 마'],
+      ['ask', false, '절대 아마존에서 검색하지 마'],
+      ['ask', false, '아직 초안 작성하지 마'],
+    ],
+    reported: [
+      ['dictate', false, '부장님이 답장 작성해 달라고 했어요'],
+      ['dictate', false, '김 대리가 초안 작성해 줘라고 말했어'],
+      ['dictate', false, '"답장 작성: 알겠습니다"라고 적혀 있었어요'],
+      ['dictate', false, '매뉴얼에 초안 작성이라고 써 있어'],
+      ['dictate', false, '인용: 이메일 작성해 줘, 감사 인사'],
+      ['dictate', false, '“초안 작성, 안내문”은 템플릿 예시예요'],
+      ['dictate', false, '고객이 답장해 달라고 하더라'],
+      ['dictate', false, '답장이라는 말이 너무 많아'],
+      ['dictate', false, '「메시지 작성해 줘」라는 작업이 있어'],
+      ['dictate', false, '과장님이 이메일 작성하라고 했어'],
+      ['dictate', false, '채팅에 “답장해 줘”라고 적혀 있었어'],
+      ['dictate', false, '"초안 작성, 보고서"가 메모에 남아 있었어'],
+      ['dictate', true, '팀장님이 이거 짧게 해 달라고 했어'],
+      ['dictate', true, '"이거 영어로 번역해 줘"라고 적혀 있었어요'],
+      ['dictate', true, '상사가 다시 써 달라고 하더라'],
+      ['dictate', true, '인용: 이거 더 정중하게'],
+      ['dictate', true, '“문법 고쳐 줘”는 예문이에요'],
+      ['dictate', true, '번역이라는 말의 쓰임'],
+      ['dictate', true, '선생님이 맞춤법 고쳐 달라고 말했어요'],
+      ['dictate', true, '"이거 목록으로 바꿔 줘"가 메모에 있어'],
+      ['dictate', true, '고객이 부드럽게 해 달라고 했어'],
+      ['dictate', true, '“이거 다듬어 줘”라고 써 있었어'],
+      ['ask', false, '부장님이 구글에서 찾아 달라고 했어'],
+      ['ask', false, '"유튜브에서 고양이 검색해 줘"라고 적혀 있어'],
+      ['ask', false, '인용: 아마존에서 책 검색'],
+      ['ask', false, '친구가 검색해 달라고 하더라'],
+      ['ask', false, '“구글에서 날씨 찾아 줘”라고 써 있었어'],
+      ['ask', false, '검색이라는 말의 뜻'],
+      ['ask', false, '과장님이 답장 작성하라고 했어'],
+      ['ask', false, '"깃허브에서 검색해 줘"는 메모야'],
+    ],
+    identifiers: [
+      ['dictate', false, 'draft_note.py 고쳐 줘'],
+      ['dictate', false, 'reply_helper를 답장에 써'],
+      ['dictate', false, 'write() 반환값 확인'],
+      ['dictate', false, 'compose.yaml에 답장 설정이 있어'],
+      ['dictate', false, 'format.rs 다듬어 줘'],
+      ['dictate', false, 'draft-v2 브랜치는 머지됐어'],
+      ['dictate', false, '답장_템플릿 업데이트했어'],
+      ['dictate', false, '초안(임시) 파일'],
+      ['dictate', false, 'make_draft 함수로 초안 작성'],
+      ['dictate', false, 'search-index.json 확인'],
+      ['dictate', true, 'translate_text.py 번역해 줘'],
+      ['dictate', true, 'rewrite() 다시 써 줘'],
+      ['dictate', true, 'format.ts 다듬어 줘'],
+      ['dictate', true, 'fix-typo.sh로 맞춤법 고쳐 줘'],
+      ['dictate', true, '번역(영어) 열'],
+      ['dictate', true, '짧게_하기 플래그'],
+      ['ask', false, 'search_api.rs 찾아 줘'],
+      ['ask', false, 'find() 결과 검색'],
+      ['ask', false, '검색_기록이 무거워'],
+      ['ask', false, 'search-utils 검색해 줘'],
+    ],
+    midSentence: [
+      ['dictate', false, '내일 답장할게요'],
+      ['dictate', false, '나중에 초안 보낼게요'],
+      ['dictate', false, '다음 주에 이메일 작성할 예정이에요'],
+      ['dictate', false, '아마 답장은 늦어질 거예요'],
+      ['dictate', false, '팀에서 초안을 검토할게요'],
+      ['dictate', false, '우리는 답장 작성을 먼저 해요'],
+      ['dictate', false, '언젠가 답장할게요'],
+      ['dictate', false, '계획은 내일 초안 작성이에요'],
+      ['dictate', false, '내일 아침에 메시지 작성해서 보낼게요'],
+      ['dictate', false, '나중에 답장해도 될까요'],
+      ['dictate', false, '아마 초안 작성은 오늘 끝나요'],
+      ['dictate', false, '다음 주 답장 일정 정리'],
+      ['dictate', true, '나중에 이거 짧게 할게요'],
+      ['dictate', true, '내일 영어로 번역할게요'],
+      ['dictate', true, '팀에서 문법 고쳐서 보낼게요'],
+      ['dictate', true, '아마 다시 써야 할 것 같아요'],
+      ['ask', false, '나중에 구글에서 검색할게요'],
+      ['ask', false, '내일 호텔 찾아볼게요'],
+      ['ask', false, '팀에서 검색 방법을 정할게요'],
+      ['ask', false, '언젠가 유튜브에서 찾아볼게요'],
+    ],
+    missingPayload: [
+      ['dictate', '초안 작성'],
+      ['dictate', '답장 작성해 줘.'],
+      ['dictate', '이메일 작성해 줘'],
+      ['dictate', '답장해 줘!'],
+      ['dictate', '메시지 작성, '],
+      ['ask', '구글에서 검색해 줘'],
+      ['ask', 'google에서 찾아 줘'],
+      ['ask', '유튜브에서 검색'],
+    ],
+    disabled: [
+      ['dictate', false, 'draft_insert', '초안 작성: 내일 메모'],
+      ['dictate', true, 'rewrite_selection', '이거 짧게 해 줘'],
+      ['dictate', true, 'translate_selection', '이거 영어로 번역해 줘'],
+      ['ask', false, 'search', '구글에서 러스트 검색해 줘'],
+    ],
+    askWithSelection: [
+      '이거 다시 써 줘',
+      '이거 짧게 해 줘',
+      '이거 영어로 번역해 줘',
+      '문법 고쳐 줘',
+      '이거 목록으로 바꿔 줘',
+      '이거 더 정중하게',
+    ],
+    automatic: [
+      ['draft', '초안 작성: 다음 주 회의 안내', '다음 주 회의 안내'],
+      ['draft', '답장해 줘, 확인했습니다', '확인했습니다'],
+      ['translate', '이거 영어로 번역해 줘'],
+      ['rewrite', '이거 짧게 해 줘'],
+      ['search', '구글에서 서울 날씨 검색해 줘', 'google', '서울 날씨'],
+      ['mixed', 'dictate', false, 'draft 내일 메모 작성'],
+      ['mixed', 'dictate', true, 'translate 이거 영어로'],
+      ['mixed', 'ask', false, 'search 서울 날씨 찾아 줘'],
+    ],
+  },
+  de: {
+    prefix: 'de',
+    locale: 'de',
+    draftSeparator: ' ',
+    drafts: [
+      ['Schreibe', 'eine kurze Nachricht an das Team über die Verschiebung'],
+      ['Schreib', 'eine Erinnerung für die Abgabe am Freitag'],
+      ['Entwirf', 'eine Einladung zum Sommerfest'],
+      ['Verfasse', 'eine Antwort an Frau Keller wegen des Angebots'],
+      ['Antworte mit', 'Danke, das passt mir gut'],
+      ['Schreibe:', 'Wir sind ab Montag wieder im Büro'],
+      ['Entwirf', 'eine Absage für das Vorstellungsgespräch'],
+      ['Verfasse', 'einen Statusbericht für das Projekt Atlas'],
+      ['Schreib', 'eine Nachricht an Jonas, dass der Termin steht'],
+      ['Antworte mit:', 'Gerne, ich schicke die Unterlagen morgen'],
+      ['Schreibe', 'eine höfliche Nachfrage zur offenen Rechnung'],
+      ['Entwirf', 'einen Beitrag für den Newsletter im Juni'],
+      ['Verfasse', 'eine Dankesmail an das Support-Team'],
+      ['Schreib', 'eine Zusammenfassung des Meetings für Lisa'],
+      ['Schreibe', 'eine Einladung zur Retrospektive am Donnerstag'],
+      ['Entwirf', 'eine Pressemitteilung zur neuen Version'],
+      ['Verfasse', 'eine Entschuldigung für die verspätete Lieferung'],
+      ['Antworte mit', 'Ja, Mittwoch um zehn Uhr klappt'],
+      ['Schreib', 'eine kurze Notiz für die Übergabe'],
+      ['Schreibe', 'eine Anfrage an den Vermieter wegen der Heizung'],
+      ['Entwirf', 'eine Agenda für den Workshop'],
+      ['Verfasse', 'eine Stellenanzeige für eine Werkstudentin'],
+      ['Schreib', 'eine Glückwunschkarte für Herrn Brandt'],
+      ['Schreibe', 'eine Antwort an den Kunden zur Lieferzeit'],
+      ['Entwirf', 'eine Erinnerung an die Reisekostenabrechnung'],
+      ['Verfasse', 'eine Nachricht an alle, dass das WLAN gewartet wird'],
+      ['Antworte mit', 'Klingt gut, bis morgen'],
+      ['Schreib', 'eine Notiz zum Kundengespräch mit Müller & Co.'],
+      ['Schreibe', 'eine Übergabe für die Urlaubsvertretung'],
+      ['Entwirf', 'eine Kündigung für das Fitnessstudio'],
+      ['Verfasse', 'eine Rückmeldung zum Entwurf von Sarah'],
+      ['Schreib', 'eine Begrüßung für neue Kolleginnen'],
+      ['Schreibe', 'eine Bitte um Freigabe des Budgets.'],
+      ['Entwirf', 'eine Antwort auf die Beschwerde von gestern'],
+      ['Verfasse', 'ein Protokoll der Teamsitzung'],
+    ],
+    askDrafts: [
+      ['Schreibe', 'eine Antwort an Tom zur Terminverschiebung'],
+      ['Entwirf', 'eine E-Mail an die Buchhaltung'],
+      ['Verfasse', 'eine Nachricht an den Hausmeister'],
+      ['Schreib', 'eine Notiz für das Planning'],
+      ['Antworte mit', 'Passt, danke dir'],
+      ['Schreibe', 'eine Einladung zum Onboarding'],
+      ['Entwirf', 'einen kurzen Projektsteckbrief'],
+      ['Verfasse', 'eine Anfrage für ein Angebot'],
+      ['Schreib', 'eine Erinnerung an das Team-Frühstück'],
+      ['Schreibe', 'eine Nachricht an Paula wegen der Folien'],
+    ],
+    rewrites: [
+      'Schreib das um',
+      'Schreibe das um, bitte etwas lockerer',
+      'Schreib das um in einfacher Sprache',
+      'Formuliere das um',
+      'Formuliere das um für den Kunden',
+      'Formuliere das um, aber höflicher',
+      'Mach das kürzer',
+      'Mach das kürzer, maximal zwei Sätze',
+      'Mach das kürzer für Slack',
+      'Mach das länger',
+      'Mach das freundlicher',
+      'Mach das freundlicher und persönlicher',
+      'Mach das herzlicher',
+      'Mach das formeller',
+      'Mach das formeller für den Vorstand',
+      'Mach das prägnanter',
+      'Korrigiere die Grammatik',
+      'Korrigiere die Grammatik und die Kommas',
+      'Korrigiere die Rechtschreibung',
+      'Korrigiere die Rechtschreibung bitte',
+      'Formatiere das als Liste',
+      'Formatiere das als Tabelle',
+      'Formatiere das als nummerierte Liste',
+      'Mach daraus eine Stichpunktliste',
+      'Mach daraus eine E-Mail',
+    ],
+    translations: [
+      'Übersetze das ins Englische',
+      'Übersetze das ins Französische',
+      'Übersetze das ins Englische, bitte förmlich',
+      'Übersetze das auf Spanisch',
+      'Übersetze das auf Englisch',
+      'Übersetze das in einfaches Englisch',
+      'Übersetze das in Japanisch',
+      'Übersetze die Auswahl ins Italienische',
+      'Übersetze die Auswahl ins Englische',
+      'Übersetze die Auswahl auf Polnisch',
+      'Übersetze das ins Niederländische.',
+      'Übersetze das auf Koreanisch',
+    ],
+    selectionQuestions: [
+      'Fasse das zusammen',
+      'Fasse das in drei Punkten zusammen',
+      'Erkläre das',
+      'Erkläre das einfacher',
+      'Erklär das bitte',
+      'Vergleiche das mit der alten Version',
+      'Was bedeutet das?',
+      'Warum ist das so formuliert?',
+      'Wieso klingt das so streng?',
+      'Wie kann man das verbessern?',
+      'Wer hat das verfasst?',
+      'Wann wurde das veröffentlicht?',
+      'Wo steht das im Vertrag?',
+      'Was ist die Kernaussage?',
+      'Ist das verständlich?',
+    ],
+    searches: [
+      ['Suche {query} auf {provider}', 'google', 'Rust Ownership'],
+      ['Suche auf {provider} nach {query}', 'youtube', 'Sauerteig Anleitung'],
+      ['Such {query} auf {provider}', 'amazon', 'USB-C Kabel'],
+      ['Finde {query} auf {provider}', 'github', 'tauri global shortcut'],
+      ['Suche {query} auf {provider}', 'google', 'Rezepte für Kartoffelsalat'],
+      ['Suche auf {provider} nach {query}', 'google', 'Öffnungszeiten Bürgeramt'],
+      ['Suche {query} auf {provider}', 'youtube', 'Gitarre lernen für Anfänger'],
+      ['Such auf {provider} nach {query}', 'amazon', 'Wanderschuhe Größe 43'],
+      ['Suche {query} auf {provider}', 'github', 'serde json examples'],
+      ['Finde {query} auf {provider}', 'youtube', 'Vortrag über Tauri'],
+      ['Suche {query} auf {provider}', 'amazon', 'ergonomische Maus'],
+      ['Suche auf {provider} nach {query}', 'github', 'whisper rust bindings'],
+      ['Such {query} auf {provider}', 'google', 'Wetter Hamburg morgen'],
+      ['Finde {query} auf {provider}', 'google', 'Bahnstreik aktuell'],
+      ['Suche {query} auf {provider}', 'youtube', 'Dokumentation Alpen'],
+      ['Suche auf {provider} nach {query}', 'amazon', 'Kaffeemühle elektrisch'],
+      ['Such {query} auf {provider}', 'github', 'opentypeless issues'],
+      ['Suche {query} auf {provider}', 'google', 'Steuererklärung Frist 2026'],
+      ['Finde {query} auf {provider}', 'amazon', 'Reisekoffer Handgepäck'],
+      ['Suche {query} auf {provider}', 'google', 'Übersetzungsbüro Berlin'],
+      ['Suche auf {provider} nach {query}', 'youtube', 'Yoga am Morgen'],
+      ['Such {query} auf {provider}', 'youtube', 'Tagesschau heute'],
+      ['Finde {query} auf {provider}', 'github', 'tokio select macro'],
+      ['Suche {query} auf {provider}', 'amazon', 'Notizbuch A5 kariert'],
+      ['Suche auf {provider} nach {query}', 'google', 'Zugverbindung München Wien'],
+    ],
+    safeDictation: [
+      'Morgen um neun treffen wir uns im Büro',
+      'Die Präsentation ist fertig',
+      'Ich komme etwas später, der Zug hat Verspätung',
+      'Bitte bring die Unterlagen mit',
+      'Das Angebot gilt bis Ende des Monats',
+      'Danke für die schnelle Rückmeldung',
+      'Wir haben das Budget für das dritte Quartal freigegeben',
+      'Der Drucker im zweiten Stock ist wieder frei',
+      'Liebe Grüße aus Köln',
+      'Das Meeting wurde auf Donnerstag verschoben',
+      'Die Schreibtische werden am Freitag geliefert',
+      'Unsere Antwortzeiten haben sich verbessert',
+      'Ich habe die Rechnung bezahlt',
+      'Heute ist die Kantine geschlossen',
+      'Wir sehen uns nach der Mittagspause',
+    ],
+    safeQuestions: [
+      'Wie spät ist es in Tokio?',
+      'Was ist die Hauptstadt von Kanada?',
+      'Wann beginnt die Sommerzeit?',
+      'Wie viele Einwohner hat Leipzig?',
+      'Warum ist der Himmel blau?',
+    ],
+    negated: [
+      ['dictate', false, 'Schreib keine Antwort an den Kunden'],
+      ['dictate', false, 'Schreibe keine E-Mail an die Gruppe'],
+      ['dictate', false, 'Bitte nicht antworten, ich kläre das selbst'],
+      ['dictate', false, 'Noch nicht schreiben, wir warten auf die Zahlen'],
+      ['dictate', false, 'Entwirf nichts für Herrn Meyer'],
+      ['dictate', false, 'Verfasse keine Absage'],
+      ['dictate', false, 'Nicht übersetzen, der Text bleibt so'],
+      ['dictate', false, 'Niemals eine Antwort ohne Freigabe schreiben'],
+      ['dictate', false, 'Keine Antwort schreiben, bevor Anna zustimmt'],
+      ['dictate', false, 'Lieber nicht schreiben, das wirkt zu direkt'],
+      ['dictate', false, 'Schreib nicht an den Vorstand'],
+      ['dictate', false, 'Entwirf keinen Vertrag ohne Rechtsabteilung'],
+      ['dictate', true, 'Nicht übersetzen'],
+      ['dictate', true, 'Bitte nicht umformulieren'],
+      ['dictate', true, 'Bitte nicht korrigieren, das ist ein Zitat'],
+      ['dictate', true, 'Keine Übersetzung nötig'],
+      ['dictate', true, 'Noch nicht umschreiben'],
+      ['dictate', true, 'Schreib das nicht um'],
+      ['dictate', true, 'Formatiere nichts'],
+      ['dictate', true, 'Korrigiere nicht die Namen'],
+      ['dictate', true, 'Nicht formatieren, nur lesen'],
+      ['dictate', true, 'Übersetze nicht die Produktnamen'],
+      ['ask', false, 'Nicht suchen, ich weiß es schon'],
+      ['ask', false, 'Such nicht auf Google'],
+      ['ask', false, 'Suche nichts auf YouTube'],
+      ['ask', false, 'Bitte nicht suchen'],
+      ['ask', false, 'Schreib keine Antwort'],
+      ['ask', false, 'Noch nicht schreiben'],
+      ['ask', false, 'Finde nichts auf Amazon'],
+      ['ask', false, 'Keine Suche auf GitHub'],
+    ],
+    reported: [
+      ['dictate', false, 'Anna sagt, schreib eine Antwort an den Kunden'],
+      ['dictate', false, 'Mein Chef sagte, entwirf eine Einladung'],
+      ['dictate', false, 'Tom hat gesagt: schreibe eine Notiz'],
+      ['dictate', false, 'Sie bat mich, eine Antwort zu verfassen'],
+      ['dictate', false, 'Der Satz schreib eine Mail steht im Handbuch'],
+      ['dictate', false, 'Das Wort entwirf kommt im Text vor'],
+      ['dictate', false, 'Zitat: schreibe eine kurze Nachricht'],
+      ['dictate', false, '„Schreib eine Antwort“ stand auf dem Zettel'],
+      ['dictate', false, 'Im Ticket steht "Entwirf eine Mail"'],
+      ['dictate', false, 'Er sagt immer, verfasse einen Bericht'],
+      ['dictate', false, 'Die Kundin hat gesagt, schreib ihr zurück'],
+      ['dictate', false, '„Antworte mit Ja“ war die Vorgabe'],
+      ['dictate', true, 'Lisa sagte, formuliere das um'],
+      ['dictate', true, 'Er hat gesagt, mach das kürzer'],
+      ['dictate', true, '„Übersetze das ins Englische“ stand in der Notiz'],
+      ['dictate', true, 'Der Satz korrigiere die Grammatik ist ein Beispiel'],
+      ['dictate', true, 'Sie bat mich, das zu übersetzen'],
+      ['dictate', true, 'Zitat: mach das freundlicher'],
+      ['dictate', true, 'Im Handbuch steht "Formatiere das als Liste"'],
+      ['dictate', true, 'Der Chef sagt, schreib das um'],
+      ['dictate', true, 'Das Wort übersetze ist falsch geschrieben'],
+      ['dictate', true, '„Mach das formeller“ hat Paul vorgeschlagen'],
+      ['ask', false, 'Jana sagte, suche Rust auf Google'],
+      ['ask', false, 'Er hat gesagt, such das auf YouTube'],
+      ['ask', false, '„Suche Kabel auf Amazon“ stand im Chat'],
+      ['ask', false, 'Sie bat mich, auf GitHub zu suchen'],
+      ['ask', false, 'Zitat: finde tokio auf GitHub'],
+      ['ask', false, 'Im Protokoll steht "Suche Hotels auf Google"'],
+      ['ask', false, 'Der Kunde sagt, schreib eine Antwort'],
+      ['ask', false, 'Das Wort suche wird oft verwendet'],
+    ],
+    identifiers: [
+      ['dictate', false, 'schreib_helper.py ist kaputt'],
+      ['dictate', false, 'Die Funktion suche() liefert null'],
+      ['dictate', false, 'entwirf.ts muss angepasst werden'],
+      ['dictate', false, 'Das Modul format.rs ist fertig'],
+      ['dictate', false, 'Öffne suche-index.json'],
+      ['dictate', false, 'korrigier_text wird morgen gemergt'],
+      ['dictate', false, 'Der Branch schreib-api ist gemergt'],
+      ['dictate', false, 'verfasse_bericht hat einen Fehler'],
+      ['dictate', false, 'Die Datei compose.yaml fehlt'],
+      ['dictate', false, 'Rufe antworte(nachricht) auf'],
+      ['dictate', true, 'formatiere_liste() ist veraltet'],
+      ['dictate', true, 'übersetze_text.py prüfen'],
+      ['dictate', true, 'Der Test schreib.test.ts schlägt fehl'],
+      ['dictate', true, 'formatiere_kurz ist ein Feature-Flag'],
+      ['dictate', true, 'korrigiere(grammatik) im Parser'],
+      ['dictate', true, 'finde-dubletten.sh läuft'],
+      ['ask', false, 'suche_api.rs hat einen Bug'],
+      ['ask', false, 'finde() gibt leere Treffer'],
+      ['ask', false, 'Das Paket suche-utils veraltet'],
+      ['ask', false, 'schreib_log ist zu groß'],
+    ],
+    midSentence: [
+      ['dictate', false, 'Ich muss morgen eine Antwort schreiben'],
+      ['dictate', false, 'Wir sollten eine Einladung entwerfen'],
+      ['dictate', false, 'Können wir den Bericht zusammen verfassen'],
+      ['dictate', false, 'Ich könnte dem Kunden schreiben'],
+      ['dictate', false, 'Der nächste Schritt ist eine Antwort an Lisa'],
+      ['dictate', false, 'Wir haben besprochen, wie wir den Text formulieren'],
+      ['dictate', false, 'Ich plane, eine Mail zu schreiben'],
+      ['dictate', false, 'Vielleicht schreibe ich ihm heute Abend'],
+      ['dictate', false, 'Das Team wird die Antwort vorbereiten'],
+      ['dictate', false, 'Ich möchte eine Notiz schreiben'],
+      ['dictate', false, 'Ich sollte die Einladung verfassen'],
+      ['dictate', false, 'Ich will morgen die Übersetzung prüfen'],
+      ['dictate', true, 'Wir sollten das kürzer formulieren'],
+      ['dictate', true, 'Vielleicht übersetze ich das später'],
+      ['dictate', true, 'Ich muss das noch korrigieren'],
+      ['dictate', true, 'Können wir das ins Englische übersetzen'],
+      ['ask', false, 'Ich muss noch Hotels auf Google suchen'],
+      ['ask', false, 'Vielleicht suche ich das auf YouTube'],
+      ['ask', false, 'Wir sollten eine Antwort schreiben'],
+      ['ask', false, 'Das Team wird auf GitHub suchen'],
+    ],
+    missingPayload: [
+      ['dictate', 'Schreibe'],
+      ['dictate', 'Entwirf.'],
+      ['dictate', 'Verfasse:'],
+      ['dictate', 'Antworte mit'],
+      ['dictate', 'Schreib!'],
+      ['ask', 'Suche'],
+      ['ask', 'Such'],
+      ['ask', 'Finde.'],
+    ],
+    disabled: [
+      ['dictate', false, 'draft_insert', 'Schreibe eine Notiz für morgen'],
+      ['dictate', true, 'rewrite_selection', 'Mach das kürzer'],
+      ['dictate', true, 'translate_selection', 'Übersetze das ins Englische'],
+      ['ask', false, 'search', 'Suche Rust auf Google'],
+    ],
+    askWithSelection: [
+      'Schreib das um',
+      'Mach das kürzer',
+      'Übersetze das ins Englische',
+      'Korrigiere die Grammatik',
+      'Formatiere das als Liste',
+      'Mach das freundlicher',
+    ],
+  },
+  es: {
+    prefix: 'es',
+    locale: 'es',
+    draftSeparator: ' ',
+    drafts: [
+      ['Escribe', 'un mensaje corto al equipo sobre el retraso'],
+      ['Redacta', 'una invitación para la comida de verano'],
+      ['Responde con', 'Gracias, me viene bien'],
+      ['Contesta con', 'Perfecto, nos vemos el lunes'],
+      ['Escribe:', 'Estaremos de vuelta en la oficina el martes'],
+      ['Redacta', 'una respuesta a la señora Ortega sobre el presupuesto'],
+      ['Escribe', 'un recordatorio para la entrega del viernes'],
+      ['Redacta', 'un informe de estado del proyecto Atlas'],
+      ['Responde con:', 'Claro, mando los documentos mañana'],
+      ['Escribe', 'una nota para el traspaso de turno'],
+      ['Redacta', 'una disculpa por el envío tardío'],
+      ['Escribe', 'un correo a Javier para confirmar la cita'],
+      ['Contesta con', 'Sí, el miércoles a las diez me va bien'],
+      ['Redacta', 'una oferta de empleo para una becaria'],
+      ['Escribe', 'una felicitación de cumpleaños para Marta'],
+      ['Redacta', 'un resumen de la reunión para Lucía'],
+      ['Escribe', 'una petición al casero sobre la calefacción'],
+      ['Redacta', 'una agenda para el taller del jueves'],
+      ['Escribe', 'un aviso de que el wifi estará en mantenimiento'],
+      ['Responde con', 'Suena bien, hasta mañana'],
+      ['Redacta', 'un comunicado de prensa sobre la nueva versión'],
+      ['Escribe', 'una bienvenida para las nuevas compañeras'],
+      ['Redacta', 'un acta de la reunión de equipo'],
+      ['Escribe', 'una solicitud de aprobación del presupuesto.'],
+      ['Redacta', 'una respuesta a la queja de ayer'],
+      ['Escribe', 'una consulta amable sobre la factura pendiente'],
+      ['Redacta', 'una carta de baja para el gimnasio'],
+      ['Escribe', 'un comentario sobre el borrador de Sara'],
+      ['Contesta con:', 'De acuerdo, lo reviso esta tarde'],
+      ['Redacta', 'un mensaje para el boletín de junio'],
+      ['Escribe', 'un agradecimiento al equipo de soporte'],
+      ['Redacta', 'una nota sobre la llamada con García & Hijos'],
+      ['Escribe', 'un aviso de vacaciones para los clientes'],
+      ['Redacta', 'una propuesta de horario para el sprint'],
+      ['Escribe', 'una invitación a la retrospectiva'],
+    ],
+    askDrafts: [
+      ['Escribe', 'una respuesta a Tomás sobre el cambio de fecha'],
+      ['Redacta', 'un correo a contabilidad'],
+      ['Responde con', 'Vale, gracias'],
+      ['Escribe', 'una nota para la planificación'],
+      ['Redacta', 'un mensaje al conserje'],
+      ['Contesta con', 'Me parece bien'],
+      ['Escribe', 'una invitación a la formación'],
+      ['Redacta', 'una ficha breve del proyecto'],
+      ['Escribe', 'un recordatorio del desayuno de equipo'],
+      ['Redacta', 'una solicitud de presupuesto'],
+    ],
+    rewrites: [
+      'Reescribe esto',
+      'Reescribe esto con un tono más relajado',
+      'Reescribe esto en lenguaje sencillo',
+      'Reformula esto',
+      'Reformula esto para el cliente',
+      'Reformula esto, pero más educado',
+      'Hazlo más corto',
+      'Hazlo más corto, dos frases como máximo',
+      'Hazlo más corto para Slack',
+      'Hazlo más largo',
+      'Hazlo más cálido',
+      'Hazlo más amable',
+      'Hazlo más amable y personal',
+      'Hazlo más formal',
+      'Hazlo más formal para la dirección',
+      'Hazlo más conciso',
+      'Corrige la gramática',
+      'Corrige la gramática y las comas',
+      'Corrige la ortografía',
+      'Corrige la ortografía, por favor',
+      'Dale formato de lista',
+      'Dale formato de tabla',
+      'Dale formato de lista numerada',
+      'Convierte esto en viñetas',
+      'Convierte esto en un correo',
+    ],
+    translations: [
+      'Traduce esto al inglés',
+      'Traduce esto al francés',
+      'Traduce esto al inglés, en tono formal',
+      'Traduce esto a alemán',
+      'Traduce esto a japonés',
+      'Traduce esto al inglés sencillo',
+      'Traduce la selección al italiano',
+      'Traduce la selección al inglés',
+      'Traduce la selección a portugués',
+      'Traduce esto al neerlandés.',
+      'Traduce esto al coreano',
+      'Traduce la selección al catalán',
+    ],
+    selectionQuestions: [
+      'Resume esto',
+      'Resume esto en tres puntos',
+      'Explica esto',
+      'Explica esto de forma más simple',
+      'Explícame esto',
+      'Compara esto con la versión anterior',
+      '¿Qué significa esto?',
+      '¿Por qué está escrito así?',
+      '¿Cómo se puede mejorar?',
+      '¿Quién escribió esto?',
+      '¿Cuándo se publicó?',
+      '¿Dónde aparece esto en el contrato?',
+      'Qué idea principal tiene',
+      '¿Se entiende bien?',
+      '¿Es demasiado largo?',
+    ],
+    searches: [
+      ['Busca {query} en {provider}', 'google', 'ownership en Rust'],
+      ['Busca en {provider} {query}', 'youtube', 'recetas de pasta fresca'],
+      ['Encuentra {query} en {provider}', 'amazon', 'cable USB-C'],
+      ['Busca {query} en {provider}', 'github', 'tauri global shortcut'],
+      ['Busca {query} en {provider}', 'google', 'horario de la biblioteca'],
+      ['Busca en {provider} {query}', 'google', 'farmacias de guardia Sevilla'],
+      ['Busca {query} en {provider}', 'youtube', 'aprender guitarra desde cero'],
+      ['Encuentra en {provider} {query}', 'amazon', 'botas de montaña talla 43'],
+      ['Busca {query} en {provider}', 'github', 'serde json examples'],
+      ['Encuentra {query} en {provider}', 'youtube', 'charla sobre Tauri'],
+      ['Busca {query} en {provider}', 'amazon', 'ratón ergonómico'],
+      ['Busca en {provider} {query}', 'github', 'whisper rust bindings'],
+      ['Busca {query} en {provider}', 'google', 'tiempo en Madrid mañana'],
+      ['Encuentra {query} en {provider}', 'google', 'huelga de trenes hoy'],
+      ['Busca {query} en {provider}', 'youtube', 'documental sobre los Andes'],
+      ['Busca en {provider} {query}', 'amazon', 'molinillo de café eléctrico'],
+      ['Busca {query} en {provider}', 'github', 'opentypeless issues'],
+      ['Busca {query} en {provider}', 'google', 'plazo de la declaración de la renta'],
+      ['Encuentra {query} en {provider}', 'amazon', 'maleta de cabina'],
+      ['Busca {query} en {provider}', 'google', 'academia de idiomas en Valencia'],
+      ['Busca en {provider} {query}', 'youtube', 'yoga por la mañana'],
+      ['Busca {query} en {provider}', 'youtube', 'telediario de hoy'],
+      ['Encuentra {query} en {provider}', 'github', 'tokio select macro'],
+      ['Busca {query} en {provider}', 'amazon', 'cuaderno A5 cuadriculado'],
+      ['Busca en {provider} {query}', 'google', 'tren de Barcelona a Lyon'],
+    ],
+    safeDictation: [
+      'Mañana a las nueve nos vemos en la oficina',
+      'La presentación ya está lista',
+      'Llego un poco tarde, el tren va con retraso',
+      'Trae los documentos, por favor',
+      'La oferta es válida hasta final de mes',
+      'Gracias por la respuesta tan rápida',
+      'Hemos aprobado el presupuesto del tercer trimestre',
+      'La impresora del segundo piso ya funciona',
+      'Un saludo desde Bilbao',
+      'La reunión se ha movido al jueves',
+      'Los escritorios llegan el viernes',
+      'Nuestros tiempos de respuesta han mejorado',
+      'Ya he pagado la factura',
+      'Hoy el comedor está cerrado',
+      'Nos vemos después de comer',
+    ],
+    safeQuestions: [
+      '¿Qué hora es en Tokio?',
+      '¿Cuál es la capital de Canadá?',
+      '¿Cuándo cambia la hora?',
+      '¿Cuántos habitantes tiene Zaragoza?',
+      '¿Por qué el cielo es azul?',
+    ],
+    negated: [
+      ['dictate', false, 'No escribas una respuesta al cliente'],
+      ['dictate', false, 'No redactes el correo todavía'],
+      ['dictate', false, 'Por favor no respondas, lo gestiono yo'],
+      ['dictate', false, 'Todavía no escribas nada, esperamos los datos'],
+      ['dictate', false, 'No redactes nada para el señor Molina'],
+      ['dictate', false, 'Nunca escribas a la dirección sin permiso'],
+      ['dictate', false, 'Aún no contestes a Laura'],
+      ['dictate', false, 'Mejor no escribas, suena demasiado directo'],
+      ['dictate', false, 'No escribas al proveedor'],
+      ['dictate', false, 'No redactes un contrato sin el equipo legal'],
+      ['dictate', false, 'No respondas con un sí todavía'],
+      ['dictate', false, 'Nunca redactes promesas de plazos'],
+      ['dictate', true, 'No traduzcas esto'],
+      ['dictate', true, 'Por favor no reformules esto'],
+      ['dictate', true, 'No corrijas nada, es una cita'],
+      ['dictate', true, 'No hace falta traducir, no traduzcas'],
+      ['dictate', true, 'Todavía no reescribas esto'],
+      ['dictate', true, 'No lo reescribas'],
+      ['dictate', true, 'No le des formato'],
+      ['dictate', true, 'No corrijas los nombres propios'],
+      ['dictate', true, 'Nunca traduzcas los nombres de producto'],
+      ['dictate', true, 'Mejor no lo conviertas en lista'],
+      ['ask', false, 'No busques, ya lo sé'],
+      ['ask', false, 'No busques en Google'],
+      ['ask', false, 'Por favor no busques nada en YouTube'],
+      ['ask', false, 'Todavía no busques'],
+      ['ask', false, 'No escribas una respuesta'],
+      ['ask', false, 'Aún no redactes nada'],
+      ['ask', false, 'No encuentres nada en Amazon'],
+      ['ask', false, 'Nunca busques en GitHub por mí'],
+    ],
+    reported: [
+      ['dictate', false, 'Ana dice, escribe una respuesta al cliente'],
+      ['dictate', false, 'Mi jefe dijo que redacte una invitación'],
+      ['dictate', false, 'Tomás dijo: escribe una nota'],
+      ['dictate', false, 'Me pidió que escribiera una respuesta'],
+      ['dictate', false, 'La frase escribe un correo sale en el manual'],
+      ['dictate', false, 'La palabra redacta aparece en el texto'],
+      ['dictate', false, 'Cita: escribe un mensaje corto'],
+      ['dictate', false, '«Escribe una respuesta» ponía en la nota'],
+      ['dictate', false, 'En el ticket pone "Redacta un correo"'],
+      ['dictate', false, 'Él siempre dice que escriba un informe'],
+      ['dictate', false, 'La clienta dijo que le respondiera'],
+      ['dictate', false, '“Responde con un sí” era la instrucción'],
+      ['dictate', true, 'Lucía dijo, reformula esto'],
+      ['dictate', true, 'Él dijo: hazlo más corto'],
+      ['dictate', true, '«Traduce esto al inglés» estaba en la nota'],
+      ['dictate', true, 'La frase corrige la gramática es un ejemplo'],
+      ['dictate', true, 'Me pidió que lo tradujera'],
+      ['dictate', true, 'Cita: hazlo más amable'],
+      ['dictate', true, 'El manual dice "Dale formato de lista"'],
+      ['dictate', true, 'El jefe dice, reescribe esto'],
+      ['dictate', true, 'La palabra traduce está mal escrita'],
+      ['dictate', true, '“Hazlo más formal” lo propuso Pablo'],
+      ['ask', false, 'Juana dijo, busca Rust en Google'],
+      ['ask', false, 'Él dijo que lo buscara en YouTube'],
+      ['ask', false, '«Busca cables en Amazon» decía el chat'],
+      ['ask', false, 'Me pidió que buscara en GitHub'],
+      ['ask', false, 'Cita: encuentra tokio en GitHub'],
+      ['ask', false, 'El acta dice "Busca hoteles en Google"'],
+      ['ask', false, 'El cliente dice, escribe una respuesta'],
+      ['ask', false, 'La palabra busca se usa mucho'],
+    ],
+    identifiers: [
+      ['dictate', false, 'escribe_helper.py está roto'],
+      ['dictate', false, 'La función busca() devuelve null'],
+      ['dictate', false, 'redacta.ts hay que ajustarlo'],
+      ['dictate', false, 'El módulo escribe.rs está listo'],
+      ['dictate', false, 'Abre busca-indice.json'],
+      ['dictate', false, 'corrige_texto se fusiona mañana'],
+      ['dictate', false, 'La rama escribe-api ya está fusionada'],
+      ['dictate', false, 'redacta_informe tiene un fallo'],
+      ['dictate', false, 'Falta el archivo responde.yaml'],
+      ['dictate', false, 'Llama a responde(mensaje)'],
+      ['dictate', true, 'formato_lista() está obsoleto'],
+      ['dictate', true, 'Revisa traduce_texto.py'],
+      ['dictate', true, 'El test escribe.test.ts falla'],
+      ['dictate', true, 'hazlo_corto es un feature flag'],
+      ['dictate', true, 'corrige(gramatica) en el parser'],
+      ['dictate', true, 'reformula-todo.sh se está ejecutando'],
+      ['ask', false, 'busca_api.rs tiene un bug'],
+      ['ask', false, 'encuentra() da resultados vacíos'],
+      ['ask', false, 'El paquete busca-utils está obsoleto'],
+      ['ask', false, 'escribe_log es demasiado grande'],
+    ],
+    midSentence: [
+      ['dictate', false, 'Tengo que escribir una respuesta mañana'],
+      ['dictate', false, 'Deberíamos redactar una invitación'],
+      ['dictate', false, 'Podemos escribir el informe juntos'],
+      ['dictate', false, 'Quizás le escriba esta noche'],
+      ['dictate', false, 'El siguiente paso es responder a Lisa'],
+      ['dictate', false, 'Hablamos de cómo redactar el texto'],
+      ['dictate', false, 'Planeo escribir un correo'],
+      ['dictate', false, 'El equipo va a escribir la respuesta'],
+      ['dictate', false, 'Quiero escribir una nota'],
+      ['dictate', false, 'Debería redactar la invitación'],
+      ['dictate', false, 'A lo mejor escribo al cliente'],
+      ['dictate', false, 'Quizá conteste mañana'],
+      ['dictate', true, 'Deberíamos hacerlo más corto, hazlo luego'],
+      ['dictate', true, 'Quizás lo traduzca más tarde'],
+      ['dictate', true, 'Tengo que corregir esto'],
+      ['dictate', true, 'Podemos traducir esto al inglés'],
+      ['ask', false, 'Tengo que buscar hoteles en Google'],
+      ['ask', false, 'Quizás lo busque en YouTube'],
+      ['ask', false, 'Deberíamos escribir una respuesta'],
+      ['ask', false, 'El equipo va a buscar en GitHub'],
+    ],
+    missingPayload: [
+      ['dictate', 'Escribe'],
+      ['dictate', 'Redacta.'],
+      ['dictate', 'Escribe:'],
+      ['dictate', 'Responde con'],
+      ['dictate', 'Contesta con!'],
+      ['ask', 'Busca'],
+      ['ask', 'Encuentra'],
+      ['ask', 'Busca.'],
+    ],
+    disabled: [
+      ['dictate', false, 'draft_insert', 'Escribe una nota para mañana'],
+      ['dictate', true, 'rewrite_selection', 'Hazlo más corto'],
+      ['dictate', true, 'translate_selection', 'Traduce esto al inglés'],
+      ['ask', false, 'search', 'Busca Rust en Google'],
+    ],
+    askWithSelection: [
+      'Reescribe esto',
+      'Hazlo más corto',
+      'Traduce esto al inglés',
+      'Corrige la gramática',
+      'Dale formato de lista',
+      'Hazlo más amable',
+    ],
+  },
+  fr: {
+    prefix: 'fr',
+    locale: 'fr',
+    draftSeparator: ' ',
+    drafts: [
+      ['Écris', "un message court à l'équipe sur le retard"],
+      ['Rédige', "une invitation pour le pique-nique d'été"],
+      ['Réponds avec', 'Merci, ça me convient'],
+      ['Réponds par', 'Parfait, à lundi'],
+      ['Écris :', 'Nous serons de retour au bureau mardi'],
+      ['Rédige', 'une réponse à Madame Lefèvre au sujet du devis'],
+      ['Écris', 'un rappel pour la livraison de vendredi'],
+      ['Rédige', "un point d'étape sur le projet Atlas"],
+      ['Compose', 'un mot de bienvenue pour les stagiaires'],
+      ['Écris', 'une note pour la passation'],
+      ['Rédige', 'des excuses pour la livraison tardive'],
+      ['Écris', 'un mail à Julien pour confirmer le rendez-vous'],
+      ['Réponds avec', 'Oui, mercredi à dix heures me va'],
+      ['Rédige', 'une offre de stage en marketing'],
+      ['Écris', "une carte d'anniversaire pour Camille"],
+      ['Rédige', 'un compte rendu de la réunion pour Léa'],
+      ['Écris', 'une demande au propriétaire pour le chauffage'],
+      ['Rédige', "un ordre du jour pour l'atelier de jeudi"],
+      ['Compose', 'une annonce de maintenance du wifi'],
+      ['Réponds par', 'Ça marche, à demain'],
+      ['Rédige', 'un communiqué de presse sur la nouvelle version'],
+      ['Écris', "un message d'accueil pour les nouvelles collègues"],
+      ['Rédige', "le procès-verbal de la réunion d'équipe"],
+      ['Écris', 'une demande de validation du budget.'],
+      ['Rédige', "une réponse à la réclamation d'hier"],
+      ['Écris', 'une relance polie pour la facture en attente'],
+      ['Rédige', 'une lettre de résiliation pour la salle de sport'],
+      ['Écris', 'un retour sur le brouillon de Sarah'],
+      ['Réponds avec :', "D'accord, je regarde cet après-midi"],
+      ['Compose', 'un article pour la newsletter de juin'],
+      ['Écris', "un remerciement à l'équipe support"],
+      ['Rédige', "une note sur l'appel avec Dupont & Fils"],
+      ['Écris', "un message d'absence pour les clients"],
+      ['Rédige', 'une proposition de planning pour le sprint'],
+      ['Écris', 'une invitation à la rétrospective'],
+    ],
+    askDrafts: [
+      ['Écris', 'une réponse à Thomas sur le changement de date'],
+      ['Rédige', 'un mail à la comptabilité'],
+      ['Réponds avec', 'Très bien, merci'],
+      ['Écris', 'une note pour la planification'],
+      ['Rédige', 'un message au gardien'],
+      ['Réponds par', 'Ça me va'],
+      ['Compose', 'une invitation à la formation'],
+      ['Rédige', 'une fiche projet courte'],
+      ['Écris', "un rappel pour le petit-déjeuner d'équipe"],
+      ['Rédige', 'une demande de devis'],
+    ],
+    rewrites: [
+      'Réécris ça',
+      'Réécris ça sur un ton plus détendu',
+      'Réécris ceci en langage simple',
+      'Reformule ça',
+      'Reformule ça pour le client',
+      'Reformule ceci, mais plus poliment',
+      'Rends ça plus court',
+      'Rends ça plus court, deux phrases maximum',
+      'Rends ça plus court pour Slack',
+      'Rends ça plus long',
+      'Rends ça plus chaleureux',
+      'Rends ça plus amical',
+      'Rends ça plus amical et personnel',
+      'Rends ça plus formel',
+      'Rends ça plus formel pour la direction',
+      'Rends ça plus concis',
+      'Corrige la grammaire',
+      'Corrige la grammaire et la ponctuation',
+      "Corrige l'orthographe",
+      "Corrige l’orthographe, s'il te plaît",
+      'Mets ça en forme de liste',
+      'Mets ça en forme de tableau',
+      'Mets ça en forme de liste numérotée',
+      'Transforme ça en puces',
+      'Transforme ça en e-mail',
+    ],
+    translations: [
+      'Traduis ça en anglais',
+      'Traduis ça en espagnol',
+      'Traduis ça en anglais, ton formel',
+      'Traduis ceci en allemand',
+      'Traduis ceci en japonais',
+      'Traduis ça en anglais simple',
+      'Traduis la sélection en italien',
+      'Traduis la sélection en anglais',
+      'Traduis la sélection en portugais',
+      'Traduis ça en néerlandais.',
+      'Traduis ça en coréen',
+      'Traduis ceci en polonais',
+    ],
+    selectionQuestions: [
+      'Résume ça',
+      'Résume ça en trois points',
+      'Explique ça',
+      'Explique ceci plus simplement',
+      "Compare ça avec l'ancienne version",
+      "Qu'est-ce que ça veut dire ?",
+      'Qu’est-ce que ça implique ?',
+      "Pourquoi c'est formulé comme ça ?",
+      'Comment améliorer ce passage ?',
+      'Qui a écrit ça ?',
+      'Quand est-ce que ça a été publié ?',
+      'Où est-ce dans le contrat ?',
+      "C'est clair ?",
+      'Est-ce trop long ?',
+      'Résume ceci pour un enfant',
+    ],
+    searches: [
+      ['Cherche {query} sur {provider}', 'google', 'ownership en Rust'],
+      ['Cherche sur {provider} {query}', 'youtube', 'recette de crêpes'],
+      ['Trouve {query} sur {provider}', 'amazon', 'câble USB-C'],
+      ['Recherche {query} sur {provider}', 'github', 'tauri global shortcut'],
+      ['Cherche {query} sur {provider}', 'google', 'horaires de la médiathèque'],
+      ['Recherche sur {provider} {query}', 'google', 'pharmacie de garde Lyon'],
+      ['Cherche {query} sur {provider}', 'youtube', 'apprendre la guitare'],
+      ['Trouve sur {provider} {query}', 'amazon', 'chaussures de randonnée pointure 43'],
+      ['Cherche {query} sur {provider}', 'github', 'serde json examples'],
+      ['Trouve {query} sur {provider}', 'youtube', 'conférence sur Tauri'],
+      ['Cherche {query} sur {provider}', 'amazon', 'souris ergonomique'],
+      ['Recherche sur {provider} {query}', 'github', 'whisper rust bindings'],
+      ['Recherche {query} sur {provider}', 'google', 'météo Nantes demain'],
+      ['Trouve {query} sur {provider}', 'google', "grève SNCF aujourd'hui"],
+      ['Cherche {query} sur {provider}', 'youtube', 'documentaire sur les Alpes'],
+      ['Cherche sur {provider} {query}', 'amazon', 'moulin à café électrique'],
+      ['Recherche {query} sur {provider}', 'github', 'opentypeless issues'],
+      ['Cherche {query} sur {provider}', 'google', "date limite déclaration d'impôts"],
+      ['Trouve {query} sur {provider}', 'amazon', 'valise cabine'],
+      ['Recherche {query} sur {provider}', 'google', 'école de langues à Bordeaux'],
+      ['Cherche sur {provider} {query}', 'youtube', 'yoga du matin'],
+      ['Cherche {query} sur {provider}', 'youtube', 'journal de 20 heures'],
+      ['Trouve {query} sur {provider}', 'github', 'tokio select macro'],
+      ['Cherche {query} sur {provider}', 'amazon', 'carnet A5 à carreaux'],
+      ['Recherche sur {provider} {query}', 'google', 'train Paris Genève'],
+    ],
+    safeDictation: [
+      'Demain à neuf heures on se retrouve au bureau',
+      'La présentation est prête',
+      "J'arrive un peu en retard, le train est bloqué",
+      "Apporte les documents, s'il te plaît",
+      "L'offre est valable jusqu'à la fin du mois",
+      'Merci pour la réponse rapide',
+      'Nous avons validé le budget du troisième trimestre',
+      "L'imprimante du deuxième étage marche à nouveau",
+      'Bonjour de Marseille',
+      'La réunion est déplacée à jeudi',
+      'Les bureaux arrivent vendredi',
+      'Nos délais de réponse se sont améliorés',
+      "J'ai payé la facture",
+      "La cantine est fermée aujourd'hui",
+      'On se voit après le déjeuner',
+    ],
+    safeQuestions: [
+      'Quelle heure est-il à Tokyo ?',
+      'Quelle est la capitale du Canada ?',
+      "Quand passe-t-on à l'heure d'été ?",
+      "Combien d'habitants compte Lille ?",
+      'Pourquoi le ciel est bleu ?',
+    ],
+    negated: [
+      ['dictate', false, "N'écris pas de réponse au client"],
+      ['dictate', false, 'Ne rédige pas le mail tout de suite'],
+      ['dictate', false, "Ne réponds pas, je m'en occupe"],
+      ['dictate', false, "Pas encore, n'écris rien, on attend les chiffres"],
+      ['dictate', false, 'Ne rédige rien pour Monsieur Moreau'],
+      ['dictate', false, 'Jamais écrire à la direction sans accord'],
+      ['dictate', false, 'Ne réponds pas encore à Laura'],
+      ['dictate', false, "Surtout pas écrire ça, c'est trop direct"],
+      ['dictate', false, 'N’écris pas au fournisseur'],
+      ['dictate', false, 'Ne rédige pas de contrat sans le service juridique'],
+      ['dictate', false, "Ne réponds pas par oui pour l'instant"],
+      ['dictate', false, 'Jamais rédiger de promesses de délais'],
+      ['dictate', true, 'Ne traduis pas ça'],
+      ['dictate', true, "Ne reformule pas ça, s'il te plaît"],
+      ['dictate', true, "Ne corrige rien, c'est voulu"],
+      ['dictate', true, 'Pas besoin de traduire'],
+      ['dictate', true, 'Ne réécris pas ceci'],
+      ['dictate', true, 'Ne le réécris pas'],
+      ['dictate', true, 'Ne le transforme pas en liste'],
+      ['dictate', true, 'Ne corrige pas les noms propres'],
+      ['dictate', true, 'Jamais traduire les noms de produit'],
+      ['dictate', true, "N'explique pas, reformule seulement"],
+      ['ask', false, 'Ne cherche pas, je sais déjà'],
+      ['ask', false, 'Ne cherche pas sur Google'],
+      ['ask', false, 'Ne cherche rien sur YouTube'],
+      ['ask', false, 'Pas la peine de chercher'],
+      ['ask', false, "N'écris pas de réponse"],
+      ['ask', false, "Ne rédige rien pour l'instant"],
+      ['ask', false, 'Ne trouve rien sur Amazon'],
+      ['ask', false, 'Jamais chercher sur GitHub pour moi'],
+    ],
+    reported: [
+      ['dictate', false, 'Anne dit, écris une réponse au client'],
+      ['dictate', false, 'Mon chef a dit de rédiger une invitation'],
+      ['dictate', false, 'Thomas a dit : écris une note'],
+      ['dictate', false, "Elle m'a demandé de rédiger une réponse"],
+      ['dictate', false, 'La phrase écris un mail est dans le manuel'],
+      ['dictate', false, 'Le mot rédige apparaît dans le texte'],
+      ['dictate', false, 'Citation : écris un message court'],
+      ['dictate', false, '« Écris une réponse » était sur le post-it'],
+      ['dictate', false, 'Dans le ticket il y a "Rédige un mail"'],
+      ['dictate', false, "Il dit toujours d'écrire un rapport"],
+      ['dictate', false, 'La cliente m’a demandé de lui répondre'],
+      ['dictate', false, '“Réponds par oui” était la consigne'],
+      ['dictate', true, 'Léa a dit, reformule ça'],
+      ['dictate', true, 'Il a dit : rends ça plus court'],
+      ['dictate', true, '« Traduis ça en anglais » était dans la note'],
+      ['dictate', true, 'La phrase corrige la grammaire est un exemple'],
+      ['dictate', true, "Elle m'a demandé de traduire ça"],
+      ['dictate', true, 'Citation : rends ça plus amical'],
+      ['dictate', true, 'Le manuel contient "Mets ça en forme de liste"'],
+      ['dictate', true, 'Le chef dit, réécris ça'],
+      ['dictate', true, 'Le mot traduis est mal orthographié'],
+      ['dictate', true, '“Rends ça plus formel” a proposé Paul'],
+      ['ask', false, 'Jeanne a dit, cherche Rust sur Google'],
+      ['ask', false, 'Il a dit de chercher ça sur YouTube'],
+      ['ask', false, '« Cherche des câbles sur Amazon » disait le chat'],
+      ['ask', false, "Elle m'a demandé de chercher sur GitHub"],
+      ['ask', false, 'Citation : trouve tokio sur GitHub'],
+      ['ask', false, 'Le compte rendu contient "Cherche des hôtels sur Google"'],
+      ['ask', false, 'Le client dit, écris une réponse'],
+      ['ask', false, 'Le mot cherche est très courant'],
+    ],
+    identifiers: [
+      ['dictate', false, 'ecris_helper.py est cassé'],
+      ['dictate', false, 'La fonction cherche() renvoie null'],
+      ['dictate', false, 'rédige.ts doit être modifié'],
+      ['dictate', false, 'Le module écris.rs est prêt'],
+      ['dictate', false, 'Ouvre cherche-index.json'],
+      ['dictate', false, 'corrige_texte sera fusionné demain'],
+      ['dictate', false, 'La branche écris-api est fusionnée'],
+      ['dictate', false, 'redige_rapport a un bug'],
+      ['dictate', false, 'Il manque le fichier compose.yaml'],
+      ['dictate', false, 'Appelle reponds(message)'],
+      ['dictate', true, 'transforme_liste() est obsolète'],
+      ['dictate', true, 'Vérifie traduis_texte.py'],
+      ['dictate', true, 'Le test écris.test.ts échoue'],
+      ['dictate', true, 'reformule.rs a une régression'],
+      ['dictate', true, 'corrige(grammaire) dans le parseur'],
+      ['dictate', true, 'traduis-tout.sh tourne encore'],
+      ['ask', false, 'cherche_api.rs a un bug'],
+      ['ask', false, 'trouve() renvoie des résultats vides'],
+      ['ask', false, 'Le paquet cherche-utils est obsolète'],
+      ['ask', false, 'ecris_log est trop gros'],
+    ],
+    midSentence: [
+      ['dictate', false, 'Je dois écrire une réponse demain'],
+      ['dictate', false, 'On devrait rédiger une invitation'],
+      ['dictate', false, 'On peut écrire le rapport ensemble'],
+      ['dictate', false, 'Peut-être que je lui écris ce soir'],
+      ['dictate', false, 'La prochaine étape est de répondre à Léa'],
+      ['dictate', false, 'On a discuté de comment rédiger le texte'],
+      ['dictate', false, 'Je prévois de lui écrire un mail'],
+      ['dictate', false, "L'équipe va rédiger la réponse"],
+      ['dictate', false, 'Je veux écrire une note'],
+      ['dictate', false, "Je devrais rédiger l'invitation"],
+      ['dictate', false, 'Nous devrions répondre au client'],
+      ['dictate', false, 'Je voudrais écrire à Marc'],
+      ['dictate', true, 'On devrait reformuler ça plus tard'],
+      ['dictate', true, 'Peut-être que je traduirai ça plus tard'],
+      ['dictate', true, 'Je dois corriger ça'],
+      ['dictate', true, 'On peut traduire ça en anglais'],
+      ['ask', false, 'Je dois chercher des hôtels sur Google'],
+      ['ask', false, 'Peut-être que je cherche ça sur YouTube'],
+      ['ask', false, 'On devrait écrire une réponse'],
+      ['ask', false, "L'équipe va chercher sur GitHub"],
+    ],
+    missingPayload: [
+      ['dictate', 'Écris'],
+      ['dictate', 'Rédige.'],
+      ['dictate', 'Écris :'],
+      ['dictate', 'Réponds avec'],
+      ['dictate', 'Compose !'],
+      ['ask', 'Cherche'],
+      ['ask', 'Recherche'],
+      ['ask', 'Trouve.'],
+    ],
+    disabled: [
+      ['dictate', false, 'draft_insert', 'Écris une note pour demain'],
+      ['dictate', true, 'rewrite_selection', 'Rends ça plus court'],
+      ['dictate', true, 'translate_selection', 'Traduis ça en anglais'],
+      ['ask', false, 'search', 'Cherche Rust sur Google'],
+    ],
+    askWithSelection: [
+      'Réécris ça',
+      'Rends ça plus court',
+      'Traduis ça en anglais',
+      'Corrige la grammaire',
+      'Mets ça en forme de liste',
+      'Rends ça plus amical',
+    ],
+  },
+}
+
+const providerNames = Object.fromEntries(providers.map(([display, id]) => [id, display]))
+
+function buildLocale(name) {
+  const data = localeCorpora[name]
+  const corpus = corpusBuilder(data.prefix)
+  const { locale } = data
+
+  for (const [id, mode, entries] of [
+    ['draft', 'dictate', data.drafts],
+    ['ask-draft', 'ask', data.askDrafts],
+  ]) {
+    for (const [verb, payload] of entries) {
+      corpus.add({
+        id,
+        mode,
+        locale,
+        utterance: `${verb}${data.draftSeparator}${payload}`,
+        expectedKind: 'draft_insert',
+        expectedPlacement: 'insert_at_cursor',
+        expectedPayload: payload,
+      })
+    }
+  }
+  const addRewrite = (utterance, caseLocale = locale) =>
+    corpus.add({
+      id: 'rewrite',
+      locale: caseLocale,
+      utterance,
+      hasSelection: true,
+      expectedKind: 'rewrite_selection',
+      expectedPlacement: 'replace_selection',
+    })
+  const addTranslation = (utterance, caseLocale = locale) =>
+    corpus.add({
+      id: 'translate',
+      locale: caseLocale,
+      utterance,
+      hasSelection: true,
+      expectedKind: 'translate_selection',
+      expectedPlacement: 'replace_selection',
+    })
+  const addSearch = (utterance, provider, query, caseLocale = locale) =>
+    corpus.add({
+      id: 'search',
+      mode: 'ask',
+      locale: caseLocale,
+      utterance,
+      expectedKind: 'search',
+      expectedPlacement: 'open_url',
+      expectedProvider: provider,
+      expectedPayload: query,
+    })
+
+  data.rewrites.forEach((utterance) => addRewrite(utterance))
+  data.translations.forEach((utterance) => addTranslation(utterance))
+  for (const utterance of data.selectionQuestions) {
+    corpus.add({
+      id: 'selection-question',
+      locale,
+      utterance,
+      hasSelection: true,
+      ...fallback('dictate', true),
+    })
+  }
+  for (const [template, provider, query] of data.searches) {
+    addSearch(
+      template.replace('{query}', query).replace('{provider}', providerNames[provider]),
+      provider,
+      query,
+    )
+  }
+  for (const [id, mode, utterances] of [
+    ['default-safe', 'dictate', data.safeDictation],
+    ['open-question', 'ask', data.safeQuestions],
+  ]) {
+    for (const utterance of utterances) {
+      corpus.add({ id, mode, locale, utterance, ...fallback(mode, false) })
+    }
+  }
+  for (const [id, reason, entries] of [
+    ['negated', 'negated', data.negated],
+    ['reported', 'quoted_or_reported', data.reported],
+    ['identifier', 'code_or_identifier', data.identifiers],
+    ['mid-sentence', 'ambiguous', data.midSentence],
+  ]) {
+    for (const [mode, hasSelection, utterance] of entries) {
+      corpus.blocked({ id, mode, locale, utterance, hasSelection, reason })
+    }
+  }
+  for (const [mode, utterance] of data.missingPayload) {
+    corpus.blocked({ id: 'missing-payload', mode, locale, utterance, reason: 'missing_payload' })
+  }
+  for (const [mode, hasSelection, flag, utterance] of data.disabled) {
+    corpus.add({
+      id: 'feature-disabled',
+      mode,
+      locale,
+      utterance,
+      hasSelection,
+      routeFlags: flags({ [flag]: false }),
+      ...fallback(mode, hasSelection),
+      expectedFallbackReason: 'feature_disabled',
+      destructiveBlocker: true,
+    })
+  }
+  for (const utterance of data.askWithSelection) {
+    corpus.blocked({
+      id: 'ask-selection-nondestructive',
+      mode: 'ask',
+      locale,
+      utterance,
+      hasSelection: true,
+      reason: null,
+    })
+  }
+  for (const [kind, ...entry] of data.automatic ?? []) {
+    if (kind === 'draft') {
+      const [utterance, payload] = entry
+      corpus.add({
+        id: 'automatic-positive',
+        locale: 'automatic',
+        utterance,
+        expectedKind: 'draft_insert',
+        expectedPlacement: 'insert_at_cursor',
+        expectedPayload: payload,
+      })
+    } else if (kind === 'rewrite') {
+      addRewrite(entry[0], 'automatic')
+    } else if (kind === 'translate') {
+      addTranslation(entry[0], 'automatic')
+    } else if (kind === 'search') {
+      addSearch(...entry, 'automatic')
+    } else {
+      const [mode, hasSelection, utterance] = entry
+      corpus.blocked({
+        id: 'automatic-mixed-blocker',
+        mode,
+        locale: 'automatic',
+        utterance,
+        hasSelection,
+        reason: 'ambiguous',
+      })
+    }
+  }
+
+  addEditCases(corpus, locale, editCommands[name])
   return corpus.cases
 }
 
@@ -747,6 +2589,11 @@ const corpora = {
   voice_intent_en: buildEnglish(),
   voice_intent_zh_hans: buildChinese({ traditional: false }),
   voice_intent_zh_hant: buildChinese({ traditional: true }),
+  voice_intent_ja: buildLocale('ja'),
+  voice_intent_ko: buildLocale('ko'),
+  voice_intent_de: buildLocale('de'),
+  voice_intent_es: buildLocale('es'),
+  voice_intent_fr: buildLocale('fr'),
 }
 
 for (const [name, cases] of Object.entries(corpora)) {
@@ -1,9 +1,10 @@
 import { useCallback, useEffect, useRef, useState } from 'react'
-import { Check, Copy, Loader2, Mic, Square, X } from 'lucide-react'
+import { Check, Copy, Loader2, Mic, RotateCcw, Square, X } from 'lucide-react'
 import { useTranslation } from 'react-i18next'
 import { getCurrentWindow } from '@tauri-apps/api/window'
 import {
   abortAskDictation,
+  resetAskSession,
   startAskDictation,
   stopAskDictation,
   takePendingAskMessage,
@@ -288,6 +289,14 @@ export function AskPanel({ embedded = false, showHeader = true, title = 'Ask' }:
 
   const answer = result?.answer ?? ''
 
+  const startNewConversation = useCallback(() => {
+    void resetAskSession()
+      .then(() => {
+        setResult((current) => (current ? { ...current, sessionTurns: 0 } : current))
+      })
+      .catch(() => {})
+  }, [])
+
   const copyAnswer = useCallback(() => {
     if (!answer) return
     navigator.clipboard
@@ -316,6 +325,14 @@ export function AskPanel({ embedded = false, showHeader = true, title = 'Ask' }:
   const displayTitle = title === 'Ask' ? t('ask.title') : title
   const resultText = error || answer
   const canCopyAnswer = Boolean(answer && !error && result?.output !== 'openedSearch')
+  const sessionTurns = error ? 0 : (result?.sessionTurns ?? 0)
+  const knowledgeSources = error ? [] : (result?.knowledgeSources ?? [])
+  const sourcesNote =
+    knowledgeSources.length > 0 ? (
+      <p className="mt-2 text-[11px] leading-4 text-text-tertiary">
+        {t('ask.knowledgeSources', { files: knowledgeSources.join(', ') })}
+      </p>
+    ) : null
   const recordingContextLabel = recordingContext?.usedSelectedText
     ? recordingContext.selectedTextTruncated
       ? t('ask.usingSelectedTextTruncated')
@@ -335,10 +352,23 @@ export function AskPanel({ embedded = false, showHeader = true, title = 'Ask' }:
             : t('ask.usingSelectedText')
           : result?.output === 'openedSearch' && result.searchProvider
             ? t('ask.searchOpened', { provider: result.searchProvider })
-            : t('ask.questionLabel')
+            : sessionTurns > 1
+              ? t('ask.followUpTurn', { count: sessionTurns })
+              : t('ask.questionLabel')
   const copyAction = canCopyAnswer ? (
     <div className="flex shrink-0 items-center gap-2">
       {copied && <span className="text-[11px] text-success">{t('ask.copied')}</span>}
+      {sessionTurns > 0 && (
+        <button
+          type="button"
+          aria-label={t('ask.newConversation')}
+          title={t('ask.newConversation')}
+          onClick={startNewConversation}
+          className="flex h-7 w-7 items-center justify-center rounded-[6px] border border-border bg-bg-secondary text-text-tertiary transition-colors hover:border-border-focus hover:text-accent cursor-pointer"
+        >
+          <RotateCcw size={13} />
+        </button>
+      )}
       <button
         type="button"
         aria-label={t('ask.copyAnswer')}
@@ -463,6 +493,7 @@ export function AskPanel({ embedded = false, showHeader = true, title = 'Ask' }:
                   >
                     {resultText}
                   </p>
+                  {sourcesNote}
                 </div>
               </>
             )}
@@ -509,6 +540,7 @@ export function AskPanel({ embedded = false, showHeader = true, title = 'Ask' }:
             >
               {resultText}
             </p>
+            {sourcesNote}
           </div>
         )}
       </div>
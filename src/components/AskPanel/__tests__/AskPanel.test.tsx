@@ -4,6 +4,7 @@ import i18n from '../../../i18n'
 import { AskPanel } from '../AskPanel'
 import {
   abortAskDictation,
+  resetAskSession,
   startAskDictation,
   stopAskDictation,
   takePendingAskMessage,
@@ -59,6 +60,7 @@ vi.mock('../../../lib/tauri', () => ({
   startAskDictation: vi.fn(),
   stopAskDictation: vi.fn(),
   abortAskDictation: vi.fn(),
+  resetAskSession: vi.fn(),
   takePendingAskMessage: vi.fn(),
 }))
 
@@ -93,6 +95,8 @@ function askResult(
     requestedPlacement: 'popup_answer' as const,
     actualPlacement: 'popup_answer' as const,
     fallbackReason: null,
+    sessionTurns: 1,
+    knowledgeSources: [],
     ...overrides,
   }
 }
@@ -301,6 +305,39 @@ describe('AskPanel', () => {
     })
   })
 
+  it('labels follow-up answers and starts a new conversation on request', async () => {
+    vi.mocked(resetAskSession).mockResolvedValueOnce(undefined)
+    render(<AskPanel />)
+
+    await waitFor(() => {
+      expect(tauriEventMock.listen).toHaveBeenCalledWith('ask:result', expect.any(Function))
+    })
+    tauriEventMock.emit('ask:result', askResult({ sessionTurns: 3 }))
+
+    expect(await screen.findByText('Follow-up · turn 3')).toBeDefined()
+    fireEvent.click(screen.getByRole('button', { name: 'New conversation' }))
+
+    expect(resetAskSession).toHaveBeenCalledTimes(1)
+    await waitFor(() => {
+      expect(screen.queryByRole('button', { name: 'New conversation' })).toBeNull()
+    })
+    expect(screen.getByText('Question')).toBeDefined()
+  })
+
+  it('lists knowledge base files used for the answer', async () => {
+    render(<AskPanel />)
+
+    await waitFor(() => {
+      expect(tauriEventMock.listen).toHaveBeenCalledWith('ask:result', expect.any(Function))
+    })
+    tauriEventMock.emit(
+      'ask:result',
+      askResult({ knowledgeSources: ['ops/runbook.md', 'notes/keys.txt'] }),
+    )
+
+    expect(await screen.findByText('Sources: ops/runbook.md, notes/keys.txt')).toBeDefined()
+  })
+
   it('renders a pending hotkey result when the native event was missed', async () => {
     vi.mocked(takePendingAskMessage).mockResolvedValueOnce({
       kind: 'result',
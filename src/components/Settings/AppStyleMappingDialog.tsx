@@ -1,8 +1,18 @@
 import { useEffect, useMemo, useRef, useState } from 'react'
 import { useTranslation } from 'react-i18next'
 import type { AppConfig, ContextFamily, ContextProfileSummary } from '../../stores/appStore'
-import { saveCustomAppMapping, updateCustomAppMapping } from '../../lib/tauri'
-import type { CustomAppMappingView, MappingCandidateView } from '../../lib/tauri'
+import {
+  saveCustomAppMapping,
+  setCustomAppMappingOutputPolicy,
+  updateCustomAppMapping,
+} from '../../lib/tauri'
+import type {
+  AppMatcherPatternType,
+  AppOutputPolicy,
+  AppMatcherType,
+  CustomAppMappingView,
+  MappingCandidateView,
+} from '../../lib/tauri'
 import { AppLogo } from '../AppLogo'
 
 const CONTEXT_FAMILIES: ContextFamily[] = [
@@ -18,7 +28,28 @@ const CONTEXT_FAMILIES: ContextFamily[] = [
   'general',
 ]
 
+const MATCHER_LABEL_KEYS: Record<AppMatcherType, string> = {
+  native_bundle_id: 'settings.mappingMatcherNative',
+  native_executable: 'settings.mappingMatcherNative',
+  exact_web_host: 'settings.mappingMatcherWeb',
+  web_host_suffix: 'settings.mappingMatcherHostSuffix',
+  executable_glob: 'settings.mappingMatcherExecutableGlob',
+  window_title_pattern: 'settings.mappingMatcherWindowTitle',
+}
+
+const INSERTION_STRATEGIES = [
+  'auto',
+  'keyboard',
+  'clipboardPaste',
+  'clipboardCopyOnly',
+  'windowsSendInput',
+  'waylandVirtualKeyboard',
+] as const
+const PASTE_SHORTCUTS = ['ctrlV', 'ctrlShiftV', 'shiftInsert'] as const
+const NEWLINE_MODES = ['enter', 'shiftEnter', 'crlf'] as const
+
 type MappingConfig = Pick<AppConfig, 'custom_scenes' | 'family_scene_assignments'>
+type MatchScope = 'candidate' | AppMatcherPatternType
 
 interface AppStyleMappingDialogProps {
   candidate: MappingCandidateView | null
@@ -33,6 +64,30 @@ function clampLabel(value: string) {
   return Array.from(value).slice(0, 40).join('')
 }
 
+function patternScopes(candidate: MappingCandidateView): AppMatcherPatternType[] {
+  return candidate.matcherType === 'exact_web_host'
+    ? ['web_host_suffix', 'window_title_pattern']
+    : ['executable_glob', 'window_title_pattern']
+}
+
+/** Drops unset fields so an untouched policy compares equal to `{}`. */
+function compactPolicy(policy: AppOutputPolicy): AppOutputPolicy {
+  return Object.fromEntries(
+    Object.entries(policy).filter(([, value]) => value !== undefined && value !== ''),
+  ) as AppOutputPolicy
+}
+
+function samePolicy(a: AppOutputPolicy, b: AppOutputPolicy) {
+  return JSON.stringify(compactPolicy(a)) === JSON.stringify(compactPolicy(b))
+}
+
+/** `docs.example.com` suggests `example.com`; a two-label host stays as is. */
+function suggestedPattern(scope: MatchScope, candidate: MappingCandidateView | null) {
+  if (scope !== 'web_host_suffix' || !candidate) return ''
+  const labels = candidate.displayValue.split('.')
+  return labels.length > 2 ? labels.slice(1).join('.') : candidate.displayValue
+}
+
 export function AppStyleMappingDialog({
   candidate,
   mapping = null,
@@ -54,6 +109,9 @@ export function AppStyleMappingDialog({
       ? mapping.sceneId
       : '',
   )
+  const [scope, setScope] = useState<MatchScope>('candidate')
+  const [pattern, setPattern] = useState('')
+  const [outputPolicy, setOutputPolicy] = useState<AppOutputPolicy>(mapping?.outputPolicy ?? {})
   const [saving, setSaving] = useState(false)
   const [error, setError] = useState<string | null>(null)
   const labelRef = useRef<HTMLInputElement>(null)
@@ -61,7 +119,11 @@ export function AppStyleMappingDialog({
   const displayValue = mapping?.displayValue ?? candidate?.displayValue ?? context.appLabel
   const iconKey = mapping?.iconKey ?? candidate?.iconKey ?? context.iconKey
   const matcherType = mapping?.matcherType ?? candidate?.matcherType
-  const canSave = !saving && Boolean(label.trim()) && Boolean(mapping || candidate)
+  const canSave =
+    !saving &&
+    Boolean(label.trim()) &&
+    Boolean(mapping || candidate) &&
+    (scope === 'candidate' || Boolean(pattern.trim()))
 
   const sceneOptions = useMemo(
     () => config.custom_scenes.map((scene) => ({ id: scene.id, label: scene.name })),
@@ -87,8 +149,9 @@ export function AppStyleMappingDialog({
     setSaving(true)
     setError(null)
     try {
+      let saved: CustomAppMappingView | null = null
       if (mapping) {
-        await updateCustomAppMapping({
+        saved = await updateCustomAppMapping({
           id: mapping.id,
           label: label.trim(),
           family,
@@ -96,13 +159,19 @@ export function AppStyleMappingDialog({
           enabled: mapping.enabled,
         })
       } else if (candidate) {
-        await saveCustomAppMapping({
+        saved = await saveCustomAppMapping({
           candidateGeneration: candidate.generation,
+          ...(scope === 'candidate'
+            ? {}
+            : { pattern: { matcherType: scope, value: pattern.trim() } }),
           label: label.trim(),
           family,
           sceneId: sceneId || null,
         })
       }
+      if (saved && !samePolicy(outputPolicy, saved.outputPolicy ?? {})) {
+        await setCustomAppMappingOutputPolicy(saved.id, compactPolicy(outputPolicy))
+      }
       await onSaved()
     } catch (saveError) {
       setError(saveError instanceof Error ? saveError.message : String(saveError))
@@ -132,17 +201,51 @@ export function AppStyleMappingDialog({
             <div className="min-w-0">
               {matcherType && (
                 <p className="text-[11px] text-text-tertiary">
-                  {t(
-                    matcherType === 'exact_web_host'
-                      ? 'settings.mappingMatcherWeb'
-                      : 'settings.mappingMatcherNative',
-                  )}
+                  {t(MATCHER_LABEL_KEYS[matcherType])}
                 </p>
               )}
               <p className="truncate text-[12px] text-text-secondary">{displayValue}</p>
             </div>
           </div>
 
+          {!mapping && candidate && (
+            <label className="block text-[11px] text-text-secondary">
+              {t('settings.mappingMatchScope')}
+              <select
+                value={scope}
+                onChange={(event) => {
+                  const next = event.target.value as MatchScope
+                  setScope(next)
+                  setPattern(suggestedPattern(next, candidate))
+                }}
+                className="mt-1 w-full rounded-[8px] border border-border bg-bg-secondary px-3 py-2 text-[13px] text-text-primary outline-none focus:border-border-focus"
+              >
+                <option value="candidate">{t(MATCHER_LABEL_KEYS[candidate.matcherType])}</option>
+                {patternScopes(candidate).map((value) => (
+                  <option key={value} value={value}>
+                    {t(MATCHER_LABEL_KEYS[value])}
+                  </option>
+                ))}
+              </select>
+            </label>
+          )}
+
+          {scope !== 'candidate' && (
+            <label className="block text-[11px] text-text-secondary">
+              {t('settings.mappingPattern')}
+              <input
+                value={pattern}
+                onChange={(event) => setPattern(event.target.value)}
+                className="mt-1 w-full rounded-[8px] border border-border bg-bg-secondary px-3 py-2 text-[13px] text-text-primary outline-none focus:border-border-focus"
+              />
+              {scope !== 'web_host_suffix' && (
+                <span className="mt-1 block text-text-tertiary">
+                  {t('settings.mappingPatternHint')}
+                </span>
+              )}
+            </label>
+          )}
+
           <label className="block text-[11px] text-text-secondary">
             {t('settings.mappingLabel')}
             <input
@@ -184,6 +287,102 @@ export function AppStyleMappingDialog({
             </select>
           </label>
 
+          <div className="grid grid-cols-2 gap-2">
+            <label className="block text-[11px] text-text-secondary">
+              {t('settings.mappingInsertion')}
+              <select
+                value={outputPolicy.insertionStrategy ?? ''}
+                onChange={(event) => {
+                  const insertionStrategy = event.target.value || undefined
+                  setOutputPolicy({
+                    ...outputPolicy,
+                    insertionStrategy,
+                    // Newlines are only configurable for Windows SendInput.
+                    newlineMode:
+                      insertionStrategy === 'windowsSendInput'
+                        ? outputPolicy.newlineMode
+                        : undefined,
+                  })
+                }}
+                className="mt-1 w-full rounded-[8px] border border-border bg-bg-secondary px-3 py-2 text-[13px] text-text-primary outline-none focus:border-border-focus"
+              >
+                <option value="">{t('settings.mappingOutputDefault')}</option>
+                {INSERTION_STRATEGIES.map((value) => (
+                  <option key={value} value={value}>
+                    {t(`settings.mappingInsertionStrategies.${value}`)}
+                  </option>
+                ))}
+              </select>
+            </label>
+
+            <label className="block text-[11px] text-text-secondary">
+              {t('settings.mappingPasteShortcut')}
+              <select
+                value={outputPolicy.pasteShortcut ?? ''}
+                onChange={(event) =>
+                  setOutputPolicy({
+                    ...outputPolicy,
+                    pasteShortcut: event.target.value || undefined,
+                  })
+                }
+                className="mt-1 w-full rounded-[8px] border border-border bg-bg-secondary px-3 py-2 text-[13px] text-text-primary outline-none focus:border-border-focus"
+              >
+                <option value="">{t('settings.mappingOutputDefault')}</option>
+                {PASTE_SHORTCUTS.map((value) => (
+                  <option key={value} value={value}>
+                    {t(`settings.mappingPasteShortcuts.${value}`)}
+                  </option>
+                ))}
+              </select>
+            </label>
+
+            {outputPolicy.insertionStrategy === 'windowsSendInput' && (
+              <label className="block text-[11px] text-text-secondary">
+                {t('settings.mappingNewlineMode')}
+                <select
+                  value={outputPolicy.newlineMode ?? ''}
+                  onChange={(event) =>
+                    setOutputPolicy({
+                      ...outputPolicy,
+                      newlineMode: event.target.value || undefined,
+                    })
+                  }
+                  className="mt-1 w-full rounded-[8px] border border-border bg-bg-secondary px-3 py-2 text-[13px] text-text-primary outline-none focus:border-border-focus"
+                >
+                  <option value="">{t('settings.mappingOutputDefault')}</option>
+                  {NEWLINE_MODES.map((value) => (
+                    <option key={value} value={value}>
+                      {t(`settings.mappingNewlineModes.${value}`)}
+                    </option>
+                  ))}
+                </select>
+              </label>
+            )}
+
+            <label className="block text-[11px] text-text-secondary">
+              {t('settings.mappingStreamingInsert')}
+              <select
+                value={
+                  outputPolicy.streamingInsertEnabled === undefined
+                    ? ''
+                    : String(outputPolicy.streamingInsertEnabled)
+                }
+                onChange={(event) =>
+                  setOutputPolicy({
+                    ...outputPolicy,
+                    streamingInsertEnabled:
+                      event.target.value === '' ? undefined : event.target.value === 'true',
+                  })
+                }
+                className="mt-1 w-full rounded-[8px] border border-border bg-bg-secondary px-3 py-2 text-[13px] text-text-primary outline-none focus:border-border-focus"
+              >
+                <option value="">{t('settings.mappingOutputDefault')}</option>
+                <option value="true">{t('settings.mappingStreamingOn')}</option>
+                <option value="false">{t('settings.mappingStreamingOff')}</option>
+              </select>
+            </label>
+          </div>
+
           {error && <p className="text-[11px] text-error">{error}</p>}
         </div>
 
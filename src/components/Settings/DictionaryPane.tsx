@@ -508,6 +508,12 @@ export function DictionaryPane() {
             </button>
           </div>
 
+          {correctionRules.some((rule) => rule.enabled) && (
+            <p className="text-[11px] text-text-tertiary">
+              {t('dictionary.correctionsDisableStreaming')}
+            </p>
+          )}
+
           <div className="overflow-hidden rounded-[8px] border border-border">
             {filteredCorrections.length === 0 ? (
               <div className="px-3 py-8 text-center text-[13px] text-text-tertiary">
@@ -13,8 +13,11 @@ import type { HotkeyStatus } from '../../lib/tauri'
 import { SegmentedControl } from './shared/SegmentedControl'
 import { Toggle } from './shared/Toggle'
 import { ShortcutBindingList } from './ShortcutBindingList'
+import { KnowledgeBaseSettings } from './KnowledgeBaseSettings'
 
 const MAC_ACCESSIBILITY_HOTKEY_ERROR = 'Accessibility permission may be denied'
+const AUTO_STOP_SILENCE_OPTIONS_MS = [1000, 2000, 3000]
+const ASK_ANSWER_TOKEN_OPTIONS = [80, 200, 400, 1024]
 
 export function GeneralPane() {
   const config = useAppStore((s) => s.config)
@@ -204,6 +207,40 @@ export function GeneralPane() {
           value={config.hotkey_mode}
           onChange={(v) => updateConfig({ hotkey_mode: v as HotkeyMode })}
         />
+        {config.hotkey_mode === 'toggle' && (
+          <div className="mt-3">
+            <p className="mb-1.5 text-[12px] text-text-secondary">{t('settings.autoStopSilence')}</p>
+            <SegmentedControl
+              options={[
+                { value: '0', label: t('settings.autoStopSilenceOff') },
+                ...AUTO_STOP_SILENCE_OPTIONS_MS.map((ms) => ({
+                  value: String(ms),
+                  label: t('settings.autoStopSilenceSeconds', { seconds: ms / 1000 }),
+                })),
+              ]}
+              value={String(config.auto_stop_silence_ms)}
+              onChange={(v) => updateConfig({ auto_stop_silence_ms: Number(v) })}
+            />
+          </div>
+        )}
+      </Section>
+
+      <Section title={t('settings.askAnswerLength')}>
+        <SegmentedControl
+          options={ASK_ANSWER_TOKEN_OPTIONS.map((tokens) => ({
+            value: String(tokens),
+            label: t('settings.askAnswerTokens', { tokens }),
+          }))}
+          value={String(config.ask_answer_token_limit)}
+          onChange={(v) => updateConfig({ ask_answer_token_limit: Number(v) })}
+        />
+        <p className="mt-1.5 text-[11px] text-text-tertiary">
+          {t('settings.askAnswerLengthDesc')}
+        </p>
+      </Section>
+
+      <Section title={t('settings.knowledgeBase')}>
+        <KnowledgeBaseSettings />
       </Section>
 
       <Section title={t('settings.outputMode')}>
@@ -0,0 +1,188 @@
+import { useCallback, useEffect, useRef, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import { Loader2, RefreshCw } from 'lucide-react'
+import { useAppStore } from '../../stores/appStore'
+import {
+  clearKnowledgeBase,
+  getKnowledgeBaseStatus,
+  readCredential,
+  reindexKnowledgeBase,
+  setCredential,
+} from '../../lib/tauri'
+import type { KnowledgeBaseStatus } from '../../lib/tauri'
+import { Toggle } from './shared/Toggle'
+
+const EMBEDDING_CREDENTIAL_PROVIDER = 'embeddings'
+const INPUT_CLASS =
+  'w-full px-3 py-2 bg-bg-secondary border border-border rounded-[10px] text-[13px] text-text-primary outline-none focus:border-border-focus transition-colors'
+
+/** Mirrors the backend rule: HTTPS, or plain HTTP to a loopback server. */
+export function isAllowedEmbeddingUrl(value: string): boolean {
+  let url: URL
+  try {
+    url = new URL(value.trim())
+  } catch {
+    return false
+  }
+  if (url.username || url.password || url.search || url.hash) return false
+  if (url.protocol === 'https:') return url.hostname !== ''
+  if (url.protocol !== 'http:') return false
+  return (
+    url.hostname === 'localhost' ||
+    url.hostname === '[::1]' ||
+    /^127(\.\d+){3}$/.test(url.hostname)
+  )
+}
+
+export function KnowledgeBaseSettings() {
+  const config = useAppStore((s) => s.config)
+  const updateConfig = useAppStore((s) => s.updateConfig)
+  const { t } = useTranslation()
+  const [status, setStatus] = useState<KnowledgeBaseStatus | null>(null)
+  const [indexing, setIndexing] = useState(false)
+  const [error, setError] = useState<string | null>(null)
+  const [apiKey, setApiKey] = useState('')
+  const keySaveRef = useRef<ReturnType<typeof setTimeout> | null>(null)
+
+  useEffect(() => {
+    if (!config.knowledge_base_enabled) return
+    let cancelled = false
+    getKnowledgeBaseStatus()
+      .then((next) => {
+        if (!cancelled) setStatus(next)
+      })
+      .catch((err) => console.error('[knowledge] failed to load status', err))
+    readCredential('knowledge', EMBEDDING_CREDENTIAL_PROVIDER)
+      .then((secret) => {
+        if (!cancelled) setApiKey(secret ?? '')
+      })
+      .catch((err) => console.error('[credentials] failed to read embeddings key', err))
+    return () => {
+      cancelled = true
+    }
+  }, [config.knowledge_base_enabled])
+
+  useEffect(
+    () => () => {
+      if (keySaveRef.current) clearTimeout(keySaveRef.current)
+    },
+    [],
+  )
+
+  const persistApiKey = useCallback((value: string) => {
+    if (keySaveRef.current) clearTimeout(keySaveRef.current)
+    keySaveRef.current = setTimeout(() => {
+      keySaveRef.current = null
+      setCredential('knowledge', EMBEDDING_CREDENTIAL_PROVIDER, value).catch((err) =>
+        console.error('[credentials] failed to save embeddings key', err),
+      )
+    }, 350)
+  }, [])
+
+  const reindex = async () => {
+    setIndexing(true)
+    setError(null)
+    try {
+      setStatus(await reindexKnowledgeBase())
+    } catch (err) {
+      const message = err instanceof Error ? err.message : String(err)
+      setError(
+        message === 'knowledge_folder_missing' ? t('settings.knowledgeFolderMissing') : message,
+      )
+    } finally {
+      setIndexing(false)
+    }
+  }
+
+  const clear = async () => {
+    setError(null)
+    try {
+      await clearKnowledgeBase()
+      setStatus(await getKnowledgeBaseStatus())
+    } catch (err) {
+      setError(err instanceof Error ? err.message : String(err))
+    }
+  }
+
+  return (
+    <div className="space-y-2.5">
+      <Toggle
+        checked={config.knowledge_base_enabled}
+        onChange={(checked) => updateConfig({ knowledge_base_enabled: checked })}
+        label={t('settings.knowledgeBaseEnabled')}
+      />
+      <p className="text-[11px] text-text-tertiary">{t('settings.knowledgeBaseDesc')}</p>
+      {config.knowledge_base_enabled && (
+        <>
+          <div className="flex gap-2">
+            <input
+              aria-label={t('settings.knowledgeFolder')}
+              value={config.knowledge_base_folder}
+              onChange={(e) => updateConfig({ knowledge_base_folder: e.target.value })}
+              placeholder={t('settings.knowledgeFolderPlaceholder')}
+              className={`${INPUT_CLASS} min-w-0 flex-1`}
+            />
+            <button
+              type="button"
+              onClick={reindex}
+              disabled={!config.knowledge_base_folder || indexing}
+              className="px-3 py-2 bg-accent text-white rounded-[10px] text-[12px] border-none cursor-pointer hover:bg-accent-hover disabled:opacity-40 disabled:cursor-not-allowed transition-colors flex items-center gap-1.5"
+            >
+              {indexing ? <Loader2 size={13} className="animate-spin" /> : <RefreshCw size={13} />}
+              {t('settings.knowledgeReindex')}
+            </button>
+          </div>
+          {status && status.documentCount > 0 && (
+            <p className="text-[11px] text-text-tertiary">
+              {t('settings.knowledgeStatus', {
+                documents: status.documentCount,
+                passages: status.chunkCount,
+                embedded: status.embeddedChunkCount,
+              })}{' '}
+              <button
+                type="button"
+                onClick={clear}
+                className="text-accent bg-transparent border-none p-0 cursor-pointer hover:underline"
+              >
+                {t('settings.knowledgeClear')}
+              </button>
+            </p>
+          )}
+          {error && <p className="text-[11px] text-error">{error}</p>}
+          <input
+            aria-label={t('settings.knowledgeEmbeddingUrl')}
+            value={config.knowledge_embedding_base_url}
+            onChange={(e) => updateConfig({ knowledge_embedding_base_url: e.target.value })}
+            placeholder={t('settings.knowledgeEmbeddingUrlPlaceholder')}
+            className={INPUT_CLASS}
+          />
+          {config.knowledge_embedding_base_url.trim() &&
+            !isAllowedEmbeddingUrl(config.knowledge_embedding_base_url) && (
+              <p className="text-[11px] text-error">{t('settings.knowledgeEmbeddingUrlInvalid')}</p>
+            )}
+          <div className="flex gap-2">
+            <input
+              aria-label={t('settings.knowledgeEmbeddingModel')}
+              value={config.knowledge_embedding_model}
+              onChange={(e) => updateConfig({ knowledge_embedding_model: e.target.value })}
+              placeholder={t('settings.knowledgeEmbeddingModel')}
+              className={`${INPUT_CLASS} min-w-0 flex-1`}
+            />
+            <input
+              type="password"
+              aria-label={t('settings.apiKey')}
+              value={apiKey}
+              onChange={(e) => {
+                setApiKey(e.target.value)
+                persistApiKey(e.target.value)
+              }}
+              placeholder={t('settings.apiKey')}
+              className={`${INPUT_CLASS} min-w-0 flex-1`}
+            />
+          </div>
+          <p className="text-[11px] text-text-tertiary">{t('settings.knowledgeEmbeddingDesc')}</p>
+        </>
+      )}
+    </div>
+  )
+}
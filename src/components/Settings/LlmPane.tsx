@@ -3,7 +3,12 @@ import { useTranslation } from 'react-i18next'
 import { useAppStore } from '../../stores/appStore'
 import type { PolishStyle } from '../../stores/appStore'
 import { hasManagedCloudAccess, useAuthStore } from '../../stores/authStore'
-import { LLM_PROVIDERS, LLM_DEFAULT_CONFIG, llmProviderRequiresApiKey } from '../../lib/constants'
+import {
+  LLM_PROVIDERS,
+  LLM_DEFAULT_CONFIG,
+  LLM_TIMEOUT_OPTIONS_MS,
+  llmProviderRequiresApiKey,
+} from '../../lib/constants'
 import {
   benchLlmConnection,
   fetchLlmModels,
@@ -49,6 +54,27 @@ export function LlmPane() {
   const goUpgrade = () => {
     window.location.hash = '#/upgrade'
   }
+  const fallbackOptions = LLM_PROVIDERS.filter(
+    (provider) =>
+      provider.value !== config.llm_provider && (provider.value !== 'cloud' || hasCloudAccess),
+  )
+  const toggleFallbackProvider = (provider: string) => {
+    const current = config.llm_fallback_chain
+    const defaults = LLM_DEFAULT_CONFIG[provider]
+    updateConfig({
+      llm_fallback_chain: current.some((entry) => entry.provider === provider)
+        ? current.filter((entry) => entry.provider !== provider)
+        : [
+            ...current,
+            {
+              provider,
+              model: defaults?.model ?? '',
+              base_url: defaults?.baseUrl ?? '',
+              timeout_ms: 0,
+            },
+          ],
+    })
+  }
 
   const models = useAppStore((s) => s.llmModels)
   const setModels = useAppStore((s) => s.setLlmModels)
@@ -407,6 +433,49 @@ export function LlmPane() {
         </>
       )}
 
+      <FormField label={t('settings.llmFallbackProviders')}>
+        <div className="flex flex-wrap gap-1.5">
+          {fallbackOptions.map((provider) => {
+            const position = config.llm_fallback_chain.findIndex(
+              (entry) => entry.provider === provider.value,
+            )
+            return (
+              <button
+                key={provider.value}
+                type="button"
+                aria-pressed={position >= 0}
+                onClick={() => toggleFallbackProvider(provider.value)}
+                className={`px-2.5 py-1 rounded-[8px] border text-[12px] transition-colors ${
+                  position >= 0
+                    ? 'border-accent bg-accent/10 text-text-primary'
+                    : 'border-border bg-bg-secondary text-text-secondary hover:border-border-focus'
+                }`}
+              >
+                {position >= 0 ? `${position + 1}. ` : ''}
+                {t(provider.labelKey)}
+              </button>
+            )
+          })}
+        </div>
+        <p className="text-[11px] text-text-tertiary mt-1.5">
+          {t('settings.llmFallbackProvidersHint')}
+        </p>
+      </FormField>
+
+      <FormField label={t('settings.llmTimeout')}>
+        <select
+          value={config.llm_timeout_ms}
+          onChange={(e) => updateConfig({ llm_timeout_ms: Number(e.target.value) })}
+          className="w-full px-3 py-2.5 bg-bg-secondary border border-border rounded-[10px] text-[13px] text-text-primary outline-none focus:border-border-focus transition-colors"
+        >
+          {LLM_TIMEOUT_OPTIONS_MS.map((value) => (
+            <option key={value} value={value}>
+              {t('settings.llmTimeoutSeconds', { seconds: value / 1000 })}
+            </option>
+          ))}
+        </select>
+      </FormField>
+
       <div className="space-y-3 pt-1">
         <div>
           <Toggle
@@ -415,6 +484,17 @@ export function LlmPane() {
             label={t('settings.enableAiPolish')}
           />
         </div>
+        <div>
+          <Toggle
+            checked={config.spoken_formatting_enabled}
+            disabled={config.polish_enabled}
+            onChange={(checked) => updateConfig({ spoken_formatting_enabled: checked })}
+            label={t('settings.spokenFormatting')}
+          />
+          <p className="mt-1 ml-[52px] text-[11px] text-text-tertiary">
+            {t('settings.spokenFormattingHint')}
+          </p>
+        </div>
         <div>
           <Toggle
             checked={config.context_adaptation_enabled}
@@ -0,0 +1,88 @@
+import { useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+
+interface SceneExportDialogProps {
+  scenes: Array<{ id: string; name: string }>
+  exporting: boolean
+  onCancel: () => void
+  onConfirm: (sceneIds: string[]) => void
+}
+
+export function SceneExportDialog({
+  scenes,
+  exporting,
+  onCancel,
+  onConfirm,
+}: SceneExportDialogProps) {
+  const { t } = useTranslation()
+  const [selected, setSelected] = useState(() => new Set(scenes.map((scene) => scene.id)))
+
+  useEffect(() => {
+    const onKeyDown = (event: KeyboardEvent) => {
+      if (event.key !== 'Escape' || exporting) return
+      event.preventDefault()
+      onCancel()
+    }
+    window.addEventListener('keydown', onKeyDown)
+    return () => window.removeEventListener('keydown', onKeyDown)
+  }, [exporting, onCancel])
+
+  const toggle = (id: string) => {
+    const next = new Set(selected)
+    if (!next.delete(id)) next.add(id)
+    setSelected(next)
+  }
+
+  return (
+    <div className="fixed inset-0 z-50 flex items-center justify-center bg-black/25 px-5">
+      <div className="fixed inset-0" onClick={exporting ? undefined : onCancel} />
+      <div
+        role="dialog"
+        aria-modal="true"
+        aria-label={t('scenes.exportTitle')}
+        className="relative z-10 w-full max-w-[420px] rounded-[10px] border border-border bg-bg-primary shadow-float"
+      >
+        <div className="border-b border-border px-4 py-3">
+          <h3 className="text-[14px] font-medium text-text-primary">{t('scenes.exportTitle')}</h3>
+          <p className="mt-0.5 text-[11px] text-text-tertiary">{t('scenes.exportDesc')}</p>
+        </div>
+        <div className="max-h-64 space-y-1.5 overflow-y-auto px-4 py-3">
+          {scenes.map((scene) => (
+            <label
+              key={scene.id}
+              className="flex items-center gap-2 text-[12px] text-text-primary cursor-pointer"
+            >
+              <input
+                type="checkbox"
+                checked={selected.has(scene.id)}
+                onChange={() => toggle(scene.id)}
+              />
+              <span className="min-w-0 truncate">{scene.name}</span>
+            </label>
+          ))}
+        </div>
+        <div className="flex justify-end gap-2 px-4 py-3">
+          <button
+            type="button"
+            onClick={onCancel}
+            disabled={exporting}
+            className="rounded-[8px] border border-border bg-transparent px-3 py-1.5 text-[12px] text-text-secondary hover:text-text-primary disabled:opacity-50"
+          >
+            {t('common.cancel')}
+          </button>
+          <button
+            type="button"
+            autoFocus
+            onClick={() =>
+              onConfirm(scenes.map((scene) => scene.id).filter((id) => selected.has(id)))
+            }
+            disabled={selected.size === 0 || exporting}
+            className="rounded-[8px] border-none bg-accent px-3 py-1.5 text-[12px] text-white hover:bg-accent-hover disabled:opacity-40"
+          >
+            {t('scenes.exportSelected', { count: selected.size })}
+          </button>
+        </div>
+      </div>
+    </div>
+  )
+}
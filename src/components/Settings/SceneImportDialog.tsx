@@ -0,0 +1,128 @@
+import { useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import type { SceneConflictResolution, ScenePackPreview } from '../../lib/tauri'
+
+const RESOLUTIONS: SceneConflictResolution[] = ['keep_both', 'replace', 'skip']
+
+interface SceneImportDialogProps {
+  fileName: string
+  preview: ScenePackPreview
+  committing: boolean
+  onCancel: () => void
+  onConfirm: (resolution: SceneConflictResolution) => void
+}
+
+export function SceneImportDialog({
+  fileName,
+  preview,
+  committing,
+  onCancel,
+  onConfirm,
+}: SceneImportDialogProps) {
+  const { t } = useTranslation()
+  const [resolution, setResolution] = useState<SceneConflictResolution>('keep_both')
+  const hasConflicts =
+    preview.scenes.some((scene) => scene.conflict !== 'none') ||
+    preview.systemOverrides.some((item) => item.conflict)
+
+  useEffect(() => {
+    const onKeyDown = (event: KeyboardEvent) => {
+      if (event.key !== 'Escape' || committing) return
+      event.preventDefault()
+      onCancel()
+    }
+    window.addEventListener('keydown', onKeyDown)
+    return () => window.removeEventListener('keydown', onKeyDown)
+  }, [committing, onCancel])
+
+  return (
+    <div className="fixed inset-0 z-50 flex items-center justify-center bg-black/25 px-5">
+      <div className="fixed inset-0" onClick={committing ? undefined : onCancel} />
+      <div
+        role="dialog"
+        aria-modal="true"
+        aria-label={t('scenes.importTitle')}
+        className="relative z-10 w-full max-w-[420px] rounded-[10px] border border-border bg-bg-primary shadow-float"
+      >
+        <div className="border-b border-border px-4 py-3">
+          <h3 className="text-[14px] font-medium text-text-primary">{t('scenes.importTitle')}</h3>
+          <p className="mt-0.5 truncate text-[11px] text-text-tertiary">{fileName}</p>
+        </div>
+        <div className="max-h-56 space-y-1.5 overflow-y-auto px-4 py-3">
+          {preview.scenes.map((scene, index) => (
+            <div key={`${scene.id}-${index}`} className="flex items-center justify-between gap-3">
+              <p className="min-w-0 truncate text-[12px] text-text-primary">{scene.name}</p>
+              {scene.conflict !== 'none' && (
+                <span className="flex-none text-[11px] text-amber-600">
+                  {t(scene.conflict === 'id' ? 'scenes.importSameId' : 'scenes.importSameName', {
+                    name: scene.existingName,
+                  })}
+                </span>
+              )}
+            </div>
+          ))}
+          {preview.systemOverrides.map((item) => (
+            <div key={item.id} className="flex items-center justify-between gap-3">
+              <p className="min-w-0 truncate text-[12px] text-text-primary">
+                {t(`scenes.systemModes.${item.id.replace(/^system_/, '')}`)}
+              </p>
+              {item.conflict && (
+                <span className="flex-none text-[11px] text-amber-600">
+                  {t('scenes.importEditedSystemScene')}
+                </span>
+              )}
+            </div>
+          ))}
+        </div>
+        {preview.errors.length > 0 && (
+          <div className="mx-4 max-h-28 overflow-y-auto border-y border-border py-1.5">
+            {preview.errors.slice(0, 20).map((error) => (
+              <p
+                key={`${error.row}-${error.code}`}
+                className="py-0.5 text-[11px] text-text-secondary"
+              >
+                {t('dictionary.importRowError', { row: error.row, code: error.code })}
+              </p>
+            ))}
+          </div>
+        )}
+        {hasConflicts && (
+          <label className="block px-4 pt-3 text-[12px] text-text-secondary">
+            <span className="block mb-1">{t('scenes.importConflicts')}</span>
+            <select
+              value={resolution}
+              onChange={(event) => setResolution(event.target.value as SceneConflictResolution)}
+              disabled={committing}
+              className="w-full rounded-[8px] border border-border bg-bg-secondary px-3 py-2 text-[12px] text-text-primary outline-none focus:border-border-focus"
+            >
+              {RESOLUTIONS.map((value) => (
+                <option key={value} value={value}>
+                  {t(`scenes.importResolutions.${value}`)}
+                </option>
+              ))}
+            </select>
+          </label>
+        )}
+        <div className="flex justify-end gap-2 px-4 py-3">
+          <button
+            type="button"
+            onClick={onCancel}
+            disabled={committing}
+            className="rounded-[8px] border border-border bg-transparent px-3 py-1.5 text-[12px] text-text-secondary hover:text-text-primary disabled:opacity-50"
+          >
+            {t('common.cancel')}
+          </button>
+          <button
+            type="button"
+            autoFocus
+            onClick={() => onConfirm(resolution)}
+            disabled={committing}
+            className="rounded-[8px] border-none bg-accent px-3 py-1.5 text-[12px] text-white hover:bg-accent-hover disabled:opacity-40"
+          >
+            {t('scenes.confirmImport')}
+          </button>
+        </div>
+      </div>
+    </div>
+  )
+}
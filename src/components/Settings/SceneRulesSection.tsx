@@ -0,0 +1,364 @@
+import { useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import { ArrowDown, ArrowUp, Pencil, Plus, Trash2 } from 'lucide-react'
+import type { ContextFamily, SceneRule } from '../../stores/appStore'
+import { listCustomAppMappings, type CustomAppMappingView } from '../../lib/tauri'
+
+const RULE_FAMILIES: ContextFamily[] = [
+  'email',
+  'work_chat',
+  'personal_chat',
+  'document',
+  'project_management',
+  'developer_collaboration',
+  'prompt_or_code',
+  'support',
+  'social',
+  'general',
+]
+
+const WEEKDAYS = [1, 2, 3, 4, 5, 6, 7]
+
+const fieldClass =
+  'w-full px-3 py-2 rounded-[8px] border border-border bg-bg-primary text-[13px] text-text-primary outline-none focus:border-border-focus'
+
+function createRuleId() {
+  if (typeof crypto !== 'undefined' && 'randomUUID' in crypto) {
+    return `rule_${crypto.randomUUID()}`
+  }
+  return `rule_${Date.now()}`
+}
+
+const emptyRule = (sceneId: string): SceneRule => ({
+  id: createRuleId(),
+  name: '',
+  enabled: true,
+  priority: 0,
+  scene_id: sceneId,
+  conditions: {
+    families: [],
+    profile_ids: [],
+    mapping_ids: [],
+    languages: [],
+    weekdays: [],
+    time_window: null,
+    selected_text: null,
+  },
+})
+
+/** Priorities follow list order so the list reads top to bottom. */
+function withListPriorities(rules: SceneRule[]): SceneRule[] {
+  return rules.map((rule, index) => ({ ...rule, priority: index }))
+}
+
+export function SceneRulesSection({
+  rules,
+  sceneOptions,
+  onChange,
+}: {
+  rules: SceneRule[]
+  sceneOptions: Array<{ id: string; name: string }>
+  onChange: (rules: SceneRule[]) => Promise<boolean>
+}) {
+  const { t } = useTranslation()
+  const [draft, setDraft] = useState<SceneRule | null>(null)
+  const [mappings, setMappings] = useState<CustomAppMappingView[]>([])
+  const ordered = [...rules].sort((a, b) => a.priority - b.priority)
+  const sceneName = (id: string) => sceneOptions.find((scene) => scene.id === id)?.name ?? id
+
+  // Mappings are device-only, so they are only read once the user edits a rule.
+  const openEditor = (rule: SceneRule) => {
+    setDraft(rule)
+    listCustomAppMappings()
+      .then(setMappings)
+      .catch(() => setMappings([]))
+  }
+
+  const saveDraft = async () => {
+    if (!draft || !draft.name.trim()) return
+    const saved = { ...draft, name: draft.name.trim() }
+    const next = ordered.some((rule) => rule.id === saved.id)
+      ? ordered.map((rule) => (rule.id === saved.id ? saved : rule))
+      : [...ordered, saved]
+    if (await onChange(withListPriorities(next))) setDraft(null)
+  }
+
+  const move = (index: number, offset: number) => {
+    const next = [...ordered]
+    const [rule] = next.splice(index, 1)
+    next.splice(index + offset, 0, rule)
+    void onChange(withListPriorities(next))
+  }
+
+  const setConditions = (conditions: Partial<SceneRule['conditions']>) => {
+    if (draft) setDraft({ ...draft, conditions: { ...draft.conditions, ...conditions } })
+  }
+
+  return (
+    <section className="space-y-3">
+      <div className="flex flex-wrap items-center justify-between gap-3">
+        <div>
+          <h3 className="text-[13px] font-semibold text-text-primary">{t('scenes.sceneRules')}</h3>
+          <p className="mt-1 text-[12px] text-text-tertiary">{t('scenes.sceneRulesDesc')}</p>
+        </div>
+        <button
+          onClick={() => openEditor(emptyRule(sceneOptions[0]?.id ?? ''))}
+          disabled={sceneOptions.length === 0}
+          className="flex items-center gap-1.5 px-3 py-1.5 rounded-[8px] border border-border bg-bg-secondary text-text-primary text-[12px] cursor-pointer hover:border-border-focus transition-colors disabled:opacity-45 disabled:cursor-not-allowed"
+        >
+          <Plus size={13} />
+          {t('scenes.newRule')}
+        </button>
+      </div>
+
+      {draft && (
+        <div className="space-y-3 rounded-[8px] border border-border bg-bg-secondary px-3 py-3">
+          <label className="block text-[12px] text-text-secondary">
+            <span className="block mb-1">{t('scenes.ruleName')}</span>
+            <input
+              value={draft.name}
+              onChange={(e) => setDraft({ ...draft, name: e.target.value })}
+              maxLength={80}
+              className={fieldClass}
+            />
+          </label>
+          <label className="block text-[12px] text-text-secondary">
+            <span className="block mb-1">{t('scenes.ruleScene')}</span>
+            <select
+              value={draft.scene_id}
+              onChange={(e) => setDraft({ ...draft, scene_id: e.target.value })}
+              className={fieldClass}
+            >
+              {sceneOptions.map((scene) => (
+                <option key={scene.id} value={scene.id}>
+                  {scene.name}
+                </option>
+              ))}
+            </select>
+          </label>
+          <div className="grid grid-cols-2 gap-2">
+            <label className="block text-[12px] text-text-secondary">
+              <span className="block mb-1">{t('scenes.ruleFamily')}</span>
+              <select
+                value={draft.conditions.families[0] ?? ''}
+                onChange={(e) =>
+                  setConditions({
+                    families: e.target.value ? [e.target.value as ContextFamily] : [],
+                  })
+                }
+                className={fieldClass}
+              >
+                <option value="">{t('scenes.ruleAny')}</option>
+                {RULE_FAMILIES.map((family) => (
+                  <option key={family} value={family}>
+                    {t(`contextFamilies.${family}`)}
+                  </option>
+                ))}
+              </select>
+            </label>
+            <label className="block text-[12px] text-text-secondary">
+              <span className="block mb-1">{t('scenes.ruleApp')}</span>
+              <select
+                value={draft.conditions.mapping_ids[0] ?? ''}
+                onChange={(e) =>
+                  setConditions({ mapping_ids: e.target.value ? [e.target.value] : [] })
+                }
+                className={fieldClass}
+              >
+                <option value="">{t('scenes.ruleAny')}</option>
+                {mappings.map((mapping) => (
+                  <option key={mapping.id} value={mapping.id}>
+                    {mapping.label}
+                  </option>
+                ))}
+              </select>
+            </label>
+            <label className="block text-[12px] text-text-secondary">
+              <span className="block mb-1">{t('scenes.ruleLanguages')}</span>
+              <input
+                value={draft.conditions.languages.join(', ')}
+                onChange={(e) =>
+                  setConditions({
+                    languages: e.target.value
+                      .split(',')
+                      .map((value) => value.trim().toLowerCase())
+                      .filter(Boolean),
+                  })
+                }
+                placeholder="en, zh"
+                className={fieldClass}
+              />
+            </label>
+            <label className="block text-[12px] text-text-secondary">
+              <span className="block mb-1">{t('scenes.ruleSelectedText')}</span>
+              <select
+                value={
+                  draft.conditions.selected_text === null
+                    ? ''
+                    : String(draft.conditions.selected_text)
+                }
+                onChange={(e) =>
+                  setConditions({
+                    selected_text: e.target.value === '' ? null : e.target.value === 'true',
+                  })
+                }
+                className={fieldClass}
+              >
+                <option value="">{t('scenes.ruleAny')}</option>
+                <option value="true">{t('scenes.ruleSelectedYes')}</option>
+                <option value="false">{t('scenes.ruleSelectedNo')}</option>
+              </select>
+            </label>
+            <label className="block text-[12px] text-text-secondary">
+              <span className="block mb-1">{t('scenes.ruleTimeFrom')}</span>
+              <input
+                type="time"
+                value={draft.conditions.time_window?.start ?? ''}
+                onChange={(e) =>
+                  setConditions({
+                    time_window: e.target.value
+                      ? { start: e.target.value, end: draft.conditions.time_window?.end ?? '' }
+                      : null,
+                  })
+                }
+                className={fieldClass}
+              />
+            </label>
+            <label className="block text-[12px] text-text-secondary">
+              <span className="block mb-1">{t('scenes.ruleTimeTo')}</span>
+              <input
+                type="time"
+                value={draft.conditions.time_window?.end ?? ''}
+                onChange={(e) =>
+                  setConditions({
+                    time_window: e.target.value
+                      ? { start: draft.conditions.time_window?.start ?? '', end: e.target.value }
+                      : null,
+                  })
+                }
+                className={fieldClass}
+              />
+            </label>
+          </div>
+          <div className="flex flex-wrap gap-1.5" role="group" aria-label={t('scenes.ruleDays')}>
+            {WEEKDAYS.map((day) => {
+              const selected = draft.conditions.weekdays.includes(day)
+              return (
+                <button
+                  key={day}
+                  type="button"
+                  aria-pressed={selected}
+                  onClick={() =>
+                    setConditions({
+                      weekdays: selected
+                        ? draft.conditions.weekdays.filter((value) => value !== day)
+                        : [...draft.conditions.weekdays, day].sort((a, b) => a - b),
+                    })
+                  }
+                  className={`px-2.5 py-1 rounded-[7px] border text-[11px] cursor-pointer ${
+                    selected
+                      ? 'border-accent bg-accent/10 text-accent'
+                      : 'border-border bg-bg-primary text-text-secondary'
+                  }`}
+                >
+                  {t(`scenes.weekdaysShort.${day}`)}
+                </button>
+              )
+            })}
+          </div>
+          <div className="flex items-center justify-end gap-2">
+            <button
+              onClick={() => setDraft(null)}
+              className="px-3 py-1.5 rounded-[8px] border border-border bg-transparent text-[12px] text-text-secondary cursor-pointer hover:text-text-primary transition-colors"
+            >
+              {t('common.cancel')}
+            </button>
+            <button
+              onClick={() => void saveDraft()}
+              disabled={!draft.name.trim() || !draft.scene_id}
+              className="px-3 py-1.5 rounded-[8px] border border-border bg-bg-primary text-[12px] text-text-primary cursor-pointer disabled:opacity-50 disabled:cursor-not-allowed"
+            >
+              {t('common.save')}
+            </button>
+          </div>
+        </div>
+      )}
+
+      {ordered.length === 0 ? (
+        <p className="text-[12px] text-text-tertiary">{t('scenes.noSceneRules')}</p>
+      ) : (
+        <div className="space-y-2">
+          {ordered.map((rule, index) => (
+            <div
+              key={rule.id}
+              className="flex items-center justify-between gap-3 rounded-[8px] border border-border px-3 py-2.5"
+            >
+              <label className="flex min-w-0 items-center gap-2">
+                <input
+                  type="checkbox"
+                  checked={rule.enabled}
+                  aria-label={t('scenes.ruleEnabled', { name: rule.name })}
+                  onChange={() =>
+                    void onChange(
+                      ordered.map((item) =>
+                        item.id === rule.id ? { ...item, enabled: !item.enabled } : item,
+                      ),
+                    )
+                  }
+                />
+                <span className="min-w-0">
+                  <span className="block truncate text-[13px] font-medium text-text-primary">
+                    {rule.name}
+                  </span>
+                  <span className="block truncate text-[11px] text-text-tertiary">
+                    {sceneName(rule.scene_id)}
+                  </span>
+                </span>
+              </label>
+              <div className="flex flex-none items-center gap-1 text-text-tertiary">
+                <button
+                  type="button"
+                  aria-label={t('scenes.moveUp')}
+                  disabled={index === 0}
+                  onClick={() => move(index, -1)}
+                  className="p-1 bg-transparent border-none cursor-pointer hover:text-text-primary disabled:opacity-40"
+                >
+                  <ArrowUp size={12} />
+                </button>
+                <button
+                  type="button"
+                  aria-label={t('scenes.moveDown')}
+                  disabled={index === ordered.length - 1}
+                  onClick={() => move(index, 1)}
+                  className="p-1 bg-transparent border-none cursor-pointer hover:text-text-primary disabled:opacity-40"
+                >
+                  <ArrowDown size={12} />
+                </button>
+                <button
+                  type="button"
+                  aria-label={t('scenes.edit')}
+                  onClick={() => openEditor(rule)}
+                  className="p-1 bg-transparent border-none cursor-pointer hover:text-text-primary"
+                >
+                  <Pencil size={12} />
+                </button>
+                <button
+                  type="button"
+                  aria-label={t('scenes.delete')}
+                  onClick={() =>
+                    void onChange(
+                      withListPriorities(ordered.filter((item) => item.id !== rule.id)),
+                    )
+                  }
+                  className="p-1 bg-transparent border-none cursor-pointer text-red-500 hover:opacity-80"
+                >
+                  <Trash2 size={12} />
+                </button>
+              </div>
+            </div>
+          ))}
+        </div>
+      )}
+    </section>
+  )
+}
@@ -9,9 +9,27 @@ import {
   type FamilySceneAssignment,
   type SystemSceneOverride,
 } from '../../stores/appStore'
-import { setFamilySceneAssignment, updateConfig as persistConfig } from '../../lib/tauri'
-import { importCustomScenesJson, serializeCustomScenes } from '../../lib/scenes/sceneImportExport'
+import {
+  exportScenePack,
+  importScenePack,
+  previewScenePackImport,
+  setFamilySceneAssignment,
+  updateConfig as persistConfig,
+  type SceneConflictResolution,
+  type ScenePackPreview,
+} from '../../lib/tauri'
 import { AppLogo } from '../AppLogo'
+import { SceneExportDialog } from './SceneExportDialog'
+import { SceneImportDialog } from './SceneImportDialog'
+import { SceneRulesSection } from './SceneRulesSection'
+
+const MAX_SCENE_PACK_BYTES = 1024 * 1024
+
+interface PendingSceneImport {
+  fileName: string
+  bytes: number[]
+  preview: ScenePackPreview
+}
 
 interface EditorState {
   mode: 'create' | 'edit' | 'system'
@@ -144,19 +162,6 @@ function nowIso() {
   return new Date().toISOString()
 }
 
-function readFileText(file: File): Promise<string> {
-  if (typeof file.text === 'function') {
-    return file.text()
-  }
-
-  return new Promise((resolve, reject) => {
-    const reader = new FileReader()
-    reader.onload = () => resolve(typeof reader.result === 'string' ? reader.result : '')
-    reader.onerror = () => reject(reader.error)
-    reader.readAsText(file)
-  })
-}
-
 export function ScenesPane() {
   const { t } = useTranslation()
   const config = useAppStore((s) => s.config)
@@ -170,6 +175,10 @@ export function ScenesPane() {
   const [editor, setEditor] = useState<EditorState | null>(null)
   const [saveError, setSaveError] = useState<string | null>(null)
   const [deleteCandidateId, setDeleteCandidateId] = useState<string | null>(null)
+  const [pendingImport, setPendingImport] = useState<PendingSceneImport | null>(null)
+  const [committingImport, setCommittingImport] = useState(false)
+  const [exportOpen, setExportOpen] = useState(false)
+  const [exporting, setExporting] = useState(false)
   const importInputRef = useRef<HTMLInputElement | null>(null)
 
   const saveConfigPatch = async (patch: Partial<AppConfig>): Promise<boolean> => {
@@ -320,15 +329,32 @@ export function ScenesPane() {
     })
   }
 
-  const handleExportCustomScenes = () => {
-    const json = serializeCustomScenes(config.custom_scenes)
-    const blob = new Blob([json], { type: 'application/json' })
-    const url = URL.createObjectURL(blob)
-    const link = document.createElement('a')
-    link.href = url
-    link.download = `opentypeless-scenes-${new Date().toISOString().slice(0, 10)}.json`
-    link.click()
-    URL.revokeObjectURL(url)
+  const exportableScenes = [
+    ...config.system_scene_overrides.map((scene) => ({
+      id: scene.id,
+      name: t(`scenes.systemModes.${systemFamilyForId(scene.id)}`),
+    })),
+    ...sceneOptions,
+  ]
+
+  const handleExportScenes = async (sceneIds: string[]) => {
+    setExporting(true)
+    setSaveError(null)
+    try {
+      const json = await exportScenePack(sceneIds)
+      const blob = new Blob([json], { type: 'application/json' })
+      const url = URL.createObjectURL(blob)
+      const link = document.createElement('a')
+      link.href = url
+      link.download = `opentypeless-scenes-${new Date().toISOString().slice(0, 10)}.json`
+      link.click()
+      URL.revokeObjectURL(url)
+      setExportOpen(false)
+    } catch {
+      setSaveError(t('scenes.exportFailed'))
+    } finally {
+      setExporting(false)
+    }
   }
 
   const handleImportCustomScenes = async (file: File | undefined) => {
@@ -336,43 +362,53 @@ export function ScenesPane() {
     setSaveError(null)
     setMergeMsg(null)
     setMergeOk(false)
+    if (file.size > MAX_SCENE_PACK_BYTES) {
+      setSaveError(t('scenes.importTooLarge'))
+      return
+    }
 
     try {
-      const json = await readFileText(file)
-      const result = importCustomScenesJson(json, {
-        existingIds: new Set(config.custom_scenes.map((scene) => scene.id)),
-        createId: createSceneId,
-        nowIso,
-      })
-
-      if (result.scenes.length === 0) {
+      const bytes = Array.from(new Uint8Array(await file.arrayBuffer()))
+      const preview = await previewScenePackImport(bytes)
+      if (preview.scenes.length === 0 && preview.systemOverrides.length === 0) {
         setSaveError(t('scenes.importNothing'))
         return
       }
+      setPendingImport({ fileName: file.name, bytes, preview })
+    } catch {
+      setSaveError(t('scenes.importFailed'))
+    }
+  }
 
-      const saved = await saveConfigPatch({
-        custom_scenes: [...config.custom_scenes, ...result.scenes],
+  const handleConfirmImport = async (resolution: SceneConflictResolution) => {
+    if (!pendingImport) return
+    setCommittingImport(true)
+    try {
+      const { report, customScenes, systemSceneOverrides } = await importScenePack(
+        pendingImport.bytes,
+        resolution,
+      )
+      applyPersistedConfigPatch({
+        custom_scenes: customScenes,
+        system_scene_overrides: systemSceneOverrides,
       })
-      if (!saved) return
+      setPendingImport(null)
       const importNotes = [
-        result.report.skippedInvalid > 0
-          ? t('scenes.importSkippedInvalid', { count: result.report.skippedInvalid })
+        report.replaced > 0 ? t('scenes.importReplaced', { count: report.replaced }) : null,
+        report.renamed > 0 ? t('scenes.importRenamedConflicts', { count: report.renamed }) : null,
+        report.skippedConflicts > 0
+          ? t('scenes.importSkippedConflicts', { count: report.skippedConflicts })
           : null,
-        result.report.skippedLimit > 0
-          ? t('scenes.importSkippedLimit', { count: result.report.skippedLimit })
+        report.skippedInvalid > 0
+          ? t('scenes.importSkippedInvalid', { count: report.skippedInvalid })
           : null,
-        result.report.renamedConflicts > 0
-          ? t('scenes.importRenamedConflicts', { count: result.report.renamedConflicts })
+        report.skippedLimit > 0
+          ? t('scenes.importSkippedLimit', { count: report.skippedLimit })
           : null,
       ].filter(Boolean)
       setMergeOk(true)
       setMergeMsg(
-        [
-          t('scenes.importedScenes', { count: result.scenes.length }),
-          importNotes.length > 0 ? importNotes.join(' · ') : null,
-        ]
-          .filter(Boolean)
-          .join(' · '),
+        [t('scenes.importedScenes', { count: report.imported }), ...importNotes].join(' · '),
       )
       setTimeout(() => {
         setMergeMsg(null)
@@ -380,6 +416,8 @@ export function ScenesPane() {
       }, 3000)
     } catch {
       setSaveError(t('scenes.importFailed'))
+    } finally {
+      setCommittingImport(false)
     }
   }
 
@@ -428,8 +466,8 @@ export function ScenesPane() {
           <h3 className="text-[13px] font-semibold text-text-primary">{t('scenes.myScenes')}</h3>
           <div className="flex flex-wrap items-center gap-2">
             <button
-              onClick={handleExportCustomScenes}
-              disabled={config.custom_scenes.length === 0}
+              onClick={() => setExportOpen(true)}
+              disabled={exportableScenes.length === 0}
               className="flex items-center gap-1.5 px-3 py-1.5 rounded-[8px] border border-border bg-transparent text-text-secondary text-[12px] cursor-pointer hover:text-text-primary hover:border-border-focus transition-colors disabled:opacity-45 disabled:cursor-not-allowed"
             >
               <Download size={13} />
@@ -528,10 +566,40 @@ export function ScenesPane() {
         )}
       </section>
 
+      <SceneRulesSection
+        rules={config.scene_rules}
+        sceneOptions={[
+          ...APP_WRITING_MODES.map((mode) => ({
+            id: mode.systemSceneId,
+            name: t(`scenes.systemModes.${mode.family}`),
+          })),
+          ...sceneOptions,
+        ]}
+        onChange={(sceneRules) => saveConfigPatch({ scene_rules: sceneRules })}
+      />
+
       {mergeMsg && (
         <p className={`text-[12px] ${!mergeOk ? 'text-red-500' : 'text-green-500'}`}>{mergeMsg}</p>
       )}
       {saveError && <p className="text-[12px] text-red-500">{saveError}</p>}
+
+      {pendingImport && (
+        <SceneImportDialog
+          fileName={pendingImport.fileName}
+          preview={pendingImport.preview}
+          committing={committingImport}
+          onCancel={() => setPendingImport(null)}
+          onConfirm={(resolution) => void handleConfirmImport(resolution)}
+        />
+      )}
+      {exportOpen && (
+        <SceneExportDialog
+          scenes={exportableScenes}
+          exporting={exporting}
+          onCancel={() => setExportOpen(false)}
+          onConfirm={(sceneIds) => void handleExportScenes(sceneIds)}
+        />
+      )}
     </div>
   )
 }
@@ -4,9 +4,11 @@ import { isMacPlatform, useAppStore } from '../../stores/appStore'
 import { hasManagedCloudAccess, useAuthStore } from '../../stores/authStore'
 import {
   STT_PROVIDERS,
+  STT_FALLBACK_PROVIDERS,
   LANGUAGES,
   APPLE_SPEECH_PROVIDER,
   CUSTOM_WHISPER_PROVIDER,
+  LOCAL_WHISPER_PROVIDER,
   CUSTOM_STT_DEFAULTS,
   CUSTOM_STT_PRESETS,
   VOLCENGINE_STT_RESOURCES,
@@ -56,6 +58,8 @@ export function SttPane() {
   const isCloud = config.stt_provider === 'cloud'
   const isAppleSpeech = config.stt_provider === APPLE_SPEECH_PROVIDER
   const isCustomWhisper = config.stt_provider === CUSTOM_WHISPER_PROVIDER
+  const isLocalWhisper = config.stt_provider === LOCAL_WHISPER_PROVIDER
+  const needsApiKey = !isCloud && !isAppleSpeech && !isLocalWhisper
   const isVolcengineDoubao = config.stt_provider === 'volcengine-doubao'
   const credentialProvider = isCustomWhisper ? CUSTOM_WHISPER_PROVIDER : config.stt_provider
   const legacyApiKey = isCustomWhisper ? config.stt_custom_api_key : config.stt_api_key
@@ -70,11 +74,26 @@ export function SttPane() {
   const visibleSttProviders = STT_PROVIDERS.filter(
     (provider) => provider.value !== APPLE_SPEECH_PROVIDER || supportsAppleSpeech,
   )
+  const fallbackOptions = STT_FALLBACK_PROVIDERS.filter(
+    (provider) =>
+      provider.value !== config.stt_provider &&
+      (provider.value !== APPLE_SPEECH_PROVIDER || supportsAppleSpeech),
+  )
+  const toggleFallbackProvider = (provider: string) => {
+    const current = config.stt_fallback_providers
+    updateConfig({
+      stt_fallback_providers: current.includes(provider)
+        ? current.filter((value) => value !== provider)
+        : [...current, provider],
+    })
+  }
   const appleSpeechReady = sttDiagnostics?.ready === true
   const appleSpeechUnavailable = sttDiagnostics?.ready === false
   const canTest = isAppleSpeech
     ? appleSpeechReady
-    : isCustomWhisper
+    : isLocalWhisper
+      ? Boolean(config.stt_local_model_path.trim())
+      : isCustomWhisper
       ? Boolean(config.stt_custom_base_url.trim() && config.stt_custom_model.trim())
       : Boolean(apiKeyDraft)
   const goUpgrade = () => {
@@ -82,7 +101,7 @@ export function SttPane() {
   }
 
   useEffect(() => {
-    if (isCloud || isAppleSpeech) {
+    if (!needsApiKey) {
       setApiKeyDraft('')
       setCredentialErrorMessage(null)
       return
@@ -100,20 +119,21 @@ export function SttPane() {
     return () => {
       cancelled = true
     }
-  }, [credentialProvider, isAppleSpeech, isCloud, legacyApiKey])
+  }, [credentialProvider, legacyApiKey, needsApiKey])
 
   useEffect(() => {
-    if (!isCustomWhisper && !isAppleSpeech) {
+    if (!isCustomWhisper && !isAppleSpeech && !isLocalWhisper) {
       setSttDiagnostics(null)
       return
     }
 
     let cancelled = false
     getSttProviderDiagnostics(
-      isAppleSpeech ? '' : apiKeyDraft,
+      isCustomWhisper ? apiKeyDraft : '',
       config.stt_provider,
       isCustomWhisper ? config.stt_custom_base_url : undefined,
       isCustomWhisper ? config.stt_custom_model : undefined,
+      isLocalWhisper ? config.stt_local_model_path : undefined,
     )
       .then((diagnostics) => {
         if (!cancelled) setSttDiagnostics(diagnostics)
@@ -130,9 +150,11 @@ export function SttPane() {
     apiKeyDraft,
     config.stt_custom_base_url,
     config.stt_custom_model,
+    config.stt_local_model_path,
     config.stt_provider,
     isAppleSpeech,
     isCustomWhisper,
+    isLocalWhisper,
   ])
 
   useEffect(() => {
@@ -173,7 +195,7 @@ export function SttPane() {
 
   const persistSttCredential = useCallback(
     (value: string, delayMs = 350) => {
-      if (isCloud || isAppleSpeech) return
+      if (!needsApiKey) return
       if (credentialSaveRef.current) clearTimeout(credentialSaveRef.current)
       credentialSaveRef.current = setTimeout(() => {
         credentialSaveRef.current = null
@@ -186,7 +208,7 @@ export function SttPane() {
           })
       }, delayMs)
     },
-    [credentialProvider, isAppleSpeech, isCloud],
+    [credentialProvider, needsApiKey],
   )
 
   const handleTest = async () => {
@@ -202,6 +224,15 @@ export function SttPane() {
           config.stt_custom_base_url,
           config.stt_custom_model,
         )
+      } else if (isLocalWhisper) {
+        ms = await benchSttConnection(
+          '',
+          config.stt_provider,
+          undefined,
+          undefined,
+          undefined,
+          config.stt_local_model_path,
+        )
       } else if (isVolcengineDoubao) {
         ms = await benchSttConnection(
           apiKeyDraft,
@@ -366,6 +397,62 @@ export function SttPane() {
             <p className="text-[12px] text-green-500">{t('settings.sttProActive')}</p>
           )}
         </div>
+      ) : isLocalWhisper ? (
+        <FormField label={t('settings.localWhisperModelPath')}>
+          <div className="flex gap-2">
+            <input
+              value={config.stt_local_model_path}
+              onChange={(e) => {
+                updateConfig({ stt_local_model_path: e.target.value })
+                setSttTestStatus('idle')
+                setSttLatencyMs(null)
+                setTestErrorMessage(null)
+              }}
+              placeholder={t('settings.localWhisperModelPathPlaceholder')}
+              className="flex-1 px-3 py-2.5 bg-bg-secondary border border-border rounded-[10px] text-[13px] text-text-primary outline-none focus:border-border-focus transition-colors"
+            />
+            <button
+              onClick={handleTest}
+              disabled={!canTest || sttTestStatus === 'testing'}
+              className="px-4 py-2.5 bg-accent text-white rounded-[10px] text-[13px] border-none cursor-pointer hover:bg-accent-hover disabled:opacity-40 disabled:cursor-not-allowed transition-colors flex items-center gap-1.5"
+            >
+              {sttTestStatus === 'testing' && <Loader2 size={14} className="animate-spin" />}
+              {t('settings.test')}
+            </button>
+          </div>
+          <p className="text-[11px] text-text-tertiary mt-1.5">
+            {t('settings.localWhisperSetupHint')}
+          </p>
+          {sttDiagnostics && (
+            <p
+              className={`flex items-center gap-1.5 text-[11px] mt-1.5 min-w-0 ${
+                sttDiagnostics.ready ? 'text-success' : 'text-text-tertiary'
+              }`}
+            >
+              {sttDiagnostics.ready ? (
+                <CheckCircle2 size={12} className="flex-shrink-0" />
+              ) : (
+                <XCircle size={12} className="flex-shrink-0" />
+              )}
+              <span className="truncate">
+                {sttDiagnostics.ready
+                  ? t('settings.localWhisperReady')
+                  : (sttDiagnostics.issues[0]?.message ?? t('settings.localSttNeedsSetup'))}
+              </span>
+            </p>
+          )}
+          {sttTestStatus === 'success' && (
+            <p className="flex items-center gap-1 text-[12px] text-success mt-2">
+              <CheckCircle2 size={13} /> {t('settings.connectionSuccess')}
+            </p>
+          )}
+          {(sttTestStatus === 'error' || testErrorMessage) && (
+            <div className="flex items-start gap-1 text-[12px] text-error mt-2">
+              <XCircle size={13} className="mt-[1px] flex-shrink-0" />
+              <span>{testErrorMessage || t('settings.connectionFailed')}</span>
+            </div>
+          )}
+        </FormField>
       ) : isAppleSpeech ? (
         <FormField label={t('providers.stt.appleSpeech')}>
           <div className="flex gap-2">
@@ -588,6 +675,33 @@ export function SttPane() {
         </>
       )}
 
+      <FormField label={t('settings.sttFallbackProviders')}>
+        <div className="flex flex-wrap gap-1.5">
+          {fallbackOptions.map((provider) => {
+            const position = config.stt_fallback_providers.indexOf(provider.value)
+            return (
+              <button
+                key={provider.value}
+                type="button"
+                aria-pressed={position >= 0}
+                onClick={() => toggleFallbackProvider(provider.value)}
+                className={`px-2.5 py-1 rounded-[8px] border text-[12px] transition-colors ${
+                  position >= 0
+                    ? 'border-accent bg-accent/10 text-text-primary'
+                    : 'border-border bg-bg-secondary text-text-secondary hover:border-border-focus'
+                }`}
+              >
+                {position >= 0 ? `${position + 1}. ` : ''}
+                {t(provider.labelKey)}
+              </button>
+            )
+          })}
+        </div>
+        <p className="text-[11px] text-text-tertiary mt-1.5">
+          {t('settings.sttFallbackProvidersHint')}
+        </p>
+      </FormField>
+
       <FormField label={t('settings.sttLanguage')}>
         <select
           value={config.stt_language}
@@ -14,10 +14,16 @@ vi.mock('react-i18next', () => ({
         'settings.editAppMappingTitle': 'Edit app writing style',
         'settings.mappingMatcherWeb': 'Exact website host',
         'settings.mappingMatcherNative': 'Desktop app',
+        'settings.mappingMatcherHostSuffix': 'Website and its subdomains',
+        'settings.mappingMatcherWindowTitle': 'Windows whose title matches',
+        'settings.mappingMatchScope': 'Match',
+        'settings.mappingPattern': 'Pattern',
         'settings.mappingLabel': 'Name',
         'settings.mappingFamily': 'Context family',
         'settings.mappingScene': 'Writing scene',
         'settings.mappingNoScene': 'Automatic',
+        'settings.mappingPasteShortcut': 'Paste shortcut',
+        'settings.mappingOutputDefault': 'Default',
         'settings.mappingSave': 'Save',
         'settings.mappingCancel': 'Cancel',
         'settings.manageAppMappingsTitle': 'Manage app mappings',
@@ -81,6 +87,7 @@ describe('AppStyleMappingDialog', () => {
       sceneId: null,
       enabled: true,
       iconKey: 'general',
+      outputPolicy: {},
     })
     vi.mocked(tauri.updateCustomAppMapping).mockResolvedValue({
       id: 'mapping-1',
@@ -91,6 +98,7 @@ describe('AppStyleMappingDialog', () => {
       sceneId: null,
       enabled: true,
       iconKey: 'general',
+      outputPolicy: {},
     })
   })
 
@@ -116,6 +124,29 @@ describe('AppStyleMappingDialog', () => {
     expect(label).toHaveValue('你'.repeat(40))
   })
 
+  it('offers a newline mode only for Windows SendInput', () => {
+    render(
+      <AppStyleMappingDialog
+        candidate={candidate}
+        context={context}
+        config={config}
+        onCancel={vi.fn()}
+        onSaved={vi.fn()}
+      />,
+    )
+
+    expect(screen.queryByLabelText('settings.mappingNewlineMode')).not.toBeInTheDocument()
+    const insertion = screen.getByLabelText('settings.mappingInsertion')
+    fireEvent.change(insertion, { target: { value: 'windowsSendInput' } })
+    fireEvent.change(screen.getByLabelText('settings.mappingNewlineMode'), {
+      target: { value: 'shiftEnter' },
+    })
+    fireEvent.change(insertion, { target: { value: 'keyboard' } })
+    expect(screen.queryByLabelText('settings.mappingNewlineMode')).not.toBeInTheDocument()
+    fireEvent.change(insertion, { target: { value: 'windowsSendInput' } })
+    expect(screen.getByLabelText('settings.mappingNewlineMode')).toHaveValue('')
+  })
+
   it('requires an explicit submit before saving the backend-owned candidate', async () => {
     const onSaved = vi.fn()
     render(
@@ -143,6 +174,36 @@ describe('AppStyleMappingDialog', () => {
     expect(onSaved).toHaveBeenCalled()
   })
 
+  it('saves a host-suffix pattern suggested from the candidate host', async () => {
+    render(
+      <AppStyleMappingDialog
+        candidate={candidate}
+        context={context}
+        config={config}
+        onCancel={vi.fn()}
+        onSaved={vi.fn()}
+      />,
+    )
+
+    expect(screen.queryByLabelText('Pattern')).not.toBeInTheDocument()
+    fireEvent.change(screen.getByLabelText('Match'), { target: { value: 'web_host_suffix' } })
+    expect(screen.getByLabelText('Pattern')).toHaveValue('example.com')
+    expect(
+      screen.queryByRole('option', { name: 'Desktop apps matching a pattern' }),
+    ).not.toBeInTheDocument()
+    fireEvent.click(screen.getByRole('button', { name: 'Save' }))
+
+    await waitFor(() =>
+      expect(tauri.saveCustomAppMapping).toHaveBeenCalledWith({
+        candidateGeneration: 9,
+        pattern: { matcherType: 'web_host_suffix', value: 'example.com' },
+        label: 'docs.example.com',
+        family: 'document',
+        sceneId: null,
+      }),
+    )
+  })
+
   it('does not expose family-wide scene assignment from the exact-app override dialog', async () => {
     render(
       <AppStyleMappingDialog
@@ -185,6 +246,7 @@ describe('AppStyleMappingDialog', () => {
       sceneId: 'custom_deleted',
       enabled: true,
       iconKey: 'general',
+      outputPolicy: {},
     }
 
     render(
@@ -210,6 +272,44 @@ describe('AppStyleMappingDialog', () => {
         enabled: true,
       }),
     )
+    expect(tauri.setCustomAppMappingOutputPolicy).not.toHaveBeenCalled()
+  })
+
+  it('stores a per-app paste shortcut only when the output policy changes', async () => {
+    const terminal: tauri.CustomAppMappingView = {
+      id: 'mapping-term',
+      label: 'Terminal',
+      matcherType: 'native_executable',
+      displayValue: 'kitty · Linux',
+      family: 'prompt_or_code',
+      sceneId: null,
+      enabled: true,
+      iconKey: 'terminal',
+      outputPolicy: {},
+    }
+    vi.mocked(tauri.updateCustomAppMapping).mockResolvedValue(terminal)
+
+    render(
+      <AppStyleMappingDialog
+        candidate={null}
+        mapping={terminal}
+        context={context}
+        config={config}
+        onCancel={vi.fn()}
+        onSaved={vi.fn()}
+      />,
+    )
+
+    fireEvent.change(screen.getByLabelText('Paste shortcut'), {
+      target: { value: 'ctrlShiftV' },
+    })
+    fireEvent.click(screen.getByRole('button', { name: 'Save' }))
+
+    await waitFor(() =>
+      expect(tauri.setCustomAppMappingOutputPolicy).toHaveBeenCalledWith('mapping-term', {
+        pasteShortcut: 'ctrlShiftV',
+      }),
+    )
   })
 })
 
@@ -223,6 +323,7 @@ describe('ManageAppMappingsDialog', () => {
     sceneId: null,
     enabled: true,
     iconKey: 'slack',
+    outputPolicy: {},
   }
 
   beforeEach(() => {
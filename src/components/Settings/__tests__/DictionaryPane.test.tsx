@@ -179,6 +179,21 @@ describe('DictionaryPane', () => {
     expect(screen.getByText('open type less')).toBeInTheDocument()
   })
 
+  it('explains that enabled corrections keep streaming insert off', () => {
+    mockAppStore.correctionRules = [
+      { id: 4, pattern: 'open type less', replacement: 'OpenTypeless', enabled: false },
+    ]
+    const { rerender } = render(<DictionaryPane />)
+    fireEvent.click(screen.getByRole('button', { name: 'Corrections' }))
+    expect(screen.queryByText('dictionary.correctionsDisableStreaming')).toBeNull()
+
+    mockAppStore.correctionRules = [
+      { id: 4, pattern: 'open type less', replacement: 'OpenTypeless', enabled: true },
+    ]
+    rerender(<DictionaryPane />)
+    expect(screen.getByText('dictionary.correctionsDisableStreaming')).toBeInTheDocument()
+  })
+
   it('edits dictionary and correction rows inline', async () => {
     mockAppStore.dictionary = [{ id: 1, word: 'Token', pronunciation: null }]
     mockAppStore.correctionRules = [
@@ -90,8 +90,11 @@ const mockAppStore = {
     llm_api_key: '',
     llm_base_url: 'https://api.openai.com/v1',
     llm_model: 'gpt-4o-mini',
+    llm_timeout_ms: 15000,
+    llm_fallback_chain: [] as any[],
     polish_enabled: true,
     context_adaptation_enabled: true,
+    spoken_formatting_enabled: true,
     polish_style: 'clean',
     polish_custom_prompt: '',
     polish_chinese_script: 'preserve',
@@ -755,6 +758,7 @@ describe('LlmPane', () => {
           sceneId: null,
           enabled: true,
           iconKey: 'slack',
+          outputPolicy: {},
         },
       ])
 
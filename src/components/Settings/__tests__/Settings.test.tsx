@@ -12,7 +12,7 @@
  */
 
 import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest'
-import { render, screen, fireEvent, waitFor, act, cleanup } from '@testing-library/react'
+import { render, screen, fireEvent, waitFor, act, cleanup, within } from '@testing-library/react'
 import React from 'react'
 import { useAppStore } from '../../../stores/appStore'
 
@@ -95,6 +95,7 @@ vi.mock('../../../lib/tauri', () => ({
       maxUploadBytes: 24 * 1024 * 1024,
       source: 'provider',
       explanationKey: 'recordingLimits.reasons.providerDuration',
+      supportsVocabularyHints: false,
     },
     mode: 'auto',
     requestedSeconds: 30,
@@ -102,7 +103,7 @@ vi.mock('../../../lib/tauri', () => ({
   }),
   updateHotkey: vi.fn().mockResolvedValue(undefined),
   updateAskHotkey: vi.fn().mockResolvedValue(undefined),
-  askAnything: vi.fn().mockResolvedValue('A concise answer.'),
+  askAnything: vi.fn().mockResolvedValue({ answer: 'A concise answer.', knowledgeSources: [] }),
   showAskWindow: vi.fn().mockResolvedValue(undefined),
   startAskFlow: vi.fn().mockResolvedValue(undefined),
   startAskDictation: vi.fn().mockResolvedValue(undefined),
@@ -217,6 +218,9 @@ vi.mock('../../../lib/tauri', () => ({
   exportDictionaryCsv: vi.fn().mockResolvedValue(''),
   listCustomAppMappings: vi.fn().mockResolvedValue([]),
   setFamilySceneAssignment: vi.fn().mockResolvedValue([]),
+  previewScenePackImport: vi.fn(),
+  importScenePack: vi.fn(),
+  exportScenePack: vi.fn().mockResolvedValue('{}'),
   updateConfig: vi.fn().mockResolvedValue(undefined),
 }))
 
@@ -253,9 +257,12 @@ vi.mock('../../../stores/authStore', () => ({
 import { Settings } from '../index'
 import {
   checkAccessibilityPermission,
+  exportScenePack,
   getConfig,
   getHotkeyRegistrationError,
+  importScenePack,
   listCustomAppMappings,
+  previewScenePackImport,
   setFamilySceneAssignment,
   setAutoStart,
   startAskFlow,
@@ -927,6 +934,39 @@ describe('Settings Scenes local custom scenes', () => {
     expect(screen.queryByText('settings.unsavedChanges')).toBeNull()
   })
 
+  it('creates a scene rule for a weekday time window', async () => {
+    renderSettings()
+    clickSidebarItem('settings.scenes')
+
+    fireEvent.click(screen.getByText('scenes.newRule'))
+    await waitFor(() => expect(listCustomAppMappings).toHaveBeenCalled())
+    fireEvent.change(screen.getByLabelText('scenes.ruleName'), {
+      target: { value: 'Work hours mail' },
+    })
+    fireEvent.change(screen.getByLabelText('scenes.ruleFamily'), { target: { value: 'email' } })
+    fireEvent.change(screen.getByLabelText('scenes.ruleTimeFrom'), { target: { value: '09:00' } })
+    fireEvent.change(screen.getByLabelText('scenes.ruleTimeTo'), { target: { value: '18:00' } })
+    fireEvent.click(screen.getByText('scenes.weekdaysShort.1'))
+    fireEvent.click(screen.getAllByText('Save')[0])
+
+    await waitFor(() => expect(vi.mocked(updateConfig)).toHaveBeenCalledTimes(1))
+    expect(useAppStore.getState().savedConfig?.scene_rules).toEqual([
+      expect.objectContaining({
+        name: 'Work hours mail',
+        enabled: true,
+        priority: 0,
+        scene_id: 'system_email',
+        conditions: expect.objectContaining({
+          families: ['email'],
+          weekdays: [1],
+          time_window: { start: '09:00', end: '18:00' },
+          selected_text: null,
+        }),
+      }),
+    ])
+    expect(screen.getByText('Work hours mail')).toBeInTheDocument()
+  })
+
   it('persists only scene fields and preserves unrelated unsaved settings', async () => {
     renderSettings()
     act(() => useAppStore.getState().updateConfig({ auto_start: false }))
@@ -1025,7 +1065,7 @@ describe('Settings Scenes local custom scenes', () => {
     expect(useAppStore.getState().config.family_scene_assignments).toEqual([])
   })
 
-  it('exports local scenes as a compact JSON file', async () => {
+  it('exports the selected scenes as a scene pack file', async () => {
     useAppStore.getState().setConfig({
       ...useAppStore.getState().config,
       custom_scenes: [
@@ -1037,9 +1077,18 @@ describe('Settings Scenes local custom scenes', () => {
           created_at: '2026-07-01T00:00:00.000Z',
           updated_at: '2026-07-01T00:00:00.000Z',
         },
+        {
+          id: 'custom_private',
+          name: 'Private Notes',
+          description: '',
+          prompt_template: 'Keep it private.',
+          created_at: '2026-07-01T00:00:00.000Z',
+          updated_at: '2026-07-01T00:00:00.000Z',
+        },
       ],
     })
     seedSavedConfig()
+    vi.mocked(exportScenePack).mockClear()
     const createObjectUrl = vi.spyOn(URL, 'createObjectURL').mockReturnValue('blob:scene-export')
     const revokeObjectUrl = vi.spyOn(URL, 'revokeObjectURL').mockImplementation(() => {})
     const click = vi.spyOn(HTMLAnchorElement.prototype, 'click').mockImplementation(() => {})
@@ -1047,66 +1096,94 @@ describe('Settings Scenes local custom scenes', () => {
     renderSettings()
     clickSidebarItem('settings.scenes')
     fireEvent.click(screen.getByText('scenes.export'))
+    const dialog = screen.getByRole('dialog', { name: 'scenes.exportTitle' })
+    fireEvent.click(within(dialog).getByLabelText('Private Notes'))
+    fireEvent.click(within(dialog).getByText('scenes.exportSelected'))
 
+    await waitFor(() => expect(click).toHaveBeenCalledTimes(1))
+    expect(exportScenePack).toHaveBeenCalledWith(['custom_existing'])
     expect(createObjectUrl).toHaveBeenCalledTimes(1)
-    expect(click).toHaveBeenCalledTimes(1)
+    expect(screen.queryByRole('dialog', { name: 'scenes.exportTitle' })).toBeNull()
 
     createObjectUrl.mockRestore()
     revokeObjectUrl.mockRestore()
     click.mockRestore()
   })
 
-  it('imports local scenes from JSON without overwriting existing ids', async () => {
+  it('previews a scene pack and imports it with the chosen conflict resolution', async () => {
+    const existing = {
+      id: 'custom_existing',
+      name: 'Existing',
+      description: '',
+      prompt_template: 'Keep as-is.',
+      created_at: '2026-07-01T00:00:00.000Z',
+      updated_at: '2026-07-01T00:00:00.000Z',
+    }
     useAppStore.getState().setConfig({
       ...useAppStore.getState().config,
-      custom_scenes: [
+      custom_scenes: [existing],
+    })
+    seedSavedConfig()
+    vi.mocked(previewScenePackImport).mockResolvedValue({
+      version: 1,
+      scenes: [
         {
           id: 'custom_existing',
-          name: 'Existing',
-          description: '',
-          prompt_template: 'Keep as-is.',
-          created_at: '2026-07-01T00:00:00.000Z',
-          updated_at: '2026-07-01T00:00:00.000Z',
+          name: 'Imported',
+          description: 'Imported scene',
+          conflict: 'id',
+          existingName: 'Existing',
         },
       ],
+      systemOverrides: [],
+      skippedInvalid: 0,
+      errors: [],
+    })
+    const imported = {
+      ...existing,
+      name: 'Imported',
+      prompt_template: 'Rewrite as a crisp note.',
+    }
+    vi.mocked(importScenePack).mockResolvedValue({
+      report: {
+        imported: 0,
+        replaced: 1,
+        renamed: 0,
+        skippedConflicts: 0,
+        skippedLimit: 0,
+        skippedInvalid: 0,
+        errors: [],
+      },
+      customScenes: [imported],
+      systemSceneOverrides: [],
     })
-    seedSavedConfig()
 
     renderSettings()
     clickSidebarItem('settings.scenes')
 
-    const file = new File(
-      [
-        JSON.stringify({
-          version: 1,
-          scenes: [
-            {
-              id: 'custom_existing',
-              name: 'Imported',
-              description: 'Imported scene',
-              promptTemplate: 'Rewrite as a crisp note.',
-            },
-          ],
-        }),
-      ],
-      'scenes.json',
-      { type: 'application/json' },
-    )
-
+    const bytes = new TextEncoder().encode('{"format":"opentypeless_scene_pack"}')
+    const file = new File([bytes], 'scenes.json', { type: 'application/json' })
+    Object.defineProperty(file, 'arrayBuffer', {
+      value: vi.fn().mockResolvedValue(bytes.buffer),
+    })
     fireEvent.change(screen.getByLabelText('scenes.import'), {
       target: { files: [file] },
     })
 
-    await waitFor(() => {
-      expect(vi.mocked(updateConfig)).toHaveBeenCalledTimes(1)
+    const dialog = await screen.findByRole('dialog', { name: 'scenes.importTitle' })
+    expect(previewScenePackImport).toHaveBeenCalledWith(Array.from(bytes))
+    expect(within(dialog).getByText('scenes.importSameId')).toBeInTheDocument()
+    fireEvent.change(within(dialog).getByLabelText('scenes.importConflicts'), {
+      target: { value: 'replace' },
     })
+    fireEvent.click(within(dialog).getByText('scenes.confirmImport'))
 
-    const { config, savedConfig } = useAppStore.getState()
-    expect(config.custom_scenes).toHaveLength(2)
-    expect(config.custom_scenes[0].id).toBe('custom_existing')
-    expect(config.custom_scenes[1].id).not.toBe('custom_existing')
-    expect(config.custom_scenes[1].name).toBe('Imported')
-    expect(savedConfig?.custom_scenes).toHaveLength(2)
+    await waitFor(() => {
+      expect(importScenePack).toHaveBeenCalledWith(Array.from(bytes), 'replace')
+      expect(useAppStore.getState().config.custom_scenes).toEqual([imported])
+    })
+    expect(useAppStore.getState().savedConfig?.custom_scenes).toEqual([imported])
+    expect(vi.mocked(updateConfig)).not.toHaveBeenCalled()
     expect(screen.queryByText('settings.unsavedChanges')).toBeNull()
   })
 })
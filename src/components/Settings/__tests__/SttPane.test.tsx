@@ -95,6 +95,8 @@ const mockAppStore = {
     stt_custom_base_url: 'http://localhost:8000/v1',
     stt_custom_model: 'Systran/faster-whisper-large-v3',
     stt_volcengine_resource_id: 'volc.seedasr.sauc.duration',
+    stt_local_model_path: '',
+    stt_fallback_providers: [] as string[],
     recording_limit_mode: 'auto' as 'auto' | 'custom',
     custom_recording_limit_seconds: 600,
     max_recording_seconds: 600,
@@ -192,6 +194,7 @@ describe('SttPane', () => {
         maxUploadBytes: null,
         source: 'productSafety',
         explanationKey: 'recordingLimits.reasons.productSafety',
+        supportsVocabularyHints: true,
       },
       mode: 'auto',
       requestedSeconds: 600,
@@ -342,6 +345,7 @@ describe('SttPane', () => {
           maxUploadBytes: 24 * 1024 * 1024,
           source: 'provider',
           explanationKey: 'recordingLimits.reasons.providerDuration',
+          supportsVocabularyHints: false,
         },
         mode: 'custom',
         requestedSeconds: 600,
@@ -376,6 +380,7 @@ describe('SttPane', () => {
           maxUploadBytes: 4_000_000,
           source: 'managedProduct',
           explanationKey: 'recordingLimits.reasons.managedCapability',
+          supportsVocabularyHints: false,
         },
         mode: 'custom',
         requestedSeconds: 30,
@@ -405,6 +410,7 @@ describe('SttPane', () => {
           maxUploadBytes: 4_000_000,
           source: 'managedProduct',
           explanationKey: 'recordingLimits.reasons.managedCapability',
+          supportsVocabularyHints: false,
         },
         mode: 'auto',
         requestedSeconds: 600,
@@ -448,6 +454,7 @@ describe('SttPane', () => {
           maxUploadBytes: 24 * 1024 * 1024,
           source: 'clientBuffer',
           explanationKey: 'recordingLimits.reasons.clientBuffer',
+          supportsVocabularyHints: true,
         },
         mode: 'custom',
         requestedSeconds: 600,
@@ -478,6 +485,7 @@ describe('SttPane', () => {
           maxUploadBytes: 4_000_000,
           source: 'managedProduct',
           explanationKey: 'recordingLimits.reasons.managedFallback',
+          supportsVocabularyHints: false,
         },
         mode: 'custom',
         requestedSeconds: 30,
@@ -520,6 +528,7 @@ describe('SttPane', () => {
           maxUploadBytes: 4_000_000,
           source: 'managedProduct',
           explanationKey: 'recordingLimits.reasons.managedFallback',
+          supportsVocabularyHints: false,
         },
         mode: 'auto',
         requestedSeconds: 30,
@@ -543,6 +552,7 @@ describe('SttPane', () => {
           maxUploadBytes: 4_000_000,
           source: 'managedProduct',
           explanationKey: 'recordingLimits.reasons.managedCapability',
+          supportsVocabularyHints: false,
         },
         mode: 'auto',
         requestedSeconds: 600,
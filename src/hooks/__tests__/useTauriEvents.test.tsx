@@ -183,6 +183,7 @@ describe('useTauriEvents', () => {
       effectiveMaxSeconds: 600,
       providerId: 'cloud',
       explanationKey: 'recordingLimits.reasons.managedCapability',
+      supportsVocabularyHints: false,
     }
     act(() => {
       eventListeners.get('recording:deadline-warning')?.({
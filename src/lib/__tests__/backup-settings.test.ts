@@ -19,6 +19,40 @@ describe('createBackupSettings', () => {
       system_scene_overrides: [{ id: 'system_email', prompt_template: 'Use a warm email body.' }],
       active_scene: null,
       family_scene_assignments: [{ family: 'email', scene_id: 'builtin_professional_email' }],
+      scene_rules: [
+        {
+          id: 'rule_mail',
+          name: 'Mail mornings',
+          enabled: true,
+          priority: 0,
+          scene_id: 'builtin_professional_email',
+          conditions: {
+            families: ['email'],
+            profile_ids: [],
+            mapping_ids: [],
+            languages: ['en'],
+            weekdays: [1, 2, 3, 4, 5],
+            time_window: { start: '08:00', end: '12:00' },
+            selected_text: null,
+          },
+        },
+        {
+          id: 'rule_private_app',
+          name: 'Private app',
+          enabled: true,
+          priority: 1,
+          scene_id: 'builtin_meeting_notes',
+          conditions: {
+            families: [],
+            profile_ids: [],
+            mapping_ids: ['private-mapping-id'],
+            languages: [],
+            weekdays: [],
+            time_window: null,
+            selected_text: null,
+          },
+        },
+      ],
       custom_app_mappings: [{ matcher: { exactWebHost: 'private.example.com' } }],
       customAppMappings: [{ nativeBundleId: 'com.private.writer' }],
       matcher: { executable: 'private.exe' },
@@ -32,6 +66,7 @@ describe('createBackupSettings', () => {
     expect(settings.family_scene_assignments).toEqual([
       { family: 'email', scene_id: 'builtin_professional_email' },
     ])
+    expect(settings.scene_rules?.map((rule) => rule.id)).toEqual(['rule_mail'])
     expect(settings.context_adaptation_enabled).toBe(true)
     expect(settings.system_scene_overrides).toEqual([
       { id: 'system_email', prompt_template: 'Use a warm email body.' },
@@ -51,6 +86,7 @@ describe('createBackupSettings', () => {
       'private.example.com',
       'com.private.writer',
       'private.exe',
+      'private-mapping-id',
     ]) {
       expect(serialized).not.toContain(forbidden)
     }
@@ -2,6 +2,7 @@ import { describe, expect, it, vi, beforeEach } from 'vitest'
 import { invoke } from '@tauri-apps/api/core'
 import {
   askAnything,
+  resetAskSession,
   showAskWindow,
   startAskDictation,
   startAskFlow,
@@ -20,11 +21,14 @@ describe('Ask Anything Tauri wrappers', () => {
   })
 
   it('invokes the ask_anything command with the trimmed question', async () => {
-    vi.mocked(invoke).mockResolvedValueOnce('A short answer.')
+    vi.mocked(invoke).mockResolvedValueOnce({
+      answer: 'A short answer.',
+      knowledgeSources: ['ops/runbook.md'],
+    })
 
     const answer = await askAnything('  What is OpenTypeless?  ')
 
-    expect(answer).toBe('A short answer.')
+    expect(answer).toEqual({ answer: 'A short answer.', knowledgeSources: ['ops/runbook.md'] })
     expect(invoke).toHaveBeenCalledWith('ask_anything', { question: 'What is OpenTypeless?' })
   })
 
@@ -87,4 +91,12 @@ describe('Ask Anything Tauri wrappers', () => {
     })
     expect(invoke).toHaveBeenCalledWith('start_ask_dictation')
   })
+
+  it('resets the multi-turn Ask session', async () => {
+    vi.mocked(invoke).mockResolvedValueOnce(undefined)
+
+    await resetAskSession()
+
+    expect(invoke).toHaveBeenCalledWith('reset_ask_session')
+  })
 })
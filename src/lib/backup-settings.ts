@@ -4,6 +4,8 @@ import type {
   CustomScene,
   FamilySceneAssignment,
   HotkeyConfig,
+  LlmFallbackEntry,
+  SceneRule,
   ShortcutBinding,
   SystemSceneOverride,
   TranslationConfig,
@@ -20,8 +22,10 @@ type SafeScalarKey =
   | 'llm_provider'
   | 'llm_model'
   | 'llm_base_url'
+  | 'llm_timeout_ms'
   | 'polish_enabled'
   | 'context_adaptation_enabled'
+  | 'spoken_formatting_enabled'
   | 'polish_style'
   | 'polish_custom_prompt'
   | 'polish_chinese_script'
@@ -37,6 +41,10 @@ type SafeScalarKey =
   | 'windows_sendinput_newline_mode'
   | 'streaming_insert_enabled'
   | 'selected_text_enabled'
+  | 'ask_answer_token_limit'
+  | 'knowledge_base_enabled'
+  | 'knowledge_embedding_base_url'
+  | 'knowledge_embedding_model'
   | 'theme'
   | 'auto_start'
   | 'close_to_tray'
@@ -44,6 +52,7 @@ type SafeScalarKey =
   | 'recording_limit_mode'
   | 'custom_recording_limit_seconds'
   | 'max_recording_seconds'
+  | 'auto_stop_silence_ms'
   | 'history_enabled'
   | 'history_retention_days'
   | 'history_max_entries'
@@ -56,8 +65,11 @@ export type BackupSettings = Partial<Pick<AppConfig, SafeScalarKey>> & {
   system_scene_overrides?: SystemSceneOverride[]
   active_scene?: ActiveScene | null
   family_scene_assignments?: FamilySceneAssignment[]
+  scene_rules?: SceneRule[]
   translation?: TranslationConfig
   hotkeys?: HotkeyConfig
+  stt_fallback_providers?: string[]
+  llm_fallback_chain?: LlmFallbackEntry[]
 }
 
 function safeBinding(binding: ShortcutBinding | null | undefined): ShortcutBinding | null {
@@ -79,8 +91,10 @@ export function createBackupSettings(config: AppConfig): BackupSettings {
     llm_provider: config.llm_provider,
     llm_model: config.llm_model,
     llm_base_url: config.llm_base_url,
+    llm_timeout_ms: config.llm_timeout_ms,
     polish_enabled: config.polish_enabled,
     context_adaptation_enabled: config.context_adaptation_enabled,
+    spoken_formatting_enabled: config.spoken_formatting_enabled,
     polish_style: config.polish_style,
     polish_custom_prompt: config.polish_custom_prompt,
     polish_chinese_script: config.polish_chinese_script,
@@ -114,6 +128,29 @@ export function createBackupSettings(config: AppConfig): BackupSettings {
           scene_id: assignment.scene_id,
         }))
       : [],
+    // Rules on device-only app mappings would match everything elsewhere.
+    scene_rules: Array.isArray(config.scene_rules)
+      ? config.scene_rules
+          .filter((rule) => rule.conditions.mapping_ids.length === 0)
+          .map((rule) => ({
+            id: rule.id,
+            name: rule.name,
+            enabled: rule.enabled,
+            priority: rule.priority,
+            scene_id: rule.scene_id,
+            conditions: {
+              families: [...rule.conditions.families],
+              profile_ids: [...rule.conditions.profile_ids],
+              mapping_ids: [],
+              languages: [...rule.conditions.languages],
+              weekdays: [...rule.conditions.weekdays],
+              time_window: rule.conditions.time_window
+                ? { start: rule.conditions.time_window.start, end: rule.conditions.time_window.end }
+                : null,
+              selected_text: rule.conditions.selected_text,
+            },
+          }))
+      : [],
     translate_enabled: config.translate_enabled,
     target_lang: config.target_lang,
     translation: config.translation
@@ -165,6 +202,10 @@ export function createBackupSettings(config: AppConfig): BackupSettings {
     windows_sendinput_newline_mode: config.windows_sendinput_newline_mode,
     streaming_insert_enabled: config.streaming_insert_enabled,
     selected_text_enabled: config.selected_text_enabled,
+    ask_answer_token_limit: config.ask_answer_token_limit,
+    knowledge_base_enabled: config.knowledge_base_enabled,
+    knowledge_embedding_base_url: config.knowledge_embedding_base_url,
+    knowledge_embedding_model: config.knowledge_embedding_model,
     theme: config.theme,
     auto_start: config.auto_start,
     close_to_tray: config.close_to_tray,
@@ -172,11 +213,23 @@ export function createBackupSettings(config: AppConfig): BackupSettings {
     recording_limit_mode: config.recording_limit_mode,
     custom_recording_limit_seconds: config.custom_recording_limit_seconds,
     max_recording_seconds: config.max_recording_seconds,
+    auto_stop_silence_ms: config.auto_stop_silence_ms,
     history_enabled: config.history_enabled,
     history_retention_days: config.history_retention_days,
     history_max_entries: config.history_max_entries,
     ui_language: config.ui_language,
     capsule_auto_hide: config.capsule_auto_hide,
+    stt_fallback_providers: Array.isArray(config.stt_fallback_providers)
+      ? [...config.stt_fallback_providers]
+      : [],
+    llm_fallback_chain: Array.isArray(config.llm_fallback_chain)
+      ? config.llm_fallback_chain.map((entry) => ({
+          provider: entry.provider,
+          model: entry.model,
+          base_url: entry.base_url,
+          timeout_ms: entry.timeout_ms,
+        }))
+      : [],
   }
 
   if (config.voice_routing_flags) {
@@ -185,6 +238,7 @@ export function createBackupSettings(config: AppConfig): BackupSettings {
       rewrite_selection: config.voice_routing_flags.rewrite_selection,
       translate_selection: config.voice_routing_flags.translate_selection,
       search: config.voice_routing_flags.search,
+      edit_commands: config.voice_routing_flags.edit_commands,
     }
   }
 
@@ -201,8 +255,10 @@ const SAFE_SCALAR_KEYS: readonly SafeScalarKey[] = [
   'llm_provider',
   'llm_model',
   'llm_base_url',
+  'llm_timeout_ms',
   'polish_enabled',
   'context_adaptation_enabled',
+  'spoken_formatting_enabled',
   'polish_style',
   'polish_custom_prompt',
   'polish_chinese_script',
@@ -218,6 +274,10 @@ const SAFE_SCALAR_KEYS: readonly SafeScalarKey[] = [
   'windows_sendinput_newline_mode',
   'streaming_insert_enabled',
   'selected_text_enabled',
+  'ask_answer_token_limit',
+  'knowledge_base_enabled',
+  'knowledge_embedding_base_url',
+  'knowledge_embedding_model',
   'theme',
   'auto_start',
   'close_to_tray',
@@ -225,6 +285,7 @@ const SAFE_SCALAR_KEYS: readonly SafeScalarKey[] = [
   'recording_limit_mode',
   'custom_recording_limit_seconds',
   'max_recording_seconds',
+  'auto_stop_silence_ms',
   'history_enabled',
   'history_retention_days',
   'history_max_entries',
@@ -261,12 +322,33 @@ export function mergeBackupSettings(current: AppConfig, backup: unknown): AppCon
   if (Array.isArray(backup.family_scene_assignments)) {
     next.family_scene_assignments = backup.family_scene_assignments as FamilySceneAssignment[]
   }
+  if (Array.isArray(backup.scene_rules)) {
+    const localRules = (current.scene_rules ?? []).filter(
+      (rule) => rule.conditions.mapping_ids.length > 0,
+    )
+    next.scene_rules = [...(backup.scene_rules as SceneRule[]), ...localRules]
+  }
   if (isRecord(backup.translation)) {
     next.translation = backup.translation as unknown as TranslationConfig
   }
   if (isRecord(backup.hotkeys)) {
     next.hotkeys = backup.hotkeys as unknown as HotkeyConfig
   }
+  if (Array.isArray(backup.stt_fallback_providers)) {
+    next.stt_fallback_providers = backup.stt_fallback_providers.filter(
+      (provider): provider is string => typeof provider === 'string',
+    )
+  }
+  if (Array.isArray(backup.llm_fallback_chain)) {
+    next.llm_fallback_chain = backup.llm_fallback_chain.filter(
+      (entry): entry is LlmFallbackEntry =>
+        isRecord(entry) &&
+        typeof entry.provider === 'string' &&
+        typeof entry.model === 'string' &&
+        typeof entry.base_url === 'string' &&
+        typeof entry.timeout_ms === 'number',
+    )
+  }
 
   return next
 }
@@ -87,6 +87,7 @@ export function isActiveCloudPlan(plan: string): plan is (typeof ACTIVE_CLOUD_PL
 
 export const CUSTOM_WHISPER_PROVIDER = 'custom-whisper' as const
 export const APPLE_SPEECH_PROVIDER = 'apple-speech' as const
+export const LOCAL_WHISPER_PROVIDER = 'local-whisper' as const
 
 export const CUSTOM_STT_DEFAULTS = {
   preset: 'speaches',
@@ -116,10 +117,16 @@ export const STT_PROVIDERS: { value: string; labelKey: string }[] = [
   { value: 'groq-whisper', labelKey: 'providers.stt.groqWhisper' },
   { value: 'siliconflow', labelKey: 'providers.stt.siliconflow' },
   { value: APPLE_SPEECH_PROVIDER, labelKey: 'providers.stt.appleSpeech' },
+  { value: LOCAL_WHISPER_PROVIDER, labelKey: 'providers.stt.localWhisper' },
   { value: CUSTOM_WHISPER_PROVIDER, labelKey: 'providers.stt.customWhisper' },
   { value: 'cloud', labelKey: 'providers.stt.cloud' },
 ] as const
 
+// Streaming providers cannot transcribe replayed audio after the fact.
+export const STT_FALLBACK_PROVIDERS = STT_PROVIDERS.filter(
+  (provider) => !['deepgram', 'assemblyai', 'volcengine-doubao'].includes(provider.value),
+)
+
 export const VOLCENGINE_STT_RESOURCES = [
   {
     value: 'volc.seedasr.sauc.duration',
@@ -143,6 +150,7 @@ export const LLM_PROVIDERS: { value: string; labelKey: string }[] = [
   { value: 'deepseek', labelKey: 'providers.llm.deepseek' },
   { value: 'siliconflow', labelKey: 'providers.llm.siliconflow' },
   { value: 'openai', labelKey: 'providers.llm.openai' },
+  { value: 'anthropic', labelKey: 'providers.llm.anthropic' },
   { value: 'gemini', labelKey: 'providers.llm.gemini' },
   { value: 'moonshot', labelKey: 'providers.llm.moonshot' },
   { value: 'doubao', labelKey: 'providers.llm.doubao' },
@@ -163,8 +171,9 @@ export const LLM_DEFAULT_CONFIG: Record<string, { baseUrl: string; model: string
   deepseek: { baseUrl: 'https://api.deepseek.com/v1', model: 'deepseek-chat' },
   siliconflow: { baseUrl: 'https://api.siliconflow.cn/v1', model: 'Qwen/Qwen2.5-7B-Instruct' },
   openai: { baseUrl: 'https://api.openai.com/v1', model: 'gpt-4o-mini' },
+  anthropic: { baseUrl: 'https://api.anthropic.com/v1', model: 'claude-3-5-haiku-latest' },
   gemini: {
-    baseUrl: 'https://generativelanguage.googleapis.com/v1beta/openai',
+    baseUrl: 'https://generativelanguage.googleapis.com/v1beta',
     model: 'gemini-2.0-flash',
   },
   moonshot: { baseUrl: 'https://api.moonshot.cn/v1', model: 'moonshot-v1-8k' },
@@ -180,6 +189,8 @@ export const LLM_DEFAULT_CONFIG: Record<string, { baseUrl: string; model: string
   cloud: { baseUrl: `${API_BASE_URL}/api/proxy`, model: 'default' },
 }
 
+export const LLM_TIMEOUT_OPTIONS_MS = [10_000, 15_000, 30_000, 60_000] as const
+
 export function llmProviderRequiresApiKey(provider: string): boolean {
   return provider.trim().toLowerCase() !== 'ollama'
 }
@@ -7,9 +7,12 @@ import type {
   PlatformCapabilities,
   TranslationConfig,
   ContextFamily,
+  CustomScene,
   FamilySceneAssignment,
+  SystemSceneOverride,
   BrowserAccessStatus,
   BrowserTarget,
+  InsertResult,
 } from '../stores/appStore'
 
 // Pipeline commands
@@ -29,7 +32,15 @@ export async function setActiveTranslationTarget(code: string): Promise<Translat
   return invoke('set_active_translation_target', { code })
 }
 
-export type AppMatcherType = 'native_bundle_id' | 'native_executable' | 'exact_web_host'
+export type AppMatcherType =
+  | 'native_bundle_id'
+  | 'native_executable'
+  | 'exact_web_host'
+  | 'web_host_suffix'
+  | 'executable_glob'
+  | 'window_title_pattern'
+
+export type AppMatcherPatternType = 'web_host_suffix' | 'executable_glob' | 'window_title_pattern'
 
 export interface MappingCandidateView {
   generation: number
@@ -40,6 +51,14 @@ export interface MappingCandidateView {
   iconKey: string
 }
 
+/** Per-app output overrides; unset fields keep the global setting. */
+export interface AppOutputPolicy {
+  insertionStrategy?: string
+  pasteShortcut?: string
+  newlineMode?: string
+  streamingInsertEnabled?: boolean
+}
+
 export interface CustomAppMappingView {
   id: string
   label: string
@@ -49,10 +68,17 @@ export interface CustomAppMappingView {
   sceneId: string | null
   enabled: boolean
   iconKey: string
+  outputPolicy: AppOutputPolicy
+}
+
+export interface MappingPatternInput {
+  matcherType: AppMatcherPatternType
+  value: string
 }
 
 export interface SaveCustomAppMappingInput {
   candidateGeneration: number
+  pattern?: MappingPatternInput
   label: string
   family: ContextFamily
   sceneId: string | null
@@ -90,6 +116,13 @@ export async function setCustomAppMappingEnabled(id: string, enabled: boolean):
   return invoke('set_custom_app_mapping_enabled', { id, enabled })
 }
 
+export async function setCustomAppMappingOutputPolicy(
+  id: string,
+  outputPolicy: AppOutputPolicy,
+): Promise<CustomAppMappingView> {
+  return invoke('set_custom_app_mapping_output_policy', { id, outputPolicy })
+}
+
 export async function deleteCustomAppMapping(id: string): Promise<void> {
   return invoke('delete_custom_app_mapping', { id })
 }
@@ -105,6 +138,55 @@ export async function setFamilySceneAssignment(
   return invoke('set_family_scene_assignment', { input: { family, sceneId } })
 }
 
+export type SceneConflictResolution = 'skip' | 'replace' | 'keep_both'
+
+export interface ScenePackPreviewScene {
+  id: string
+  name: string
+  description: string
+  conflict: 'none' | 'id' | 'name'
+  existingName: string | null
+}
+
+export interface ScenePackPreview {
+  version: number
+  scenes: ScenePackPreviewScene[]
+  systemOverrides: Array<{ id: string; conflict: boolean }>
+  skippedInvalid: number
+  errors: DictionaryImportRowError[]
+}
+
+export interface ScenePackImportReport {
+  imported: number
+  replaced: number
+  renamed: number
+  skippedConflicts: number
+  skippedLimit: number
+  skippedInvalid: number
+  errors: DictionaryImportRowError[]
+}
+
+export interface ScenePackImportResult {
+  report: ScenePackImportReport
+  customScenes: CustomScene[]
+  systemSceneOverrides: SystemSceneOverride[]
+}
+
+export async function previewScenePackImport(bytes: number[]): Promise<ScenePackPreview> {
+  return invoke('preview_scene_pack_import', { bytes })
+}
+
+export async function importScenePack(
+  bytes: number[],
+  resolution: SceneConflictResolution,
+): Promise<ScenePackImportResult> {
+  return invoke('import_scene_pack', { bytes, resolution })
+}
+
+export async function exportScenePack(sceneIds: string[]): Promise<string> {
+  return invoke('export_scene_pack', { sceneIds })
+}
+
 export async function requestBrowserAccess(target: BrowserTarget): Promise<BrowserAccessStatus> {
   return invoke('request_browser_access', { target })
 }
@@ -136,6 +218,7 @@ export interface SttRecordingCapability {
   maxUploadBytes: number | null
   source: RecordingLimitSource
   explanationKey: string
+  supportsVocabularyHints: boolean
 }
 
 export interface ResolvedSttRecordingLimit {
@@ -171,29 +254,31 @@ export interface CredentialStatus {
   storage: 'unavailable' | 'os-vault' | 'session-only' | 'legacy-warning'
 }
 
+export type CredentialNamespace = 'stt' | 'llm' | 'knowledge'
+
 export async function getCredentialStatus(
-  namespace: 'stt' | 'llm',
+  namespace: CredentialNamespace,
   provider: string,
 ): Promise<CredentialStatus> {
   return invoke('get_credential_status', { namespace, provider })
 }
 
 export async function readCredential(
-  namespace: 'stt' | 'llm',
+  namespace: CredentialNamespace,
   provider: string,
 ): Promise<string | null> {
   return invoke('read_credential', { namespace, provider })
 }
 
 export async function setCredential(
-  namespace: 'stt' | 'llm',
+  namespace: CredentialNamespace,
   provider: string,
   value: string,
 ): Promise<void> {
   return invoke('set_credential', { namespace, provider, value })
 }
 
-export async function clearCredential(namespace: 'stt' | 'llm', provider: string): Promise<void> {
+export async function clearCredential(namespace: CredentialNamespace, provider: string): Promise<void> {
   return invoke('clear_credential', { namespace, provider })
 }
 
@@ -273,7 +358,15 @@ export async function getHotkeyStatus(): Promise<HotkeyStatus> {
 export type DiagnosticStatus = 'ok' | 'warning' | 'error' | 'notApplicable' | 'checking'
 
 export interface DiagnosticRow {
-  id: 'microphone' | 'accessibility' | 'hotkey' | 'clipboard' | 'insertion' | 'platform' | string
+  id:
+    | 'microphone'
+    | 'accessibility'
+    | 'hotkey'
+    | 'clipboard'
+    | 'insertion'
+    | 'platform'
+    | 'llm'
+    | string
   status: DiagnosticStatus
   message: string
   action: string | null
@@ -310,12 +403,14 @@ export async function getSttProviderDiagnostics(
   provider: string,
   customBaseUrl?: string,
   customModel?: string,
+  localModelPath?: string,
 ): Promise<SttProviderDiagnostics> {
   return invoke('get_stt_provider_diagnostics', {
     apiKey,
     provider,
     customBaseUrl,
     customModel,
+    localModelPath,
   })
 }
 
@@ -326,6 +421,7 @@ export async function testSttConnection(
   customBaseUrl?: string,
   customModel?: string,
   volcengineResourceId?: string,
+  localModelPath?: string,
 ): Promise<boolean> {
   return invoke('test_stt_connection', {
     apiKey,
@@ -333,6 +429,7 @@ export async function testSttConnection(
     customBaseUrl,
     customModel,
     volcengineResourceId,
+    localModelPath,
   })
 }
 
@@ -352,6 +449,7 @@ export async function benchSttConnection(
   customBaseUrl?: string,
   customModel?: string,
   volcengineResourceId?: string,
+  localModelPath?: string,
 ): Promise<number> {
   return invoke('bench_stt_connection', {
     apiKey,
@@ -359,6 +457,7 @@ export async function benchSttConnection(
     customBaseUrl,
     customModel,
     volcengineResourceId,
+    localModelPath,
   })
 }
 
@@ -398,7 +497,13 @@ export async function resumeHotkey(): Promise<void> {
 }
 
 // Ask Anything
-export async function askAnything(question: string): Promise<string> {
+export interface AskAnswer {
+  answer: string
+  /** Knowledge-base files whose passages were given to the model. */
+  knowledgeSources: string[]
+}
+
+export async function askAnything(question: string): Promise<AskAnswer> {
   return invoke('ask_anything', { question: question.trim() })
 }
 
@@ -419,12 +524,19 @@ export type VoiceIntentKind =
   | 'ask_selection'
   | 'open_question'
   | 'search'
+  | 'undo_insertion'
+  | 'delete_last_sentence'
+  | 'delete_last_word'
+  | 'new_line'
+  | 'new_paragraph'
+  | 'select_insertion'
 
 export type VoiceOutputPlacement =
   | 'insert_at_cursor'
   | 'replace_selection'
   | 'popup_answer'
   | 'open_url'
+  | 'edit_in_place'
 
 export type VoiceExecutionFallbackReason =
   | 'feature_disabled'
@@ -433,6 +545,7 @@ export type VoiceExecutionFallbackReason =
   | 'selection_lost'
   | 'focus_restore_failed'
   | 'output_failed'
+  | 'nothing_to_edit'
 
 export type AskResultOutput = 'popupAnswer' | 'openedSearch' | 'insertedText' | 'copiedFallback'
 
@@ -447,6 +560,16 @@ export interface AskDictationResult {
   requestedPlacement: VoiceOutputPlacement
   actualPlacement: VoiceOutputPlacement | null
   fallbackReason: VoiceExecutionFallbackReason | null
+  sessionTurns: number
+  knowledgeSources: string[]
+}
+
+export interface AskTurn {
+  id: number
+  session_id: string
+  created_at: string
+  question: string
+  answer: string
 }
 
 export interface AskDictationStartResult {
@@ -479,11 +602,106 @@ export async function takePendingAskMessage(): Promise<PendingAskMessage | null>
   return invoke('take_pending_ask_message')
 }
 
+export async function getAskSession(): Promise<AskTurn[]> {
+  return invoke('get_ask_session')
+}
+
+export async function resetAskSession(): Promise<void> {
+  return invoke('reset_ask_session')
+}
+
+// Knowledge base
+export interface KnowledgeBaseStatus {
+  folder: string
+  documentCount: number
+  chunkCount: number
+  embeddedChunkCount: number
+  indexedAt: string | null
+}
+
+export async function getKnowledgeBaseStatus(): Promise<KnowledgeBaseStatus> {
+  return invoke('get_knowledge_base_status')
+}
+
+export async function reindexKnowledgeBase(): Promise<KnowledgeBaseStatus> {
+  return invoke('reindex_knowledge_base')
+}
+
+export async function clearKnowledgeBase(): Promise<void> {
+  return invoke('clear_knowledge_base')
+}
+
 // History
 export async function getHistory(limit: number, offset: number): Promise<HistoryEntry[]> {
   return invoke('get_history', { limit, offset })
 }
 
+export interface HistorySearchQuery {
+  text?: string
+  context_family?: ContextFamily | null
+  provider_kind?: HistoryEntry['provider_kind'] | null
+  active_scene_id?: string | null
+  output_status?: string | null
+  created_from?: string | null
+  created_to?: string | null
+  pinned?: boolean | null
+  cursor?: number | null
+  limit?: number
+}
+
+export interface HistorySearchPage {
+  entries: HistoryEntry[]
+  next_cursor: number | null
+}
+
+export async function searchHistory(query: HistorySearchQuery): Promise<HistorySearchPage> {
+  return invoke('search_history', { query })
+}
+
+export async function deleteHistoryEntry(id: number): Promise<void> {
+  return invoke('delete_history_entry', { id })
+}
+
+export async function deleteHistoryMatching(query: HistorySearchQuery): Promise<number> {
+  return invoke('delete_history_matching', { query })
+}
+
+export async function setHistoryEntryPinned(id: number, pinned: boolean): Promise<void> {
+  return invoke('set_history_entry_pinned', { id, pinned })
+}
+
+export async function updateHistoryPolishedText(
+  id: number,
+  polishedText: string,
+): Promise<HistoryEntry> {
+  return invoke('update_history_polished_text', { id, polishedText })
+}
+
+export async function listHistoryRegenerations(id: number): Promise<HistoryEntry[]> {
+  return invoke('list_history_regenerations', { id })
+}
+
+export interface RepolishHistoryInput {
+  id: number
+  /** Omit to keep the entry's scene; an empty string polishes without one. */
+  sceneId?: string | null
+  polishStyle?: string | null
+  llmModel?: string | null
+  output?: 'none' | 'insert' | 'copy'
+}
+
+export interface RepolishHistoryResult {
+  polishedText: string
+  entry: HistoryEntry | null
+  insertResult: InsertResult | null
+}
+
+export async function repolishHistoryEntry(
+  input: RepolishHistoryInput,
+): Promise<RepolishHistoryResult> {
+  return invoke('repolish_history_entry', { input })
+}
+
 export async function clearHistory(): Promise<void> {
   return invoke('clear_history')
 }
@@ -22,6 +22,7 @@ export type SttProvider =
   | 'groq-whisper'
   | 'siliconflow'
   | 'apple-speech'
+  | 'local-whisper'
   | 'custom-whisper'
   | 'cloud'
 export type LlmProvider =
@@ -29,6 +30,7 @@ export type LlmProvider =
   | 'deepseek'
   | 'siliconflow'
   | 'openai'
+  | 'anthropic'
   | 'gemini'
   | 'moonshot'
   | 'doubao'
@@ -47,6 +49,7 @@ export type InsertionStrategy =
   | 'clipboardPaste'
   | 'clipboardCopyOnly'
   | 'windowsSendInput'
+  | 'waylandVirtualKeyboard'
 export type InsertStatus = 'inserted' | 'copiedFallback' | 'failed' | 'partiallyInserted'
 export type HotkeyMode = 'hold' | 'toggle'
 export type Theme = 'light' | 'dark' | 'system'
@@ -65,7 +68,7 @@ export type ContextFamily =
   | 'social'
   | 'general'
 export type BrowserAccessStatus = 'available' | 'needs_permission' | 'not_applicable' | 'unknown'
-export type BrowserTarget = 'safari' | 'chrome' | 'edge' | 'brave' | 'arc'
+export type BrowserTarget = 'safari' | 'chrome' | 'edge' | 'brave' | 'arc' | 'firefox'
 
 export interface ShortcutBinding {
   primary: string
@@ -91,6 +94,7 @@ export interface PlatformCapabilities {
   globalHotkeyReliable: boolean
   keyboardOutputReliable: boolean
   clipboardAutoPasteReliable: boolean
+  waylandVirtualKeyboard?: string | null
 }
 
 export interface HistoryEntry {
@@ -113,6 +117,20 @@ export interface HistoryEntry {
   active_scene_prompt_truncated: boolean
   output_status: string | null
   output_error: string | null
+  applied_corrections?: AppliedCorrection[]
+  pinned?: boolean
+  parent_id?: number | null
+  stt_provider?: string | null
+  llm_provider?: string | null
+  scene_rule_id?: string | null
+}
+
+export interface AppliedCorrection {
+  rule_id: number
+  stage: 'transcript' | 'polished'
+  pattern: string
+  replacement: string
+  count: number
 }
 
 export interface ContextProfileSummary {
@@ -173,11 +191,45 @@ export interface FamilySceneAssignment {
   scene_id: string
 }
 
+export interface SceneTimeWindow {
+  start: string
+  end: string
+}
+
+/** Empty lists and null values match anything. */
+export interface SceneRuleConditions {
+  families: ContextFamily[]
+  profile_ids: string[]
+  mapping_ids: string[]
+  languages: string[]
+  /** ISO weekday numbers, 1 for Monday. */
+  weekdays: number[]
+  time_window: SceneTimeWindow | null
+  selected_text: boolean | null
+}
+
+export interface SceneRule {
+  id: string
+  name: string
+  enabled: boolean
+  priority: number
+  scene_id: string
+  conditions: SceneRuleConditions
+}
+
 export interface VoiceRoutingFlags {
   draft_insert: boolean
   rewrite_selection: boolean
   translate_selection: boolean
   search: boolean
+  edit_commands: boolean
+}
+
+export interface LlmFallbackEntry {
+  provider: string
+  model: string
+  base_url: string
+  timeout_ms: number
 }
 
 export interface TranslationConfig {
@@ -193,13 +245,18 @@ export interface AppConfig {
   stt_custom_base_url: string
   stt_custom_model: string
   stt_volcengine_resource_id: string
+  stt_local_model_path: string
+  stt_fallback_providers: string[]
   stt_language: string
   llm_provider: LlmProvider
   llm_api_key: string
   llm_model: string
   llm_base_url: string
+  llm_timeout_ms: number
+  llm_fallback_chain: LlmFallbackEntry[]
   polish_enabled: boolean
   context_adaptation_enabled: boolean
+  spoken_formatting_enabled: boolean
   voice_routing_flags: VoiceRoutingFlags
   polish_style: PolishStyle
   polish_custom_prompt: string
@@ -208,6 +265,7 @@ export interface AppConfig {
   system_scene_overrides: SystemSceneOverride[]
   active_scene: ActiveScene | null
   family_scene_assignments: FamilySceneAssignment[]
+  scene_rules: SceneRule[]
   translate_enabled: boolean
   target_lang: string
   translation: TranslationConfig
@@ -222,6 +280,11 @@ export interface AppConfig {
   windows_sendinput_newline_mode: WindowsSendInputNewlineMode
   streaming_insert_enabled: boolean
   selected_text_enabled: boolean
+  ask_answer_token_limit: number
+  knowledge_base_enabled: boolean
+  knowledge_base_folder: string
+  knowledge_embedding_base_url: string
+  knowledge_embedding_model: string
   theme: Theme
   auto_start: boolean
   close_to_tray: boolean
@@ -229,6 +292,7 @@ export interface AppConfig {
   recording_limit_mode: 'auto' | 'custom'
   custom_recording_limit_seconds: number
   max_recording_seconds: number
+  auto_stop_silence_ms: number
   managed_stt_capability_state?: unknown
   history_enabled: boolean
   history_retention_days: number
@@ -724,18 +788,24 @@ const defaultConfig: AppConfig = {
   stt_custom_base_url: 'http://localhost:8000/v1',
   stt_custom_model: 'Systran/faster-whisper-large-v3',
   stt_volcengine_resource_id: 'volc.seedasr.sauc.duration',
+  stt_local_model_path: '',
+  stt_fallback_providers: [],
   stt_language: 'multi',
   llm_provider: 'openrouter',
   llm_api_key: '',
   llm_model: 'google/gemini-2.5-flash',
   llm_base_url: 'https://openrouter.ai/api/v1',
+  llm_timeout_ms: 15000,
+  llm_fallback_chain: [],
   polish_enabled: true,
   context_adaptation_enabled: true,
+  spoken_formatting_enabled: false,
   voice_routing_flags: {
     draft_insert: true,
     rewrite_selection: true,
     translate_selection: true,
     search: true,
+    edit_commands: true,
   },
   polish_style: 'clean',
   polish_custom_prompt: '',
@@ -744,6 +814,7 @@ const defaultConfig: AppConfig = {
   system_scene_overrides: [],
   active_scene: null,
   family_scene_assignments: [],
+  scene_rules: [],
   translate_enabled: false,
   target_lang: 'en',
   translation: { targets: ['en'], active_target: 'en' },
@@ -771,6 +842,11 @@ const defaultConfig: AppConfig = {
   windows_sendinput_newline_mode: 'enter',
   streaming_insert_enabled: false,
   selected_text_enabled: false,
+  ask_answer_token_limit: 80,
+  knowledge_base_enabled: false,
+  knowledge_base_folder: '',
+  knowledge_embedding_base_url: '',
+  knowledge_embedding_model: '',
   theme: 'system',
   auto_start: true,
   close_to_tray: true,
@@ -778,6 +854,7 @@ const defaultConfig: AppConfig = {
   recording_limit_mode: 'auto',
   custom_recording_limit_seconds: 600,
   max_recording_seconds: 30,
+  auto_stop_silence_ms: 0,
   history_enabled: true,
   history_retention_days: 0,
   history_max_entries: 5000,